@@ -0,0 +1,75 @@
+use std::{
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+#[cfg(test)]
+use std::sync::{
+    atomic::{AtomicI64, Ordering},
+    Arc,
+};
+
+/// The source of the current time, used for everything shown relative to now, such as uptimes, so that it can be fixed in tests
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Current time as a unix timestamp, in seconds
+    fn now(&self) -> i64;
+}
+
+/// The system's wall clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |i| i64::try_from(i.as_secs()).unwrap_or_default())
+    }
+}
+
+/// A clock that only moves when told to, clones share the same time, so a test can move the clock of an AppData that's behind a Mutex
+#[cfg(test)]
+#[derive(Debug, Clone, Default)]
+pub struct MockClock(Arc<AtomicI64>);
+
+#[cfg(test)]
+impl MockClock {
+    pub fn new(now: i64) -> Self {
+        Self(Arc::new(AtomicI64::new(now)))
+    }
+
+    /// Move the clock forward, or backwards with a negative number, by a number of seconds
+    pub fn advance(&self, seconds: i64) {
+        self.0.fetch_add(seconds, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+impl Clock for MockClock {
+    fn now(&self) -> i64 {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Clock, MockClock, SystemClock};
+
+    #[test]
+    /// The mock clock only moves when advanced, and every clone shares the same time
+    fn test_clock_mock() {
+        let clock = MockClock::new(1_000);
+        let clone = clock.clone();
+        assert_eq!(clock.now(), 1_000);
+        clone.advance(60);
+        assert_eq!(clock.now(), 1_060);
+        clock.advance(-1_060);
+        assert_eq!(clone.now(), 0);
+    }
+
+    #[test]
+    /// The system clock is after the time this test was written
+    fn test_clock_system() {
+        assert!(SystemClock.now() > 1_700_000_000);
+    }
+}
@@ -1,20 +1,40 @@
 use std::{
     cmp::Ordering,
-    collections::{HashSet, VecDeque},
+    collections::{BTreeMap, HashSet, VecDeque},
     fmt,
+    str::FromStr,
+    time::{Duration, Instant},
 };
 
-use bollard::service::Port;
+use bollard::{
+    container::LogOutput,
+    service::{
+        ContainerConfig, ContainerInspectResponse, HealthStatusEnum, MountPoint,
+        MountPointTypeEnum, Port,
+    },
+};
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, Local, Utc,
+};
+use clap::ValueEnum;
 use ratatui::{
     style::Color,
+    text::{Line, Span, Text},
     widgets::{ListItem, ListState},
 };
+use serde::{Deserialize, Serialize};
+use tracing::warn;
 
 use super::Header;
+use crate::ui::{log_sanitizer, LogSearch};
 
 const ONE_KB: f64 = 1000.0;
 const ONE_MB: f64 = ONE_KB * 1000.0;
 const ONE_GB: f64 = ONE_MB * 1000.0;
+const ONE_KIB: f64 = 1024.0;
+const ONE_MIB: f64 = ONE_KIB * 1024.0;
+const ONE_GIB: f64 = ONE_MIB * 1024.0;
 
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub struct ContainerId(String);
@@ -26,7 +46,7 @@ impl From<&str> for ContainerId {
 }
 
 impl ContainerId {
-    pub fn get(&self) -> &str {
+    pub const fn get(&self) -> &str {
         self.0.as_str()
     }
 
@@ -68,7 +88,7 @@ macro_rules! unit_struct {
         }
 
         impl$name {
-            pub fn get(&self) -> &str {
+            pub const fn get(&self) -> &str {
                 self.0.as_str()
             }
 
@@ -77,34 +97,60 @@ macro_rules! unit_struct {
             }
         }
 
-        impl std::fmt::Display for $name {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-                if self.0.chars().count() >= 30 {
-                    write!(
-                        f,
-                        "{}…",
-                        self.0.chars().take(29).collect::<String>()
-                    )
-                } else {
-                    write!(
-                        f,
-                        "{}",
-                        self.0
-                    )
-                }
-            }
-        }
     };
 }
 
 unit_struct!(ContainerName);
 unit_struct!(ContainerImage);
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// The longest name, or image, shown in the containers panel, anything longer is truncated with an ellipsis
+const MAX_NAME_WIDTH: usize = 30;
+
+impl fmt::Display for ContainerName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.chars().count() >= MAX_NAME_WIDTH {
+            write!(
+                f,
+                "{}…",
+                self.0.chars().take(MAX_NAME_WIDTH - 1).collect::<String>()
+            )
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+/// A long image, e.g. `registry/org/name:tag@sha256:digest`, is truncated from the start, so that the tag stays visible rather than the registry.
+/// Any digest is removed first, replaced by `@…`, as it's unreadable once cut short anyway
+impl fmt::Display for ContainerImage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.chars().count() <= MAX_NAME_WIDTH {
+            return write!(f, "{}", self.0);
+        }
+        let (reference, suffix) = self
+            .0
+            .split_once('@')
+            .map_or((self.0.as_str(), ""), |(reference, _)| (reference, "@…"));
+        let width = MAX_NAME_WIDTH - suffix.chars().count();
+        let count = reference.chars().count();
+        if count <= width {
+            write!(f, "{reference}{suffix}")
+        } else {
+            let tail = reference
+                .chars()
+                .skip(count - (width - 1))
+                .collect::<String>();
+            write!(f, "…{tail}{suffix}")
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ContainerPorts {
     pub ip: Option<String>,
     pub private: u16,
     pub public: Option<u16>,
+    pub protocol: Option<String>,
 }
 
 impl From<&Port> for ContainerPorts {
@@ -113,34 +159,63 @@ impl From<&Port> for ContainerPorts {
             ip: value.ip.clone(),
             private: value.private_port,
             public: value.public_port,
+            protocol: value
+                .typ
+                .map(|typ| typ.to_string())
+                .filter(|typ| !typ.is_empty()),
         }
     }
 }
 
 impl ContainerPorts {
     pub fn len_ip(&self) -> usize {
-        self.ip.as_ref().unwrap_or(&String::new()).chars().count()
+        self.print().0.chars().count()
     }
     pub fn len_private(&self) -> usize {
-        format!("{}", self.private).chars().count()
+        self.print().1.chars().count()
     }
     pub fn len_public(&self) -> usize {
-        format!("{}", self.public.unwrap_or_default())
-            .chars()
-            .count()
+        self.print().2.chars().count()
     }
 
+    /// The ip, the private port with its protocol, e.g. `80/tcp`, and the public port, which is `--` if the port isn't published
     pub fn print(&self) -> (String, String, String) {
         (
             self.ip
                 .as_ref()
                 .map_or(String::new(), std::borrow::ToOwned::to_owned),
-            format!("{}", self.private),
-            self.public.map_or(String::new(), |s| s.to_string()),
+            self.protocol.as_ref().map_or_else(
+                || format!("{}", self.private),
+                |protocol| format!("{}/{protocol}", self.private),
+            ),
+            self.public
+                .map_or_else(|| String::from("--"), |s| s.to_string()),
+        )
+    }
+
+    /// Sort key, by private port, then protocol, then each binding
+    const fn sort_key(&self) -> (u16, Option<&String>, Option<&String>, Option<u16>) {
+        (
+            self.private,
+            self.protocol.as_ref(),
+            self.ip.as_ref(),
+            self.public,
         )
     }
 }
 
+impl Ord for ContainerPorts {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+impl PartialOrd for ContainerPorts {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StatefulList<T> {
     pub state: ListState,
@@ -163,7 +238,9 @@ impl<T> StatefulList<T> {
     }
 
     pub fn start(&mut self) {
-        self.state.select(Some(0));
+        if !self.items.is_empty() {
+            self.state.select(Some(0));
+        }
     }
 
     pub fn next(&mut self) {
@@ -207,8 +284,135 @@ impl<T> StatefulList<T> {
     }
 }
 
+/// A mount of a container, read from its inspect data, a volume uses its name as the source, rather than its path on the host
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerMount {
+    pub typ: String,
+    pub source: String,
+    pub destination: String,
+    pub read_only: bool,
+}
+
+impl From<&MountPoint> for ContainerMount {
+    fn from(value: &MountPoint) -> Self {
+        let source = match value.typ {
+            Some(MountPointTypeEnum::VOLUME) => value.name.as_ref().or(value.source.as_ref()),
+            _ => value.source.as_ref(),
+        };
+        Self {
+            typ: value.typ.map(|i| i.to_string()).unwrap_or_default(),
+            source: source.cloned().unwrap_or_default(),
+            destination: value.destination.clone().unwrap_or_default(),
+            read_only: value.rw == Some(false),
+        }
+    }
+}
+
+/// The entrypoint & command of a container, read from its inspect data, each shown in the same exec form as the inspect output, e.g. `["nginx", "-g", "daemon off;"]`.
+/// Either is commonly unset, e.g. an image with only a CMD, or only an ENTRYPOINT
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContainerCommand {
+    pub entrypoint: Option<String>,
+    pub cmd: Option<String>,
+}
+
+impl From<&ContainerConfig> for ContainerCommand {
+    fn from(value: &ContainerConfig) -> Self {
+        let format = |args: Option<&Vec<String>>| {
+            args.filter(|i| !i.is_empty()).map(|args| {
+                let args = args
+                    .iter()
+                    .map(|i| serde_json::to_string(i).unwrap_or_default())
+                    .collect::<Vec<_>>();
+                format!("[{}]", args.join(", "))
+            })
+        };
+        Self {
+            entrypoint: format(value.entrypoint.as_ref()),
+            cmd: format(value.cmd.as_ref()),
+        }
+    }
+}
+
+/// The ip addresses of a container, read from its inspect data, a container using host networking has no address of its own
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContainerAddress {
+    Host,
+    /// Network name & ip address pairs, the primary network, the one set as the network mode, first, then by name
+    Networks(Vec<(String, String)>),
+}
+
+impl From<&ContainerInspectResponse> for ContainerAddress {
+    fn from(value: &ContainerInspectResponse) -> Self {
+        // The default network mode is the bridge network
+        let network_mode = match value
+            .host_config
+            .as_ref()
+            .and_then(|i| i.network_mode.as_deref())
+        {
+            Some("host") => return Self::Host,
+            Some("default") | None => "bridge",
+            Some(mode) => mode,
+        };
+        let mut networks = value
+            .network_settings
+            .as_ref()
+            .and_then(|i| i.networks.as_ref())
+            .map(|networks| {
+                networks
+                    .iter()
+                    .filter_map(|(name, endpoint)| {
+                        endpoint
+                            .ip_address
+                            .as_ref()
+                            .filter(|i| !i.is_empty())
+                            .map(|ip| (name.clone(), ip.clone()))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        networks.sort_by(|a, b| {
+            (a.0 != network_mode)
+                .cmp(&(b.0 != network_mode))
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        Self::Networks(networks)
+    }
+}
+
+impl ContainerAddress {
+    /// The ip address on the primary network, `host` for host networking, None if not on any network
+    pub fn primary(&self) -> Option<String> {
+        match self {
+            Self::Host => Some(String::from("host")),
+            Self::Networks(networks) => networks.first().map(|(_, ip)| ip.clone()),
+        }
+    }
+}
+
+/// Every address, followed by its network if on more than one, e.g. `172.18.0.2 (backend), 172.19.0.3 (frontend)`
+impl fmt::Display for ContainerAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Host => write!(f, "host"),
+            Self::Networks(networks) if networks.is_empty() => write!(f, "none"),
+            Self::Networks(networks) if networks.len() == 1 => write!(f, "{}", networks[0].1),
+            Self::Networks(networks) => write!(
+                f,
+                "{}",
+                networks
+                    .iter()
+                    .map(|(name, ip)| format!("{ip} ({name})"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
 /// States of the container
-#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum State {
     Dead,
     Exited,
@@ -223,14 +427,15 @@ impl State {
     pub const fn is_alive(self) -> bool {
         matches!(self, Self::Running)
     }
-    pub const fn get_color(self) -> Color {
-        match self {
-            Self::Paused => Color::Yellow,
-            Self::Removing => Color::LightRed,
-            Self::Restarting => Color::LightGreen,
-            Self::Running => Color::Green,
-            _ => Color::Red,
-        }
+
+    /// Containers in one of these states are removed by a container prune
+    pub const fn is_stopped(self) -> bool {
+        matches!(self, Self::Dead | Self::Exited | Self::Unknown)
+    }
+
+    /// Docker will only remove a container in one of these states if the force option is set
+    pub const fn requires_force(self) -> bool {
+        matches!(self, Self::Paused | Self::Restarting | Self::Running)
     }
     /// Dirty way to create order for the state, rather than impl Ord
     pub const fn order(self) -> u8 {
@@ -281,216 +486,1352 @@ impl fmt::Display for State {
     }
 }
 
-/// Items for the container control list
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum DockerControls {
-    Pause,
-    Restart,
-    Start,
-    Stop,
-    Resume,
-    Delete,
+/// Show only the containers in a given state, cycled through in the order All, Running, Exited, Paused
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum StateFilter {
+    #[default]
+    All,
+    Running,
+    Exited,
+    Paused,
 }
 
-impl DockerControls {
-    pub const fn get_color(self) -> Color {
+impl StateFilter {
+    pub const fn next(self) -> Self {
         match self {
-            Self::Pause => Color::Yellow,
-            Self::Restart => Color::Magenta,
-            Self::Start => Color::Green,
-            Self::Stop => Color::Red,
-            Self::Delete => Color::Gray,
-            Self::Resume => Color::Blue,
+            Self::All => Self::Running,
+            Self::Running => Self::Exited,
+            Self::Exited => Self::Paused,
+            Self::Paused => Self::All,
         }
     }
 
-    /// Docker commands available depending on the containers state
-    pub fn gen_vec(state: State) -> Vec<Self> {
-        match state {
-            State::Dead | State::Exited => vec![Self::Start, Self::Restart, Self::Delete],
-            State::Paused => vec![Self::Resume, Self::Stop, Self::Delete],
-            State::Restarting => vec![Self::Stop, Self::Delete],
-            State::Running => vec![Self::Pause, Self::Restart, Self::Stop, Self::Delete],
-            _ => vec![Self::Delete],
+    /// A dead container is included with the exited containers, as neither can be unpaused or restarted in place
+    pub const fn matches(self, state: State) -> bool {
+        match self {
+            Self::All => true,
+            Self::Running => matches!(state, State::Running),
+            Self::Exited => matches!(state, State::Dead | State::Exited),
+            Self::Paused => matches!(state, State::Paused),
         }
     }
 }
 
-impl fmt::Display for DockerControls {
+/// The summary bar, either hidden, totalling every container, or totalling only the containers visible with the current filters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Summary {
+    #[default]
+    Hidden,
+    All,
+    Visible,
+}
+
+impl Summary {
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Hidden => Self::All,
+            Self::All => Self::Visible,
+            Self::Visible => Self::Hidden,
+        }
+    }
+
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::Hidden => "",
+            Self::All => "all containers",
+            Self::Visible => "visible containers",
+        }
+    }
+}
+
+/// The totals shown in the summary bar, only running containers have their cpu & memory usage included
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ContainerTotals {
+    pub cpu: CpuStats,
+    pub mem: ByteStats,
+    pub running: usize,
+    pub stopped: usize,
+    pub total: usize,
+}
+
+impl<'a> FromIterator<&'a ContainerItem> for ContainerTotals {
+    fn from_iter<T: IntoIterator<Item = &'a ContainerItem>>(iter: T) -> Self {
+        iter.into_iter().fold(Self::default(), |mut acc, i| {
+            acc.total += 1;
+            if i.state.is_alive() {
+                acc.running += 1;
+                acc.cpu.0 += i.cpu_stats.back().map_or(0.0, |i| i.0);
+                acc.mem.0 += i.mem_stats.back().map_or(0, |i| i.0);
+            } else if i.state.is_stopped() {
+                acc.stopped += 1;
+            }
+            acc
+        })
+    }
+}
+
+impl fmt::Display for StateFilter {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let disp = match self {
-            Self::Pause => "pause",
-            Self::Delete => "delete",
-            Self::Restart => "restart",
-            Self::Start => "start",
-            Self::Stop => "stop",
-            Self::Resume => "resume",
+            Self::All => "all",
+            Self::Running => "running",
+            Self::Exited => "exited",
+            Self::Paused => "paused",
         };
         write!(f, "{disp}")
     }
 }
 
-pub trait Stats {
-    fn get_value(&self) -> f64;
+/// The result of a containers healthcheck, containers without a healthcheck have no health
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Health {
+    Healthy,
+    Starting,
+    Unhealthy,
 }
 
-/// Struct for frequently updated CPU stats
-/// So can use custom display formatter
-/// Use trait Stats for use as generic in draw_chart function
-#[derive(Debug, Default, Clone, Copy)]
-pub struct CpuStats(f64);
+impl Health {
+    /// Both an empty status, and `none`, mean that the container doesn't have a healthcheck
+    pub const fn from_status(status: HealthStatusEnum) -> Option<Self> {
+        match status {
+            HealthStatusEnum::HEALTHY => Some(Self::Healthy),
+            HealthStatusEnum::STARTING => Some(Self::Starting),
+            HealthStatusEnum::UNHEALTHY => Some(Self::Unhealthy),
+            HealthStatusEnum::EMPTY | HealthStatusEnum::NONE => None,
+        }
+    }
+}
 
-impl CpuStats {
-    pub const fn new(value: f64) -> Self {
-        Self(value)
+impl fmt::Display for Health {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Healthy => "healthy",
+            Self::Starting => "starting",
+            Self::Unhealthy => "unhealthy",
+        };
+        write!(f, "{disp}")
     }
 }
 
-impl Eq for CpuStats {}
+/// Parse a RFC 3339 timestamp, as used by Docker, e.g. `2024-03-01T12:34:56.123456789Z`, into a unix timestamp.
+/// Docker uses `0001-01-01T00:00:00Z` for a container that has never started, or finished, which is treated as missing
+pub fn parse_timestamp(timestamp: &str) -> Option<i64> {
+    let (date, time) = timestamp.split_once('T')?;
+    let mut date = date.splitn(3, '-').map(str::parse::<i64>);
+    let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+    if year <= 1 || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // The offset is either `Z`, or `+HH:MM` / `-HH:MM`
+    let (time, offset) = if let Some(time) = time.strip_suffix('Z') {
+        (time, 0)
+    } else {
+        let (time, offset) = time.split_at(time.rfind(['+', '-'])?);
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+        let (hours, minutes) = offset.get(1..)?.split_once(':')?;
+        (
+            time,
+            sign * (hours.parse::<i64>().ok()? * 3600 + minutes.parse::<i64>().ok()? * 60),
+        )
+    };
+    let time = time.split_once('.').map_or(time, |(time, _)| time);
+    let mut time = time.splitn(3, ':').map(str::parse::<i64>);
+    let (hour, minute, second) = (time.next()?.ok()?, time.next()?.ok()?, time.next()?.ok()?);
+
+    // Civil date to days, from http://howardhinnant.github.io/date_algorithms.html
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+    Some(days * 86_400 + hour * 3600 + minute * 60 + second - offset)
+}
 
-impl PartialEq for CpuStats {
-    fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0
-    }
+/// Parse a RFC 3339 timestamp into a unix timestamp in nanoseconds, keeping the fractional seconds that `parse_timestamp()` drops
+pub fn parse_timestamp_nanos(timestamp: &str) -> Option<i128> {
+    let seconds = parse_timestamp(timestamp)?;
+    let digits = timestamp
+        .split_once('.')
+        .map_or("", |i| i.1)
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .take(9)
+        .collect::<String>();
+    let nanos = if digits.is_empty() {
+        0
+    } else {
+        format!("{digits:0<9}").parse::<i128>().ok()?
+    };
+    Some(i128::from(seconds) * 1_000_000_000 + nanos)
 }
 
-impl PartialOrd for CpuStats {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
+/// Format a unix timestamp, in nanoseconds, in the same RFC 3339 format as the timestamps of the Docker logs, e.g. `2023-01-14T19:13:30.783138328Z`
+pub fn format_timestamp(nanos: u128) -> String {
+    let seconds = i64::try_from(nanos / 1_000_000_000).unwrap_or_default();
+    let (days, time) = (seconds.div_euclid(86_400), seconds.rem_euclid(86_400));
+
+    // Days to civil date, from http://howardhinnant.github.io/date_algorithms.html
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:09}Z",
+        time / 3600,
+        time / 60 % 60,
+        time % 60,
+        nanos % 1_000_000_000
+    )
 }
 
-impl Ord for CpuStats {
-    fn cmp(&self, other: &Self) -> Ordering {
-        if self.0 > other.0 {
-            Ordering::Greater
-        } else if (self.0 - other.0).abs() < 0.01 {
-            Ordering::Equal
-        } else {
-            Ordering::Less
-        }
-    }
+/// The default strftime format of log timestamps, and created dates, an ISO 8601 like date & time, e.g. `2023-01-14 19:13:30`
+pub const DEFAULT_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Whether times are shown in the local timezone, or in UTC, set in the config file
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeZone {
+    #[default]
+    Local,
+    Utc,
 }
 
-impl Stats for CpuStats {
-    fn get_value(&self) -> f64 {
-        self.0
-    }
+/// How log timestamps, and created dates, are shown, a strftime format and a timezone, set in the config file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeFormat {
+    format: String,
+    zone: TimeZone,
 }
 
-impl fmt::Display for CpuStats {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let disp = format!("{:05.2}%", self.0);
-        write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
+impl Default for TimeFormat {
+    fn default() -> Self {
+        Self {
+            format: DEFAULT_TIME_FORMAT.to_owned(),
+            zone: TimeZone::default(),
+        }
     }
 }
 
-/// Struct for frequently updated memory usage stats
-/// So can use custom display formatter
-/// Use trait Stats for use as generic in draw_chart function
-#[derive(Debug, Default, Clone, Copy, Eq)]
-pub struct ByteStats(u64);
+impl TimeFormat {
+    /// An empty, or invalid, format falls back to the default format, with a warning for an invalid format
+    pub fn new(format: Option<&str>, zone: TimeZone) -> Self {
+        let format = match format {
+            Some(format) if StrftimeItems::new(format).any(|i| i == Item::Error) => {
+                warn!("invalid time format \"{format}\" in the config file, using \"{DEFAULT_TIME_FORMAT}\"");
+                DEFAULT_TIME_FORMAT
+            }
+            Some(format) if !format.is_empty() => format,
+            _ => DEFAULT_TIME_FORMAT,
+        };
+        Self {
+            format: format.to_owned(),
+            zone,
+        }
+    }
 
-impl PartialEq for ByteStats {
-    fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0
+    /// Format a unix timestamp, in nanoseconds, empty if out of range
+    pub fn format(&self, nanos: i128) -> String {
+        let seconds = i64::try_from(nanos.div_euclid(1_000_000_000)).unwrap_or_default();
+        let nanos = u32::try_from(nanos.rem_euclid(1_000_000_000)).unwrap_or_default();
+        DateTime::<Utc>::from_timestamp(seconds, nanos).map_or_else(String::new, |time| match self
+            .zone
+        {
+            TimeZone::Local => time.with_timezone(&Local).format(&self.format).to_string(),
+            TimeZone::Utc => time.format(&self.format).to_string(),
+        })
     }
-}
 
-impl PartialOrd for ByteStats {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    /// Format a unix timestamp, in seconds
+    pub fn format_seconds(&self, seconds: i64) -> String {
+        self.format(i128::from(seconds) * 1_000_000_000)
     }
 }
 
-impl Ord for ByteStats {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.0.cmp(&other.0)
+/// Format a number of seconds as a short duration, e.g. `45s`, `12m`, `3h 12m`, or `2d`
+pub fn format_duration(seconds: i64) -> String {
+    let (days, hours, minutes) = (seconds / 86_400, seconds / 3600 % 24, seconds / 60 % 60);
+    if days > 0 {
+        format!("{days}d")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else if minutes > 0 {
+        format!("{minutes}m")
+    } else {
+        format!("{seconds}s")
     }
 }
 
-impl ByteStats {
-    pub const fn new(value: u64) -> Self {
-        Self(value)
+/// Only fetch the logs written after this time, set with "--since", or changed at runtime.
+/// Either relative to when the logs are fetched, e.g. `10m` or `1h30m`, or an absolute RFC 3339 timestamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Since {
+    Relative(i64),
+    Absolute(i64),
+}
+
+impl Since {
+    /// The unix timestamp to fetch logs from, a relative duration is subtracted from now
+    pub const fn timestamp(self, now: i64) -> i64 {
+        match self {
+            Self::Relative(seconds) => now.saturating_sub(seconds),
+            Self::Absolute(timestamp) => timestamp,
+        }
     }
-    pub fn update(&mut self, value: u64) {
-        self.0 = value;
+
+    /// Parse a duration made of one or more numbers, each followed by a unit, `s`, `m`, `h`, or `d`, e.g. `90s`, `1h30m`
+    fn parse_duration(input: &str) -> Option<i64> {
+        let mut seconds = 0i64;
+        let mut number = String::new();
+        for c in input.chars() {
+            if c.is_ascii_digit() {
+                number.push(c);
+                continue;
+            }
+            let unit = match c {
+                's' => 1,
+                'm' => 60,
+                'h' => 3600,
+                'd' => 86_400,
+                _ => return None,
+            };
+            let value = number.parse::<i64>().ok()?;
+            seconds = seconds.checked_add(value.checked_mul(unit)?)?;
+            number.clear();
+        }
+        (number.is_empty() && seconds > 0).then_some(seconds)
     }
 }
 
-#[allow(clippy::cast_precision_loss)]
-impl Stats for ByteStats {
-    fn get_value(&self) -> f64 {
-        self.0 as f64
+impl FromStr for Since {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+        Self::parse_duration(input)
+            .map(Self::Relative)
+            .or_else(|| parse_timestamp(input).map(Self::Absolute))
+            .ok_or_else(|| {
+                format!(
+                    "invalid since \"{input}\", expected a duration, e.g. 10m or 1h30m, or an RFC 3339 timestamp, e.g. 2024-03-01T12:00:00Z"
+                )
+            })
     }
 }
 
-/// convert from bytes to kB, MB, GB etc
-impl fmt::Display for ByteStats {
+impl fmt::Display for Since {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let as_f64 = self.get_value();
-        let p = match as_f64 {
-            x if x >= ONE_GB => format!("{y:.2} GB", y = as_f64 / ONE_GB),
-            x if x >= ONE_MB => format!("{y:.2} MB", y = as_f64 / ONE_MB),
-            _ => format!("{y:.2} kB", y = as_f64 / ONE_KB),
-        };
-        write!(f, "{p:>x$}", x = f.width().unwrap_or(1))
+        match self {
+            Self::Relative(seconds) => {
+                for (value, unit) in [
+                    (seconds / 86_400, 'd'),
+                    (seconds / 3600 % 24, 'h'),
+                    (seconds / 60 % 60, 'm'),
+                    (seconds % 60, 's'),
+                ] {
+                    if value > 0 {
+                        write!(f, "{value}{unit}")?;
+                    }
+                }
+                Ok(())
+            }
+            Self::Absolute(timestamp) => {
+                let nanos = u128::try_from(*timestamp).unwrap_or_default() * 1_000_000_000;
+                let timestamp = format_timestamp(nanos);
+                write!(
+                    f,
+                    "{}Z",
+                    timestamp
+                        .split_once('.')
+                        .map_or(timestamp.as_str(), |i| i.0)
+                )
+            }
+        }
     }
 }
 
-pub type MemTuple = (Vec<(f64, f64)>, ByteStats, State);
-pub type CpuTuple = (Vec<(f64, f64)>, CpuStats, State);
-
-/// Used to make sure that each log entry, for each container, is unique,
-/// will only push a log entry into the logs vec if timetstamp of said log entry isn't in the hashset
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
-pub struct LogsTz(String);
-
-/// The docker log, which should always contain a timestamp, is in the format `2023-01-14T19:13:30.783138328Z Lorem ipsum dolor sit amet`
-/// So just split at the inclusive index of the first space, needs to be inclusive, hence the use of format to at the space, so that we can remove the whole thing when the `-t` flag is set
-/// Need to make sure that this isn't an empty string?!
-impl From<&str> for LogsTz {
-    fn from(value: &str) -> Self {
-        Self(value.split_inclusive(' ').take(1).collect::<String>())
-    }
+/// Whether the cpu, or memory, usage of a container is above its alert threshold, set with "--cpu-alert" & "--mem-alert"
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Alert {
+    pub cpu: bool,
+    pub mem: bool,
 }
 
-impl fmt::Display for LogsTz {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0)
+impl Alert {
+    pub const fn any(self) -> bool {
+        self.cpu || self.mem
     }
 }
 
-/// Store the logs alongside a HashSet, each log *should* generate a unique timestamp,
-/// so if we store the timestamp separately in a HashSet, we can then check if we should insert a log line into the
-/// stateful list dependent on whethere the timestamp is in the HashSet or not
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Logs {
-    logs: StatefulList<ListItem<'static>>,
-    tz: HashSet<LogsTz>,
+/// Restart count at which a container is flagged as crash looping
+const RESTART_WARNING: u64 = 5;
+
+/// The number of times a container has been restarted by the Docker daemon, and its restart policy, read from an inspect of the container
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Restarts {
+    pub count: u64,
+    pub policy: String,
 }
 
-impl Default for Logs {
-    fn default() -> Self {
-        let mut logs = StatefulList::new(vec![]);
-        logs.end();
+impl Restarts {
+    /// An empty policy is the same as `no`, an `on-failure` policy includes the maximum retry count, if set
+    pub fn new(count: Option<i64>, policy: Option<&str>, max_retries: Option<i64>) -> Self {
+        let policy = match (policy.unwrap_or_default(), max_retries) {
+            ("", _) => String::from("no"),
+            ("on-failure", Some(max)) if max > 0 => format!("on-failure:{max}"),
+            (policy, _) => policy.to_owned(),
+        };
         Self {
-            logs,
-            tz: HashSet::new(),
+            count: count
+                .and_then(|i| u64::try_from(i).ok())
+                .unwrap_or_default(),
+            policy,
         }
     }
-}
+
+    /// A container that has been restarted this many times is probably crash looping
+    pub const fn is_flapping(&self) -> bool {
+        self.count >= RESTART_WARNING
+    }
+}
+
+/// Read the restart count, and the restart policy, from an inspect of the container
+impl From<&ContainerInspectResponse> for Restarts {
+    fn from(inspect: &ContainerInspectResponse) -> Self {
+        let restart_policy = inspect
+            .host_config
+            .as_ref()
+            .and_then(|i| i.restart_policy.as_ref());
+        Self::new(
+            inspect.restart_count,
+            restart_policy
+                .and_then(|i| i.name)
+                .map(|i| i.to_string())
+                .as_deref(),
+            restart_policy.and_then(|i| i.maximum_retry_count),
+        )
+    }
+}
+
+impl fmt::Display for Restarts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "restarts {} - policy {}", self.count, self.policy)
+    }
+}
+
+/// When a container was last started, and last finished, as unix timestamps, read from an inspect of the container
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Uptime {
+    pub started: Option<i64>,
+    pub finished: Option<i64>,
+}
+
+impl Uptime {
+    pub fn new(started: Option<&str>, finished: Option<&str>) -> Self {
+        Self {
+            started: started.and_then(parse_timestamp),
+            finished: finished.and_then(parse_timestamp),
+        }
+    }
+}
+
+/// Items for the container control list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DockerControls {
+    Pause,
+    Restart,
+    Start,
+    Stop,
+    Unpause,
+    Delete,
+    Kill,
+    Recreate,
+}
+
+impl DockerControls {
+    /// Used in the info box shown once a command has been successfully executed
+    pub const fn past_tense(self) -> &'static str {
+        match self {
+            Self::Pause => "paused",
+            Self::Restart => "restarted",
+            Self::Start => "started",
+            Self::Stop => "stopped",
+            Self::Unpause => "unpaused",
+            Self::Delete => "deleted",
+            Self::Kill => "killed",
+            Self::Recreate => "recreated",
+        }
+    }
+
+    /// Commands that need to be confirmed when "--confirm-destructive" is set, a recreate is always confirmed
+    pub const fn is_destructive(self) -> bool {
+        matches!(
+            self,
+            Self::Stop | Self::Restart | Self::Kill | Self::Delete | Self::Recreate
+        )
+    }
+
+    /// Docker commands available depending on the containers state
+    pub fn gen_vec(state: State) -> Vec<Self> {
+        match state {
+            State::Dead | State::Exited => vec![Self::Start, Self::Restart, Self::Delete],
+            State::Paused => vec![Self::Unpause, Self::Stop, Self::Delete],
+            State::Restarting => vec![Self::Stop, Self::Delete],
+            State::Running => vec![Self::Pause, Self::Restart, Self::Stop, Self::Delete],
+            _ => vec![Self::Delete],
+        }
+    }
+
+    /// Docker commands available for a collapsed compose project, applied to every container in the project
+    pub fn gen_group_vec() -> Vec<Self> {
+        vec![Self::Start, Self::Stop, Self::Restart]
+    }
+}
+
+impl fmt::Display for DockerControls {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Pause => "pause",
+            Self::Delete => "delete",
+            Self::Restart => "restart",
+            Self::Start => "start",
+            Self::Stop => "stop",
+            Self::Unpause => "unpause",
+            Self::Kill => "kill",
+            Self::Recreate => "recreate",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+pub trait Stats {
+    fn get_value(&self) -> f64;
+}
+
+/// Struct for frequently updated CPU stats
+/// So can use custom display formatter
+/// Use trait Stats for use as generic in draw_chart function
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+pub struct CpuStats(f64);
+
+impl CpuStats {
+    pub const fn new(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+/// How the cpu usage is shown, per core, where a container using two whole cores is at 200%, as docker stats does.
+/// Or relative to every online core, so 100% is every core fully used
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CpuMode {
+    #[default]
+    Core,
+    All,
+}
+
+impl CpuMode {
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Core => Self::All,
+            Self::All => Self::Core,
+        }
+    }
+
+    /// The cpu column heading, so it's clear which mode is in use
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Core => "cpu",
+            Self::All => "cpu/all",
+        }
+    }
+
+    /// Convert a per core usage into this mode, the usage is unchanged if the number of cores isn't known
+    #[allow(clippy::cast_precision_loss)]
+    pub fn apply(self, cpu: f64, cpus: u64) -> f64 {
+        match self {
+            Self::All if cpus > 0 => cpu / cpus as f64,
+            _ => cpu,
+        }
+    }
+
+    /// Convert a usage in this mode back into a per core usage
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_core(self, cpu: f64, cpus: u64) -> f64 {
+        match self {
+            Self::All if cpus > 0 => cpu * cpus as f64,
+            _ => cpu,
+        }
+    }
+}
+
+impl fmt::Display for CpuMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Core => "100% is one core",
+            Self::All => "100% is every core",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+impl Eq for CpuStats {}
+
+impl PartialEq for CpuStats {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl PartialOrd for CpuStats {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CpuStats {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.0 > other.0 {
+            Ordering::Greater
+        } else if (self.0 - other.0).abs() < 0.01 {
+            Ordering::Equal
+        } else {
+            Ordering::Less
+        }
+    }
+}
+
+impl Stats for CpuStats {
+    fn get_value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl fmt::Display for CpuStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = format!("{:05.2}%", self.0);
+        write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
+    }
+}
+
+/// Struct for frequently updated memory usage stats
+/// So can use custom display formatter
+/// Use trait Stats for use as generic in draw_chart function
+#[derive(Debug, Default, Clone, Copy, Eq, Serialize)]
+pub struct ByteStats(u64);
+
+impl PartialEq for ByteStats {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl PartialOrd for ByteStats {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByteStats {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl ByteStats {
+    pub const fn new(value: u64) -> Self {
+        Self(value)
+    }
+    pub const fn update(&mut self, value: u64) {
+        self.0 = value;
+    }
+
+    /// Format the bytes in a given unit, only the text changes, the value itself is always in bytes
+    pub fn format(self, unit: MemoryUnit) -> String {
+        let as_f64 = self.get_value();
+        match unit {
+            MemoryUnit::Auto => match as_f64 {
+                x if x >= ONE_GB => format!("{y:.2} GB", y = as_f64 / ONE_GB),
+                x if x >= ONE_MB => format!("{y:.2} MB", y = as_f64 / ONE_MB),
+                _ => format!("{y:.2} kB", y = as_f64 / ONE_KB),
+            },
+            MemoryUnit::Bytes => format!("{} B", self.0),
+            MemoryUnit::Kib => format!("{y:.2} KiB", y = as_f64 / ONE_KIB),
+            MemoryUnit::Mib => format!("{y:.2} MiB", y = as_f64 / ONE_MIB),
+            MemoryUnit::Gib => format!("{y:.2} GiB", y = as_f64 / ONE_GIB),
+        }
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+impl Stats for ByteStats {
+    fn get_value(&self) -> f64 {
+        self.0 as f64
+    }
+}
+
+/// The unit that memory is shown in, set with "--memory-unit" or in the config file, and cycled at runtime.
+/// Auto picks the most readable of kB, MB, or GB, for each value
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MemoryUnit {
+    #[default]
+    Auto,
+    Bytes,
+    Kib,
+    Mib,
+    Gib,
+}
+
+impl MemoryUnit {
+    /// The next unit, after GiB it wraps around to auto
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Auto => Self::Bytes,
+            Self::Bytes => Self::Kib,
+            Self::Kib => Self::Mib,
+            Self::Mib => Self::Gib,
+            Self::Gib => Self::Auto,
+        }
+    }
+}
+
+impl fmt::Display for MemoryUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Auto => "auto",
+            Self::Bytes => "bytes",
+            Self::Kib => "KiB",
+            Self::Mib => "MiB",
+            Self::Gib => "GiB",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// convert from bytes to kB, MB, GB etc
+impl fmt::Display for ByteStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let p = self.format(MemoryUnit::Auto);
+        write!(f, "{p:>x$}", x = f.width().unwrap_or(1))
+    }
+}
+
+/// A per second rate of bytes, used for the network and block i/o columns
+/// None when there's nothing to measure, e.g. a container in host network mode has no network stats
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct ByteRate(Option<u64>);
+
+impl ByteRate {
+    pub const fn new(value: Option<u64>) -> Self {
+        Self(value)
+    }
+
+    pub const fn get(self) -> u64 {
+        match self.0 {
+            Some(x) => x,
+            None => 0,
+        }
+    }
+}
+
+impl fmt::Display for ByteRate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let p = self.0.map_or_else(
+            || String::from("--"),
+            |x| format!("{}/s", ByteStats::new(x)),
+        );
+        write!(f, "{p:>x$}", x = f.width().unwrap_or(1))
+    }
+}
+
+/// The cumulative network and block i/o totals, as reported by the Docker stats endpoint
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct IoTotals {
+    /// (rx, tx), summed across every network interface, None if the container has no networks
+    pub net: Option<(u64, u64)>,
+    pub blk_read: u64,
+    pub blk_write: u64,
+}
+
+/// The per second network and block i/o rates, calculated from the difference between two IoTotals
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct IoRates {
+    pub rx: ByteRate,
+    pub tx: ByteRate,
+    pub blk_read: ByteRate,
+    pub blk_write: ByteRate,
+}
+
+impl IoRates {
+    /// Calculate the rates, returns None if no time has passed between the two measurements
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn calculate(previous: &IoTotals, current: &IoTotals, elapsed: Duration) -> Option<Self> {
+        let seconds = elapsed.as_secs_f64();
+        if seconds <= 0.0 {
+            return None;
+        }
+        let rate = |previous: u64, current: u64| {
+            (current.saturating_sub(previous) as f64 / seconds).round() as u64
+        };
+        let (rx, tx) = match (previous.net, current.net) {
+            (Some(previous), Some(current)) => (
+                Some(rate(previous.0, current.0)),
+                Some(rate(previous.1, current.1)),
+            ),
+            _ => (None, None),
+        };
+        Some(Self {
+            rx: ByteRate::new(rx),
+            tx: ByteRate::new(tx),
+            blk_read: ByteRate::new(Some(rate(previous.blk_read, current.blk_read))),
+            blk_write: ByteRate::new(Some(rate(previous.blk_write, current.blk_write))),
+        })
+    }
+
+    /// Combined network rate, used for sorting
+    pub const fn net_total(&self) -> u64 {
+        self.rx.get().saturating_add(self.tx.get())
+    }
+
+    /// Combined block i/o rate, used for sorting
+    pub const fn blk_total(&self) -> u64 {
+        self.blk_read.get().saturating_add(self.blk_write.get())
+    }
+}
+
+pub type MemTuple = (Vec<(f64, f64)>, ByteStats, State);
+pub type CpuTuple = (Vec<(f64, f64)>, CpuStats, State);
+/// Sparkline data, cpu is stored as hundredths of a percent, along with the most recent value
+pub type HistoryTuple = ((Vec<u64>, CpuStats), (Vec<u64>, ByteStats), State);
+
+/// Used to make sure that each log entry, for each container, is unique,
+/// will only push a log entry into the logs vec if timetstamp of said log entry isn't in the hashset
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct LogsTz(String);
+
+/// The docker log, which should always contain a timestamp, is in the format `2023-01-14T19:13:30.783138328Z Lorem ipsum dolor sit amet`
+/// So just split at the inclusive index of the first space, needs to be inclusive, hence the use of format to at the space, so that we can remove the whole thing when the `-t` flag is set
+/// Need to make sure that this isn't an empty string?!
+impl From<&str> for LogsTz {
+    fn from(value: &str) -> Self {
+        Self(value.split_inclusive(' ').take(1).collect::<String>())
+    }
+}
+
+impl fmt::Display for LogsTz {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Soft-wrap every line of a Text to a given width, splitting spans where needed so that the styles are kept.
+/// Width is measured in chars, in the same way as the rest of the column widths
+fn wrap_text(text: &Text<'static>, width: usize) -> Text<'static> {
+    let width = width.max(1);
+    let mut output = vec![];
+    for line in &text.lines {
+        let mut current = Line::default();
+        let mut current_width = 0;
+        for span in &line.spans {
+            let mut content = span.content.as_ref();
+            while !content.is_empty() {
+                let remaining = width - current_width;
+                let (head, tail) = content
+                    .char_indices()
+                    .nth(remaining)
+                    .map_or((content, ""), |(index, _)| content.split_at(index));
+                current_width += head.chars().count();
+                current
+                    .spans
+                    .push(Span::styled(head.to_owned(), span.style));
+                content = tail;
+                if current_width == width && !content.is_empty() {
+                    output.push(std::mem::take(&mut current));
+                    current_width = 0;
+                }
+            }
+        }
+        output.push(current);
+    }
+    Text::from(output)
+}
+
+/// The stream a log line was written to, a container with a tty only has a single combined stream, which is treated as stdout
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LogStream {
+    #[default]
+    Stdout,
+    Stderr,
+}
+
+impl From<&LogOutput> for LogStream {
+    fn from(value: &LogOutput) -> Self {
+        match value {
+            LogOutput::StdErr { .. } => Self::Stderr,
+            _ => Self::Stdout,
+        }
+    }
+}
+
+/// Which log streams are shown in the logs panel, cycled at runtime
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LogStreamFilter {
+    #[default]
+    All,
+    Stdout,
+    Stderr,
+}
+
+impl LogStreamFilter {
+    /// The next filter, after stderr it wraps around to all
+    pub const fn next(self) -> Self {
+        match self {
+            Self::All => Self::Stdout,
+            Self::Stdout => Self::Stderr,
+            Self::Stderr => Self::All,
+        }
+    }
+
+    /// Check if a line written to a given stream is shown
+    pub fn shows(self, stream: LogStream) -> bool {
+        match self {
+            Self::All => true,
+            Self::Stdout => stream == LogStream::Stdout,
+            Self::Stderr => stream == LogStream::Stderr,
+        }
+    }
+}
+
+impl fmt::Display for LogStreamFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::All => "all",
+            Self::Stdout => "stdout",
+            Self::Stderr => "stderr",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// A marker in the logs, holding the position, see `Logs::position()`, of the first line inserted after it was set.
+/// Every line is shown whilst set, or only the lines after it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogMarker {
+    Set(usize),
+    Only(usize),
+}
+
+impl LogMarker {
+    const fn position(self) -> usize {
+        match self {
+            Self::Set(i) | Self::Only(i) => i,
+        }
+    }
+}
+
+/// Store the logs alongside a HashSet, each log *should* generate a unique timestamp,
+/// so if we store the timestamp separately in a HashSet, we can then check if we should insert a log line into the
+/// stateful list dependent on whethere the timestamp is in the HashSet or not
+/// The plain text of each line, without any ansi formatting, is also kept, so that the logs can be searched,
+/// as well as the original line and timestamp, so that the Text can be regenerated, e.g. when timestamps are toggled.
+/// Text, rather than ListItems, is stored so that it can be soft-wrapped to the width of the logs panel when drawn.
+/// New lines are followed, i.e. the last line is kept selected, until the selection is moved away from the last line,
+/// following is then paused until the last line is selected again via `end()`. Whilst paused the selected line, and the scroll offset, stay on the same line as new lines are added, or old lines are removed.
+/// Separately, the stream can be paused, no new lines are then inserted, until resumed.
+/// Every line is kept, along with the stream it was written to, but only the lines shown by the filter are in the StatefulList,
+/// `visible` holds the position, see `position()`, of each of those lines, so the filter can be changed without refetching the logs
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::struct_field_names)]
+pub struct Logs {
+    dropped: usize,
+    filter: LogStreamFilter,
+    logs: StatefulList<Text<'static>>,
+    follow: bool,
+    marker: Option<LogMarker>,
+    lines: Vec<(LogsTz, LogStream, String)>,
+    stream_paused: bool,
+    text: Vec<String>,
+    tz: HashSet<LogsTz>,
+    visible: Vec<usize>,
+}
+
+impl Default for Logs {
+    fn default() -> Self {
+        let mut logs = StatefulList::new(vec![]);
+        logs.end();
+        Self {
+            dropped: 0,
+            filter: LogStreamFilter::default(),
+            follow: true,
+            logs,
+            marker: None,
+            lines: vec![],
+            stream_paused: false,
+            text: vec![],
+            tz: HashSet::new(),
+            visible: vec![],
+        }
+    }
+}
 
 impl Logs {
-    /// Only allow a new log line to be inserted if the log timestamp isn't in the tz HashSet
-    pub fn insert(&mut self, line: ListItem<'static>, tz: LogsTz) {
-        if self.tz.insert(tz) {
-            self.logs.items.push(line);
+    /// Only allow a new log line to be inserted if the log timestamp isn't in the tz HashSet, it's only shown if the filter includes its stream
+    /// `content` is the original log line, with the timestamp removed
+    pub fn insert(&mut self, line: Text<'static>, content: String, tz: LogsTz, stream: LogStream) {
+        if self.tz.insert(tz.clone()) {
+            if self.filter.shows(stream) {
+                self.visible.push(self.dropped + self.lines.len());
+                self.logs.items.push(line);
+            }
+            self.text.push(log_sanitizer::plain(&content));
+            self.lines.push((tz, stream, content));
+        }
+    }
+
+    /// Remove the oldest lines so that at most `max` lines are kept, whether shown or not, the selected line is moved so that it stays on the same line, if it still exists
+    pub fn truncate(&mut self, max: usize) {
+        let excess = self.lines.len().saturating_sub(max);
+        if excess > 0 {
+            self.text.drain(..excess);
+            for (tz, _, _) in self.lines.drain(..excess) {
+                self.tz.remove(&tz);
+            }
+            self.dropped += excess;
+            let hidden = self.visible.partition_point(|i| *i < self.dropped);
+            self.visible.drain(..hidden);
+            self.logs.items.drain(..hidden);
+            // Both the selected line, and the first visible line, are moved, so that the view doesn't jump whilst not following
+            let selected = self.logs.state.selected();
+            self.logs
+                .state
+                .select(selected.map(|i| i.saturating_sub(hidden)));
+            let offset = self.logs.state.offset_mut();
+            *offset = offset.saturating_sub(hidden);
+        }
+    }
+
+    /// The lines shown by the filter, along with their plain text
+    fn visible_lines(&self) -> impl Iterator<Item = (&(LogsTz, LogStream, String), &String)> {
+        self.visible
+            .iter()
+            .filter_map(|i| i.checked_sub(self.dropped))
+            .filter_map(|i| self.lines.get(i).zip(self.text.get(i)))
+    }
+
+    pub const fn filter(&self) -> LogStreamFilter {
+        self.filter
+    }
+
+    /// Only show the lines written to the streams included by the filter.
+    /// The same line stays selected, or the closest earlier line if it's no longer shown, unless following, in which case the last line is selected
+    pub fn set_filter(
+        &mut self,
+        filter: LogStreamFilter,
+        gen_item: impl Fn(&LogsTz, LogStream, &str) -> Text<'static>,
+    ) {
+        let position = self.position();
+        self.filter = filter;
+        let first = match self.marker {
+            Some(LogMarker::Only(i)) => i,
+            _ => 0,
+        };
+        self.visible = self
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, stream, _))| filter.shows(*stream))
+            .map(|(index, _)| index + self.dropped)
+            .filter(|i| *i >= first)
+            .collect();
+        self.rebuild(gen_item);
+        *self.logs.state.offset_mut() = 0;
+        if self.visible.is_empty() {
+            self.logs.state.select(None);
+        } else if self.follow {
+            self.logs.end();
+        } else {
+            let selected = position.map_or(0, |position| {
+                self.visible
+                    .partition_point(|i| *i <= position)
+                    .saturating_sub(1)
+            });
+            self.logs.state.select(Some(selected));
+        }
+    }
+
+    pub const fn marker(&self) -> Option<LogMarker> {
+        self.marker
+    }
+
+    /// The number of lines, shown or not, inserted since the marker was set, 0 without a marker.
+    /// The marker position isn't changed when older lines are dropped, so it always refers to the same point
+    pub fn marker_count(&self) -> usize {
+        self.marker.map_or(0, |marker| {
+            (self.dropped + self.lines.len()).saturating_sub(marker.position())
+        })
+    }
+
+    /// Set a marker after the newest line, then only show the lines after it, then clear it, so that every line is shown again
+    pub fn cycle_marker(&mut self, gen_item: impl Fn(&LogsTz, LogStream, &str) -> Text<'static>) {
+        self.marker = match self.marker {
+            None => Some(LogMarker::Set(self.dropped + self.lines.len())),
+            Some(LogMarker::Set(i)) => Some(LogMarker::Only(i)),
+            Some(LogMarker::Only(_)) => None,
         };
+        // Setting the marker doesn't change which lines are shown
+        if !matches!(self.marker, Some(LogMarker::Set(_))) {
+            self.set_filter(self.filter, gen_item);
+        }
+    }
+
+    /// Following is paused whenever the selected line is moved away from the last line, and only resumed by `end()`
+    fn unfollow_if_moved(&mut self) {
+        let at_end = self
+            .logs
+            .state
+            .selected()
+            .is_none_or(|i| i + 1 >= self.logs.items.len());
+        self.follow = self.follow && at_end;
+    }
+
+    /// Whether new lines are followed, false once following has been paused by scrolling away from the last line
+    pub const fn follow(&self) -> bool {
+        self.follow
+    }
+
+    /// Whether new lines are currently being ignored, they are fetched once the stream is resumed
+    pub const fn stream_paused(&self) -> bool {
+        self.stream_paused
+    }
+
+    pub const fn toggle_stream(&mut self) {
+        self.stream_paused = !self.stream_paused;
+    }
+
+    /// The number of old lines that have been removed, due to the "--log-max-lines" limit
+    pub const fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Regenerate every shown Text from the original log lines, the selected line is unchanged
+    pub fn rebuild(&mut self, gen_item: impl Fn(&LogsTz, LogStream, &str) -> Text<'static>) {
+        self.logs.items = self
+            .visible_lines()
+            .map(|((tz, stream, content), _)| gen_item(tz, *stream, content))
+            .collect();
+    }
+
+    /// Get the plain text of every shown log line, optionally prefixed with the timestamp
+    pub fn get_text(&self, timestamps: bool) -> Vec<String> {
+        self.visible_lines()
+            .map(|((tz, _, _), text)| {
+                if timestamps {
+                    format!("{tz}{text}")
+                } else {
+                    text.clone()
+                }
+            })
+            .collect()
+    }
+
+    /// The position of the selected line, counting every line ever inserted, so that it still refers to the same line after older lines are dropped, or the filter is changed
+    pub fn position(&self) -> Option<usize> {
+        self.logs
+            .state
+            .selected()
+            .and_then(|i| self.visible.get(i))
+            .copied()
+    }
+
+    /// The first and last index, inclusive, of the lines between an anchor position, see `position()`, and the selected line.
+    /// Without an anchor, or if the anchor line has since been dropped, or isn't shown, just the selected line is used
+    pub fn selected_range(&self, anchor: Option<usize>) -> Option<(usize, usize)> {
+        let selected = self.logs.state.selected()?;
+        let anchor = anchor
+            .and_then(|i| self.visible.binary_search(&i).ok())
+            .unwrap_or(selected);
+        Some((anchor.min(selected), anchor.max(selected)))
+    }
+
+    /// Get the plain text of the lines between an anchor position and the selected line, optionally prefixed with the timestamp
+    pub fn get_selected_text(&self, anchor: Option<usize>, timestamps: bool) -> Vec<String> {
+        self.selected_range(anchor).map_or(vec![], |(start, end)| {
+            self.get_text(timestamps)
+                .into_iter()
+                .skip(start)
+                .take(end - start + 1)
+                .collect()
+        })
+    }
+
+    /// The first and last index, inclusive, of the lines shown in the logs panel, starting from the scroll offset, where a soft-wrapped line takes up more than one row.
+    /// The first line is always included, even if it's taller than the panel, as it's still drawn
+    pub fn visible_range(&self, rows: usize, width: usize) -> Option<(usize, usize)> {
+        let start = self.logs.state.offset();
+        if start >= self.logs.items.len() {
+            return None;
+        }
+        let mut used = 0;
+        let mut end = start;
+        for index in start..self.logs.items.len() {
+            used += self.wrapped_height(index, width);
+            if used > rows && index > start {
+                break;
+            }
+            end = index;
+        }
+        Some((start, end))
+    }
+
+    /// Get the plain text of the lines shown in the logs panel, see `visible_range()`, optionally prefixed with the timestamp
+    pub fn get_visible_text(&self, rows: usize, width: usize, timestamps: bool) -> Vec<String> {
+        self.visible_range(rows, width)
+            .map_or(vec![], |(start, end)| {
+                self.get_text(timestamps)
+                    .into_iter()
+                    .skip(start)
+                    .take(end - start + 1)
+                    .collect()
+            })
+    }
+
+    /// Get the indexes of all the lines that match the search
+    pub fn matches(&self, search: &LogSearch) -> Vec<usize> {
+        self.visible_lines()
+            .enumerate()
+            .filter_map(|(index, (_, text))| search.is_match(text).then_some(index))
+            .collect()
+    }
+
+    /// Get the index, and color, of every line that contains a severity token, e.g. `ERROR`, as a whole word, case-insensitive.
+    /// If a line contains multiple tokens, the first token in the line is used
+    pub fn severity(&self, tokens: &BTreeMap<String, Color>) -> Vec<(usize, Color)> {
+        if tokens.is_empty() {
+            return vec![];
+        }
+        self.visible_lines()
+            .enumerate()
+            .filter_map(|(index, (_, text))| {
+                text.split(|c: char| !c.is_alphanumeric())
+                    .find_map(|word| {
+                        tokens
+                            .iter()
+                            .find(|(token, _)| token.eq_ignore_ascii_case(word))
+                    })
+                    .map(|(_, color)| (index, *color))
+            })
+            .collect()
+    }
+
+    /// Select the next line that matches the search, wraps around to the first match
+    pub fn next_match(&mut self, search: &LogSearch) {
+        let matches = self.matches(search);
+        let selected = self.logs.state.selected();
+        let next = matches
+            .iter()
+            .find(|i| selected.is_none_or(|s| **i > s))
+            .or_else(|| matches.first());
+        if let Some(next) = next {
+            self.logs.state.select(Some(*next));
+        }
+        self.unfollow_if_moved();
+    }
+
+    /// Select the previous line that matches the search, wraps around to the last match
+    pub fn previous_match(&mut self, search: &LogSearch) {
+        let matches = self.matches(search);
+        let selected = self.logs.state.selected();
+        let previous = matches
+            .iter()
+            .rev()
+            .find(|i| selected.is_none_or(|s| **i < s))
+            .or_else(|| matches.last());
+        if let Some(previous) = previous {
+            self.logs.state.select(Some(*previous));
+        }
+        self.unfollow_if_moved();
+    }
+
+    /// Get every log line as a ListItem, if a width is given each line is soft-wrapped to that width
+    pub fn to_vec(&self, wrap_width: Option<usize>) -> Vec<ListItem<'static>> {
+        self.logs
+            .items
+            .iter()
+            .map(|i| {
+                wrap_width.map_or_else(
+                    || ListItem::new(i.clone()),
+                    |width| ListItem::new(wrap_text(i, width)),
+                )
+            })
+            .collect()
+    }
+
+    /// The number of rows a log line takes up when soft-wrapped to a given width, is always at least 1
+    fn wrapped_height(&self, index: usize, width: usize) -> usize {
+        self.logs.items.get(index).map_or(1, |i| {
+            i.lines
+                .iter()
+                .map(|line| line.width().div_ceil(width.max(1)).max(1))
+                .sum()
+        })
+    }
+
+    /// Move the selected line down by a page of rows, a soft-wrapped line counts as multiple rows, but at least one line is always moved
+    pub fn page_down(&mut self, rows: usize, width: usize) {
+        let mut used = 0;
+        while let Some(selected) = self.logs.state.selected() {
+            let next = selected + 1;
+            if next >= self.logs.items.len() {
+                break;
+            }
+            used += self.wrapped_height(next, width);
+            if used > rows && used > self.wrapped_height(next, width) {
+                break;
+            }
+            self.logs.state.select(Some(next));
+            if used >= rows {
+                break;
+            }
+        }
+        self.unfollow_if_moved();
     }
 
-    pub fn to_vec(&self) -> Vec<ListItem<'static>> {
-        self.logs.items.clone()
+    /// Move the selected line up by a page of rows, a soft-wrapped line counts as multiple rows, but at least one line is always moved
+    pub fn page_up(&mut self, rows: usize, width: usize) {
+        let mut used = 0;
+        while let Some(selected) = self.logs.state.selected() {
+            if selected == 0 {
+                break;
+            }
+            let previous = selected - 1;
+            used += self.wrapped_height(previous, width);
+            if used > rows && used > self.wrapped_height(previous, width) {
+                break;
+            }
+            self.logs.state.select(Some(previous));
+            if used >= rows {
+                break;
+            }
+        }
+        self.unfollow_if_moved();
     }
 
     /// The rest of the methods are basically forwarding from the underlying StatefulList
@@ -500,24 +1841,25 @@ impl Logs {
 
     pub fn next(&mut self) {
         self.logs.next();
+        self.unfollow_if_moved();
     }
 
     pub fn previous(&mut self) {
         self.logs.previous();
+        self.unfollow_if_moved();
     }
 
+    /// Select the last line, and resume following new lines
     pub fn end(&mut self) {
         self.logs.end();
+        self.follow = true;
     }
     pub fn start(&mut self) {
         self.logs.start();
+        self.unfollow_if_moved();
     }
 
-    pub fn len(&self) -> usize {
-        self.logs.items.len()
-    }
-
-    pub fn state(&mut self) -> &mut ListState {
+    pub const fn state(&mut self) -> &mut ListState {
         &mut self.logs.state
     }
 }
@@ -525,35 +1867,99 @@ impl Logs {
 /// Info for each container
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ContainerItem {
+    /// The ip addresses from the inspect data, None until the first inspect has completed
+    pub address: Option<ContainerAddress>,
+    pub alert: Alert,
+    pub command: Option<String>,
+    /// The entrypoint & cmd from the inspect data, None until the first inspect has completed
+    pub container_command: Option<ContainerCommand>,
     pub cpu_stats: VecDeque<CpuStats>,
+    /// The number of online cpus, as read from the stats, 0 until the first stats are read
+    pub cpus: u64,
     pub created: u64,
     pub docker_controls: StatefulList<DockerControls>,
+    pub env: Option<Vec<String>>,
+    pub health: Option<Health>,
     pub id: ContainerId,
     pub image: ContainerImage,
+    pub image_id: Option<String>,
+    pub image_size: Option<ByteStats>,
+    pub inspect: Option<String>,
+    pub io_rates: IoRates,
     pub is_oxker: bool,
+    /// The container that oxker itself is running in
+    pub is_self: bool,
+    pub labels: Option<BTreeMap<String, String>>,
+    pub last_io: Option<(Instant, IoTotals)>,
     pub last_updated: u64,
+    /// The logging driver from the inspect data, None until the first inspect has completed
+    pub log_driver: Option<String>,
     pub logs: Logs,
+    /// Set when Docker refuses to read the logs, normally as the logging driver doesn't support reading
+    pub logs_unreadable: bool,
     pub mem_limit: ByteStats,
     pub mem_stats: VecDeque<ByteStats>,
+    pub mounts: Option<Vec<ContainerMount>>,
     pub name: ContainerName,
     pub ports: Vec<ContainerPorts>,
+    pub project: Option<String>,
+    pub restarts: Option<Restarts>,
+    pub run_command: Option<String>,
     pub rx: ByteStats,
+    /// The compose service, read from its `com.docker.compose.service` label
+    pub service: Option<String>,
+    /// Shells to try first when execing into the container, read from its `oxker.shell` label
+    pub shells: Vec<String>,
     pub state: State,
     pub status: String,
     pub tx: ByteStats,
+    pub uptime: Option<Uptime>,
 }
 
-/// Basic display information, for when running in debug mode
-impl fmt::Display for ContainerItem {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{}, {}, {}, {}",
-            self.id.get_short(),
-            self.name,
-            self.cpu_stats.back().unwrap_or(&CpuStats::new(0.0)),
-            self.mem_stats.back().unwrap_or(&ByteStats::new(0))
-        )
+/// A snapshot of a container, and its most recent stats, as printed by `--format json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ContainerOutput {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    pub project: Option<String>,
+    pub service: Option<String>,
+    pub state: State,
+    pub status: String,
+    pub health: Option<Health>,
+    pub cpu_percent: Option<CpuStats>,
+    pub memory_bytes: Option<ByteStats>,
+    pub memory_limit_bytes: ByteStats,
+    pub rx_bytes: ByteStats,
+    pub tx_bytes: ByteStats,
+    /// Bytes per second, null until two measurements have been taken
+    pub io_rates: IoRates,
+    pub ports: Vec<ContainerPorts>,
+    pub restarts: Option<Restarts>,
+    pub uptime: Option<Uptime>,
+}
+
+impl From<&ContainerItem> for ContainerOutput {
+    fn from(item: &ContainerItem) -> Self {
+        Self {
+            id: item.id.get().to_owned(),
+            name: item.name.get().to_owned(),
+            image: item.image.get().to_owned(),
+            project: item.project.clone(),
+            service: item.service.clone(),
+            state: item.state,
+            status: item.status.clone(),
+            health: item.health,
+            cpu_percent: item.cpu_stats.back().copied(),
+            memory_bytes: item.mem_stats.back().copied(),
+            memory_limit_bytes: item.mem_limit,
+            rx_bytes: item.rx,
+            tx_bytes: item.tx,
+            io_rates: item.io_rates,
+            ports: item.ports.clone(),
+            restarts: item.restarts.clone(),
+            uptime: item.uptime,
+        }
     }
 }
 
@@ -574,25 +1980,242 @@ impl ContainerItem {
         docker_controls.start();
 
         Self {
+            address: None,
+            alert: Alert::default(),
+            command: None,
+            container_command: None,
             cpu_stats: VecDeque::with_capacity(60),
+            cpus: 0,
             created,
             docker_controls,
+            env: None,
+            health: None,
             id,
             image: image.into(),
+            image_id: None,
+            image_size: None,
+            inspect: None,
+            io_rates: IoRates::default(),
             is_oxker,
+            is_self: false,
+            labels: None,
+            last_io: None,
             last_updated: 0,
+            log_driver: None,
             logs: Logs::default(),
+            logs_unreadable: false,
             mem_limit: ByteStats::default(),
             mem_stats: VecDeque::with_capacity(60),
+            mounts: None,
             name: name.into(),
             ports,
+            project: None,
+            restarts: None,
+            run_command: None,
             rx: ByteStats::default(),
+            service: None,
+            shells: vec![],
             state,
             status,
             tx: ByteStats::default(),
+            uptime: None,
+        }
+    }
+
+    /// Why the logs can't be shown, in place of "no logs found", the `none` driver never keeps any logs, any other driver is only known once a read has failed
+    pub fn get_logs_unavailable(&self) -> Option<String> {
+        match self.log_driver.as_deref() {
+            Some("none") => Some(String::from(
+                "This container's logging driver, none, doesn't keep any logs",
+            )),
+            Some(driver) if self.logs_unreadable => Some(format!(
+                "This container's logging driver, {driver}, doesn't support log reading"
+            )),
+            None if self.logs_unreadable => Some(String::from(
+                "This container's logging driver doesn't support log reading",
+            )),
+            _ => None,
+        }
+    }
+
+    /// The health is only shown for running containers, as a stopped container keeps its last health status
+    pub fn get_health(&self) -> Option<Health> {
+        self.health.filter(|_| self.state.is_alive())
+    }
+
+    /// The addresses are only shown for alive containers, as a stopped container has no address
+    pub fn get_address(&self) -> Option<&ContainerAddress> {
+        self.address.as_ref().filter(|_| self.state.is_alive())
+    }
+
+    /// The primary ip address, `host` for host networking, or `--` if not known, or not on any network
+    pub fn format_ip(&self) -> String {
+        self.get_address()
+            .and_then(ContainerAddress::primary)
+            .unwrap_or_else(|| String::from("--"))
+    }
+
+    /// Seconds since an alive container was started, or since a stopped container finished.
+    /// None if not yet known, if never started or finished, or if in the future due to clock skew
+    pub fn get_uptime(&self, now: i64) -> Option<i64> {
+        let uptime = self.uptime?;
+        let since = if self.state.is_alive() {
+            uptime.started
+        } else {
+            uptime.finished
+        }?;
+        let seconds = now - since;
+        (seconds >= 0).then_some(seconds)
+    }
+
+    /// Seconds since an alive container was started, only if it was started within the window, set with "--fresh"
+    pub fn started_within(&self, now: i64, window: u64) -> Option<i64> {
+        let seconds = self.get_uptime(now).filter(|_| self.state.is_alive())?;
+        (u64::try_from(seconds).ok()? < window).then_some(seconds)
+    }
+
+    /// The uptime of an alive container, e.g. `3h 12m`, or how long ago a stopped container finished, e.g. `2d ago`, `--` if unknown
+    pub fn format_uptime(&self, now: i64) -> String {
+        self.get_uptime(now).map_or_else(
+            || String::from("--"),
+            |seconds| {
+                if self.state.is_alive() {
+                    format_duration(seconds)
+                } else {
+                    format!("{} ago", format_duration(seconds))
+                }
+            },
+        )
+    }
+
+    /// The size of the container's image, `--` if the image has since been deleted, or the size isn't yet known
+    pub fn format_image_size(&self) -> String {
+        self.image_size
+            .map_or_else(|| String::from("--"), |i| i.to_string())
+    }
+
+    /// The compose project, or `--` if the container wasn't created by docker compose
+    pub fn format_project(&self) -> String {
+        self.project.clone().unwrap_or_else(|| String::from("--"))
+    }
+
+    /// The compose service, or `--` if the container wasn't created by docker compose
+    pub fn format_service(&self) -> String {
+        self.service.clone().unwrap_or_else(|| String::from("--"))
+    }
+
+    /// The key facts of the container, as label & value pairs, for the detail panel
+    pub fn get_detail(
+        &self,
+        now: i64,
+        memory_unit: MemoryUnit,
+        time_format: &TimeFormat,
+    ) -> Vec<(&'static str, String)> {
+        let or_unknown = |value: Option<String>| value.unwrap_or_else(|| String::from("--"));
+        let mut ports = self
+            .ports
+            .iter()
+            .map(|i| {
+                let (_, private, _) = i.print();
+                i.public
+                    .map_or_else(|| private.clone(), |public| format!("{public}:{private}"))
+            })
+            .collect::<Vec<_>>();
+        ports.sort();
+        ports.dedup();
+        vec![
+            ("image", self.image.get().to_owned()),
+            ("id", self.id.get_short()),
+            ("command", or_unknown(self.command.clone())),
+            (
+                "entrypoint",
+                self.container_command.as_ref().map_or_else(
+                    || or_unknown(None),
+                    |i| i.entrypoint.clone().unwrap_or_else(|| String::from("none")),
+                ),
+            ),
+            (
+                "cmd",
+                self.container_command.as_ref().map_or_else(
+                    || or_unknown(None),
+                    |i| i.cmd.clone().unwrap_or_else(|| String::from("none")),
+                ),
+            ),
+            (
+                "created",
+                time_format.format_seconds(i64::try_from(self.created).unwrap_or_default()),
+            ),
+            (
+                "ports",
+                or_unknown((!ports.is_empty()).then(|| ports.join(", "))),
+            ),
+            (
+                "ip",
+                or_unknown(self.get_address().map(ToString::to_string)),
+            ),
+            (
+                "mounts",
+                or_unknown(self.mounts.as_ref().map(|i| i.len().to_string())),
+            ),
+            (
+                "restart",
+                or_unknown(
+                    self.restarts
+                        .as_ref()
+                        .map(|i| format!("{}, {} restarts", i.policy, i.count)),
+                ),
+            ),
+            ("uptime", self.format_uptime(now)),
+            (
+                "cpu",
+                or_unknown(self.cpu_stats.back().map(ToString::to_string)),
+            ),
+            (
+                "memory",
+                or_unknown(self.mem_stats.back().map(|i| {
+                    format!(
+                        "{} / {}",
+                        i.format(memory_unit),
+                        self.mem_limit.format(memory_unit)
+                    )
+                })),
+            ),
+            ("net", format!("↓ {} ↑ {}", self.rx, self.tx)),
+        ]
+    }
+
+    /// Set the docker controls to those available for the current state
+    pub fn reset_docker_controls(&mut self) {
+        self.docker_controls.items = DockerControls::gen_vec(self.state);
+        // Update the list state, needs to be None if the gen_vec returns an empty vec
+        match self.state {
+            State::Removing | State::Restarting | State::Unknown => {
+                self.docker_controls.state.select(None);
+            }
+            _ => self.docker_controls.start(),
         }
     }
 
+    /// Update the network and block i/o totals, and calculate the rates since the previous update
+    pub fn update_io(&mut self, io: IoTotals, now: Instant) {
+        if let Some((previous_instant, previous)) = self.last_io.as_ref() {
+            if let Some(rates) = IoRates::calculate(
+                previous,
+                &io,
+                now.saturating_duration_since(*previous_instant),
+            ) {
+                self.io_rates = rates;
+            }
+        } else if io.net.is_none() {
+            self.io_rates.rx = ByteRate::default();
+            self.io_rates.tx = ByteRate::default();
+        }
+        let (rx, tx) = io.net.unwrap_or_default();
+        self.rx.update(rx);
+        self.tx.update(tx);
+        self.last_io = Some((now, io));
+    }
+
     /// Find the max value in the cpu stats VecDeque
     fn max_cpu_stats(&self) -> CpuStats {
         self.cpu_stats
@@ -639,6 +2262,35 @@ impl ContainerItem {
         (self.get_mem_dataset(), self.max_mem_stats(), self.state)
     }
 
+    /// Get sparkline data for cpu & memory in one function
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn get_history_data(&self) -> HistoryTuple {
+        let cpu = self
+            .cpu_stats
+            .iter()
+            .map(|i| (i.0 * 100.0) as u64)
+            .collect::<Vec<_>>();
+        let mem = self.mem_stats.iter().map(|i| i.0).collect::<Vec<_>>();
+        (
+            (cpu, self.cpu_stats.back().copied().unwrap_or_default()),
+            (mem, self.mem_stats.back().copied().unwrap_or_default()),
+            self.state,
+        )
+    }
+
+    /// The current memory usage as a percentage of the memory limit, and the limit used.
+    /// Docker normally reports the host memory as the limit of a container without a limit, but if the limit is missing the given host memory is used instead.
+    /// None if neither is known, or there is no memory usage yet
+    pub fn get_mem_percent(&self, host_memory: u64) -> Option<(f64, ByteStats)> {
+        let limit = if self.mem_limit.0 > 0 {
+            self.mem_limit
+        } else {
+            ByteStats::new(host_memory)
+        };
+        let usage = self.mem_stats.back()?;
+        (limit.0 > 0).then(|| (usage.get_value() / limit.get_value() * 100.0, limit))
+    }
+
     /// Get chart info for cpu & memory in one function
     /// So only need to call .lock() once
     pub fn get_chart_data(&self) -> (CpuTuple, MemTuple) {
@@ -656,8 +2308,15 @@ pub struct Columns {
     pub mem: (Header, u8, u8),
     pub id: (Header, u8),
     pub image: (Header, u8),
+    pub image_size: (Header, u8),
     pub net_rx: (Header, u8),
     pub net_tx: (Header, u8),
+    pub net_io: (Header, u8, u8),
+    pub blk_io: (Header, u8, u8),
+    pub uptime: (Header, u8),
+    pub project: (Header, u8),
+    pub service: (Header, u8),
+    pub ip: (Header, u8),
 }
 
 impl Columns {
@@ -671,22 +2330,80 @@ impl Columns {
             mem: (Header::Memory, 7, 7),
             id: (Header::Id, 8),
             image: (Header::Image, 5),
+            image_size: (Header::ImageSize, 10),
             net_rx: (Header::Rx, 7),
             net_tx: (Header::Tx, 7),
+            net_io: (Header::NetIo, 9, 9),
+            blk_io: (Header::BlockIo, 9, 9),
+            uptime: (Header::Uptime, 6),
+            project: (Header::Project, 7),
+            service: (Header::Service, 7),
+            ip: (Header::Ip, 2),
+        }
+    }
+
+    /// The width of a column, a column with two values has a ` / ` separator
+    pub const fn width(&self, header: Header) -> usize {
+        let (a, b) = match header {
+            Header::Name => (self.name.1, None),
+            Header::State => (self.state.1, None),
+            Header::Status => (self.status.1, None),
+            Header::Cpu => (self.cpu.1, None),
+            Header::Memory => (self.mem.1, Some(self.mem.2)),
+            Header::Id => (self.id.1, None),
+            Header::Image => (self.image.1, None),
+            Header::Rx => (self.net_rx.1, None),
+            Header::Tx => (self.net_tx.1, None),
+            Header::NetIo => (self.net_io.1, Some(self.net_io.2)),
+            Header::BlockIo => (self.blk_io.1, Some(self.blk_io.2)),
+            Header::Uptime => (self.uptime.1, None),
+            Header::ImageSize => (self.image_size.1, None),
+            Header::Project => (self.project.1, None),
+            Header::Service => (self.service.1, None),
+            Header::Ip => (self.ip.1, None),
+        };
+        match b {
+            Some(b) => a as usize + b as usize + 3,
+            None => a as usize,
         }
     }
+
+    /// The width of a whole row of the containers panel, including the margins between columns, the panel borders, and the selected marker, which is three cells wide
+    pub fn row_width(&self, headers: &[Header]) -> usize {
+        headers.iter().map(|i| self.width(*i)).sum::<usize>()
+            + headers.len().saturating_sub(1) * 3
+            + 5
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
-    use ratatui::widgets::ListItem;
+    use std::collections::{BTreeMap, HashMap};
+
+    use bollard::service::{
+        ContainerConfig, ContainerInspectResponse, EndpointSettings, HostConfig, MountPoint,
+        MountPointTypeEnum, NetworkSettings,
+    };
+    use ratatui::{
+        style::{Color, Style},
+        text::{Line, Span, Text},
+    };
 
     use crate::{
         app_data::{ContainerImage, Logs},
-        ui::log_sanitizer,
+        ui::{log_sanitizer, LogSearch},
     };
 
-    use super::{ByteStats, ContainerName, CpuStats, LogsTz};
+    use std::time::{Duration, Instant};
+
+    use super::{
+        format_duration, format_timestamp, parse_timestamp, parse_timestamp_nanos, wrap_text,
+        ByteRate, ByteStats, Columns, ContainerAddress, ContainerCommand, ContainerItem,
+        ContainerMount, ContainerName, ContainerOutput, CpuMode, CpuStats, Header, Health, IoRates,
+        IoTotals, LogMarker, LogStream, LogStreamFilter, LogsTz, MemoryUnit, Restarts, Since,
+        State, TimeFormat, TimeZone, Uptime,
+    };
 
     #[test]
     /// Display CpuStats as a string
@@ -720,6 +2437,25 @@ mod tests {
         test(150_000_000_000, "150.00 GB");
     }
 
+    #[test]
+    /// Bytes formatted in a chosen memory unit, auto is the same as the Display output, and the units cycle back to auto
+    fn test_container_state_bytestats_format() {
+        let bytes = ByteStats::new(1_610_612_736);
+        assert_eq!(bytes.format(MemoryUnit::Auto), bytes.to_string());
+        assert_eq!(bytes.format(MemoryUnit::Bytes), "1610612736 B");
+        assert_eq!(bytes.format(MemoryUnit::Kib), "1572864.00 KiB");
+        assert_eq!(bytes.format(MemoryUnit::Mib), "1536.00 MiB");
+        assert_eq!(bytes.format(MemoryUnit::Gib), "1.50 GiB");
+
+        let mut unit = MemoryUnit::Auto;
+        let mut units = vec![];
+        for _ in 0..5 {
+            unit = unit.next();
+            units.push(unit.to_string());
+        }
+        assert_eq!(units, ["bytes", "KiB", "MiB", "GiB", "auto"]);
+    }
+
     #[test]
     /// ContainerName as string truncated correctly
     fn test_container_state_container_name_to_string() {
@@ -734,16 +2470,29 @@ mod tests {
     }
 
     #[test]
-    /// ContainerImage as string truncated correctly
+    /// ContainerImage as string truncated from the start, so that the tag is kept, and any digest is removed first
     fn test_container_state_container_image() {
         let result = ContainerImage::from("name_01");
         assert_eq!(result.to_string(), "name_01");
 
         let result = ContainerImage::from("name_01_name_01_name_01_name_01_");
-        assert_eq!(result.to_string(), "name_01_name_01_name_01_name_…");
+        assert_eq!(result.to_string(), "…e_01_name_01_name_01_name_01_");
 
         let result = result.get();
         assert_eq!(result, "name_01_name_01_name_01_name_01_");
+
+        let result = ContainerImage::from("registry.example.com/org/application:1.2.3");
+        assert_eq!(result.to_string(), "…ple.com/org/application:1.2.3");
+
+        let result = ContainerImage::from(
+            "ghcr.io/org/app:1.2.3@sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+        );
+        assert_eq!(result.to_string(), "ghcr.io/org/app:1.2.3@…");
+
+        let result = ContainerImage::from(
+            "registry.example.com/org/application:1.2.3@sha256:0123456789abcdef",
+        );
+        assert_eq!(result.to_string(), "…e.com/org/application:1.2.3@…");
     }
 
     #[test]
@@ -754,9 +2503,19 @@ mod tests {
         let mut logs = Logs::default();
         let line = log_sanitizer::remove_ansi(input);
 
-        logs.insert(ListItem::new(line.clone()), tz.clone());
-        logs.insert(ListItem::new(line.clone()), tz.clone());
-        logs.insert(ListItem::new(line), tz);
+        logs.insert(
+            Text::from(line.clone()),
+            input.to_owned(),
+            tz.clone(),
+            LogStream::Stdout,
+        );
+        logs.insert(
+            Text::from(line.clone()),
+            input.to_owned(),
+            tz.clone(),
+            LogStream::Stdout,
+        );
+        logs.insert(Text::from(line), input.to_owned(), tz, LogStream::Stdout);
 
         assert_eq!(logs.logs.items.len(), 1);
 
@@ -764,10 +2523,923 @@ mod tests {
         let tz = LogsTz::from(input);
         let line = log_sanitizer::remove_ansi(input);
 
-        logs.insert(ListItem::new(line.clone()), tz.clone());
-        logs.insert(ListItem::new(line.clone()), tz.clone());
-        logs.insert(ListItem::new(line), tz);
+        logs.insert(
+            Text::from(line.clone()),
+            input.to_owned(),
+            tz.clone(),
+            LogStream::Stdout,
+        );
+        logs.insert(
+            Text::from(line.clone()),
+            input.to_owned(),
+            tz.clone(),
+            LogStream::Stdout,
+        );
+        logs.insert(Text::from(line), input.to_owned(), tz, LogStream::Stdout);
 
         assert_eq!(logs.logs.items.len(), 2);
+        assert_eq!(logs.text.len(), 2);
+    }
+
+    #[test]
+    /// Search matches are found, and next/previous match wrap around
+    fn test_container_state_logs_matches() {
+        let mut logs = Logs::default();
+        for (index, text) in ["error one", "info", "error two", "debug"]
+            .iter()
+            .enumerate()
+        {
+            let input = format!("2023-01-14T19:13:3{index}.783138328Z {text}");
+            logs.insert(
+                Text::from(log_sanitizer::remove_ansi(&input)),
+                input.clone(),
+                LogsTz::from(input.as_str()),
+                LogStream::Stdout,
+            );
+        }
+        let mut search = LogSearch::default();
+        "error".chars().for_each(|c| search.push(c));
+        assert_eq!(logs.matches(&search), vec![0, 2]);
+
+        logs.start();
+        logs.next_match(&search);
+        assert_eq!(logs.state().selected(), Some(2));
+        logs.next_match(&search);
+        assert_eq!(logs.state().selected(), Some(0));
+        logs.previous_match(&search);
+        assert_eq!(logs.state().selected(), Some(2));
+
+        let mut search = LogSearch::default();
+        search.toggle_regex();
+        "^.*Z (info|debug)$".chars().for_each(|c| search.push(c));
+        assert_eq!(logs.matches(&search), vec![1, 3]);
+    }
+
+    #[test]
+    /// The selected range is between the anchor and the selected line, in either direction, and still refers to the same lines after older lines are dropped
+    fn test_container_state_logs_selected_range() {
+        let mut logs = Logs::default();
+        for index in 0..5 {
+            let input = format!("2023-01-14T19:13:3{index}.783138328Z line {index}");
+            logs.insert(
+                Text::from(format!("line {index}")),
+                format!("line {index}"),
+                LogsTz::from(input.as_str()),
+                LogStream::Stdout,
+            );
+        }
+        logs.start();
+        assert_eq!(logs.position(), Some(0));
+        assert_eq!(logs.selected_range(None), Some((0, 0)));
+        assert_eq!(logs.get_selected_text(None, false), ["line 0"]);
+
+        logs.next();
+        logs.next();
+        assert_eq!(logs.selected_range(Some(0)), Some((0, 2)));
+        assert_eq!(logs.selected_range(Some(4)), Some((2, 4)));
+        assert_eq!(
+            logs.get_selected_text(Some(3), true),
+            [
+                "2023-01-14T19:13:32.783138328Z line 2",
+                "2023-01-14T19:13:33.783138328Z line 3"
+            ]
+        );
+
+        logs.truncate(4);
+        assert_eq!(logs.position(), Some(2));
+        assert_eq!(logs.selected_range(Some(3)), Some((1, 2)));
+        assert_eq!(logs.get_selected_text(Some(3), false), ["line 2", "line 3"]);
+        // The anchor line has been dropped, so only the selected line is used
+        assert_eq!(logs.selected_range(Some(0)), Some((1, 1)));
+    }
+
+    #[test]
+    /// Severity tokens are matched as whole words, case-insensitive, with the first token in a line used
+    fn test_container_state_logs_severity() {
+        let mut logs = Logs::default();
+        for (index, text) in [
+            "[ERROR] unable to connect",
+            "level=info msg=started",
+            "information only",
+            "WARN: retrying after error",
+            "debug",
+        ]
+        .iter()
+        .enumerate()
+        {
+            let input = format!("2023-01-14T19:13:3{index}.783138328Z {text}");
+            logs.insert(
+                Text::from((*text).to_owned()),
+                (*text).to_owned(),
+                LogsTz::from(input.as_str()),
+                LogStream::Stdout,
+            );
+        }
+        let tokens = BTreeMap::from([
+            ("error".to_owned(), Color::Red),
+            ("info".to_owned(), Color::Green),
+            ("warn".to_owned(), Color::Yellow),
+        ]);
+        assert_eq!(
+            logs.severity(&tokens),
+            vec![(0, Color::Red), (1, Color::Green), (3, Color::Yellow)]
+        );
+        assert!(logs.severity(&BTreeMap::new()).is_empty());
+    }
+
+    #[test]
+    /// Long lines are wrapped by char, keeping the style of each span, and short lines are unchanged
+    fn test_container_state_wrap_text() {
+        let text = Text::from(Line::from(vec![
+            Span::raw("abc"),
+            Span::styled("defgh", Style::default().fg(Color::Red)),
+        ]));
+        let result = wrap_text(&text, 4);
+        let lines = result
+            .lines
+            .iter()
+            .map(|i| {
+                i.spans
+                    .iter()
+                    .map(|s| s.content.to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(lines, vec![vec!["abc", "d"], vec!["efgh"]]);
+        assert_eq!(result.lines[1].spans[0].style.fg, Some(Color::Red));
+
+        let result = wrap_text(&Text::from("abc"), 4);
+        assert_eq!(result, Text::from("abc"));
+        assert_eq!(wrap_text(&Text::from(""), 4).lines.len(), 1);
+    }
+
+    #[test]
+    /// Paging counts each wrapped row, and always moves at least one line
+    fn test_container_state_logs_page() {
+        let mut logs = Logs::default();
+        for (index, text) in ["a", "bbbbbbbbbb", "c", "d", "eeeeeeeeee", "f"]
+            .iter()
+            .enumerate()
+        {
+            let input = format!("2023-01-14T19:13:3{index}.783138328Z {text}");
+            logs.insert(
+                Text::from(text.to_owned()),
+                input.clone(),
+                LogsTz::from(input.as_str()),
+                LogStream::Stdout,
+            );
+        }
+        logs.start();
+
+        // line 1 is 3 rows high when wrapped to 4 chars
+        logs.page_down(4, 4);
+        assert_eq!(logs.state().selected(), Some(2));
+        // line 4 doesn't fit in the remaining row
+        logs.page_down(2, 4);
+        assert_eq!(logs.state().selected(), Some(3));
+        // but is moved to when it's the first line, even though it is taller than the page
+        logs.page_down(2, 4);
+        assert_eq!(logs.state().selected(), Some(4));
+        logs.page_down(2, 4);
+        assert_eq!(logs.state().selected(), Some(5));
+        logs.page_down(2, 4);
+        assert_eq!(logs.state().selected(), Some(5));
+
+        logs.page_up(3, 4);
+        assert_eq!(logs.state().selected(), Some(4));
+        logs.page_up(2, 4);
+        assert_eq!(logs.state().selected(), Some(2));
+        logs.page_up(20, 4);
+        assert_eq!(logs.state().selected(), Some(0));
+
+        // Without wrapping, each line is a single row
+        logs.page_down(3, 20);
+        assert_eq!(logs.state().selected(), Some(3));
+
+        // Without soft-wrapping the width is unbounded, and paging past either end is clamped to the first, or last, line
+        logs.page_up(1, usize::MAX);
+        assert_eq!(logs.state().selected(), Some(2));
+        logs.page_down(50, usize::MAX);
+        assert_eq!(logs.state().selected(), Some(5));
+        logs.page_up(50, usize::MAX);
+        assert_eq!(logs.state().selected(), Some(0));
+    }
+
+    #[test]
+    /// Scrolling away from the last line stops following, and whilst not following the selected line, and the scroll offset, stay on the same line as old lines are removed
+    fn test_container_state_logs_follow() {
+        let mut logs = Logs::default();
+        let insert = |logs: &mut Logs, index: usize| {
+            let input = format!("2023-01-14T19:13:{index:02}.783138328Z line {index}");
+            logs.insert(
+                Text::from(input.clone()),
+                input.clone(),
+                LogsTz::from(input.as_str()),
+                LogStream::Stdout,
+            );
+        };
+        for index in 0..10 {
+            insert(&mut logs, index);
+        }
+        logs.end();
+        assert!(logs.follow());
+
+        logs.previous();
+        assert!(!logs.follow());
+        *logs.state().offset_mut() = 4;
+        for index in 10..12 {
+            insert(&mut logs, index);
+        }
+        logs.truncate(10);
+        assert_eq!(logs.state().selected(), Some(6));
+        assert_eq!(logs.state().offset(), 2);
+        assert!(!logs.follow());
+
+        // Selecting the last line again doesn't resume following, only end() does
+        logs.next();
+        logs.next();
+        logs.next();
+        logs.next();
+        assert_eq!(logs.state().selected(), Some(9));
+        assert!(!logs.follow());
+        logs.end();
+        assert!(logs.follow());
+    }
+
+    #[test]
+    /// A since is either a duration, made of one or more units, or an RFC 3339 timestamp, and is shown in the same format
+    fn test_container_state_since() {
+        assert_eq!("90s".parse::<Since>(), Ok(Since::Relative(90)));
+        assert_eq!("10m".parse::<Since>(), Ok(Since::Relative(600)));
+        assert_eq!("1h30m".parse::<Since>(), Ok(Since::Relative(5400)));
+        assert_eq!("2d".parse::<Since>(), Ok(Since::Relative(172_800)));
+        assert_eq!(
+            "2023-01-14T19:13:30Z".parse::<Since>(),
+            Ok(Since::Absolute(1_673_723_610))
+        );
+        assert_eq!(
+            "2023-01-14T20:13:30+01:00".parse::<Since>(),
+            Ok(Since::Absolute(1_673_723_610))
+        );
+        for input in ["", "0s", "10", "m", "10x", "1h30", "2023-01-14"] {
+            assert!(input.parse::<Since>().is_err(), "{input}");
+        }
+
+        assert_eq!(Since::Relative(90).to_string(), "1m30s");
+        assert_eq!(Since::Relative(5400).to_string(), "1h30m");
+        assert_eq!(Since::Relative(90_000).to_string(), "1d1h");
+        assert_eq!(
+            Since::Absolute(1_673_723_610).to_string(),
+            "2023-01-14T19:13:30Z"
+        );
+
+        assert_eq!(Since::Relative(600).timestamp(1_000), 400);
+        assert_eq!(
+            Since::Absolute(1_673_723_610).timestamp(1_000),
+            1_673_723_610
+        );
+    }
+
+    #[test]
+    /// Lines are kept whatever the filter, so the filter can be changed without refetching, the selected line stays the same, or the closest earlier line if hidden
+    fn test_container_state_logs_stream_filter() {
+        let mut logs = Logs::default();
+        let insert = |logs: &mut Logs, index: usize| {
+            let input = format!("2023-01-14T19:13:{index:02}.783138328Z ");
+            let stream = if index.is_multiple_of(2) {
+                LogStream::Stdout
+            } else {
+                LogStream::Stderr
+            };
+            logs.insert(
+                Text::from(format!("line {index}")),
+                format!("line {index}"),
+                LogsTz::from(input.as_str()),
+                stream,
+            );
+        };
+        let gen_item = |_: &LogsTz, _: LogStream, content: &str| Text::from(content.to_owned());
+        for index in 0..10 {
+            insert(&mut logs, index);
+        }
+        logs.end();
+        logs.previous();
+        logs.previous();
+        assert_eq!(logs.position(), Some(7));
+
+        logs.set_filter(LogStreamFilter::Stderr, gen_item);
+        assert_eq!(logs.filter(), LogStreamFilter::Stderr);
+        assert_eq!(
+            logs.get_text(false),
+            ["line 1", "line 3", "line 5", "line 7", "line 9"]
+        );
+        assert_eq!(logs.state().selected(), Some(3));
+        assert_eq!(logs.position(), Some(7));
+
+        // line 7 isn't stdout, so line 6 is selected instead
+        logs.set_filter(LogStreamFilter::Stdout, gen_item);
+        assert_eq!(logs.to_vec(None).len(), 5);
+        assert_eq!(logs.position(), Some(6));
+
+        // A hidden line is still kept, and counts towards the max lines
+        insert(&mut logs, 11);
+        assert_eq!(logs.to_vec(None).len(), 5);
+        logs.truncate(5);
+        assert_eq!(logs.dropped(), 6);
+        assert_eq!(logs.get_text(false), ["line 6", "line 8"]);
+        assert_eq!(logs.state().selected(), Some(0));
+        assert_eq!(logs.position(), Some(6));
+
+        logs.set_filter(LogStreamFilter::All, gen_item);
+        assert_eq!(
+            logs.get_text(false),
+            ["line 6", "line 7", "line 8", "line 9", "line 11"]
+        );
+        assert_eq!(logs.position(), Some(6));
+        assert_eq!(logs.selected_range(Some(8)), Some((0, 2)));
+
+        // Whilst following, the last line is selected
+        logs.end();
+        logs.set_filter(LogStreamFilter::Stderr, gen_item);
+        assert_eq!(logs.get_text(false), ["line 7", "line 9", "line 11"]);
+        assert_eq!(logs.state().selected(), Some(2));
+        assert!(logs.follow());
+    }
+
+    #[test]
+    /// The visible lines start from the scroll offset, a wrapped line counts as each of its rows, and the first line is always included
+    fn test_container_state_logs_visible_text() {
+        let mut logs = Logs::default();
+        assert!(logs.visible_range(4, 4).is_none());
+        for (index, text) in ["a", "bbbbbbbbbb", "c", "d", "eeeeeeeeee", "f"]
+            .iter()
+            .enumerate()
+        {
+            let input = format!("2023-01-14T19:13:3{index}.783138328Z ");
+            logs.insert(
+                Text::from((*text).to_owned()),
+                (*text).to_owned(),
+                LogsTz::from(input.as_str()),
+                LogStream::Stdout,
+            );
+        }
+
+        // line 1 is 3 rows high when wrapped to 4 chars
+        assert_eq!(logs.visible_range(4, 4), Some((0, 1)));
+        assert_eq!(logs.get_visible_text(4, 4, false), ["a", "bbbbbbbbbb"]);
+        assert_eq!(
+            logs.get_visible_text(3, usize::MAX, false),
+            ["a", "bbbbbbbbbb", "c"]
+        );
+
+        *logs.state().offset_mut() = 4;
+        assert_eq!(logs.visible_range(2, 4), Some((4, 4)));
+        assert_eq!(logs.visible_range(10, 4), Some((4, 5)));
+        assert_eq!(
+            logs.get_visible_text(10, 4, true),
+            [
+                "2023-01-14T19:13:34.783138328Z eeeeeeeeee",
+                "2023-01-14T19:13:35.783138328Z f"
+            ]
+        );
+    }
+
+    #[test]
+    /// Once set, the marker can show only the newer lines, it stays on the same point as older lines are dropped, and clearing it shows every line again
+    fn test_container_state_logs_marker() {
+        let mut logs = Logs::default();
+        let insert = |logs: &mut Logs, index: usize| {
+            let input = format!("2023-01-14T19:13:{index:02}.783138328Z ");
+            logs.insert(
+                Text::from(format!("line {index}")),
+                format!("line {index}"),
+                LogsTz::from(input.as_str()),
+                LogStream::Stdout,
+            );
+        };
+        let gen_item = |_: &LogsTz, _: LogStream, content: &str| Text::from(content.to_owned());
+        for index in 0..4 {
+            insert(&mut logs, index);
+        }
+        logs.end();
+
+        logs.cycle_marker(gen_item);
+        assert_eq!(logs.marker(), Some(LogMarker::Set(4)));
+        assert_eq!(logs.marker_count(), 0);
+        insert(&mut logs, 4);
+        insert(&mut logs, 5);
+        assert_eq!(logs.marker_count(), 2);
+        assert_eq!(logs.get_text(false).len(), 6);
+
+        logs.cycle_marker(gen_item);
+        assert_eq!(logs.marker(), Some(LogMarker::Only(4)));
+        assert_eq!(logs.get_text(false), ["line 4", "line 5"]);
+        assert_eq!(logs.state().selected(), Some(1));
+
+        // New lines are shown, and dropping the older lines doesn't move the marker
+        insert(&mut logs, 6);
+        logs.truncate(4);
+        assert_eq!(logs.dropped(), 3);
+        assert_eq!(logs.get_text(false), ["line 4", "line 5", "line 6"]);
+        assert_eq!(logs.marker_count(), 3);
+
+        // Once the marker is itself dropped, every remaining line is after it
+        logs.truncate(2);
+        assert_eq!(logs.get_text(false), ["line 5", "line 6"]);
+        assert_eq!(logs.marker_count(), 3);
+
+        logs.cycle_marker(gen_item);
+        assert!(logs.marker().is_none());
+        assert_eq!(logs.marker_count(), 0);
+        assert_eq!(logs.get_text(false), ["line 5", "line 6"]);
+
+        // A filter change keeps only the lines after the marker
+        logs.cycle_marker(gen_item);
+        insert(&mut logs, 7);
+        logs.cycle_marker(gen_item);
+        logs.set_filter(LogStreamFilter::All, gen_item);
+        assert_eq!(logs.get_text(false), ["line 7"]);
+    }
+
+    #[test]
+    /// RFC 3339 timestamps, with or without fractional seconds and offsets, are parsed, Docker's zero timestamp is missing
+    fn test_container_state_parse_timestamp() {
+        assert_eq!(parse_timestamp("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(
+            parse_timestamp("2024-02-29T12:34:56.123456789Z"),
+            Some(1_709_210_096)
+        );
+        assert_eq!(
+            parse_timestamp("2024-02-29T13:34:56.1+01:00"),
+            Some(1_709_210_096)
+        );
+        assert_eq!(
+            parse_timestamp("2024-02-29T10:04:56-02:30"),
+            Some(1_709_210_096)
+        );
+        assert_eq!(parse_timestamp("0001-01-01T00:00:00Z"), None);
+        assert_eq!(parse_timestamp(""), None);
+        assert_eq!(parse_timestamp("2024-13-01T00:00:00Z"), None);
+
+        assert_eq!(
+            parse_timestamp_nanos("2024-02-29T12:34:56.123456789Z"),
+            Some(1_709_210_096_123_456_789)
+        );
+        assert_eq!(
+            parse_timestamp_nanos("2024-02-29T13:34:56.5+01:00"),
+            Some(1_709_210_096_500_000_000)
+        );
+        assert_eq!(
+            parse_timestamp_nanos("2024-02-29T12:34:56Z"),
+            Some(1_709_210_096_000_000_000)
+        );
+        assert_eq!(parse_timestamp_nanos("0001-01-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    /// Timestamps are formatted the same as the Docker logs, and can be parsed back
+    fn test_container_state_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01T00:00:00.000000000Z");
+        assert_eq!(
+            format_timestamp(1_673_723_610_783_138_328),
+            "2023-01-14T19:13:30.783138328Z"
+        );
+        assert_eq!(
+            format_timestamp(951_782_400_000_000_001),
+            "2000-02-29T00:00:00.000000001Z"
+        );
+        assert_eq!(
+            parse_timestamp(&format_timestamp(1_709_251_199_000_000_000)),
+            Some(1_709_251_199)
+        );
+    }
+
+    #[test]
+    /// Unix timestamps are formatted in the given format, including leap years, an empty, or invalid, format falls back to the default
+    fn test_container_state_time_format() {
+        let utc = TimeFormat::new(None, TimeZone::Utc);
+        assert_eq!(utc.format(0), "1970-01-01 00:00:00");
+        assert_eq!(utc.format_seconds(951_782_400), "2000-02-29 00:00:00");
+        assert_eq!(utc.format_seconds(1_709_251_199), "2024-02-29 23:59:59");
+
+        let custom = TimeFormat::new(Some("%d/%m/%Y %H:%M:%S%.3f"), TimeZone::Utc);
+        assert_eq!(
+            custom.format(1_673_723_610_783_138_328),
+            "14/01/2023 19:13:30.783"
+        );
+        assert_eq!(
+            TimeFormat::new(Some("%H:%M"), TimeZone::Utc).format(0),
+            "00:00"
+        );
+
+        assert_eq!(TimeFormat::new(Some("%Y-%m-%d %Q"), TimeZone::Utc), utc);
+        assert_eq!(TimeFormat::new(Some(""), TimeZone::Utc), utc);
+        assert_eq!(
+            TimeFormat::default(),
+            TimeFormat::new(None, TimeZone::Local)
+        );
+    }
+
+    #[test]
+    /// Durations are shown in the largest unit, hours also show the minutes
+    fn test_container_state_format_duration() {
+        // Just now, and under a minute, are shown in seconds
+        assert_eq!(format_duration(0), "0s");
+        assert_eq!(format_duration(1), "1s");
+        assert_eq!(format_duration(59), "59s");
+        // Under an hour only the minutes are shown, the seconds are dropped
+        assert_eq!(format_duration(60), "1m");
+        assert_eq!(format_duration(60 * 12 + 5), "12m");
+        assert_eq!(format_duration(3599), "59m");
+        // Under a day, the hours & minutes
+        assert_eq!(format_duration(3600), "1h 0m");
+        assert_eq!(format_duration(3600 * 3 + 60 * 12), "3h 12m");
+        assert_eq!(format_duration(86_399), "23h 59m");
+        // A day or more only shows the days
+        assert_eq!(format_duration(86_400), "1d");
+        assert_eq!(format_duration(86_400 * 2 + 3600 * 5), "2d");
+        assert_eq!(format_duration(86_400 * 400), "400d");
+    }
+
+    #[test]
+    /// Alive containers show their uptime, stopped containers how long ago they finished, and unknown or future times are `--`
+    fn test_container_state_format_uptime() {
+        let mut item = ContainerItem::new(
+            0,
+            "1".into(),
+            "image".to_owned(),
+            false,
+            "name".to_owned(),
+            vec![],
+            State::Running,
+            String::new(),
+        );
+        assert_eq!(item.format_uptime(10_000), "--");
+
+        item.uptime = Some(Uptime::new(
+            Some("1970-01-01T00:00:00Z"),
+            Some("0001-01-01T00:00:00Z"),
+        ));
+        assert_eq!(item.format_uptime(3600 * 3 + 60 * 12), "3h 12m");
+        // Clock skew
+        item.uptime = Some(Uptime::new(Some("1970-01-01T01:00:00Z"), None));
+        assert_eq!(item.format_uptime(60), "--");
+
+        item.state = State::Exited;
+        assert_eq!(item.format_uptime(60), "--");
+        item.uptime = Some(Uptime::new(
+            Some("1970-01-01T00:00:00Z"),
+            Some("1970-01-01T00:01:00Z"),
+        ));
+        assert_eq!(item.format_uptime(86_400 * 2 + 60), "2d ago");
+    }
+
+    #[test]
+    /// Two value columns include their separator, and a row includes the margins, borders, and selected marker
+    fn test_container_state_columns_row_width() {
+        let columns = Columns::new();
+        assert_eq!(columns.width(Header::Name), 4);
+        assert_eq!(columns.width(Header::Memory), 17);
+        assert_eq!(
+            columns.row_width(&[Header::Name, Header::Memory, Header::Id]),
+            40
+        );
+        assert_eq!(columns.row_width(&[]), 5);
+    }
+
+    #[test]
+    /// All cores mode divides by the number of cpus, unless it isn't known
+    fn test_container_state_cpu_mode() {
+        assert_eq!(CpuMode::default().next(), CpuMode::All);
+        assert_eq!(CpuMode::All.next(), CpuMode::Core);
+        assert!((CpuMode::Core.apply(150.0, 4) - 150.0).abs() < f64::EPSILON);
+        assert!((CpuMode::All.apply(150.0, 4) - 37.5).abs() < f64::EPSILON);
+        assert!((CpuMode::All.apply(150.0, 0) - 150.0).abs() < f64::EPSILON);
+        assert!((CpuMode::All.to_core(37.5, 4) - 150.0).abs() < f64::EPSILON);
+        assert_eq!(CpuMode::Core.label(), "cpu");
+        assert_eq!(CpuMode::All.label(), "cpu/all");
+    }
+
+    #[test]
+    /// Only an alive container, started within the window, is fresh
+    fn test_container_state_started_within() {
+        let mut item = ContainerItem::new(
+            0,
+            "1".into(),
+            "image".to_owned(),
+            false,
+            "name".to_owned(),
+            vec![],
+            State::Running,
+            String::new(),
+        );
+        assert_eq!(item.started_within(10, 30), None);
+
+        item.uptime = Some(Uptime::new(Some("1970-01-01T00:00:00Z"), None));
+        assert_eq!(item.started_within(10, 30), Some(10));
+        assert_eq!(item.started_within(29, 30), Some(29));
+        assert_eq!(item.started_within(30, 30), None);
+        assert_eq!(item.started_within(10, 0), None);
+
+        item.state = State::Exited;
+        assert_eq!(item.started_within(10, 30), None);
+    }
+
+    #[test]
+    /// The none driver is always unavailable, any other driver only once a read has failed
+    fn test_container_state_logs_unavailable() {
+        let mut item = ContainerItem::new(
+            0,
+            "1".into(),
+            "image".to_owned(),
+            false,
+            "name".to_owned(),
+            vec![],
+            State::Running,
+            String::new(),
+        );
+        assert!(item.get_logs_unavailable().is_none());
+
+        item.logs_unreadable = true;
+        assert_eq!(
+            item.get_logs_unavailable().unwrap(),
+            "This container's logging driver doesn't support log reading"
+        );
+
+        item.log_driver = Some(String::from("syslog"));
+        assert_eq!(
+            item.get_logs_unavailable().unwrap(),
+            "This container's logging driver, syslog, doesn't support log reading"
+        );
+        item.logs_unreadable = false;
+        assert!(item.get_logs_unavailable().is_none());
+
+        item.log_driver = Some(String::from("none"));
+        assert_eq!(
+            item.get_logs_unavailable().unwrap(),
+            "This container's logging driver, none, doesn't keep any logs"
+        );
+    }
+
+    #[test]
+    /// Memory percentage uses the container limit, or the host memory when there's no limit, and is None when neither is known
+    fn test_container_state_get_mem_percent() {
+        let mut item = ContainerItem::new(
+            0,
+            "1".into(),
+            "image".to_owned(),
+            false,
+            "name".to_owned(),
+            vec![],
+            State::Running,
+            String::new(),
+        );
+        assert!(item.get_mem_percent(1000).is_none());
+
+        item.mem_stats.push_back(ByteStats::new(250));
+        assert!(item.get_mem_percent(0).is_none());
+        assert_eq!(
+            item.get_mem_percent(1000),
+            Some((25.0, ByteStats::new(1000)))
+        );
+
+        item.mem_limit = ByteStats::new(500);
+        assert_eq!(
+            item.get_mem_percent(1000),
+            Some((50.0, ByteStats::new(500)))
+        );
+        assert_eq!(item.get_mem_percent(0), Some((50.0, ByteStats::new(500))));
+    }
+
+    #[test]
+    /// An empty restart policy is shown as `no`, an `on-failure` policy includes the maximum retry count, and a high restart count is flagged
+    fn test_container_state_restarts() {
+        let restarts = Restarts::new(Some(2), Some("always"), Some(0));
+        assert_eq!(restarts.to_string(), "restarts 2 - policy always");
+        assert!(!restarts.is_flapping());
+
+        let restarts = Restarts::new(None, Some(""), None);
+        assert_eq!(restarts.to_string(), "restarts 0 - policy no");
+
+        let restarts = Restarts::new(Some(5), Some("on-failure"), Some(10));
+        assert_eq!(restarts.to_string(), "restarts 5 - policy on-failure:10");
+        assert!(restarts.is_flapping());
+
+        let restarts = Restarts::new(Some(-1), Some("on-failure"), Some(0));
+        assert_eq!(restarts.to_string(), "restarts 0 - policy on-failure");
+    }
+
+    #[test]
+    /// ByteRate displayed as a per second rate, or -- if unknown
+    fn test_container_state_byterate_to_string() {
+        assert_eq!(ByteRate::new(None).to_string(), "--");
+        assert_eq!(ByteRate::new(Some(0)).to_string(), "0.00 kB/s");
+        assert_eq!(ByteRate::new(Some(1_200_000)).to_string(), "1.20 MB/s");
+    }
+
+    #[test]
+    /// IoRates calculated from two IoTotals, no network gives no network rate
+    fn test_container_state_io_rates() {
+        let previous = IoTotals {
+            net: Some((1000, 2000)),
+            blk_read: 0,
+            blk_write: 500,
+        };
+        let current = IoTotals {
+            net: Some((3000, 2000)),
+            blk_read: 4000,
+            blk_write: 500,
+        };
+        let result = IoRates::calculate(&previous, &current, Duration::from_secs(2)).unwrap();
+        assert_eq!(result.rx, ByteRate::new(Some(1000)));
+        assert_eq!(result.tx, ByteRate::new(Some(0)));
+        assert_eq!(result.blk_read, ByteRate::new(Some(2000)));
+        assert_eq!(result.blk_write, ByteRate::new(Some(0)));
+        assert_eq!(result.net_total(), 1000);
+        assert_eq!(result.blk_total(), 2000);
+
+        assert!(IoRates::calculate(&previous, &current, Duration::ZERO).is_none());
+
+        let current = IoTotals {
+            net: None,
+            ..current
+        };
+        let result = IoRates::calculate(&previous, &current, Duration::from_secs(1)).unwrap();
+        assert_eq!(result.rx, ByteRate::new(None));
+        assert_eq!(result.tx, ByteRate::new(None));
+
+        // A counter reset, e.g. a container restart, doesn't underflow
+        let now = Instant::now();
+        let mut item = crate::tests::gen_item(&crate::app_data::ContainerId::from("1"), 1);
+        item.update_io(previous, now);
+        item.update_io(IoTotals::default(), now + Duration::from_secs(1));
+        assert_eq!(item.io_rates.blk_write, ByteRate::new(Some(0)));
+    }
+
+    #[test]
+    /// The json output uses the latest stats, lowercase state & health names, and null for anything not yet known
+    fn test_container_state_output_json() {
+        let mut item = crate::tests::gen_item(&crate::app_data::ContainerId::from("1"), 1);
+        let result = serde_json::to_value(ContainerOutput::from(&item)).unwrap();
+        assert_eq!(result["id"], "1");
+        assert_eq!(result["name"], "container_1");
+        assert_eq!(result["image"], "image_1");
+        assert_eq!(result["state"], "running");
+        assert_eq!(result["status"], "Up 1 hour");
+        assert!(result["health"].is_null());
+        assert!(result["cpu_percent"].is_null());
+        assert!(result["memory_bytes"].is_null());
+        assert!(result["io_rates"]["rx"].is_null());
+        assert_eq!(result["ports"][0]["private"], 8001);
+
+        item.cpu_stats.push_back(CpuStats::new(1.5));
+        item.cpu_stats.push_back(CpuStats::new(12.5));
+        item.mem_stats.push_back(ByteStats::new(1024));
+        item.mem_limit = ByteStats::new(4096);
+        item.health = Some(Health::Unhealthy);
+        item.state = State::Exited;
+        let result = serde_json::to_value(ContainerOutput::from(&item)).unwrap();
+        assert_eq!(result["cpu_percent"], 12.5);
+        assert_eq!(result["memory_bytes"], 1024);
+        assert_eq!(result["memory_limit_bytes"], 4096);
+        assert_eq!(result["health"], "unhealthy");
+        assert_eq!(result["state"], "exited");
+    }
+
+    #[test]
+    /// A volume mount uses its name as the source, a bind mount its host path, and rw of false is read-only
+    fn test_container_state_mount_from() {
+        let mount = MountPoint {
+            typ: Some(MountPointTypeEnum::VOLUME),
+            name: Some(String::from("data")),
+            source: Some(String::from("/var/lib/docker/volumes/data/_data")),
+            destination: Some(String::from("/data")),
+            rw: Some(true),
+            ..Default::default()
+        };
+        let result = ContainerMount::from(&mount);
+        assert_eq!(result.typ, "volume");
+        assert_eq!(result.source, "data");
+        assert_eq!(result.destination, "/data");
+        assert!(!result.read_only);
+
+        let mount = MountPoint {
+            typ: Some(MountPointTypeEnum::BIND),
+            name: None,
+            source: Some(String::from("/home/user/nginx.conf")),
+            destination: Some(String::from("/etc/nginx/nginx.conf")),
+            rw: Some(false),
+            ..Default::default()
+        };
+        let result = ContainerMount::from(&mount);
+        assert_eq!(result.typ, "bind");
+        assert_eq!(result.source, "/home/user/nginx.conf");
+        assert!(result.read_only);
+    }
+
+    #[test]
+    /// The entrypoint & cmd are shown in exec form, and an unset, or empty, entrypoint or cmd is None
+    fn test_container_state_command_from() {
+        let config = ContainerConfig {
+            entrypoint: Some(vec![String::from("/docker-entrypoint.sh")]),
+            cmd: Some(vec![
+                String::from("nginx"),
+                String::from("-g"),
+                String::from("daemon off;"),
+            ]),
+            ..Default::default()
+        };
+        let result = ContainerCommand::from(&config);
+        assert_eq!(
+            result.entrypoint,
+            Some(String::from(r#"["/docker-entrypoint.sh"]"#))
+        );
+        assert_eq!(
+            result.cmd,
+            Some(String::from(r#"["nginx", "-g", "daemon off;"]"#))
+        );
+
+        let config = ContainerConfig {
+            entrypoint: Some(vec![]),
+            cmd: None,
+            ..Default::default()
+        };
+        assert_eq!(ContainerCommand::from(&config), ContainerCommand::default());
+    }
+
+    #[test]
+    /// The primary network is first, networks without an address are skipped, and host networking has no address of its own
+    fn test_container_state_address_from() {
+        let gen_inspect =
+            |network_mode: &str, networks: &[(&str, &str)]| ContainerInspectResponse {
+                host_config: Some(HostConfig {
+                    network_mode: Some(network_mode.to_owned()),
+                    ..Default::default()
+                }),
+                network_settings: Some(NetworkSettings {
+                    networks: Some(
+                        networks
+                            .iter()
+                            .map(|(name, ip)| {
+                                (
+                                    (*name).to_owned(),
+                                    EndpointSettings {
+                                        ip_address: Some((*ip).to_owned()),
+                                        ..Default::default()
+                                    },
+                                )
+                            })
+                            .collect::<HashMap<_, _>>(),
+                    ),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+
+        let result = ContainerAddress::from(&gen_inspect("default", &[("bridge", "172.17.0.2")]));
+        assert_eq!(result.primary(), Some(String::from("172.17.0.2")));
+        assert_eq!(result.to_string(), "172.17.0.2");
+
+        let result = ContainerAddress::from(&gen_inspect(
+            "frontend",
+            &[
+                ("backend", "172.18.0.2"),
+                ("frontend", "172.19.0.3"),
+                ("stopped", ""),
+            ],
+        ));
+        assert_eq!(result.primary(), Some(String::from("172.19.0.3")));
+        assert_eq!(
+            result.to_string(),
+            "172.19.0.3 (frontend), 172.18.0.2 (backend)"
+        );
+
+        let result = ContainerAddress::from(&gen_inspect("host", &[("host", "")]));
+        assert_eq!(result, ContainerAddress::Host);
+        assert_eq!(result.primary(), Some(String::from("host")));
+        assert_eq!(result.to_string(), "host");
+
+        let result = ContainerAddress::from(&gen_inspect("none", &[("none", "")]));
+        assert_eq!(result.primary(), None);
+        assert_eq!(result.to_string(), "none");
+
+        let mut item = ContainerItem::new(
+            0,
+            "1".into(),
+            "image".to_owned(),
+            false,
+            "name".to_owned(),
+            vec![],
+            State::Running,
+            String::new(),
+        );
+        assert_eq!(item.format_ip(), "--");
+        item.address = Some(ContainerAddress::from(&gen_inspect(
+            "bridge",
+            &[("bridge", "172.17.0.2")],
+        )));
+        assert_eq!(item.format_ip(), "172.17.0.2");
+        item.state = State::Exited;
+        assert_eq!(item.format_ip(), "--");
     }
 }
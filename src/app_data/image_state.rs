@@ -0,0 +1,127 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use bollard::service::ImageSummary;
+
+use super::{ByteStats, TimeFormat};
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct ImageId(String);
+
+impl From<&str> for ImageId {
+    fn from(x: &str) -> Self {
+        Self(x.to_owned())
+    }
+}
+
+impl ImageId {
+    pub const fn get(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Only return the first 8 chars of the id, without the `sha256:` prefix, in the same way as a ContainerId
+    pub fn get_short(&self) -> String {
+        let id = self.0.split_once(':').map_or(self.0.as_str(), |i| i.1);
+        id.chars().take(8).collect::<String>()
+    }
+}
+
+impl Ord for ImageId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for ImageId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// An image, as shown in the images view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageItem {
+    pub containers: usize,
+    pub created: i64,
+    pub id: ImageId,
+    pub size: ByteStats,
+    pub tags: String,
+}
+
+impl ImageItem {
+    /// Create an image from the docker summary, containers is the number of containers, running or not, that use this image.
+    /// Dangling images have no tags, and are shown as `<none>:<none>`, in the same way as the docker cli
+    pub fn new(summary: &ImageSummary, containers: &HashMap<String, usize>) -> Self {
+        let tags = summary
+            .repo_tags
+            .iter()
+            .filter(|i| i.as_str() != "<none>:<none>")
+            .cloned()
+            .collect::<Vec<_>>();
+        let tags = if tags.is_empty() {
+            String::from("<none>:<none>")
+        } else {
+            tags.join(", ")
+        };
+        Self {
+            containers: containers.get(&summary.id).copied().unwrap_or_default(),
+            created: summary.created,
+            id: ImageId::from(summary.id.as_str()),
+            size: ByteStats::new(u64::try_from(summary.size).unwrap_or_default()),
+            tags,
+        }
+    }
+
+    /// An image used by any container, running or not, can only be removed with force
+    pub const fn in_use(&self) -> bool {
+        self.containers > 0
+    }
+
+    /// When the image was created, in the configured time format
+    pub fn get_created(&self, time_format: &TimeFormat) -> String {
+        time_format.format_seconds(self.created)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::ImageSummary;
+
+    use super::{ImageId, ImageItem};
+    use crate::app_data::{TimeFormat, TimeZone};
+
+    #[test]
+    /// Short id has the sha256 prefix removed, dangling images have no tags, and containers are counted
+    fn test_image_state_new() {
+        assert_eq!(ImageId::from("sha256:0123456789ab").get_short(), "01234567");
+        assert_eq!(ImageId::from("0123456789ab").get_short(), "01234567");
+
+        let summary = ImageSummary {
+            id: "sha256:0123456789ab".to_owned(),
+            repo_tags: vec!["redis:latest".to_owned(), "redis:7".to_owned()],
+            created: 0,
+            size: 2000,
+            ..Default::default()
+        };
+        let containers = HashMap::from([("sha256:0123456789ab".to_owned(), 2)]);
+        let image = ImageItem::new(&summary, &containers);
+        assert_eq!(image.tags, "redis:latest, redis:7");
+        assert_eq!(image.containers, 2);
+        assert!(image.in_use());
+        assert_eq!(image.size.to_string(), "2.00 kB");
+        assert_eq!(
+            image.get_created(&TimeFormat::new(None, TimeZone::Utc)),
+            "1970-01-01 00:00:00"
+        );
+
+        let summary = ImageSummary {
+            repo_tags: vec!["<none>:<none>".to_owned()],
+            ..summary
+        };
+        let image = ImageItem::new(&summary, &HashMap::new());
+        assert_eq!(image.tags, "<none>:<none>");
+        assert!(!image.in_use());
+    }
+}
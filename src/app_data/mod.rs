@@ -1,39 +1,206 @@
-use bollard::models::ContainerSummary;
+use bollard::{models::ContainerSummary, system::Version};
+use clap::ValueEnum;
 use core::fmt;
 use parking_lot::Mutex;
-use ratatui::widgets::{ListItem, ListState};
+use ratatui::{
+    style::{Color, Style},
+    text::{Span, Text},
+    widgets::{ListItem, ListState},
+};
+use serde::{Deserialize, Serialize};
 use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    net::IpAddr,
     sync::Arc,
-    time::{SystemTime, UNIX_EPOCH},
+    time::{Duration, Instant},
 };
 
+mod clock;
 mod container_state;
+mod image_state;
+mod network_state;
+mod volume_state;
 
 use crate::{
     app_error::AppError,
+    config::{Theme, ViewConfig},
     parse_args::CliArgs,
-    ui::{log_sanitizer, GuiState, Status},
+    ui::{log_sanitizer, GuiState, IdMode, LogSearch, Status},
     ENTRY_POINT,
 };
+pub use clock::*;
 pub use container_state::*;
+pub use image_state::*;
+pub use network_state::*;
+pub use volume_state::*;
+
+/// Bounds of the update interval, in ms, when changed at runtime
+const MIN_INTERVAL: u32 = 100;
+const MAX_INTERVAL: u32 = 10_000;
+/// Width of the mark, and its space, drawn before the name of a marked container
+const MARK_WIDTH: u8 = 2;
+/// Width of the suffix drawn after the name of the container that oxker is running in
+const SELF_WIDTH: u8 = 7;
+/// Width of the health indicator, and its space, drawn after the state of a container with a healthcheck
+const HEALTH_WIDTH: u8 = 2;
+/// Label set by docker compose, used to group containers by project
+const COMPOSE_PROJECT: &str = "com.docker.compose.project";
+/// Label set by docker compose, the service that a container is a replica of
+const COMPOSE_SERVICE: &str = "com.docker.compose.service";
+/// Label to set the shells, comma separated, to try first when execing into a container, e.g. `oxker.shell=bash,ash`
+const SHELL_LABEL: &str = "oxker.shell";
+
+/// The order that the sort headers are cycled through
+const SORT_HEADERS: [Header; 16] = [
+    Header::Name,
+    Header::State,
+    Header::Status,
+    Header::Cpu,
+    Header::Memory,
+    Header::Id,
+    Header::Image,
+    Header::Rx,
+    Header::Tx,
+    Header::NetIo,
+    Header::BlockIo,
+    Header::Uptime,
+    Header::ImageSize,
+    Header::Project,
+    Header::Service,
+    Header::Ip,
+];
+
+/// The container engine behind the Docker socket, Podman serves a Docker compatible api, but some responses differ
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Engine {
+    #[default]
+    Docker,
+    Podman,
+}
+
+impl Engine {
+    /// Podman lists itself as a `Podman Engine` component in the version response
+    pub fn new(version: &Version) -> Self {
+        let is_podman = version.components.as_ref().is_some_and(|components| {
+            components
+                .iter()
+                .any(|i| i.name.to_lowercase().contains("podman"))
+        });
+        if is_podman {
+            Self::Podman
+        } else {
+            Self::Docker
+        }
+    }
+}
+
+impl fmt::Display for Engine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Docker => "docker",
+            Self::Podman => "podman",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// Round trip time at which a ping is flagged as slow
+const SLOW_PING: Duration = Duration::from_millis(250);
 
+/// How healthy the connection to the daemon looks, from the most recent ping
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PingHealth {
+    Healthy,
+    Slow,
+    Failing,
+}
+
+/// The round trip time of the most recent successful ping of the daemon, and the number of pings that have failed since
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Ping {
+    latency: Option<Duration>,
+    failures: u8,
+}
+
+impl Ping {
+    /// `None` until the daemon has been pinged
+    pub fn health(&self) -> Option<PingHealth> {
+        if self.failures > 0 {
+            Some(PingHealth::Failing)
+        } else {
+            self.latency.map(|latency| {
+                if latency >= SLOW_PING {
+                    PingHealth::Slow
+                } else {
+                    PingHealth::Healthy
+                }
+            })
+        }
+    }
+}
+
+/// The round trip time in ms, or that the ping has failed
+impl fmt::Display for Ping {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.failures, self.latency) {
+            (0, Some(latency)) => write!(f, "● {}ms", latency.as_millis()),
+            (0, None) => Ok(()),
+            _ => write!(f, "● no response"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum SortedOrder {
     Asc,
     Desc,
 }
 
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// The columns of the containers panel, in the order they are shown, unless set in the config file.
+/// The image size column is only shown if set in the config file, as it needs an extra request to the Docker daemon each update.
+/// The compose project & service columns are also only shown if set in the config file
+pub const DEFAULT_COLUMNS: [Header; 12] = [
+    Header::Name,
+    Header::State,
+    Header::Status,
+    Header::Cpu,
+    Header::Memory,
+    Header::Id,
+    Header::Image,
+    Header::Rx,
+    Header::Tx,
+    Header::NetIo,
+    Header::BlockIo,
+    Header::Uptime,
+];
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+#[value(rename_all = "snake_case")]
 pub enum Header {
     State,
     Status,
     Cpu,
+    #[serde(alias = "mem")]
+    #[value(alias = "mem")]
     Memory,
     Id,
     Name,
     Image,
     Rx,
     Tx,
+    #[serde(alias = "net")]
+    #[value(alias = "net")]
+    NetIo,
+    #[serde(alias = "block")]
+    #[value(alias = "block")]
+    BlockIo,
+    Uptime,
+    ImageSize,
+    Project,
+    Service,
+    Ip,
 }
 
 /// Convert Header enum into strings to display
@@ -49,6 +216,13 @@ impl fmt::Display for Header {
             Self::Image => "image",
             Self::Rx => "↓ rx",
             Self::Tx => "↑ tx",
+            Self::NetIo => "net ↓/↑",
+            Self::BlockIo => "disk r/w",
+            Self::Uptime => "uptime",
+            Self::ImageSize => "image size",
+            Self::Project => "project",
+            Self::Service => "service",
+            Self::Ip => "ip",
         };
         write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
     }
@@ -59,8 +233,37 @@ impl fmt::Display for Header {
 #[cfg(not(test))]
 pub struct AppData {
     containers: StatefulList<ContainerItem>,
+    hidden_containers: Vec<ContainerItem>,
+    selected_container: Option<ContainerId>,
+    filter_term: Option<String>,
+    search_term: Option<String>,
+    state_filter: StateFilter,
+    summary: Summary,
+    grouped: bool,
+    collapsed_groups: HashSet<Option<String>>,
+    marked: HashSet<ContainerId>,
+    images: StatefulList<ImageItem>,
+    image_sizes: HashMap<String, ByteStats>,
+    networks: StatefulList<NetworkItem>,
+    network_inspect: Option<(NetworkId, String)>,
+    volumes: StatefulList<VolumeItem>,
+    docker_interval: u32,
+    host_memory: u64,
+    engine: Engine,
+    ping: Ping,
+    time_format: TimeFormat,
+    redraw: bool,
     error: Option<AppError>,
     sorted_by: Option<(Header, SortedOrder)>,
+    logs_with_timestamps: bool,
+    log_streams: LogStreamFilter,
+    log_since: Option<Since>,
+    cpu_mode: CpuMode,
+    memory_unit: MemoryUnit,
+    shells: Vec<String>,
+    theme: Theme,
+    visible_columns: Vec<Header>,
+    clock: Arc<dyn Clock>,
     pub args: CliArgs,
 }
 
@@ -68,43 +271,238 @@ pub struct AppData {
 #[cfg(test)]
 pub struct AppData {
     pub containers: StatefulList<ContainerItem>,
+    pub hidden_containers: Vec<ContainerItem>,
+    pub selected_container: Option<ContainerId>,
+    pub filter_term: Option<String>,
+    pub search_term: Option<String>,
+    pub state_filter: StateFilter,
+    pub summary: Summary,
+    pub grouped: bool,
+    pub collapsed_groups: HashSet<Option<String>>,
+    pub marked: HashSet<ContainerId>,
+    pub images: StatefulList<ImageItem>,
+    pub image_sizes: HashMap<String, ByteStats>,
+    pub networks: StatefulList<NetworkItem>,
+    pub network_inspect: Option<(NetworkId, String)>,
+    pub volumes: StatefulList<VolumeItem>,
+    pub docker_interval: u32,
+    pub host_memory: u64,
+    pub engine: Engine,
+    pub ping: Ping,
+    pub time_format: TimeFormat,
+    pub redraw: bool,
     pub error: Option<AppError>,
     pub sorted_by: Option<(Header, SortedOrder)>,
+    pub logs_with_timestamps: bool,
+    pub log_streams: LogStreamFilter,
+    pub log_since: Option<Since>,
+    pub cpu_mode: CpuMode,
+    pub memory_unit: MemoryUnit,
+    pub shells: Vec<String>,
+    pub theme: Theme,
+    pub visible_columns: Vec<Header>,
+    pub clock: Arc<dyn Clock>,
     pub args: CliArgs,
 }
 
 impl AppData {
     /// Generate a default app_state
-    pub fn default(args: CliArgs) -> Self {
+    pub fn default(args: CliArgs, theme: Theme) -> Self {
+        let order = if args.sort_desc {
+            SortedOrder::Desc
+        } else {
+            SortedOrder::Asc
+        };
+        let sorted_by = args.sort.map(|header| (header, order));
+        let state_filter = args.state.unwrap_or_default();
         Self {
+            logs_with_timestamps: args.timestamp,
+            log_streams: LogStreamFilter::default(),
+            log_since: args.since,
+            docker_interval: args.docker_interval,
+            cpu_mode: CpuMode::default(),
+            memory_unit: MemoryUnit::default(),
+            shells: vec![],
+            theme,
             args,
             containers: StatefulList::new(vec![]),
+            hidden_containers: vec![],
+            selected_container: None,
+            filter_term: None,
+            search_term: None,
+            state_filter,
+            summary: Summary::Hidden,
+            grouped: false,
+            collapsed_groups: HashSet::new(),
+            marked: HashSet::new(),
+            images: StatefulList::new(vec![]),
+            image_sizes: HashMap::new(),
+            networks: StatefulList::new(vec![]),
+            network_inspect: None,
+            volumes: StatefulList::new(vec![]),
+            host_memory: 0,
+            engine: Engine::default(),
+            ping: Ping::default(),
+            time_format: TimeFormat::default(),
             error: None,
-            sorted_by: None,
+            redraw: true,
+            sorted_by,
+            visible_columns: DEFAULT_COLUMNS.to_vec(),
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Check if the docker data has changed since the last frame was drawn, resetting the flag
+    pub const fn take_redraw(&mut self) -> bool {
+        std::mem::replace(&mut self.redraw, false)
+    }
+
+    /// Get the columns of the containers panel, in the order they are shown
+    pub fn get_visible_columns(&self) -> Vec<Header> {
+        self.visible_columns.clone()
+    }
+
+    /// Get how the cpu usage is shown
+    pub const fn get_cpu_mode(&self) -> CpuMode {
+        self.cpu_mode
+    }
+
+    /// Switch between per core, and all cores, cpu usage, returns the new mode so that it can be shown in the info box.
+    /// The cpu history of every container is converted, so the charts stay continuous
+    pub fn cycle_cpu_mode(&mut self) -> CpuMode {
+        let previous = self.cpu_mode;
+        self.cpu_mode = previous.next();
+        for container in &mut self.containers.items {
+            let cpus = container.cpus;
+            for cpu in &mut container.cpu_stats {
+                let core = previous.to_core(cpu.get_value(), cpus);
+                *cpu = CpuStats::new(self.cpu_mode.apply(core, cpus));
+            }
+        }
+        self.cpu_mode
+    }
+
+    /// Get the unit that memory is shown in
+    pub const fn get_memory_unit(&self) -> MemoryUnit {
+        self.memory_unit
+    }
+
+    /// Set the unit that memory is shown in, as read from the "--memory-unit" argument, or the config file
+    pub const fn set_memory_unit(&mut self, memory_unit: MemoryUnit) {
+        self.memory_unit = memory_unit;
+    }
+
+    /// Change to the next memory unit, returns the new unit so that it can be shown in the info box
+    pub const fn cycle_memory_unit(&mut self) -> MemoryUnit {
+        self.memory_unit = self.memory_unit.next();
+        self.memory_unit
+    }
+
+    /// The image sizes are only requested from the Docker daemon if they're shown, or used to sort the containers
+    pub fn image_sizes_needed(&self) -> bool {
+        self.visible_columns.contains(&Header::ImageSize)
+            || self
+                .sorted_by
+                .is_some_and(|(header, _)| header == Header::ImageSize)
+    }
+
+    /// Set which columns of the containers panel are shown, and in what order, as read from the config file
+    pub fn set_visible_columns(&mut self, columns: Vec<Header>) {
+        self.visible_columns = columns;
+    }
+
+    /// Set the total memory of the Docker host, used for the memory gauge of containers without a memory limit
+    pub const fn set_host_memory(&mut self, host_memory: u64) {
+        self.host_memory = host_memory;
+    }
+
+    /// Set the engine behind the Docker socket, read from the version response once connected
+    pub const fn set_engine(&mut self, engine: Engine) {
+        self.engine = engine;
+    }
+
+    /// Get the engine behind the Docker socket
+    pub const fn get_engine(&self) -> Engine {
+        self.engine
+    }
+
+    /// Record the round trip time of a ping, or `None` if it failed, returning the number of pings that have failed in a row.
+    /// Only a change to the shown latency, or health, needs a redraw
+    pub fn set_ping(&mut self, round_trip: Option<Duration>) -> u8 {
+        let previous = self.ping;
+        match round_trip {
+            Some(latency) => {
+                self.ping = Ping {
+                    latency: Some(latency),
+                    failures: 0,
+                };
+            }
+            None => self.ping.failures = self.ping.failures.saturating_add(1),
         }
+        if self.ping.to_string() != previous.to_string() {
+            self.redraw = true;
+        }
+        self.ping.failures
+    }
+
+    /// Get the most recent ping of the daemon
+    pub const fn get_ping(&self) -> Ping {
+        self.ping
+    }
+
+    /// Get the restart count & restart policy of the selected container
+    pub fn get_selected_restarts(&self) -> Option<Restarts> {
+        self.get_selected_container()
+            .and_then(|i| i.restarts.clone())
     }
 
-    /// Current time as unix timestamp
-    #[allow(clippy::expect_used)]
-    fn get_systemtime() -> u64 {
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("In our known reality, this error should never occur")
-            .as_secs()
+    /// Get the memory usage of the selected container, as a percentage of its limit, and the limit itself
+    pub fn get_mem_gauge(&self) -> Option<(f64, ByteStats)> {
+        self.get_selected_container()
+            .and_then(|i| i.get_mem_percent(self.host_memory))
+    }
+
+    /// Get the color theme
+    pub fn get_theme(&self) -> Theme {
+        self.theme.clone()
+    }
+
+    /// Current time as unix timestamp, in seconds, read from the clock
+    pub fn now(&self) -> i64 {
+        self.clock.now()
     }
 
-    /// Container sort related methods
+    // Container sort related methods
 
-    /// Change the sorted order, also set the selected container state to match new order
+    /// Change the sorted order, the selected container is kept selected, see `sort_containers()`
     fn set_sorted(&mut self, x: Option<(Header, SortedOrder)>) {
         self.sorted_by = x;
         self.sort_containers();
-        self.containers
-            .state
-            .select(self.containers.items.iter().position(|i| {
-                self.get_selected_container_id()
-                    .map_or(false, |id| i.id == id)
-            }));
+    }
+
+    /// Change the sorted header to the next header in the column order, after the final header sorting is removed
+    /// The current order, ascending or descending, is kept
+    pub fn cycle_sort_header(&mut self) {
+        let output = match self.get_sorted() {
+            None => Some((SORT_HEADERS[0], SortedOrder::Asc)),
+            Some((header, order)) => SORT_HEADERS
+                .iter()
+                .position(|i| i == &header)
+                .and_then(|i| SORT_HEADERS.get(i + 1))
+                .map(|i| (*i, order)),
+        };
+        self.set_sorted(output);
+    }
+
+    /// Flip the order of the current sort, between ascending and descending
+    pub fn reverse_sort_order(&mut self) {
+        if let Some((header, order)) = self.get_sorted() {
+            let order = match order {
+                SortedOrder::Asc => SortedOrder::Desc,
+                SortedOrder::Desc => SortedOrder::Asc,
+            };
+            self.set_sorted(Some((header, order)));
+        }
     }
 
     /// Remove the sorted header & order, and sort by default - created datetime
@@ -126,13 +524,35 @@ impl AppData {
         self.set_sorted(output);
     }
 
+    /// Restore the saved sort, state filter, grouping, and log timestamps, the "-t", "--sort", and "--state", arguments take priority over the saved settings
+    pub fn restore_view(&mut self, view: &ViewConfig) {
+        if let Some(header) = view.sort.filter(|_| self.args.sort.is_none()) {
+            self.sorted_by = Some((header, view.sort_order.unwrap_or(SortedOrder::Asc)));
+        }
+        if let Some(state_filter) = view.state_filter.filter(|_| self.args.state.is_none()) {
+            self.state_filter = state_filter;
+        }
+        if let Some(grouped) = view.grouped {
+            self.grouped = grouped;
+        }
+        if let Some(summary) = view.summary {
+            self.summary = summary;
+        }
+        if let Some(timestamps) = view.log_timestamps.filter(|_| self.args.timestamp) {
+            self.logs_with_timestamps = timestamps;
+        }
+    }
+
     pub const fn get_sorted(&self) -> Option<(Header, SortedOrder)> {
         self.sorted_by
     }
 
-    /// Sort the containers vec, based on a heading (and if clash, then by name), either ascending or descending,
+    /// Sort the containers vec, based on a heading (and if clash, then by name, and then id), either ascending or descending,
     /// If not sort set, then sort by created time
+    /// The sort is stable, so equal containers don't jump around, and the selected container stays selected even if it's index changes
+    #[allow(clippy::too_many_lines)]
     pub fn sort_containers(&mut self) {
+        let now = self.now();
         if let Some((head, ord)) = self.sorted_by {
             let sort_closure = |a: &ContainerItem, b: &ContainerItem| -> std::cmp::Ordering {
                 let item_ord = match ord {
@@ -145,46 +565,104 @@ impl AppData {
                         .state
                         .order()
                         .cmp(&item_ord.1.state.order())
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get()))
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
                     Header::Status => item_ord
                         .0
                         .status
                         .cmp(&item_ord.1.status)
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get()))
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
                     Header::Cpu => item_ord
                         .0
                         .cpu_stats
                         .back()
                         .cmp(&item_ord.1.cpu_stats.back())
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get()))
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
                     Header::Memory => item_ord
                         .0
                         .mem_stats
                         .back()
                         .cmp(&item_ord.1.mem_stats.back())
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get()))
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
 
                     Header::Id => item_ord
                         .0
                         .id
                         .cmp(&item_ord.1.id)
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get()))
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
                     Header::Image => item_ord
                         .0
                         .image
                         .get()
                         .cmp(item_ord.1.image.get())
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get()))
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
                     Header::Rx => item_ord
                         .0
                         .rx
                         .cmp(&item_ord.1.rx)
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get()))
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
                     Header::Tx => item_ord
                         .0
                         .tx
                         .cmp(&item_ord.1.tx)
-                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get()))
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
+                    Header::NetIo => item_ord
+                        .0
+                        .io_rates
+                        .net_total()
+                        .cmp(&item_ord.1.io_rates.net_total())
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get()))
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
+                    Header::BlockIo => item_ord
+                        .0
+                        .io_rates
+                        .blk_total()
+                        .cmp(&item_ord.1.io_rates.blk_total())
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get()))
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
+                    Header::Uptime => item_ord
+                        .0
+                        .get_uptime(now)
+                        .cmp(&item_ord.1.get_uptime(now))
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get()))
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
+                    Header::ImageSize => item_ord
+                        .0
+                        .image_size
+                        .cmp(&item_ord.1.image_size)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get()))
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
+                    // Replicas of a service are kept together, ordered by name, e.g. web-1, web-2
+                    Header::Project => item_ord
+                        .0
+                        .project
+                        .cmp(&item_ord.1.project)
+                        .then_with(|| item_ord.0.service.cmp(&item_ord.1.service))
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get()))
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
+                    Header::Service => item_ord
+                        .0
+                        .service
+                        .cmp(&item_ord.1.service)
+                        .then_with(|| item_ord.0.project.cmp(&item_ord.1.project))
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get()))
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
+                    // Compared as addresses, so that 172.17.0.10 is after 172.17.0.9
+                    Header::Ip => item_ord
+                        .0
+                        .format_ip()
+                        .parse::<IpAddr>()
+                        .ok()
+                        .cmp(&item_ord.1.format_ip().parse::<IpAddr>().ok())
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get()))
+                        .then_with(|| item_ord.0.id.cmp(&item_ord.1.id)),
 
                     Header::Name => item_ord
                         .0
@@ -200,180 +678,1012 @@ impl AppData {
                 a.created
                     .cmp(&b.created)
                     .then_with(|| a.name.get().cmp(b.name.get()))
+                    .then_with(|| a.id.cmp(&b.id))
             });
         }
+        self.sort_groups();
+        self.resolve_selection();
     }
 
-    /// Container state methods
+    // Container selection methods
 
-    /// Just get the total number of containers
-    pub fn get_container_len(&self) -> usize {
-        self.containers.items.len()
+    /// Remember the id of the selected container, called whenever the selection is changed directly, so that the same container can be found again after the list changes
+    fn anchor_selection(&mut self) {
+        self.selected_container = self.get_selected_container_id();
     }
 
-    /// Get all the ContainerItems
-    pub fn get_container_items(&self) -> &[ContainerItem] {
-        &self.containers.items
+    /// Re-find the index of the anchored container after the list has been updated, reordered, or filtered.
+    /// Only if the container is no longer in the list is the current index kept, clamped to the new length
+    fn resolve_selection(&mut self) {
+        let len = self.containers.items.len();
+        let index = self
+            .selected_container
+            .as_ref()
+            .and_then(|id| self.containers.items.iter().position(|i| &i.id == id))
+            .or_else(|| {
+                self.containers
+                    .state
+                    .selected()
+                    .map(|i| i.min(len.saturating_sub(1)))
+            })
+            .filter(|_| len > 0);
+        self.containers.state.select(index);
+        self.anchor_selection();
     }
 
-    /// Get title for containers section
-    pub fn container_title(&self) -> String {
-        self.containers.get_state_title()
+    // Container group methods
+
+    /// When grouped, sort the containers by group, the sort is stable, so each group keeps the order of the header sort
+    fn sort_groups(&mut self) {
+        if self.grouped {
+            self.containers
+                .items
+                .sort_by(|a, b| Self::group_order(a).cmp(&Self::group_order(b)));
+        }
     }
 
-    /// Select the first container
-    pub fn containers_start(&mut self) {
-        self.containers.start();
+    /// Order of the groups, by project name, with the ungrouped containers last
+    const fn group_order(item: &ContainerItem) -> (bool, Option<&String>) {
+        (item.project.is_none(), item.project.as_ref())
     }
 
-    /// select the last container
-    pub fn containers_end(&mut self) {
-        self.containers.end();
+    /// Check if the containers are grouped by compose project
+    pub const fn is_grouped(&self) -> bool {
+        self.grouped
     }
 
-    /// Select the next container
-    pub fn containers_next(&mut self) {
-        self.containers.next();
+    /// Group, or ungroup, the containers by compose project, ungrouping expands every group
+    pub fn toggle_grouped(&mut self) {
+        self.grouped = !self.grouped;
+        self.collapsed_groups.clear();
+        self.filter_containers();
     }
 
-    /// select the previous container
-    pub fn containers_previous(&mut self) {
-        self.containers.previous();
+    /// Collapse, or expand, the group of the selected container
+    pub fn toggle_group_collapsed(&mut self) {
+        if !self.grouped {
+            return;
+        }
+        if let Some(project) = self.get_selected_container().map(|i| i.project.clone()) {
+            if !self.collapsed_groups.remove(&project) {
+                self.collapsed_groups.insert(project);
+            }
+            self.filter_containers();
+        }
     }
 
-    /// Get ListState of containers
-    pub fn get_container_state(&mut self) -> &mut ListState {
-        &mut self.containers.state
+    /// Check if a group is collapsed, a project of None is the ungrouped containers
+    pub fn is_group_collapsed(&self, project: Option<&String>) -> bool {
+        self.collapsed_groups.contains(&project.cloned())
     }
 
-    /// Get Option of the current selected container
-    pub fn get_selected_container(&self) -> Option<&ContainerItem> {
+    /// Number of containers, and number of running containers, in a group, including those that are hidden
+    pub fn get_group_count(&self, project: Option<&String>) -> (usize, usize) {
         self.containers
-            .state
-            .selected()
-            .and_then(|i| self.containers.items.get(i))
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+            .filter(|i| i.project.as_ref() == project)
+            .fold((0, 0), |(total, running), i| {
+                (total + 1, running + usize::from(i.state.is_alive()))
+            })
     }
 
-    /// Find the longest port when it's transformed into a string, defaults are header lens (ip, private, public)
-    pub fn get_longest_port(&self) -> (usize, usize, usize) {
-        let mut longest_ip = 5;
-        let mut longest_private = 10;
-        let mut longest_public = 9;
+    /// If the selected container represents a collapsed group, return the ids of every container in that group
+    pub fn get_selected_group_ids(&self) -> Option<Vec<ContainerId>> {
+        let project = &self.get_selected_container()?.project;
+        self.collapsed_groups.contains(project).then(|| {
+            self.containers
+                .items
+                .iter()
+                .chain(self.hidden_containers.iter())
+                .filter(|i| &i.project == project)
+                .map(|i| i.id.clone())
+                .collect()
+        })
+    }
 
-        for item in &self.containers.items {
-            // if let Some(ports) = item.ports.as_ref() {
-            longest_ip = longest_ip.max(
-                item.ports
-                    .iter()
-                    .map(ContainerPorts::len_ip)
-                    .max()
-                    .unwrap_or(3),
-            );
-            longest_private = longest_private.max(
-                item.ports
-                    .iter()
-                    .map(ContainerPorts::len_private)
-                    .max()
-                    .unwrap_or(8),
-            );
-            longest_public = longest_public.max(
-                item.ports
-                    .iter()
-                    .map(ContainerPorts::len_public)
-                    .max()
-                    .unwrap_or(6),
-            );
+    /// Number of lines needed to draw the containers, in the grouped view every expanded group has a heading line
+    pub fn get_container_lines(&self) -> usize {
+        let len = self.containers.items.len();
+        if self.grouped {
+            let groups = self
+                .containers
+                .items
+                .iter()
+                .map(|i| &i.project)
+                .collect::<HashSet<_>>();
+            len + groups
+                .into_iter()
+                .filter(|i| !self.collapsed_groups.contains(*i))
+                .count()
+        } else {
+            len
         }
-        // }
-
-        (longest_ip, longest_private, longest_public)
-        // )
     }
-    /// Get Option of the current selected container's ports, sorted by private port
-    pub fn get_selected_ports(&mut self) -> Option<(Vec<ContainerPorts>, State)> {
-        if let Some(item) = self.get_mut_selected_container() {
-            let mut ports = item.ports.clone();
-            ports.sort_by(|a, b| a.private.cmp(&b.private));
-            return Some((ports, item.state));
+
+    /// Only the first container of a collapsed group stays visible, it represents the whole group, and its docker controls apply to every container in the group
+    fn collapse_groups(&mut self) {
+        let mut seen = HashSet::new();
+        let collapsed = &self.collapsed_groups;
+        let (visible, mut hidden): (Vec<_>, Vec<_>) = std::mem::take(&mut self.containers.items)
+            .into_iter()
+            .partition(|i| !collapsed.contains(&i.project) || seen.insert(i.project.clone()));
+        self.containers.items = visible;
+        let group_controls = DockerControls::gen_group_vec();
+        for item in &mut self.containers.items {
+            let is_collapsed = collapsed.contains(&item.project);
+            if is_collapsed && item.docker_controls.items != group_controls {
+                item.docker_controls.items.clone_from(&group_controls);
+                item.docker_controls.start();
+            } else if !is_collapsed && item.docker_controls.items == group_controls {
+                item.reset_docker_controls();
+            }
         }
-        None
+        for item in &mut hidden {
+            if item.docker_controls.items == group_controls {
+                item.reset_docker_controls();
+            }
+        }
+        self.hidden_containers.append(&mut hidden);
     }
 
-    /// Get mutable Option of the current selected container
-    fn get_mut_selected_container(&mut self) -> Option<&mut ContainerItem> {
-        self.containers
-            .state
-            .selected()
-            .and_then(|i| self.containers.items.get_mut(i))
+    // Container multi-select methods
+
+    /// Mark, or unmark, the selected container, docker commands are applied to every marked container
+    pub fn toggle_marked(&mut self) {
+        if let Some(id) = self.get_selected_container_id() {
+            if !self.marked.remove(&id) {
+                self.marked.insert(id);
+            }
+        }
     }
 
-    /// return a mutable container by given id
-    fn get_container_by_id(&mut self, id: &ContainerId) -> Option<&mut ContainerItem> {
-        self.containers.items.iter_mut().find(|i| &i.id == id)
+    /// Check if a container is marked
+    pub fn is_marked(&self, id: &ContainerId) -> bool {
+        self.marked.contains(id)
     }
 
-    /// Get the ContainerName of by ID
-    pub fn get_container_name_by_id(&mut self, id: &ContainerId) -> Option<ContainerName> {
-        self.containers
-            .items
-            .iter_mut()
-            .find(|i| &i.id == id)
-            .map(|i| i.name.clone())
+    /// The ids of the marked containers, in the order they are displayed, hidden containers last, None if no containers are marked
+    pub fn get_marked_ids(&self) -> Option<Vec<ContainerId>> {
+        if self.marked.is_empty() {
+            return None;
+        }
+        Some(
+            self.containers
+                .items
+                .iter()
+                .chain(self.hidden_containers.iter())
+                .filter(|i| self.marked.contains(&i.id))
+                .map(|i| i.id.clone())
+                .collect(),
+        )
     }
 
-    /// Find the id of the currently selected container.
-    /// If any containers on system, will always return a ContainerId
-    /// Only returns None when no containers found.
-    pub fn get_selected_container_id(&self) -> Option<ContainerId> {
-        self.get_selected_container().map(|i| i.id.clone())
+    /// Unmark every container
+    pub fn clear_marked(&mut self) {
+        self.marked.clear();
     }
 
-    /// Get the Id and State for the currently selected container - used by the exec check method
-    pub fn get_selected_container_id_state_name(&self) -> Option<(ContainerId, State, String)> {
-        self.get_selected_container()
-            .map(|i| (i.id.clone(), i.state, i.name.get().to_owned()))
+    // Container filter methods
+
+    /// Get the current filter term, is Some("") when the filter input has just been opened
+    pub const fn get_filter_term(&self) -> Option<&String> {
+        self.filter_term.as_ref()
     }
-    /// Selected DockerCommand methods
 
-    /// Get the current selected docker command
-    /// So know which command to execute
-    pub fn selected_docker_controls(&self) -> Option<DockerControls> {
-        self.get_selected_container().and_then(|i| {
-            i.docker_controls.state.selected().and_then(|x| {
-                i.docker_controls
-                    .items
-                    .get(x)
-                    .map(std::borrow::ToOwned::to_owned)
-            })
-        })
+    /// Add a char to the filter term, the filter is applied live
+    pub fn filter_push(&mut self, c: char) {
+        self.filter_term.get_or_insert_with(String::new).push(c);
+        self.filter_containers();
     }
 
-    /// Change selected choice of docker commands of selected container
-    pub fn docker_controls_next(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.docker_controls.next();
+    /// Remove the final char from the filter term
+    pub fn filter_pop(&mut self) {
+        if let Some(term) = self.filter_term.as_mut() {
+            term.pop();
         }
+        self.filter_containers();
     }
 
-    /// Change selected choice of docker commands of selected container
-    pub fn docker_controls_previous(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.docker_controls.previous();
+    /// Start a filter, keeping any existing term so that it can be edited
+    pub fn filter_start(&mut self) {
+        if self.filter_term.is_none() {
+            self.filter_term = Some(String::new());
         }
     }
 
-    /// Change selected choice of docker commands of selected container
-    pub fn docker_controls_start(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.docker_controls.start();
-        }
+    /// Remove the filter, so that all containers are visible again
+    pub fn filter_clear(&mut self) {
+        self.filter_term = None;
+        self.filter_containers();
     }
 
-    /// Change selected choice of docker commands of selected container
-    pub fn docker_controls_end(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.docker_controls.end();
-        }
+    // Container search methods
+
+    /// Get the current search term, is Some("") when the search input has just been opened
+    pub const fn get_search_term(&self) -> Option<&String> {
+        self.search_term.as_ref()
+    }
+
+    /// Start a search, keeping any existing term so that it can be edited
+    pub fn search_start(&mut self) {
+        if self.search_term.is_none() {
+            self.search_term = Some(String::new());
+        }
+    }
+
+    /// Add a char to the search term, the selection jumps to the first match from the selected container onwards
+    pub fn search_push(&mut self, c: char) {
+        self.search_term.get_or_insert_with(String::new).push(c);
+        self.search_select(true, true);
+    }
+
+    /// Remove the final char from the search term, the selection jumps back to the first match of the shorter term
+    pub fn search_pop(&mut self) {
+        if let Some(term) = self.search_term.as_mut() {
+            term.pop();
+        }
+        self.search_select(true, true);
+    }
+
+    /// Remove the search, the selected container is unchanged
+    pub fn search_clear(&mut self) {
+        self.search_term = None;
+    }
+
+    /// Check if there is a search term to jump between matches of
+    pub fn has_search(&self) -> bool {
+        self.search_term.as_ref().is_some_and(|i| !i.is_empty())
+    }
+
+    /// Select the next, or previous, visible container whose name contains the search term, wrapping around the list
+    pub fn search_next(&mut self, forward: bool) {
+        self.search_select(forward, false);
+    }
+
+    /// Select the closest container whose name contains the search term, case-insensitive.
+    /// The position is found from the selected container's id, rather than its index, so that a re-sort between key presses doesn't skip any matches.
+    /// The selected container itself is only checked first when include_selected is true, i.e. whilst the term is being typed
+    fn search_select(&mut self, forward: bool, include_selected: bool) {
+        let Some(term) = self
+            .search_term
+            .as_ref()
+            .filter(|i| !i.is_empty())
+            .map(|i| i.to_lowercase())
+        else {
+            return;
+        };
+        let len = self.containers.items.len();
+        let selected_id = self.get_selected_container_id();
+        let start = selected_id
+            .and_then(|id| self.containers.items.iter().position(|i| i.id == id))
+            .unwrap_or_default();
+        let offsets = if include_selected { 0..len } else { 1..len + 1 };
+        let index = offsets
+            .map(|offset| {
+                if forward {
+                    (start + offset) % len
+                } else {
+                    (start + len - offset % len) % len
+                }
+            })
+            .find(|i| {
+                self.containers.items[*i]
+                    .name
+                    .get()
+                    .to_lowercase()
+                    .contains(&term)
+            });
+        if let Some(index) = index {
+            self.containers.state.select(Some(index));
+            self.anchor_selection();
+        }
+    }
+
+    /// Get the current state filter
+    pub const fn get_state_filter(&self) -> StateFilter {
+        self.state_filter
+    }
+
+    /// Cycle the state filter, All -> Running -> Exited -> Paused -> All
+    pub fn cycle_state_filter(&mut self) {
+        self.state_filter = self.state_filter.next();
+        self.filter_containers();
+    }
+
+    /// Get the current summary bar setting
+    pub const fn get_summary(&self) -> Summary {
+        self.summary
+    }
+
+    /// Cycle the summary bar, Hidden -> All -> Visible -> Hidden
+    pub const fn cycle_summary(&mut self) {
+        self.summary = self.summary.next();
+    }
+
+    /// Get the totals for the summary bar, of either every container, or only the visible containers, is None when the summary bar is hidden
+    pub fn get_totals(&self) -> Option<ContainerTotals> {
+        match self.summary {
+            Summary::Hidden => None,
+            Summary::All => Some(
+                self.containers
+                    .items
+                    .iter()
+                    .chain(self.hidden_containers.iter())
+                    .collect(),
+            ),
+            Summary::Visible => Some(self.containers.items.iter().collect()),
+        }
+    }
+
+    /// Check if a container name, image, or compose service or project, contains the filter term, case-insensitive
+    fn filter_matches(term: &str, container: &ContainerItem) -> bool {
+        let label_matches =
+            |label: Option<&String>| label.is_some_and(|i| i.to_lowercase().contains(term));
+        term.is_empty()
+            || container.name.get().to_lowercase().contains(term)
+            || container.image.get().to_lowercase().contains(term)
+            || label_matches(container.service.as_ref())
+            || label_matches(container.project.as_ref())
+    }
+
+    /// Split all the containers into the visible containers and the hidden containers, based on the filter term, the state filter, and any collapsed groups.
+    /// Hidden containers are still updated, so their logs are current when the filter is cleared, but their stats aren't streamed.
+    /// The selected container is kept selected if it's still visible, else its group, if collapsed, else the first visible container is selected
+    fn filter_containers(&mut self) {
+        let selected_id = self.selected_container.clone();
+        let selected_project = self.get_selected_container().map(|i| i.project.clone());
+        let term = self
+            .filter_term
+            .as_ref()
+            .map(|i| i.to_lowercase())
+            .unwrap_or_default();
+
+        let mut all = std::mem::take(&mut self.containers.items);
+        all.append(&mut self.hidden_containers);
+        let state_filter = self.state_filter;
+        let (visible, hidden) = all
+            .into_iter()
+            .partition(|i| Self::filter_matches(&term, i) && state_filter.matches(i.state));
+        self.containers.items = visible;
+        self.hidden_containers = hidden;
+
+        self.sort_containers();
+        self.collapse_groups();
+        let index = selected_id
+            .and_then(|id| self.containers.items.iter().position(|i| i.id == id))
+            .or_else(|| {
+                selected_project
+                    .filter(|project| self.grouped && self.collapsed_groups.contains(project))
+                    .and_then(|project| {
+                        self.containers
+                            .items
+                            .iter()
+                            .position(|i| i.project == project)
+                    })
+            });
+        if index.is_some() {
+            self.containers.state.select(index);
+        } else if self.containers.items.is_empty() {
+            self.containers.state.select(None);
+        } else {
+            self.containers.start();
+        }
+        self.anchor_selection();
+    }
+
+    // Container state methods
+
+    /// Just get the total number of containers
+    pub const fn get_container_len(&self) -> usize {
+        self.containers.items.len()
+    }
+
+    /// Get the number of stopped containers, including any hidden by the filter, which would be removed by a prune
+    pub fn get_stopped_len(&self) -> usize {
+        self.containers
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+            .filter(|i| i.state.is_stopped())
+            .count()
+    }
+
+    // Image methods
+
+    /// Replace the images, sorted by created time, newest first, keeping the same image selected if it still exists
+    pub fn update_images(&mut self, mut images: Vec<ImageItem>) {
+        images.sort_by(|a, b| b.created.cmp(&a.created).then_with(|| a.id.cmp(&b.id)));
+        let selected = self.get_selected_image().map(|i| i.id.clone());
+        let index = selected
+            .and_then(|id| images.iter().position(|i| i.id == id))
+            .or_else(|| (!images.is_empty()).then_some(0))
+            .map(|i| i.min(images.len().saturating_sub(1)));
+        self.images.items = images;
+        self.images.state.select(index);
+        self.redraw = true;
+    }
+
+    /// Replace the size of each image, by image id, and set the image size of every container from it.
+    /// A container whose image has been deleted has no size
+    pub fn update_image_sizes(&mut self, image_sizes: HashMap<String, ByteStats>) {
+        self.image_sizes = image_sizes;
+        self.redraw = true;
+        for container in self
+            .containers
+            .items
+            .iter_mut()
+            .chain(self.hidden_containers.iter_mut())
+        {
+            container.image_size = container
+                .image_id
+                .as_ref()
+                .and_then(|id| self.image_sizes.get(id))
+                .copied();
+        }
+    }
+
+    /// Get all the ImageItems
+    pub fn get_image_items(&self) -> &[ImageItem] {
+        &self.images.items
+    }
+
+    /// Get the ListState of the images list
+    pub const fn get_image_state(&mut self) -> &mut ListState {
+        &mut self.images.state
+    }
+
+    /// Get the current selected image, if any
+    pub fn get_selected_image(&self) -> Option<&ImageItem> {
+        self.images
+            .state
+            .selected()
+            .and_then(|i| self.images.items.get(i))
+    }
+
+    /// Get an image by id
+    pub fn get_image_by_id(&self, id: &ImageId) -> Option<&ImageItem> {
+        self.images.items.iter().find(|i| &i.id == id)
+    }
+
+    /// Remove an image from the list, once it has been removed by docker, keeping the selected index within bounds
+    pub fn remove_image(&mut self, id: &ImageId) {
+        self.images.items.retain(|i| &i.id != id);
+        self.redraw = true;
+        if let Some(selected) = self.images.state.selected() {
+            let len = self.images.items.len();
+            self.images
+                .state
+                .select((len > 0).then(|| selected.min(len - 1)));
+        }
+    }
+
+    /// The title of the images panel, e.g. 2/5
+    pub fn images_title(&self) -> String {
+        self.images.get_state_title()
+    }
+
+    pub fn images_next(&mut self) {
+        self.images.next();
+    }
+
+    pub fn images_previous(&mut self) {
+        self.images.previous();
+    }
+
+    pub fn images_start(&mut self) {
+        self.images.start();
+    }
+
+    pub fn images_end(&mut self) {
+        self.images.end();
+    }
+
+    // Network methods
+
+    /// Replace the networks, sorted by name, keeping the same network selected if it still exists
+    pub fn update_networks(&mut self, mut networks: Vec<NetworkItem>) {
+        networks.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+        let selected = self.get_selected_network().map(|i| i.id.clone());
+        let index = selected
+            .and_then(|id| networks.iter().position(|i| i.id == id))
+            .or_else(|| (!networks.is_empty()).then_some(0))
+            .map(|i| i.min(networks.len().saturating_sub(1)));
+        self.networks.items = networks;
+        self.networks.state.select(index);
+        self.redraw = true;
+    }
+
+    /// Get all the NetworkItems
+    pub fn get_network_items(&self) -> &[NetworkItem] {
+        &self.networks.items
+    }
+
+    /// Get the ListState of the networks list
+    pub const fn get_network_state(&mut self) -> &mut ListState {
+        &mut self.networks.state
+    }
+
+    /// Get the current selected network, if any
+    pub fn get_selected_network(&self) -> Option<&NetworkItem> {
+        self.networks
+            .state
+            .selected()
+            .and_then(|i| self.networks.items.get(i))
+    }
+
+    /// Get a network by id
+    pub fn get_network_by_id(&self, id: &NetworkId) -> Option<&NetworkItem> {
+        self.networks.items.iter().find(|i| &i.id == id)
+    }
+
+    /// Remove a network from the list, once it has been removed by docker, keeping the selected index within bounds
+    pub fn remove_network(&mut self, id: &NetworkId) {
+        self.networks.items.retain(|i| &i.id != id);
+        self.redraw = true;
+        if let Some(selected) = self.networks.state.selected() {
+            let len = self.networks.items.len();
+            self.networks
+                .state
+                .select((len > 0).then(|| selected.min(len - 1)));
+        }
+    }
+
+    /// Get the inspect output of a network by ID, is None until the inspect has completed.
+    /// Only the most recently inspected network is kept, as only one inspect panel can be open at a time
+    pub fn get_network_inspect_by_id(&self, id: &NetworkId) -> Option<String> {
+        self.network_inspect
+            .as_ref()
+            .filter(|i| &i.0 == id)
+            .map(|i| i.1.clone())
+    }
+
+    /// Store the pretty-printed inspect output of a network, replaces any previous output
+    pub fn update_network_inspect(&mut self, id: NetworkId, inspect: String) {
+        self.network_inspect = Some((id, inspect));
+        self.redraw = true;
+    }
+
+    /// The title of the networks panel, e.g. 2/5
+    pub fn networks_title(&self) -> String {
+        self.networks.get_state_title()
+    }
+
+    pub fn networks_next(&mut self) {
+        self.networks.next();
+    }
+
+    pub fn networks_previous(&mut self) {
+        self.networks.previous();
+    }
+
+    pub fn networks_start(&mut self) {
+        self.networks.start();
+    }
+
+    pub fn networks_end(&mut self) {
+        self.networks.end();
+    }
+
+    // Volume methods
+
+    /// Replace the volumes, sorted by name, keeping the same volume selected if it still exists
+    pub fn update_volumes(&mut self, mut volumes: Vec<VolumeItem>) {
+        volumes.sort_by(|a, b| a.name.cmp(&b.name));
+        let selected = self.get_selected_volume().map(|i| i.name.clone());
+        let index = selected
+            .and_then(|name| volumes.iter().position(|i| i.name == name))
+            .or_else(|| (!volumes.is_empty()).then_some(0))
+            .map(|i| i.min(volumes.len().saturating_sub(1)));
+        self.volumes.items = volumes;
+        self.volumes.state.select(index);
+        self.redraw = true;
+    }
+
+    /// Get all the VolumeItems
+    pub fn get_volume_items(&self) -> &[VolumeItem] {
+        &self.volumes.items
+    }
+
+    /// Get the ListState of the volumes list
+    pub const fn get_volume_state(&mut self) -> &mut ListState {
+        &mut self.volumes.state
+    }
+
+    /// Get the current selected volume, if any
+    pub fn get_selected_volume(&self) -> Option<&VolumeItem> {
+        self.volumes
+            .state
+            .selected()
+            .and_then(|i| self.volumes.items.get(i))
+    }
+
+    /// Get a volume by name
+    pub fn get_volume_by_name(&self, name: &VolumeName) -> Option<&VolumeItem> {
+        self.volumes.items.iter().find(|i| &i.name == name)
+    }
+
+    /// Remove a volume from the list, once it has been removed by docker, keeping the selected index within bounds
+    pub fn remove_volume(&mut self, name: &VolumeName) {
+        self.volumes.items.retain(|i| &i.name != name);
+        self.redraw = true;
+        if let Some(selected) = self.volumes.state.selected() {
+            let len = self.volumes.items.len();
+            self.volumes
+                .state
+                .select((len > 0).then(|| selected.min(len - 1)));
+        }
+    }
+
+    /// The title of the volumes panel, e.g. 2/5
+    pub fn volumes_title(&self) -> String {
+        self.volumes.get_state_title()
+    }
+
+    pub fn volumes_next(&mut self) {
+        self.volumes.next();
+    }
+
+    pub fn volumes_previous(&mut self) {
+        self.volumes.previous();
+    }
+
+    pub fn volumes_start(&mut self) {
+        self.volumes.start();
+    }
+
+    pub fn volumes_end(&mut self) {
+        self.volumes.end();
+    }
+
+    /// Get all the ContainerItems
+    pub fn get_container_items(&self) -> &[ContainerItem] {
+        &self.containers.items
+    }
+
+    /// A snapshot of every container, including any hidden by the filter, for the `--format json` output
+    pub fn get_container_output(&self) -> Vec<ContainerOutput> {
+        self.all_containers().map(ContainerOutput::from).collect()
+    }
+
+    /// Every container, including those hidden by the filter or the state filter
+    pub fn all_containers(&self) -> impl Iterator<Item = &ContainerItem> {
+        self.containers
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+    }
+
+    /// Get title for containers section
+    pub fn container_title(&self) -> String {
+        let title = self.containers.get_state_title();
+        if self.marked.is_empty() {
+            title
+        } else {
+            format!("{title} - {} marked", self.marked.len())
+        }
+    }
+
+    /// Select the first container
+    pub fn containers_start(&mut self) {
+        self.containers.start();
+        self.anchor_selection();
+    }
+
+    /// select the last container
+    pub fn containers_end(&mut self) {
+        self.containers.end();
+        self.anchor_selection();
+    }
+
+    /// Select the next container
+    pub fn containers_next(&mut self) {
+        self.containers.next();
+        self.anchor_selection();
+    }
+
+    /// select the previous container
+    pub fn containers_previous(&mut self) {
+        self.containers.previous();
+        self.anchor_selection();
+    }
+
+    /// Select the container at a given row number, where 1 is the first container, clamped to the number of containers
+    pub fn containers_go_to(&mut self, row: usize) {
+        let len = self.containers.items.len();
+        if len > 0 {
+            self.containers.state.select(Some(row.clamp(1, len) - 1));
+            self.anchor_selection();
+        }
+    }
+
+    /// Select the container drawn at a given row of the containers panel, where 0 is the first visible row.
+    /// When grouped, the first container of each group is drawn below the group heading, and a collapsed group is only the heading
+    pub fn containers_select_row(&mut self, row: usize) {
+        let offset = self.containers.state.offset();
+        let mut previous_project = offset
+            .checked_sub(1)
+            .and_then(|i| self.containers.items.get(i))
+            .map(|i| &i.project);
+        let mut used = 0;
+        let mut selected = None;
+        for (index, item) in self.containers.items.iter().enumerate().skip(offset) {
+            let heading = self.grouped && previous_project != Some(&item.project);
+            previous_project = Some(&item.project);
+            used += if heading && !self.is_group_collapsed(item.project.as_ref()) {
+                2
+            } else {
+                1
+            };
+            if row < used {
+                selected = Some(index);
+                break;
+            }
+        }
+        if selected.is_some() {
+            self.containers.state.select(selected);
+            self.anchor_selection();
+        }
+    }
+
+    /// Get ListState of containers
+    pub const fn get_container_state(&mut self) -> &mut ListState {
+        &mut self.containers.state
+    }
+
+    /// Get Option of the current selected container
+    pub fn get_selected_container(&self) -> Option<&ContainerItem> {
+        self.containers
+            .state
+            .selected()
+            .and_then(|i| self.containers.items.get(i))
+    }
+
+    /// Find the longest port when it's transformed into a string, defaults are header lens (ip, private, public)
+    pub fn get_longest_port(&self) -> (usize, usize, usize) {
+        let mut longest_ip = 5;
+        let mut longest_private = 10;
+        let mut longest_public = 9;
+
+        for item in &self.containers.items {
+            // if let Some(ports) = item.ports.as_ref() {
+            longest_ip = longest_ip.max(
+                item.ports
+                    .iter()
+                    .map(ContainerPorts::len_ip)
+                    .max()
+                    .unwrap_or(3),
+            );
+            longest_private = longest_private.max(
+                item.ports
+                    .iter()
+                    .map(ContainerPorts::len_private)
+                    .max()
+                    .unwrap_or(8),
+            );
+            longest_public = longest_public.max(
+                item.ports
+                    .iter()
+                    .map(ContainerPorts::len_public)
+                    .max()
+                    .unwrap_or(6),
+            );
+        }
+        // }
+
+        (longest_ip, longest_private, longest_public)
+        // )
+    }
+    /// Get Option of the current selected container's ports, sorted by private port, then protocol, with duplicates removed
+    pub fn get_selected_ports(&mut self) -> Option<(Vec<ContainerPorts>, State)> {
+        if let Some(item) = self.get_mut_selected_container() {
+            let mut ports = item.ports.clone();
+            // Each binding of a port, such as both IPv4 & IPv6, is a separate item, Docker can also return duplicates
+            ports.sort();
+            ports.dedup();
+            return Some((ports, item.state));
+        }
+        None
+    }
+
+    /// Get mutable Option of the current selected container
+    fn get_mut_selected_container(&mut self) -> Option<&mut ContainerItem> {
+        self.containers
+            .state
+            .selected()
+            .and_then(|i| self.containers.items.get_mut(i))
+    }
+
+    /// return a mutable container by given id, as the container is about to be changed the next frame gets drawn
+    fn get_container_by_id(&mut self, id: &ContainerId) -> Option<&mut ContainerItem> {
+        self.redraw = true;
+        self.containers
+            .items
+            .iter_mut()
+            .chain(self.hidden_containers.iter_mut())
+            .find(|i| &i.id == id)
+    }
+
+    /// Get the ids of the visible containers that are alive, in display order, but with the selected container first, so that its stats are always streamed.
+    /// When the summary bar totals every container, the hidden containers that are alive are included last
+    pub fn get_stat_stream_ids(&self) -> Vec<ContainerId> {
+        let selected = self
+            .get_selected_container()
+            .filter(|i| i.state.is_alive())
+            .map(|i| i.id.clone());
+        selected
+            .iter()
+            .cloned()
+            .chain(
+                self.containers
+                    .items
+                    .iter()
+                    .filter(|i| i.state.is_alive() && Some(&i.id) != selected.as_ref())
+                    .map(|i| i.id.clone()),
+            )
+            .chain(
+                self.hidden_containers
+                    .iter()
+                    .filter(|i| self.summary == Summary::All && i.state.is_alive())
+                    .map(|i| i.id.clone()),
+            )
+            .collect()
+    }
+
+    /// Get the State of a container by ID
+    pub fn get_container_state_by_id(&self, id: &ContainerId) -> Option<State> {
+        self.containers
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+            .find(|i| &i.id == id)
+            .map(|i| i.state)
+    }
+
+    /// Remove a container by ID, used once a container has been successfully deleted, so that the selected index doesn't point at a stale item.
+    /// If the selected container is the one removed, the container that takes its index is selected
+    pub fn remove_container(&mut self, id: &ContainerId) {
+        self.redraw = true;
+        self.marked.remove(id);
+        self.hidden_containers.retain(|i| &i.id != id);
+        if let Some(index) = self.containers.items.iter().position(|i| &i.id == id) {
+            self.containers.items.remove(index);
+            self.resolve_selection();
+        }
+    }
+
+    /// Update the name of a container once renamed, re-sorting in case the containers are sorted by name
+    pub fn rename_container(&mut self, id: &ContainerId, name: &str) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.name.set(name.to_owned());
+            self.sort_containers();
+        }
+    }
+
+    /// Get the cached inspect output of a container by ID, is None until the first inspect has completed
+    pub fn get_inspect_by_id(&self, id: &ContainerId) -> Option<String> {
+        self.containers
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+            .find(|i| &i.id == id)
+            .and_then(|i| i.inspect.clone())
+    }
+
+    /// Get the mounts of a container by ID, is None until the container has been inspected
+    pub fn get_mounts_by_id(&self, id: &ContainerId) -> Option<Vec<ContainerMount>> {
+        self.containers
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+            .find(|i| &i.id == id)
+            .and_then(|i| i.mounts.clone())
+    }
+
+    /// Get the cached docker run command of a container by ID, is None until the first inspect has completed
+    pub fn get_run_command_by_id(&self, id: &ContainerId) -> Option<String> {
+        self.containers
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+            .find(|i| &i.id == id)
+            .and_then(|i| i.run_command.clone())
+    }
+
+    /// Get the cached environment variables of a container by ID, is None until the first inspect has completed
+    pub fn get_env_by_id(&self, id: &ContainerId) -> Option<Vec<String>> {
+        self.containers
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+            .find(|i| &i.id == id)
+            .and_then(|i| i.env.clone())
+    }
+
+    /// Get the cached labels of a container by ID, sorted by key, is None until the first inspect has completed
+    pub fn get_labels_by_id(&self, id: &ContainerId) -> Option<BTreeMap<String, String>> {
+        self.containers
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+            .find(|i| &i.id == id)
+            .and_then(|i| i.labels.clone())
+    }
+
+    /// Get the ContainerName of by ID
+    pub fn get_container_name_by_id(&mut self, id: &ContainerId) -> Option<ContainerName> {
+        self.get_container_by_id(id).map(|i| i.name.clone())
+    }
+
+    /// Find the id of the currently selected container.
+    /// If any containers on system, will always return a ContainerId
+    /// Only returns None when no containers found.
+    pub fn get_selected_container_id(&self) -> Option<ContainerId> {
+        self.get_selected_container().map(|i| i.id.clone())
+    }
+
+    /// Set the shells to try, in order, when execing into any container
+    pub fn set_shells(&mut self, shells: Vec<String>) {
+        self.shells = shells;
+    }
+
+    /// Get the shells to try when execing into the selected container, any from its `oxker.shell` label first, then the `--shell` and config file shells
+    pub fn get_exec_shells(&self) -> Vec<String> {
+        self.get_selected_container()
+            .map(|i| i.shells.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .chain(self.shells.iter().cloned())
+            .collect()
+    }
+
+    /// Get the Id and State for the currently selected container - used by the exec check method
+    pub fn get_selected_container_id_state_name(&self) -> Option<(ContainerId, State, String)> {
+        self.get_selected_container()
+            .map(|i| (i.id.clone(), i.state, i.name.get().to_owned()))
+    }
+    // Selected DockerCommand methods
+
+    /// Get the current selected docker command
+    /// So know which command to execute
+    pub fn selected_docker_controls(&self) -> Option<DockerControls> {
+        self.get_selected_container().and_then(|i| {
+            i.docker_controls.state.selected().and_then(|x| {
+                i.docker_controls
+                    .items
+                    .get(x)
+                    .map(std::borrow::ToOwned::to_owned)
+            })
+        })
+    }
+
+    /// Change selected choice of docker commands of selected container
+    pub fn docker_controls_next(&mut self) {
+        if let Some(i) = self.get_mut_selected_container() {
+            i.docker_controls.next();
+        }
+    }
+
+    /// Change selected choice of docker commands of selected container
+    pub fn docker_controls_previous(&mut self) {
+        if let Some(i) = self.get_mut_selected_container() {
+            i.docker_controls.previous();
+        }
+    }
+
+    /// Change selected choice of docker commands of selected container
+    pub fn docker_controls_start(&mut self) {
+        if let Some(i) = self.get_mut_selected_container() {
+            i.docker_controls.start();
+        }
+    }
+
+    /// Change selected choice of docker commands of selected container
+    pub fn docker_controls_end(&mut self) {
+        if let Some(i) = self.get_mut_selected_container() {
+            i.docker_controls.end();
+        }
     }
 
     /// Get mutable Option of the currently selected container DockerControls state
@@ -382,67 +1692,320 @@ impl AppData {
             .map(|i| &mut i.docker_controls.state)
     }
 
-    /// Get mutable Option of the currently selected container DockerControls items
-    /// TODO command or control, need a uniform name across the application
-    pub fn get_control_items(&mut self) -> Option<&mut Vec<DockerControls>> {
-        self.get_mut_selected_container()
-            .map(|i| &mut i.docker_controls.items)
+    /// Get mutable Option of the currently selected container DockerControls items
+    /// TODO command or control, need a uniform name across the application
+    pub fn get_control_items(&mut self) -> Option<&mut Vec<DockerControls>> {
+        self.get_mut_selected_container()
+            .map(|i| &mut i.docker_controls.items)
+    }
+
+    // Logs related methods
+
+    /// Get the title for log panel for selected container, will be either
+    /// 1) "logs x/x - container_name" where container_name is 32 chars max
+    /// 2) "logs x/x (y dropped) - container_name" when older lines have been removed due to the "--log-max-lines" limit
+    /// 3) "logs - container_name" when no logs found, again 32 chars max
+    /// 4) "" no container currently selected - aka no containers on system
+    pub fn get_log_title(&self) -> String {
+        self.get_selected_container()
+            .map_or_else(String::new, |ci| {
+                let logs_len = ci.logs.get_state_title();
+                let paused = if ci.logs.follow() { "" } else { "paused " };
+                let streams = match self.log_streams {
+                    LogStreamFilter::All => String::new(),
+                    streams => format!("{streams} only "),
+                };
+                let since = self
+                    .log_since
+                    .map_or_else(String::new, |i| format!("since {i} "));
+                let marker = ci.logs.marker().map_or_else(String::new, |marker| {
+                    let count = ci.logs.marker_count();
+                    match marker {
+                        LogMarker::Set(_) => format!("marker +{count} "),
+                        LogMarker::Only(_) => format!("{count} new since marker "),
+                    }
+                });
+                let prefix = if logs_len.is_empty() {
+                    format!(" {marker}{since}")
+                } else if ci.logs.dropped() > 0 {
+                    format!(
+                        "{logs_len} ({} dropped) {paused}{streams}{marker}{since}",
+                        ci.logs.dropped()
+                    )
+                } else {
+                    format!("{logs_len} {paused}{streams}{marker}{since}")
+                };
+                format!("{}- {}", prefix, ci.name.get())
+            })
+    }
+
+    /// Check if new log lines of the selected container are being followed, is true if no container is selected
+    pub fn get_log_follow(&self) -> bool {
+        self.get_selected_container()
+            .is_none_or(|i| i.logs.follow())
+    }
+
+    /// select next selected log line
+    pub fn log_next(&mut self) {
+        if let Some(i) = self.get_mut_selected_container() {
+            i.logs.next();
+        }
+    }
+
+    /// select previous selected log line
+    pub fn log_previous(&mut self) {
+        if let Some(i) = self.get_mut_selected_container() {
+            i.logs.previous();
+        }
+    }
+
+    /// select last selected log line
+    pub fn log_end(&mut self) {
+        if let Some(i) = self.get_mut_selected_container() {
+            i.logs.end();
+        }
+    }
+
+    /// select first selected log line
+    pub fn log_start(&mut self) {
+        if let Some(i) = self.get_mut_selected_container() {
+            i.logs.start();
+        }
+    }
+
+    /// Get mutable Vec of current containers logs
+    pub fn get_logs(&mut self, wrap_width: Option<usize>) -> Vec<ListItem<'static>> {
+        self.containers
+            .state
+            .selected()
+            .and_then(|i| self.containers.items.get_mut(i))
+            .map_or(vec![], |i| i.logs.to_vec(wrap_width))
+    }
+
+    /// The logs of the selected container haven't been fetched yet, so the logs panel shows a loading message, rather than no logs.
+    /// oxker's own logs are never fetched, and whilst paused nothing is fetched
+    pub fn get_logs_fetching(&self) -> bool {
+        self.get_selected_container()
+            .is_some_and(|i| i.last_updated == 0 && !i.is_oxker && !i.logs.stream_paused())
+    }
+
+    /// Why the logs of the selected container can't be shown, if its logging driver doesn't support reading
+    pub fn get_logs_unavailable(&self) -> Option<String> {
+        self.get_selected_container()
+            .and_then(ContainerItem::get_logs_unavailable)
+    }
+
+    /// Move the selected log line by a page of rows, where soft-wrapped lines take up more than one row
+    pub fn log_page(&mut self, down: bool, rows: usize, width: usize) {
+        if let Some(i) = self.get_mut_selected_container() {
+            if down {
+                i.logs.page_down(rows, width);
+            } else {
+                i.logs.page_up(rows, width);
+            }
+        }
+    }
+
+    /// The position of the selected log line of the selected container, see `Logs::position()`
+    pub fn get_log_position(&self) -> Option<usize> {
+        self.get_selected_container()
+            .and_then(|i| i.logs.position())
+    }
+
+    /// The first and last index, inclusive, of the log lines between an anchor position and the selected log line, of the selected container
+    pub fn get_log_range(&self, anchor: Option<usize>) -> Option<(usize, usize)> {
+        self.get_selected_container()
+            .and_then(|i| i.logs.selected_range(anchor))
+    }
+
+    /// Get the plain text of the log lines between an anchor position and the selected log line, includes timestamps if they are currently shown
+    pub fn get_log_selected_text(&self, anchor: Option<usize>) -> Vec<String> {
+        self.get_selected_container().map_or(vec![], |i| {
+            i.logs.get_selected_text(anchor, self.logs_with_timestamps)
+        })
+    }
+
+    /// Get the plain text of the log lines shown in the logs panel, of the selected container, includes timestamps if they are currently shown
+    pub fn get_log_visible_text(&self, rows: usize, width: usize) -> Vec<String> {
+        self.get_selected_container().map_or(vec![], |i| {
+            i.logs
+                .get_visible_text(rows, width, self.logs_with_timestamps)
+        })
+    }
+
+    /// Get the plain text of every buffered log line of the selected container, includes timestamps if they are currently shown
+    pub fn get_log_text(&self) -> Vec<String> {
+        self.get_selected_container()
+            .map_or(vec![], |i| i.logs.get_text(self.logs_with_timestamps))
+    }
+
+    /// Get the plain text of a containers buffered logs, by ID, includes timestamps if they are currently shown
+    pub fn get_logs_text_by_id(&self, id: &ContainerId) -> Option<Vec<String>> {
+        self.containers
+            .items
+            .iter()
+            .find(|i| &i.id == id)
+            .map(|i| i.logs.get_text(self.logs_with_timestamps))
+    }
+
+    pub const fn get_logs_with_timestamps(&self) -> bool {
+        self.logs_with_timestamps
+    }
+
+    /// Set how log timestamps, and created dates, are shown, read from the config file
+    pub fn set_time_format(&mut self, time_format: TimeFormat) {
+        self.time_format = time_format;
+    }
+
+    /// Get how log timestamps, and created dates, are shown
+    pub const fn get_time_format(&self) -> &TimeFormat {
+        &self.time_format
+    }
+
+    /// Show, or hide, the timestamp of every log line, the already buffered logs are regenerated, rather than re-requested from Docker
+    pub fn toggle_log_timestamps(&mut self) {
+        self.logs_with_timestamps = !self.logs_with_timestamps;
+        let (args, theme) = (&self.args, &self.theme);
+        let timestamps = self.logs_with_timestamps.then_some(&self.time_format);
+        for container in &mut self.containers.items {
+            container.logs.rebuild(|tz, stream, content| {
+                Self::gen_log_item(args, theme, timestamps, tz, stream, content)
+            });
+        }
+    }
+
+    /// Get the time that logs are fetched from, None if only limited by "--log-tail"
+    pub const fn get_log_since(&self) -> Option<Since> {
+        self.log_since
     }
 
-    /// Logs related methods
+    /// Get the unix timestamp that logs are fetched from, a relative since is counted back from now
+    pub fn get_log_since_timestamp(&self) -> Option<i64> {
+        let now = self.now();
+        self.log_since.map(|i| i.timestamp(now))
+    }
 
-    /// Get the title for log panel for selected container, will be either
-    /// 1) "logs x/x - container_name" where container_name is 32 chars max
-    /// 2) "logs - container_name" when no logs found, again 32 chars max
-    /// 3) "" no container currently selected - aka no containers on system
-    pub fn get_log_title(&self) -> String {
-        self.get_selected_container()
-            .map_or_else(String::new, |ci| {
-                let logs_len = ci.logs.get_state_title();
-                let prefix = if logs_len.is_empty() {
-                    String::from(" ")
-                } else {
-                    format!("{logs_len} ")
-                };
-                format!("{}- {}", prefix, ci.name.get())
-            })
+    /// Change the time that logs are fetched from, the buffered logs of every container are cleared, so that they are refetched from the new time
+    pub fn set_log_since(&mut self, since: Option<Since>) {
+        self.log_since = since;
+        for container in self
+            .containers
+            .items
+            .iter_mut()
+            .chain(self.hidden_containers.iter_mut())
+        {
+            container.logs = Logs::default();
+            container.last_updated = 0;
+        }
     }
 
-    /// select next selected log line
-    pub fn log_next(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.logs.next();
+    /// Cycle the log streams that are shown, between all, stdout, and stderr, for every container, the buffered logs are filtered rather than re-requested from Docker
+    pub fn cycle_log_streams(&mut self) -> LogStreamFilter {
+        self.log_streams = self.log_streams.next();
+        let (args, theme) = (&self.args, &self.theme);
+        let timestamps = self.logs_with_timestamps.then_some(&self.time_format);
+        for container in self
+            .containers
+            .items
+            .iter_mut()
+            .chain(self.hidden_containers.iter_mut())
+        {
+            container
+                .logs
+                .set_filter(self.log_streams, |tz, stream, content| {
+                    Self::gen_log_item(args, theme, timestamps, tz, stream, content)
+                });
         }
+        self.log_streams
     }
 
-    /// select previous selected log line
-    pub fn log_previous(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.logs.previous();
+    /// Generate the Text for a log line, the timestamp, if shown, is in the configured time format, and styled separately from the rest of the line.
+    /// A timestamp that can't be parsed is shown as sent by Docker. A stderr line is colored, unless colored by its own ansi formatting
+    fn gen_log_item(
+        args: &CliArgs,
+        theme: &Theme,
+        timestamps: Option<&TimeFormat>,
+        tz: &LogsTz,
+        stream: LogStream,
+        content: &str,
+    ) -> Text<'static> {
+        let mut lines = if args.color {
+            log_sanitizer::colorize_logs(content)
+        } else if args.raw {
+            log_sanitizer::raw(content)
+        } else {
+            log_sanitizer::remove_ansi(content)
+        };
+        if stream == LogStream::Stderr {
+            for span in lines.iter_mut().flat_map(|line| line.spans.iter_mut()) {
+                if span.style.fg.is_none() {
+                    span.style.fg = Some(theme.log_stderr);
+                }
+            }
+        }
+        if let Some(time_format) = timestamps {
+            if let Some(line) = lines.first_mut() {
+                let tz = tz.to_string();
+                let timestamp = parse_timestamp_nanos(tz.trim())
+                    .map_or(tz, |nanos| format!("{} ", time_format.format(nanos)));
+                line.spans.insert(
+                    0,
+                    Span::styled(timestamp, Style::default().fg(theme.log_timestamp)),
+                );
+            }
         }
+        Text::from(lines)
     }
 
-    /// select last selected log line
-    pub fn log_end(&mut self) {
+    /// Get the indexes of the selected containers log lines that match the search
+    pub fn get_log_matches(&self, search: &LogSearch) -> Vec<usize> {
+        self.get_selected_container()
+            .map_or(vec![], |i| i.logs.matches(search))
+    }
+
+    /// Get the index, and color, of each of the selected containers log lines that contain a severity token
+    pub fn get_log_severity(&self, tokens: &BTreeMap<String, Color>) -> Vec<(usize, Color)> {
+        self.get_selected_container()
+            .map_or(vec![], |i| i.logs.severity(tokens))
+    }
+
+    /// Pause, or resume, the log stream of the selected container
+    pub fn toggle_log_stream(&mut self) {
         if let Some(i) = self.get_mut_selected_container() {
-            i.logs.end();
+            i.logs.toggle_stream();
         }
     }
 
-    /// select first selected log line
-    pub fn log_start(&mut self) {
+    /// Cycle the log marker of the selected container, between set, showing only the lines after it, and cleared
+    pub fn cycle_log_marker(&mut self) -> Option<LogMarker> {
+        let timestamps = self.logs_with_timestamps.then_some(&self.time_format);
+        let (args, theme) = (&self.args, &self.theme);
+        let index = self.containers.state.selected()?;
+        let logs = &mut self.containers.items.get_mut(index)?.logs;
+        logs.cycle_marker(|tz, stream, content| {
+            Self::gen_log_item(args, theme, timestamps, tz, stream, content)
+        });
+        logs.marker()
+    }
+
+    /// Check if the log stream of the selected container is paused
+    pub fn get_log_stream_paused(&self) -> bool {
+        self.get_selected_container()
+            .is_some_and(|i| i.logs.stream_paused())
+    }
+
+    /// Select the next log line that matches the search
+    pub fn log_next_match(&mut self, search: &LogSearch) {
         if let Some(i) = self.get_mut_selected_container() {
-            i.logs.start();
+            i.logs.next_match(search);
         }
     }
 
-    /// Get mutable Vec of current containers logs
-    pub fn get_logs(&mut self) -> Vec<ListItem<'static>> {
-        self.containers
-            .state
-            .selected()
-            .and_then(|i| self.containers.items.get_mut(i))
-            .map_or(vec![], |i| i.logs.to_vec())
+    /// Select the previous log line that matches the search
+    pub fn log_previous_match(&mut self, search: &LogSearch) {
+        if let Some(i) = self.get_mut_selected_container() {
+            i.logs.previous_match(search);
+        }
     }
 
     /// Get mutable Option of the currently selected container Logs state
@@ -454,7 +2017,7 @@ impl AppData {
             .map(|i| i.logs.state())
     }
 
-    /// Chart data related methods
+    // Chart data related methods
 
     /// Get mutable Option of the currently selected container chart data
     pub fn get_chart_data(&mut self) -> Option<(CpuTuple, MemTuple)> {
@@ -465,11 +2028,24 @@ impl AppData {
             .map(|i| i.get_chart_data())
     }
 
-    /// Error related methods
+    /// Get the key facts of the selected container, for the detail panel
+    pub fn get_selected_detail(&self) -> Option<Vec<(&'static str, String)>> {
+        let now = self.now();
+        self.get_selected_container()
+            .map(|i| i.get_detail(now, self.memory_unit, &self.time_format))
+    }
+
+    /// Get the cpu & memory history of the selected container, for the sparklines
+    pub fn get_history_data(&self) -> Option<HistoryTuple> {
+        self.get_selected_container()
+            .map(ContainerItem::get_history_data)
+    }
+
+    // Error related methods
 
     /// return single app_state error
-    pub const fn get_error(&self) -> Option<AppError> {
-        self.error
+    pub fn get_error(&self) -> Option<AppError> {
+        self.error.clone()
     }
 
     /// remove single app_state error
@@ -481,26 +2057,64 @@ impl AppData {
     pub fn set_error(&mut self, error: AppError, gui_state: &Arc<Mutex<GuiState>>, status: Status) {
         gui_state.lock().status_push(status);
         self.error = Some(error);
+        self.redraw = true;
     }
 
     /// Check if the selected container is a dockerised version of oxker
     /// So that can disallow commands to be send
     /// Is a shabby way of implementing this
     pub fn is_oxker(&self) -> bool {
-        self.get_selected_container().map_or(false, |i| i.is_oxker)
+        self.get_selected_container().is_some_and(|i| i.is_oxker)
+    }
+
+    /// The current minimum update interval for docker information, in ms, initially set by the `-d` argument
+    pub const fn get_docker_interval(&self) -> u32 {
+        self.docker_interval
+    }
+
+    /// The current update interval, only if it has been changed at runtime
+    pub fn get_changed_interval(&self) -> Option<u32> {
+        (self.docker_interval != self.args.docker_interval).then_some(self.docker_interval)
+    }
+
+    /// Halve the update interval, to a minimum of 100ms, an interval already below the minimum is unchanged
+    pub fn interval_faster(&mut self) {
+        self.docker_interval = (self.docker_interval / 2)
+            .max(MIN_INTERVAL)
+            .min(self.docker_interval);
+    }
+
+    /// Double the update interval, to a maximum of 10 seconds, an interval already above the maximum is unchanged
+    pub fn interval_slower(&mut self) {
+        self.docker_interval = self
+            .docker_interval
+            .saturating_mul(2)
+            .min(MAX_INTERVAL)
+            .max(self.docker_interval);
     }
 
-    /// Check if selected container is oxker and also that oxker is being run in a container
+    /// Check if selected container is oxker and also that oxker is being run in a container, or is the container that oxker is running in
     pub fn is_oxker_in_container(&self) -> bool {
-        self.get_selected_container()
-            .map_or(false, |i| i.is_oxker && self.args.in_container)
+        self.get_selected_container_id()
+            .is_some_and(|id| self.is_oxker_in_container_by_id(&id))
+    }
+
+    /// Check if a container is oxker and also that oxker is being run in a container, or is the container that oxker is running in
+    pub fn is_oxker_in_container_by_id(&self, id: &ContainerId) -> bool {
+        self.containers
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+            .find(|i| &i.id == id)
+            .is_some_and(|i| i.is_self || (i.is_oxker && self.args.in_container))
     }
 
     /// Find the widths for the strings in the containers panel.
-    /// So can display nicely and evenly
-    pub fn get_width(&self) -> Columns {
+    /// So can display nicely and evenly, the id column is as wide as the short, or full, ids
+    pub fn get_width(&self, id_mode: IdMode) -> Columns {
         let mut columns = Columns::new();
         let count = |x: &str| u8::try_from(x.chars().count()).unwrap_or(12);
+        let now = self.now();
 
         // Should probably find a refactor here somewhere
         for container in &self.containers.items {
@@ -508,7 +2122,8 @@ impl AppData {
                 &container
                     .cpu_stats
                     .back()
-                    .unwrap_or(&CpuStats::default())
+                    .copied()
+                    .unwrap_or_default()
                     .to_string(),
             );
 
@@ -516,62 +2131,134 @@ impl AppData {
                 &container
                     .mem_stats
                     .back()
-                    .unwrap_or(&ByteStats::default())
-                    .to_string(),
+                    .copied()
+                    .unwrap_or_default()
+                    .format(self.memory_unit),
             );
 
             // Issue here!
             columns.cpu.1 = columns.cpu.1.max(cpu_count);
+            columns.id.1 = columns.id.1.max(count(&id_mode.format(&container.id)));
             columns.image.1 = columns.image.1.max(count(&container.image.to_string()));
             columns.mem.1 = columns.mem.1.max(mem_current_count);
-            columns.mem.2 = columns.mem.2.max(count(&container.mem_limit.to_string()));
-            columns.name.1 = columns.name.1.max(count(&container.name.to_string()));
+            columns.mem.2 = columns
+                .mem
+                .2
+                .max(count(&container.mem_limit.format(self.memory_unit)));
+            // A marked container has the mark prefix before its name, and the container that oxker is running in has a suffix after its name
+            let mark_count = if self.marked.contains(&container.id) {
+                MARK_WIDTH
+            } else {
+                0
+            };
+            let self_count = if container.is_self { SELF_WIDTH } else { 0 };
+            columns.name.1 = columns
+                .name
+                .1
+                .max(count(&container.name.to_string()) + mark_count + self_count);
             columns.net_rx.1 = columns.net_rx.1.max(count(&container.rx.to_string()));
             columns.net_tx.1 = columns.net_tx.1.max(count(&container.tx.to_string()));
-            columns.state.1 = columns.state.1.max(count(&container.state.to_string()));
+            columns.net_io.1 = columns
+                .net_io
+                .1
+                .max(count(&container.io_rates.rx.to_string()));
+            columns.net_io.2 = columns
+                .net_io
+                .2
+                .max(count(&container.io_rates.tx.to_string()));
+            columns.blk_io.1 = columns
+                .blk_io
+                .1
+                .max(count(&container.io_rates.blk_read.to_string()));
+            columns.blk_io.2 = columns
+                .blk_io
+                .2
+                .max(count(&container.io_rates.blk_write.to_string()));
+            // A container with a healthcheck has the health indicator after its state
+            let health_count = if container.get_health().is_some() {
+                HEALTH_WIDTH
+            } else {
+                0
+            };
+            columns.state.1 = columns
+                .state
+                .1
+                .max(count(&container.state.to_string()) + health_count);
             columns.status.1 = columns.status.1.max(count(&container.status));
+            columns.uptime.1 = columns.uptime.1.max(count(&container.format_uptime(now)));
+            columns.image_size.1 = columns
+                .image_size
+                .1
+                .max(count(&container.format_image_size()));
+            columns.project.1 = columns.project.1.max(count(&container.format_project()));
+            columns.service.1 = columns.service.1.max(count(&container.format_service()));
+            columns.ip.1 = columns.ip.1.max(count(&container.format_ip()));
         }
+        // The cpu heading changes with the cpu mode, and needs room for the sort prefix
+        columns.cpu.1 = columns.cpu.1.max(count(self.cpu_mode.label()) + 2);
         columns
     }
 
-    /// Update related methods
+    // Update related methods
 
-    /// Update container mem, cpu, & network stats, in single function so only need to call .lock() once
+    /// Update container mem, cpu, & network stats, in single function so only need to call .lock() once.
+    /// The cpu usage is per core, and is stored in the current cpu mode, using the number of online cpus.
     /// Will also, if a sort is set, sort the containers
     pub fn update_stats_by_id(
         &mut self,
         id: &ContainerId,
-        cpu_stat: Option<f64>,
+        cpu_stat: Option<(f64, u64)>,
         mem_stat: Option<u64>,
         mem_limit: u64,
-        rx: u64,
-        tx: u64,
+        io: IoTotals,
     ) {
+        let history = self.args.history;
+        let cpu_mode = self.cpu_mode;
         if let Some(container) = self.get_container_by_id(id) {
-            if container.cpu_stats.len() >= 60 {
+            while container.cpu_stats.len() >= history {
                 container.cpu_stats.pop_front();
             }
-            if container.mem_stats.len() >= 60 {
+            while container.mem_stats.len() >= history {
                 container.mem_stats.pop_front();
             }
 
-            if let Some(cpu) = cpu_stat {
-                container.cpu_stats.push_back(CpuStats::new(cpu));
+            if let Some((cpu, cpus)) = cpu_stat {
+                container.cpus = cpus;
+                container
+                    .cpu_stats
+                    .push_back(CpuStats::new(cpu_mode.apply(cpu, cpus)));
             }
             if let Some(mem) = mem_stat {
                 container.mem_stats.push_back(ByteStats::new(mem));
             }
 
-            container.rx.update(rx);
-            container.tx.update(tx);
+            container.update_io(io, Instant::now());
             container.mem_limit.update(mem_limit);
         }
         // need to benchmark this?
         self.sort_containers();
     }
 
+    /// Parse the comma separated shells from the `oxker.shell` label of a container
+    fn parse_shells(labels: Option<&HashMap<String, String>>) -> Vec<String> {
+        labels
+            .and_then(|labels| labels.get(SHELL_LABEL))
+            .map(|i| {
+                i.split(',')
+                    .map(str::trim)
+                    .filter(|i| !i.is_empty())
+                    .map(ToOwned::to_owned)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Update, or insert, containers
+    #[allow(clippy::too_many_lines)]
     pub fn update_containers(&mut self, all_containers: &mut [ContainerSummary]) {
+        self.redraw = true;
+        // Hidden containers are appended, so the existing visible indexes, and therefore the selected index, are unchanged
+        self.containers.items.append(&mut self.hidden_containers);
         let all_ids = self
             .containers
             .items
@@ -581,28 +2268,17 @@ impl AppData {
 
         // Only sort it no containers currently set, as afterwards the order is fixed
         if self.containers.items.is_empty() {
-            all_containers.sort_by(|a, b| a.created.cmp(&b.created));
-        }
-
-        if !all_containers.is_empty() && self.containers.state.selected().is_none() {
-            self.containers.start();
+            all_containers.sort_by_key(|a| a.created);
         }
 
-        for (index, id) in all_ids.iter().enumerate() {
+        // Removed by id, rather than index, as each removal shifts the index of every later container
+        for id in &all_ids {
             if !all_containers
                 .iter()
                 .filter_map(|i| i.id.as_ref())
                 .any(|x| x == id.get())
             {
-                // If removed container is currently selected, then change selected to previous
-                // This will default to 0 in any edge cases
-                if self.containers.state.selected().is_some() {
-                    self.containers.previous();
-                }
-                // Check is some, else can cause out of bounds error, if containers get removed before a docker update
-                if self.containers.items.get(index).is_some() {
-                    self.containers.items.remove(index);
-                }
+                self.remove_container(id);
             }
         }
 
@@ -613,7 +2289,7 @@ impl AppData {
                         if f.starts_with('/') {
                             f.remove(0);
                         }
-                        (*f).to_string()
+                        (*f).clone()
                     })
                 });
 
@@ -623,10 +2299,16 @@ impl AppData {
 
                 let id = ContainerId::from(id.as_str());
 
-                let is_oxker = i
-                    .command
+                // The hostname fallback is only the short id
+                let is_self = self
+                    .args
+                    .self_id
                     .as_ref()
-                    .map_or(false, |i| i.starts_with(ENTRY_POINT));
+                    .is_some_and(|self_id| id.get().starts_with(self_id.as_str()));
+                let is_oxker = is_self
+                    || i.command
+                        .as_ref()
+                        .is_some_and(|i| i.starts_with(ENTRY_POINT));
 
                 let state = State::from(i.state.as_ref().map_or("dead", |z| z));
                 let status = i
@@ -642,76 +2324,255 @@ impl AppData {
                 let created = i
                     .created
                     .map_or(0, |i| u64::try_from(i).unwrap_or_default());
+                let project = i
+                    .labels
+                    .as_ref()
+                    .and_then(|labels| labels.get(COMPOSE_PROJECT))
+                    .cloned();
+                let service = i
+                    .labels
+                    .as_ref()
+                    .and_then(|labels| labels.get(COMPOSE_SERVICE))
+                    .cloned();
+                let command = i.command.clone().filter(|i| !i.is_empty());
+                let shells = Self::parse_shells(i.labels.as_ref());
+                let image_size = i
+                    .image_id
+                    .as_ref()
+                    .and_then(|id| self.image_sizes.get(id))
+                    .copied();
                 // If container info already in containers Vec, then just update details
                 if let Some(item) = self.get_container_by_id(&id) {
                     if item.name.get() != name {
                         item.name.set(name);
-                    };
+                    }
                     if item.status != status {
                         item.status = status;
-                    };
+                    }
                     if item.state != state {
-                        item.docker_controls.items = DockerControls::gen_vec(state);
-                        // Update the list state, needs to be None if the gen_vec returns an empty vec
-                        match state {
-                            State::Removing | State::Restarting | State::Unknown => {
-                                item.docker_controls.state.select(None);
-                            }
-                            _ => item.docker_controls.start(),
-                        };
                         item.state = state;
-                    };
+                        // Re-read the started & finished times, as either will have changed
+                        item.uptime = None;
+                        item.alert = Alert::default();
+                        item.reset_docker_controls();
+                    }
 
                     item.ports = ports;
+                    item.project = project;
+                    item.service = service;
+                    item.command = command;
+                    item.shells = shells;
+                    item.is_self = is_self;
+                    item.image_id.clone_from(&i.image_id);
+                    item.image_size = image_size;
 
                     if item.image.get() != image {
                         item.image.set(image);
-                    };
+                    }
                 } else {
                     // container not known, so make new ContainerItem and push into containers Vec
-                    let container = ContainerItem::new(
+                    let mut container = ContainerItem::new(
                         created, id, image, is_oxker, name, ports, state, status,
                     );
+                    container.project = project;
+                    container.service = service;
+                    container.command = command;
+                    container.shells = shells;
+                    container.is_self = is_self;
+                    container.image_id.clone_from(&i.image_id);
+                    container.image_size = image_size;
                     self.containers.items.push(container);
                 }
             }
         }
+        self.resolve_selection();
+        if self.containers.state.selected().is_none() {
+            self.containers_start();
+        }
+        if self.filter_term.is_some() || self.state_filter != StateFilter::All || self.grouped {
+            self.filter_containers();
+        }
     }
 
-    /// update logs of a given container, based on id
-    pub fn update_log_by_id(&mut self, logs: Vec<String>, id: &ContainerId) {
-        let color = self.args.color;
-        let raw = self.args.raw;
+    /// Store the pretty-printed inspect output of a given container, replaces any previously cached output
+    pub fn update_inspect_by_id(&mut self, id: &ContainerId, inspect: String) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.inspect = Some(inspect);
+        }
+    }
+
+    /// Store the reconstructed docker run command of a given container, replaces any previously cached command
+    pub fn update_run_command_by_id(&mut self, id: &ContainerId, run_command: String) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.run_command = Some(run_command);
+        }
+    }
+
+    /// Update the health of a given container, returns true if a previously healthy, or starting, container has just become unhealthy
+    pub fn update_health_by_id(&mut self, id: &ContainerId, health: Option<Health>) -> bool {
+        self.get_container_by_id(id).is_some_and(|container| {
+            let previous = std::mem::replace(&mut container.health, health);
+            health == Some(Health::Unhealthy) && previous.is_some_and(|i| i != Health::Unhealthy)
+        })
+    }
+
+    /// Compare the latest cpu & memory usage of a given container against the alert thresholds, returns a message if either has just been exceeded.
+    /// A container stays in alert until its usage drops back below the threshold, so each alert is only raised once
+    pub fn update_alert_by_id(&mut self, id: &ContainerId) -> Option<String> {
+        let (cpu_alert, mem_alert) = (self.args.cpu_alert, self.args.mem_alert);
+        let host_memory = self.host_memory;
+        let container = self.get_container_by_id(id)?;
+        let cpu = container.cpu_stats.back().map(Stats::get_value);
+        let mem = container.get_mem_percent(host_memory).map(|i| i.0);
+        let above = |threshold: Option<f64>, value: Option<f64>| {
+            threshold
+                .zip(value)
+                .is_some_and(|(threshold, value)| value > threshold)
+        };
+        let alert = Alert {
+            cpu: above(cpu_alert, cpu),
+            mem: above(mem_alert, mem),
+        };
+        let previous = std::mem::replace(&mut container.alert, alert);
+
+        let exceeded = [
+            (alert.cpu && !previous.cpu, "cpu", cpu_alert),
+            (alert.mem && !previous.mem, "memory", mem_alert),
+        ]
+        .into_iter()
+        .filter_map(|(exceeded, name, threshold)| {
+            threshold
+                .filter(|_| exceeded)
+                .map(|threshold| format!("{name} above {threshold}%"))
+        })
+        .collect::<Vec<_>>();
+        (!exceeded.is_empty()).then(|| format!("{} {}", container.name.get(), exceeded.join(", ")))
+    }
+
+    /// Check if any container is currently above an alert threshold
+    pub fn has_alert(&self) -> bool {
+        self.containers
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+            .any(|i| i.alert.any())
+    }
 
-        let timestamp = self.args.timestamp;
+    /// Update the restart count & restart policy of a given container
+    pub fn update_restarts_by_id(&mut self, id: &ContainerId, restarts: Restarts) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.restarts = Some(restarts);
+        }
+    }
+
+    /// Update the memory limit of a given container, the limit is otherwise only updated by the stats of the container
+    pub fn update_mem_limit_by_id(&mut self, id: &ContainerId, mem_limit: u64) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.mem_limit.update(mem_limit);
+        }
+    }
+
+    /// Update the logging driver of a given container
+    pub fn update_log_driver_by_id(&mut self, id: &ContainerId, log_driver: Option<String>) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.log_driver = log_driver;
+        }
+    }
+
+    /// Mark the logs of a given container as unreadable, or readable again once a read has succeeded
+    pub fn set_logs_unreadable_by_id(&mut self, id: &ContainerId, unreadable: bool) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.logs_unreadable = unreadable;
+        }
+    }
+
+    /// Update the ip addresses of a given container
+    pub fn update_address_by_id(&mut self, id: &ContainerId, address: ContainerAddress) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.address = Some(address);
+        }
+    }
+
+    /// Update the mounts of a given container
+    pub fn update_mounts_by_id(&mut self, id: &ContainerId, mounts: Vec<ContainerMount>) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.mounts = Some(mounts);
+        }
+    }
+
+    /// Update the entrypoint & cmd of a given container
+    pub fn update_container_command_by_id(&mut self, id: &ContainerId, command: ContainerCommand) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.container_command = Some(command);
+        }
+    }
+
+    /// Update the started & finished times of a given container
+    pub fn update_uptime_by_id(&mut self, id: &ContainerId, uptime: Uptime) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.uptime = Some(uptime);
+        }
+    }
 
+    /// Check if the started & finished times of a given container are yet to be read
+    pub fn uptime_unknown(&self, id: &ContainerId) -> bool {
+        self.containers
+            .items
+            .iter()
+            .chain(&self.hidden_containers)
+            .find(|i| &i.id == id)
+            .is_some_and(|i| i.uptime.is_none())
+    }
+
+    /// Store the `KEY=VALUE` environment variables of a given container, replaces any previously cached variables
+    pub fn update_env_by_id(&mut self, id: &ContainerId, env: Vec<String>) {
+        if let Some(container) = self.get_container_by_id(id) {
+            container.env = Some(env);
+        }
+    }
+
+    /// Store the labels of a given container, replaces any previously cached labels
+    pub fn update_labels_by_id(&mut self, id: &ContainerId, labels: BTreeMap<String, String>) {
         if let Some(container) = self.get_container_by_id(id) {
-            if !container.is_oxker {
-                container.last_updated = Self::get_systemtime();
-                let current_len = container.logs.len();
+            container.labels = Some(labels);
+        }
+    }
 
-                for mut i in logs {
+    /// update logs of a given container, based on id
+    pub fn update_log_by_id(&mut self, logs: Vec<(LogStream, String)>, id: &ContainerId) {
+        let args = self.args.clone();
+        let theme = self.theme.clone();
+        let time_format = self.time_format.clone();
+        let timestamps = self.logs_with_timestamps.then_some(&time_format);
+        let log_max_lines = self.args.log_max_lines;
+        let log_streams = self.log_streams;
+        let now = u64::try_from(self.now()).unwrap_or_default();
+
+        if let Some(container) = self.get_container_by_id(id) {
+            // last_updated is left unchanged whilst the stream is paused, so that every line is fetched once resumed
+            if !container.is_oxker && !container.logs.stream_paused() {
+                container.last_updated = now;
+                // A container created since the log streams were last cycled still has the default filter
+                if container.logs.filter() != log_streams {
+                    container
+                        .logs
+                        .set_filter(log_streams, |tz, stream, content| {
+                            Self::gen_log_item(&args, &theme, timestamps, tz, stream, content)
+                        });
+                }
+                for (stream, i) in logs {
                     let tz = LogsTz::from(i.as_str());
-                    if !timestamp {
-                        i = i.replace(&tz.to_string(), "");
-                    }
-                    let lines = if color {
-                        log_sanitizer::colorize_logs(&i)
-                    } else if raw {
-                        log_sanitizer::raw(&i)
-                    } else {
-                        log_sanitizer::remove_ansi(&i)
-                    };
-                    container.logs.insert(ListItem::new(lines), tz);
+                    let tz_string = tz.to_string();
+                    let content = i.strip_prefix(&tz_string).unwrap_or(&i).to_owned();
+                    let item = Self::gen_log_item(&args, &theme, timestamps, &tz, stream, &content);
+                    container.logs.insert(item, content, tz, stream);
                 }
 
-                // Set the logs selected row for each container
-                // Either when no long currently selected, or currently selected (before updated) is already at end
-                if container.logs.state().selected().is_none()
-                    || container.logs.state().selected().map_or(1, |f| f + 1) == current_len
-                {
+                // Keep the last line selected, unless following has been paused by scrolling up
+                if container.logs.follow() {
                     container.logs.end();
                 }
+                container.logs.truncate(log_max_lines);
             }
         }
     }
@@ -722,8 +2583,9 @@ impl AppData {
 mod tests {
 
     use super::*;
-    use crate::tests::{gen_appdata, gen_container_summary, gen_containers};
-    use std::collections::VecDeque;
+    use crate::tests::{gen_appdata, gen_container_summary, gen_containers, gen_stdout, NOW};
+    use bollard::service::{Port, PortTypeEnum};
+    use std::collections::{HashMap, VecDeque};
 
     // ******* //
     // Sort by //
@@ -903,6 +2765,37 @@ mod tests {
         assert_eq!(c.id, ContainerId::from("2"));
     }
 
+    #[test]
+    /// Sort by header: ip, compared as addresses rather than strings, a container without an address is first
+    fn test_app_data_set_sort_by_header_ip() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        for (id, ip) in [("1", "172.17.0.9"), ("2", "172.17.0.10")] {
+            app_data.update_address_by_id(
+                &ContainerId::from(id),
+                ContainerAddress::Networks(vec![(String::from("bridge"), String::from(ip))]),
+            );
+        }
+
+        // descending
+        app_data.set_sorted(Some((Header::Ip, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("1"));
+        assert_eq!(c.id, ContainerId::from("3"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::Ip, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("3"));
+        assert_eq!(b.id, ContainerId::from("1"));
+        assert_eq!(c.id, ContainerId::from("2"));
+    }
+
     #[test]
     /// Sort by header: id
     fn test_app_data_set_sort_by_header_id() {
@@ -1078,19 +2971,174 @@ mod tests {
             i.rx = ByteStats::new(83);
         }
 
-        app_data.set_sorted(Some((Header::Rx, SortedOrder::Asc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("2"));
-        assert_eq!(b.id, ContainerId::from("3"));
-        assert_eq!(c.id, ContainerId::from("1"));
+        app_data.set_sorted(Some((Header::Rx, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("1"));
+
+        app_data.set_sorted(None);
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("2"));
+        assert_eq!(c.id, ContainerId::from("3"));
+    }
+
+    #[test]
+    /// The selected container stays selected when the containers are re-sorted
+    fn test_app_data_sort_keeps_selected() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("1"))
+        );
+
+        app_data.set_sorted(Some((Header::Name, SortedOrder::Desc)));
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("1"))
+        );
+        assert_eq!(app_data.get_container_state().selected(), Some(2));
+
+        // Stats update causes a re-sort
+        app_data.update_stats_by_id(
+            &ContainerId::from("2"),
+            Some((10.0, 4)),
+            Some(10),
+            10,
+            IoTotals::default(),
+        );
+        app_data.set_sorted(Some((Header::Cpu, SortedOrder::Desc)));
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("1"))
+        );
+
+        app_data.reset_sorted();
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("1"))
+        );
+        assert_eq!(app_data.get_container_state().selected(), Some(0));
+    }
+
+    #[test]
+    /// Equal values are sorted by name, then id, so the order is always the same
+    fn test_app_data_sort_stable() {
+        let (_ids, mut containers) = gen_containers();
+        for i in &mut containers {
+            i.name = ContainerName::from("same_name");
+        }
+        let mut app_data = gen_appdata(&containers);
+
+        for _ in 0..3 {
+            app_data.set_sorted(Some((Header::Cpu, SortedOrder::Asc)));
+            let result = app_data
+                .get_container_items()
+                .iter()
+                .map(|i| i.id.clone())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                result,
+                vec![
+                    ContainerId::from("1"),
+                    ContainerId::from("2"),
+                    ContainerId::from("3")
+                ]
+            );
+        }
+    }
+
+    #[test]
+    /// Cycle through each sort header, and then back to no sort, reverse the sort order
+    fn test_app_data_cycle_sort_header() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        app_data.reverse_sort_order();
+        assert!(app_data.get_sorted().is_none());
+
+        app_data.cycle_sort_header();
+        assert_eq!(
+            app_data.get_sorted(),
+            Some((Header::Name, SortedOrder::Asc))
+        );
+
+        app_data.reverse_sort_order();
+        assert_eq!(
+            app_data.get_sorted(),
+            Some((Header::Name, SortedOrder::Desc))
+        );
+
+        app_data.cycle_sort_header();
+        assert_eq!(
+            app_data.get_sorted(),
+            Some((Header::State, SortedOrder::Desc))
+        );
+
+        for _ in 0..10 {
+            app_data.cycle_sort_header();
+        }
+        assert_eq!(
+            app_data.get_sorted(),
+            Some((Header::Uptime, SortedOrder::Desc))
+        );
+        app_data.cycle_sort_header();
+        assert_eq!(
+            app_data.get_sorted(),
+            Some((Header::ImageSize, SortedOrder::Desc))
+        );
+        app_data.cycle_sort_header();
+        app_data.cycle_sort_header();
+        assert_eq!(
+            app_data.get_sorted(),
+            Some((Header::Service, SortedOrder::Desc))
+        );
+        app_data.cycle_sort_header();
+        assert_eq!(app_data.get_sorted(), Some((Header::Ip, SortedOrder::Desc)));
+        app_data.cycle_sort_header();
+        assert!(app_data.get_sorted().is_none());
+    }
+
+    #[test]
+    /// Image sizes are only needed if shown or sorted by, containers with a deleted image have no size, and sort first
+    fn test_app_data_image_sizes() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert!(!app_data.image_sizes_needed());
+        app_data.set_sort_by_header(Header::ImageSize);
+        assert!(app_data.image_sizes_needed());
+        app_data.reset_sorted();
+        app_data.set_visible_columns(vec![Header::Name, Header::ImageSize]);
+        assert!(app_data.image_sizes_needed());
+
+        app_data.containers.items[0].image_id = Some("sha256:1".to_owned());
+        app_data.containers.items[1].image_id = Some("sha256:2".to_owned());
+        app_data.containers.items[2].image_id = Some("sha256:deleted".to_owned());
+        app_data.update_image_sizes(HashMap::from([
+            ("sha256:1".to_owned(), ByteStats::new(3_000_000)),
+            ("sha256:2".to_owned(), ByteStats::new(1_000)),
+        ]));
+
+        let sizes = app_data
+            .get_container_items()
+            .iter()
+            .map(ContainerItem::format_image_size)
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, ["3.00 MB", "1.00 kB", "--"]);
+        assert_eq!(app_data.get_width(IdMode::Short).image_size.1, 10);
 
-        app_data.set_sorted(None);
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("1"));
-        assert_eq!(b.id, ContainerId::from("2"));
-        assert_eq!(c.id, ContainerId::from("3"));
+        app_data.set_sort_by_header(Header::ImageSize);
+        let result = app_data
+            .get_container_items()
+            .iter()
+            .map(|i| i.id.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(result, [ids[2].clone(), ids[1].clone(), ids[0].clone()]);
     }
 
     // **************** //
@@ -1201,32 +3249,642 @@ mod tests {
             ))
         );
 
-        // Calling previous when at end has no effect
+        // Calling previous when at end has no effect
+        app_data.containers_next();
+        let result = app_data.get_selected_container_id();
+        assert_eq!(result, Some(ContainerId::from("3")));
+        let result = app_data.get_selected_container_id_state_name();
+        assert_eq!(
+            result,
+            Some((
+                ContainerId::from("3"),
+                State::Running,
+                "container_3".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    /// go to previous container
+    fn test_app_data_containers_prev() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        app_data.containers_end();
+        app_data.containers_previous();
+        let result = app_data.get_container_state();
+        assert_eq!(result.selected(), Some(1));
+        assert_eq!(result.offset(), 0);
+    }
+
+    // ****** //
+    // Filter //
+    // ****** //
+
+    #[test]
+    /// Search jumps between containers whose name matches, wrapping around, without hiding any containers, and continues from the selected container after a re-sort
+    fn test_app_data_search_containers() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers.start();
+
+        app_data.search_start();
+        assert_eq!(app_data.get_search_term(), Some(&String::new()));
+        assert!(!app_data.has_search());
+
+        app_data.search_push('C');
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[0].clone()));
+        assert_eq!(app_data.get_container_len(), 3);
+
+        app_data.search_next(true);
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[1].clone()));
+        app_data.search_next(true);
+        app_data.search_next(true);
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[0].clone()));
+        app_data.search_next(false);
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[2].clone()));
+
+        // Sorted by name descending, container_3 is now first, so the next match is container_2
+        app_data.set_sorted(Some((Header::Name, SortedOrder::Desc)));
+        app_data.search_next(true);
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[1].clone()));
+
+        // The selection jumps as each char is typed, and stays put if there is no match
+        app_data.search_pop();
+        for c in "_1".chars() {
+            app_data.search_push(c);
+        }
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[0].clone()));
+        app_data.search_push('9');
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[0].clone()));
+
+        app_data.search_clear();
+        assert!(app_data.get_search_term().is_none());
+        assert!(!app_data.has_search());
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[0].clone()));
+    }
+
+    #[test]
+    /// Only visible, running, containers have their stats streamed, with the selected container first
+    fn test_app_data_get_stat_stream_ids() {
+        let (ids, mut containers) = gen_containers();
+        containers[1].state = State::Exited;
+        let mut app_data = gen_appdata(&containers);
+        assert_eq!(
+            app_data.get_stat_stream_ids(),
+            [ids[0].clone(), ids[2].clone()]
+        );
+
+        app_data.containers_end();
+        assert_eq!(
+            app_data.get_stat_stream_ids(),
+            [ids[2].clone(), ids[0].clone()]
+        );
+
+        for c in "container_1".chars() {
+            app_data.filter_push(c);
+        }
+        assert_eq!(app_data.get_stat_stream_ids(), [ids[0].clone()]);
+
+        // The hidden containers are included whilst the summary bar totals every container
+        app_data.cycle_summary();
+        assert_eq!(app_data.get_summary(), Summary::All);
+        assert_eq!(
+            app_data.get_stat_stream_ids(),
+            [ids[0].clone(), ids[2].clone()]
+        );
+        assert_eq!(app_data.get_totals().map(|i| i.total), Some(3));
+        app_data.cycle_summary();
+        assert_eq!(app_data.get_stat_stream_ids(), [ids[0].clone()]);
+        assert_eq!(app_data.get_totals().map(|i| i.total), Some(1));
+        app_data.cycle_summary();
+        assert_eq!(app_data.get_totals(), None);
+    }
+
+    #[test]
+    /// Filter containers by name or image, case-insensitive, selected container is kept if visible
+    fn test_app_data_filter_containers() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_end();
+
+        app_data.filter_start();
+        assert_eq!(app_data.get_filter_term(), Some(&String::new()));
+        assert_eq!(app_data.get_container_len(), 3);
+
+        for c in "CONTAINER_3".chars() {
+            app_data.filter_push(c);
+        }
+        assert_eq!(app_data.get_container_len(), 1);
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[2].clone()));
+        assert_eq!(app_data.get_container_state().selected(), Some(0));
+
+        // Widening the filter keeps the same container selected
+        app_data.filter_pop();
+        assert_eq!(app_data.get_container_len(), 3);
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[2].clone()));
+        assert_eq!(app_data.get_container_state().selected(), Some(2));
+
+        // Filter by image, selected container is hidden, so first visible container is selected
+        app_data.filter_clear();
+        for c in "Image_2".chars() {
+            app_data.filter_push(c);
+        }
+        assert_eq!(app_data.get_container_len(), 1);
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[1].clone()));
+
+        app_data.filter_clear();
+        assert!(app_data.get_filter_term().is_none());
+        assert_eq!(app_data.get_container_len(), 3);
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[1].clone()));
+    }
+
+    #[test]
+    /// The state filter cycles through each state, hidden containers are kept, and combine with the name filter
+    fn test_app_data_state_filter() {
+        let (_, mut containers) = gen_containers();
+        containers[1].state = State::Exited;
+        containers[2].state = State::Paused;
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+
+        let names = |app_data: &AppData| {
+            app_data
+                .get_container_items()
+                .iter()
+                .map(|i| i.name.get().to_owned())
+                .collect::<Vec<_>>()
+        };
+
+        app_data.cycle_state_filter();
+        assert_eq!(app_data.get_state_filter(), StateFilter::Running);
+        assert_eq!(names(&app_data), ["container_1"]);
+        app_data.cycle_state_filter();
+        assert_eq!(names(&app_data), ["container_2"]);
+        assert_eq!(app_data.get_stopped_len(), 1);
+        app_data.cycle_state_filter();
+        assert_eq!(names(&app_data), ["container_3"]);
+
+        // Combined with the name filter, no containers match
+        app_data.filter_push('1');
+        assert!(names(&app_data).is_empty());
+        app_data.filter_clear();
+
+        app_data.cycle_state_filter();
+        assert_eq!(app_data.get_state_filter(), StateFilter::All);
+        assert_eq!(
+            names(&app_data),
+            ["container_1", "container_2", "container_3"]
+        );
+    }
+
+    #[test]
+    /// When no containers match the filter, nothing is selected, and navigation doesn't panic
+    fn test_app_data_filter_containers_no_match() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_end();
+
+        app_data.filter_push('z');
+        assert_eq!(app_data.get_container_len(), 0);
+        assert!(app_data.get_container_state().selected().is_none());
+        assert!(app_data.get_selected_container().is_none());
+
+        app_data.containers_next();
+        app_data.containers_previous();
+        app_data.containers_end();
+        assert!(app_data.get_selected_container().is_none());
+
+        app_data.filter_clear();
+        assert_eq!(app_data.get_container_len(), 3);
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[0].clone()));
+    }
+
+    #[test]
+    /// Hidden containers are still updated, and stay hidden when docker updates the containers
+    fn test_app_data_filter_containers_hidden_updated() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        for c in "container_1".chars() {
+            app_data.filter_push(c);
+        }
+        app_data.update_stats_by_id(&ids[1], Some((10.0, 4)), Some(10), 10, IoTotals::default());
+
+        let mut input = [
+            gen_container_summary(1, "running"),
+            gen_container_summary(2, "paused"),
+            gen_container_summary(3, "running"),
+        ];
+        app_data.update_containers(&mut input);
+        assert_eq!(app_data.get_container_len(), 1);
+        assert_eq!(
+            app_data.get_container_state_by_id(&ids[1]),
+            Some(State::Paused)
+        );
+        assert!(app_data.get_container_name_by_id(&ids[2]).is_some());
+
+        app_data.remove_container(&ids[2]);
+        app_data.filter_clear();
+        let result = app_data.get_container_items();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[1].cpu_stats, VecDeque::from([CpuStats::new(10.0)]));
+    }
+
+    #[test]
+    /// Only the most recent "--history" stats are kept, and are returned as sparkline data
+    fn test_app_data_stats_history() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.args.history = 3;
+        app_data.containers_start();
+
+        for i in 1..=5_u32 {
+            app_data.update_stats_by_id(
+                &ids[0],
+                Some((f64::from(i), 4)),
+                Some(u64::from(i) * 10),
+                100,
+                IoTotals::default(),
+            );
+        }
+        let result = app_data.get_container_items();
+        assert_eq!(
+            result[0].cpu_stats,
+            VecDeque::from([CpuStats::new(3.0), CpuStats::new(4.0), CpuStats::new(5.0)])
+        );
+        assert_eq!(
+            result[0].mem_stats,
+            VecDeque::from([ByteStats::new(30), ByteStats::new(40), ByteStats::new(50)])
+        );
+
+        let ((cpu, cpu_current), (mem, mem_current), state) = app_data.get_history_data().unwrap();
+        assert_eq!(cpu, vec![300, 400, 500]);
+        assert_eq!(cpu_current, CpuStats::new(5.0));
+        assert_eq!(mem, vec![30, 40, 50]);
+        assert_eq!(mem_current, ByteStats::new(50));
+        assert_eq!(state, State::Running);
+    }
+
+    /// Generate container summaries, with the given compose project labels
+    fn gen_compose_summaries(projects: &[Option<&str>]) -> Vec<ContainerSummary> {
+        projects
+            .iter()
+            .enumerate()
+            .map(|(index, project)| {
+                let mut summary = gen_container_summary(index + 1, "running");
+                summary.labels = project.map(|project| {
+                    HashMap::from([(COMPOSE_PROJECT.to_owned(), project.to_owned())])
+                });
+                summary
+            })
+            .collect()
+    }
+
+    #[test]
+    /// The container that oxker is running in is found by its id, and is treated as oxker, with room for the suffix after its name
+    fn test_app_data_update_containers_is_self() {
+        let mut app_data = gen_appdata(&[]);
+        app_data.args.in_container = true;
+        app_data.args.self_id = Some("2".to_owned());
+        let mut input = vec![
+            gen_container_summary(1, "running"),
+            gen_container_summary(2, "running"),
+        ];
+        app_data.update_containers(&mut input);
+
+        assert!(!app_data.is_oxker_in_container());
+        assert_eq!(app_data.get_width(IdMode::Short).name.1, 18);
+        app_data.containers_next();
+        assert!(app_data.is_oxker_in_container());
+        assert!(app_data.is_oxker());
+        assert!(app_data.is_oxker_in_container_by_id(&ContainerId::from("2")));
+        assert!(!app_data.is_oxker_in_container_by_id(&ContainerId::from("1")));
+    }
+
+    #[test]
+    /// The shells from the `oxker.shell` label of the selected container are tried before the `--shell` and config file shells
+    fn test_app_data_get_exec_shells() {
+        let mut app_data = gen_appdata(&[]);
+        let mut input = vec![
+            gen_container_summary(1, "running"),
+            gen_container_summary(2, "running"),
+        ];
+        input[0].labels = Some(HashMap::from([(
+            SHELL_LABEL.to_owned(),
+            "bash, ash,".to_owned(),
+        )]));
+        app_data.update_containers(&mut input);
+        app_data.set_shells(vec!["zsh".to_owned()]);
+
+        assert_eq!(app_data.get_exec_shells(), ["bash", "ash", "zsh"]);
+        app_data.containers_next();
+        assert_eq!(app_data.get_exec_shells(), ["zsh"]);
+    }
+
+    #[test]
+    /// The compose project is parsed from the container labels, and grouping sorts the containers by project, with ungrouped containers last
+    fn test_app_data_group_sort() {
+        let mut app_data = gen_appdata(&[]);
+        let mut input = gen_compose_summaries(&[Some("web"), None, Some("db"), Some("web")]);
+        app_data.update_containers(&mut input);
+
+        let projects = |app_data: &AppData| {
+            app_data
+                .get_container_items()
+                .iter()
+                .map(|i| (i.name.get().to_owned(), i.project.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            projects(&app_data)
+                .into_iter()
+                .map(|(_, project)| project)
+                .collect::<Vec<_>>(),
+            vec![
+                Some("web".to_owned()),
+                None,
+                Some("db".to_owned()),
+                Some("web".to_owned())
+            ]
+        );
+        assert_eq!(app_data.get_container_lines(), 4);
+
+        app_data.toggle_grouped();
+        assert!(app_data.is_grouped());
+        assert_eq!(
+            projects(&app_data),
+            vec![
+                ("container_3".to_owned(), Some("db".to_owned())),
+                ("container_1".to_owned(), Some("web".to_owned())),
+                ("container_4".to_owned(), Some("web".to_owned())),
+                ("container_2".to_owned(), None),
+            ]
+        );
+        // Each group has a heading line
+        assert_eq!(app_data.get_container_lines(), 7);
+        assert_eq!(app_data.get_group_count(Some(&"web".to_owned())), (2, 2));
+        assert_eq!(app_data.get_group_count(None), (1, 1));
+
+        app_data.toggle_grouped();
+        assert!(!app_data.is_grouped());
+        assert_eq!(app_data.get_container_lines(), 4);
+    }
+
+    #[test]
+    /// The compose service is parsed from the container labels, sorting by service keeps the replicas of a service together, and the filter matches the service & project
+    fn test_app_data_compose_service() {
+        let mut app_data = gen_appdata(&[]);
+        let mut input = gen_compose_summaries(&[Some("shop"), Some("shop"), None, Some("shop")]);
+        for (summary, service) in input.iter_mut().zip(["web", "db", "", "web"]) {
+            if let Some(labels) = summary.labels.as_mut() {
+                labels.insert(COMPOSE_SERVICE.to_owned(), service.to_owned());
+            }
+        }
+        app_data.update_containers(&mut input);
+        let services = |app_data: &AppData| {
+            app_data
+                .get_container_items()
+                .iter()
+                .map(|i| (i.name.get().to_owned(), i.format_service()))
+                .collect::<Vec<_>>()
+        };
+
+        app_data.set_sort_by_header(Header::Service);
+        assert_eq!(
+            services(&app_data),
+            vec![
+                ("container_3".to_owned(), "--".to_owned()),
+                ("container_2".to_owned(), "db".to_owned()),
+                ("container_1".to_owned(), "web".to_owned()),
+                ("container_4".to_owned(), "web".to_owned()),
+            ]
+        );
+
+        app_data.set_visible_columns(vec![Header::Name, Header::Service, Header::Project]);
+        let width = app_data.get_width(IdMode::Short);
+        assert_eq!(width.service, (Header::Service, 7));
+        assert_eq!(width.project, (Header::Project, 7));
+
+        app_data.filter_start();
+        for c in "WEB".chars() {
+            app_data.filter_push(c);
+        }
+        assert_eq!(
+            services(&app_data),
+            vec![
+                ("container_1".to_owned(), "web".to_owned()),
+                ("container_4".to_owned(), "web".to_owned()),
+            ]
+        );
+
+        app_data.filter_clear();
+        app_data.filter_start();
+        for c in "shop".chars() {
+            app_data.filter_push(c);
+        }
+        assert_eq!(app_data.get_container_len(), 3);
+    }
+
+    #[test]
+    /// Going to a row selects the container at that 1-based row, clamped to the list
+    fn test_app_data_containers_go_to() {
+        let mut app_data = gen_appdata(&[]);
+        app_data.containers_go_to(2);
+        assert!(app_data.get_container_state().selected().is_none());
+
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_go_to(2);
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("2"))
+        );
+        app_data.containers_go_to(100);
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("3"))
+        );
+        app_data.containers_go_to(0);
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("1"))
+        );
+    }
+
+    #[test]
+    /// Clicking a row selects the container drawn on it, when grouped the headings take up a row, and a collapsed group is only its heading
+    fn test_app_data_containers_select_row() {
+        let mut app_data = gen_appdata(&[]);
+        let mut input = gen_compose_summaries(&[Some("web"), None, Some("db"), Some("web")]);
+        app_data.update_containers(&mut input);
+        let selected = |app_data: &AppData| app_data.get_selected_container_id().unwrap();
+
+        app_data.containers_select_row(2);
+        assert_eq!(selected(&app_data), ContainerId::from("3"));
+        // Past the last container, the selection is unchanged
+        app_data.containers_select_row(10);
+        assert_eq!(selected(&app_data), ContainerId::from("3"));
+
+        app_data.toggle_grouped();
+        for (row, id) in [
+            (0, "3"),
+            (1, "3"),
+            (2, "1"),
+            (3, "1"),
+            (4, "4"),
+            (5, "2"),
+            (6, "2"),
+        ] {
+            app_data.containers_select_row(row);
+            assert_eq!(selected(&app_data), ContainerId::from(id));
+        }
+
+        app_data.containers_select_row(3);
+        app_data.toggle_group_collapsed();
+        for (row, id) in [(1, "3"), (2, "1"), (3, "2"), (4, "2")] {
+            app_data.containers_select_row(row);
+            assert_eq!(selected(&app_data), ContainerId::from(id));
+        }
+    }
+
+    #[test]
+    /// A collapsed group is represented by its first container, with group controls that apply to every container in the group
+    fn test_app_data_group_collapse() {
+        let mut app_data = gen_appdata(&[]);
+        let mut input = gen_compose_summaries(&[Some("web"), None, Some("db"), Some("web")]);
+        app_data.update_containers(&mut input);
+
+        // Not grouped, so collapsing does nothing
+        app_data.containers_start();
+        app_data.toggle_group_collapsed();
+        assert_eq!(app_data.get_container_len(), 4);
+        assert!(app_data.get_selected_group_ids().is_none());
+
+        app_data.toggle_grouped();
+        app_data.containers_start();
+        app_data.containers_next();
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("1"))
+        );
+
+        app_data.toggle_group_collapsed();
+        let web = Some("web".to_owned());
+        assert!(app_data.is_group_collapsed(web.as_ref()));
+        assert_eq!(app_data.get_container_len(), 3);
+        // The collapsed group has only the heading line
+        assert_eq!(app_data.get_container_lines(), 5);
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("1"))
+        );
+        assert_eq!(
+            app_data.get_selected_group_ids(),
+            Some(vec![ContainerId::from("1"), ContainerId::from("4")])
+        );
+        assert_eq!(
+            app_data
+                .get_selected_container()
+                .unwrap()
+                .docker_controls
+                .items,
+            DockerControls::gen_group_vec()
+        );
+        assert_eq!(app_data.get_group_count(web.as_ref()), (2, 2));
+
+        // Hidden containers are still updated
+        let mut input = gen_compose_summaries(&[Some("web"), None, Some("db"), Some("web")]);
+        input[3].state = Some("exited".to_owned());
+        app_data.update_containers(&mut input);
+        assert_eq!(app_data.get_container_len(), 3);
+        assert_eq!(app_data.get_group_count(web.as_ref()), (2, 1));
+
+        app_data.toggle_group_collapsed();
+        assert!(!app_data.is_group_collapsed(web.as_ref()));
+        assert_eq!(app_data.get_container_len(), 4);
+        assert!(app_data.get_selected_group_ids().is_none());
+        assert_ne!(
+            app_data
+                .get_selected_container()
+                .unwrap()
+                .docker_controls
+                .items,
+            DockerControls::gen_group_vec()
+        );
+    }
+
+    #[test]
+    /// Marked containers are tracked by id, so stay marked after a sort, and are unmarked once removed
+    fn test_app_data_marked() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert!(app_data.get_marked_ids().is_none());
+
+        app_data.containers_start();
+        app_data.toggle_marked();
         app_data.containers_next();
-        let result = app_data.get_selected_container_id();
-        assert_eq!(result, Some(ContainerId::from("3")));
-        let result = app_data.get_selected_container_id_state_name();
+        app_data.containers_next();
+        app_data.toggle_marked();
+        assert!(app_data.is_marked(&ids[0]));
+        assert!(!app_data.is_marked(&ids[1]));
+        assert!(app_data.is_marked(&ids[2]));
+        assert_eq!(app_data.container_title(), " 3/3 - 2 marked");
+        // The mark prefix is included in the name width
+        assert_eq!(app_data.get_width(IdMode::Short).name.1, 13);
+
+        app_data.set_sorted(Some((Header::Name, SortedOrder::Desc)));
         assert_eq!(
-            result,
-            Some((
-                ContainerId::from("3"),
-                State::Running,
-                "container_3".to_owned()
-            ))
+            app_data.get_marked_ids(),
+            Some(vec![ids[2].clone(), ids[0].clone()])
         );
+
+        // Toggling again unmarks
+        app_data.containers_start();
+        app_data.toggle_marked();
+        assert_eq!(app_data.get_marked_ids(), Some(vec![ids[0].clone()]));
+
+        app_data.remove_container(&ids[0]);
+        assert!(app_data.get_marked_ids().is_none());
+        assert_eq!(app_data.container_title(), " 1/2");
+
+        app_data.toggle_marked();
+        assert!(app_data.get_marked_ids().is_some());
+        app_data.clear_marked();
+        assert!(app_data.get_marked_ids().is_none());
     }
 
     #[test]
-    /// go to previous container
-    fn test_app_data_containers_prev() {
+    /// The update interval is halved or doubled, within the bounds, and is only "changed" when it differs from the argument
+    fn test_app_data_docker_interval() {
         let (_ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        assert_eq!(app_data.get_docker_interval(), 1000);
+        assert!(app_data.get_changed_interval().is_none());
 
-        app_data.containers_end();
-        app_data.containers_previous();
-        let result = app_data.get_container_state();
-        assert_eq!(result.selected(), Some(1));
-        assert_eq!(result.offset(), 0);
+        for expected in [500, 250, 125, 100, 100] {
+            app_data.interval_faster();
+            assert_eq!(app_data.get_docker_interval(), expected);
+        }
+        assert_eq!(app_data.get_changed_interval(), Some(100));
+
+        for expected in [200, 400, 800, 1600, 3200, 6400, 10_000, 10_000] {
+            app_data.interval_slower();
+            assert_eq!(app_data.get_docker_interval(), expected);
+        }
+
+        // An interval set by argument outside of the bounds isn't moved further outside of them
+        app_data.docker_interval = 50;
+        app_data.interval_faster();
+        assert_eq!(app_data.get_docker_interval(), 50);
+        app_data.interval_slower();
+        assert_eq!(app_data.get_docker_interval(), 100);
+
+        app_data.docker_interval = 20_000;
+        app_data.interval_slower();
+        assert_eq!(app_data.get_docker_interval(), 20_000);
+        app_data.interval_faster();
+        assert_eq!(app_data.get_docker_interval(), 10_000);
     }
 
     #[test]
@@ -1259,6 +3917,69 @@ mod tests {
         assert_eq!(result, Some(&mut containers[1]));
     }
 
+    #[test]
+    /// Get the state of a container by id
+    fn test_app_data_get_container_state_by_id() {
+        let (_ids, containers) = gen_containers();
+        let app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_state_by_id(&ContainerId::from("2"));
+        assert_eq!(result, Some(State::Running));
+        let result = app_data.get_container_state_by_id(&ContainerId::from("4"));
+        assert!(result.is_none());
+    }
+
+    #[test]
+    /// Changes from docker mark the data as changed, and taking the flag resets it, selection changes are marked by the input handler instead
+    fn test_app_data_redraw() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert!(app_data.take_redraw());
+        assert!(!app_data.take_redraw());
+
+        app_data.containers_next();
+        assert!(!app_data.take_redraw());
+
+        app_data.update_volumes(vec![]);
+        assert!(app_data.take_redraw());
+
+        app_data.update_log_by_id(vec![], &ContainerId::from("1"));
+        assert!(app_data.take_redraw());
+        assert!(!app_data.take_redraw());
+    }
+
+    #[test]
+    /// Removing a container keeps the same container selected, or the previous one if the selected container is removed
+    fn test_app_data_remove_container() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        // Remove the last, selected, container
+        app_data.containers_end();
+        app_data.remove_container(&ContainerId::from("3"));
+        assert_eq!(app_data.get_container_len(), 2);
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("2"))
+        );
+
+        // Remove a container before the selected container
+        app_data.remove_container(&ContainerId::from("1"));
+        assert_eq!(app_data.get_container_len(), 1);
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("2"))
+        );
+
+        // Unknown id is a no-op
+        app_data.remove_container(&ContainerId::from("4"));
+        assert_eq!(app_data.get_container_len(), 1);
+
+        app_data.remove_container(&ContainerId::from("2"));
+        assert_eq!(app_data.get_container_len(), 0);
+        assert!(app_data.get_selected_container_id().is_none());
+    }
+
     #[test]
     /// Get just the containers name by id
     fn test_app_data_get_container_name_by_id() {
@@ -1269,6 +3990,42 @@ mod tests {
         assert_eq!(result, Some(ContainerName::from("container_2")));
     }
 
+    #[test]
+    /// Renaming a container updates its name, and re-sorts the containers, keeping the same container selected
+    fn test_app_data_rename_container() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.set_sort_by_header(Header::Name);
+        app_data.containers_start();
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("1"))
+        );
+
+        app_data.rename_container(&ContainerId::from("1"), "container_4");
+        assert_eq!(
+            app_data.get_container_name_by_id(&ContainerId::from("1")),
+            Some(ContainerName::from("container_4"))
+        );
+        let names = app_data
+            .get_container_items()
+            .iter()
+            .map(|i| i.name.get().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["container_2", "container_3", "container_4"]);
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("1"))
+        );
+
+        // Unknown ids are ignored
+        app_data.rename_container(&ContainerId::from("4"), "container_5");
+        assert!(app_data
+            .get_container_items()
+            .iter()
+            .all(|i| i.name.get() != "container_5"));
+    }
+
     #[test]
     /// Get the id of the currently selected container
     fn test_app_data_get_selected_container_id() {
@@ -1411,7 +4168,7 @@ mod tests {
         test_state(
             State::Paused,
             &mut vec![
-                DockerControls::Resume,
+                DockerControls::Unpause,
                 DockerControls::Stop,
                 DockerControls::Delete,
             ],
@@ -1430,99 +4187,555 @@ mod tests {
                 DockerControls::Delete,
             ],
         );
-        test_state(State::Unknown, &mut vec![DockerControls::Delete]);
+        test_state(State::Unknown, &mut vec![DockerControls::Delete]);
+    }
+
+    // **** //
+    // Logs //
+    // **** //
+
+    #[test]
+    /// log title string generated correctly
+    fn test_app_data_get_log_title() {
+        let (ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        // No container selected select
+        let result = app_data.get_log_title();
+        assert_eq!(result, "");
+
+        // No logs
+        app_data.containers.start();
+        let result = app_data.get_log_title();
+        assert_eq!(result, " - container_1");
+
+        // On last line of logs
+        let logs = (1..=3).map(|i| format!("{i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 3/3 - container_1");
+
+        // Change log state to no longer be at the end
+        app_data.log_previous();
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 2/3 paused - container_1");
+
+        // The number of lines since the log marker
+        assert_eq!(app_data.cycle_log_marker(), Some(LogMarker::Set(3)));
+        app_data.update_log_by_id(gen_stdout(vec![String::from("4")]), &ids[0]);
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 2/4 paused marker +1 - container_1");
+
+        assert_eq!(app_data.cycle_log_marker(), Some(LogMarker::Only(3)));
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 1/1 paused 1 new since marker - container_1");
+
+        // The same line stays selected once every line is shown again
+        assert_eq!(app_data.cycle_log_marker(), None);
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 4/4 paused - container_1");
+    }
+
+    #[test]
+    /// log title string generated correctly after container change
+    fn test_app_data_get_log_title_after_container_change() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        // No container selected select
+        let result = app_data.get_log_title();
+        assert_eq!(result, "");
+
+        app_data.containers_start();
+
+        let result = app_data.get_log_title();
+        assert_eq!(result, " - container_1");
+
+        // change container
+        app_data.containers_next();
+        let result = app_data.get_log_title();
+        assert_eq!(result, " - container_2");
+
+        // On last line of logs
+        let logs = (1..=3).map(|i| format!("{i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[1]);
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 3/3 - container_2");
+
+        // Change log state to no longer be at the end
+        app_data.log_previous();
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 2/3 paused - container_2");
+    }
+
+    #[test]
+    /// update logs by id works
+    fn test_app_data_update_log_by_id() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        // No container selected select
+        let result = app_data.get_log_title();
+        assert_eq!(result, "");
+
+        app_data.containers_start();
+        let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
+
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+        // app_data.log_start();
+
+        let result = app_data.get_log_state();
+        assert!(result.is_some());
+        assert_eq!(result.as_ref().unwrap().selected(), Some(2));
+        assert_eq!(result.unwrap().offset(), 0);
+
+        let result = app_data.get_logs(None);
+        assert_eq!(result.len(), 3);
+
+        let result = app_data.get_log_title();
+        assert_eq!(result, " 3/3 - container_1");
+    }
+
+    #[test]
+    /// Only a transition from healthy, or starting, to unhealthy is reported, and health is only shown for running containers
+    fn test_app_data_update_health_by_id() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        assert!(!app_data.update_health_by_id(&ids[0], Some(Health::Unhealthy)));
+        assert!(!app_data.update_health_by_id(&ids[0], Some(Health::Unhealthy)));
+        assert!(!app_data.update_health_by_id(&ids[0], Some(Health::Starting)));
+        assert!(app_data.update_health_by_id(&ids[0], Some(Health::Unhealthy)));
+        assert!(!app_data.update_health_by_id(&ids[0], None));
+        assert!(!app_data.update_health_by_id(&ContainerId::from("4"), Some(Health::Healthy)));
+
+        app_data.update_health_by_id(&ids[0], Some(Health::Healthy));
+        assert_eq!(
+            app_data.get_container_items()[0].get_health(),
+            Some(Health::Healthy)
+        );
+        app_data.containers.items[0].state = State::Exited;
+        assert_eq!(app_data.get_container_items()[0].get_health(), None);
+    }
+
+    #[test]
+    /// The "--sort", "--sort-desc", and "--state", arguments set the initial sort & state filter, and take priority over the saved settings
+    fn test_app_data_sort_state_args() {
+        let (_, containers) = gen_containers();
+        let mut args = gen_appdata(&containers).args;
+        args.sort = Some(Header::Cpu);
+        args.sort_desc = true;
+        args.state = Some(StateFilter::Running);
+        let mut app_data = AppData::default(args, Theme::default());
+        assert_eq!(
+            app_data.get_sorted(),
+            Some((Header::Cpu, SortedOrder::Desc))
+        );
+        assert_eq!(app_data.get_state_filter(), StateFilter::Running);
+
+        app_data.restore_view(&ViewConfig {
+            sort: Some(Header::Name),
+            state_filter: Some(StateFilter::Exited),
+            ..ViewConfig::default()
+        });
+        assert_eq!(
+            app_data.get_sorted(),
+            Some((Header::Cpu, SortedOrder::Desc))
+        );
+        assert_eq!(app_data.get_state_filter(), StateFilter::Running);
+    }
+
+    #[test]
+    /// Saved view settings are restored, the "-t" argument takes priority over the saved log timestamps
+    fn test_app_data_restore_view() {
+        let (_, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.args.timestamp = true;
+        app_data.restore_view(&ViewConfig::default());
+        assert_eq!(app_data.get_sorted(), None);
+
+        let view = ViewConfig {
+            sort: Some(Header::Cpu),
+            state_filter: Some(StateFilter::Exited),
+            grouped: Some(true),
+            log_timestamps: Some(false),
+            ..ViewConfig::default()
+        };
+        app_data.restore_view(&view);
+        assert_eq!(app_data.get_sorted(), Some((Header::Cpu, SortedOrder::Asc)));
+        assert_eq!(app_data.get_state_filter(), StateFilter::Exited);
+        assert!(app_data.is_grouped());
+        assert!(!app_data.get_logs_with_timestamps());
+
+        let mut app_data = gen_appdata(&containers);
+        app_data.args.timestamp = false;
+        app_data.logs_with_timestamps = false;
+        app_data.restore_view(&ViewConfig {
+            log_timestamps: Some(true),
+            ..ViewConfig::default()
+        });
+        assert!(!app_data.get_logs_with_timestamps());
+    }
+
+    #[test]
+    /// An alert message is only returned when a threshold is first exceeded, and the alert is cleared once back below the threshold
+    fn test_app_data_update_alert_by_id() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let update = |app_data: &mut AppData, cpu: f64, mem: u64| {
+            app_data.update_stats_by_id(
+                &ids[0],
+                Some((cpu, 4)),
+                Some(mem),
+                1000,
+                IoTotals::default(),
+            );
+            app_data.update_alert_by_id(&ids[0])
+        };
+
+        // No thresholds set
+        assert!(update(&mut app_data, 90.0, 900).is_none());
+        assert!(!app_data.has_alert());
+
+        app_data.args.cpu_alert = Some(80.0);
+        app_data.args.mem_alert = Some(50.0);
+        assert_eq!(
+            update(&mut app_data, 90.0, 100),
+            Some("container_1 cpu above 80%".to_owned())
+        );
+        assert!(app_data.has_alert());
+        assert!(update(&mut app_data, 95.0, 100).is_none());
+        assert_eq!(
+            update(&mut app_data, 95.0, 600),
+            Some("container_1 memory above 50%".to_owned())
+        );
+        assert_eq!(
+            app_data.containers.items[0].alert,
+            Alert {
+                cpu: true,
+                mem: true
+            }
+        );
+
+        assert!(update(&mut app_data, 10.0, 100).is_none());
+        assert!(!app_data.has_alert());
+        assert_eq!(
+            update(&mut app_data, 85.5, 600),
+            Some("container_1 cpu above 80%, memory above 50%".to_owned())
+        );
+        assert!(app_data
+            .update_alert_by_id(&ContainerId::from("4"))
+            .is_none());
+    }
+
+    #[test]
+    /// Only the most recent "--log-max-lines" lines are kept, the number of dropped lines is shown in the title, and the selected line is kept
+    fn test_app_data_update_log_by_id_max_lines() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.args.log_max_lines = 3;
+        app_data.containers_start();
+
+        let logs = (1..=2).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+        assert_eq!(app_data.get_log_title(), " 2/2 - container_1");
+        app_data.log_start();
+
+        let logs = (3..=5).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+        assert_eq!(
+            app_data.get_logs_text_by_id(&ids[0]),
+            Some(vec![
+                "line 3".to_owned(),
+                "line 4".to_owned(),
+                "line 5".to_owned()
+            ])
+        );
+        // The selected line was removed, so the oldest remaining line is selected
+        assert_eq!(
+            app_data.get_log_title(),
+            " 1/3 (2 dropped) paused - container_1"
+        );
+
+        // When following the logs, the last line stays selected
+        app_data.log_end();
+        let logs = (6..=7).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+        assert_eq!(app_data.get_log_title(), " 3/3 (4 dropped) - container_1");
+    }
+
+    #[test]
+    /// New log lines are followed, scrolling up pauses following until log_end is called, even if the last line is selected again
+    fn test_app_data_update_log_by_id_follow() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+
+        let logs = (1..=3).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+        assert_eq!(app_data.get_log_title(), " 3/3 - container_1");
+
+        app_data.log_previous();
+        let logs = (4..=5).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+        assert_eq!(app_data.get_log_title(), " 2/5 paused - container_1");
+
+        app_data.log_page(true, 10, 10);
+        let logs = (6..=7).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+        assert_eq!(app_data.get_log_title(), " 5/7 paused - container_1");
+
+        app_data.log_end();
+        assert_eq!(app_data.get_log_title(), " 7/7 - container_1");
+        let logs = (8..=9).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+        assert_eq!(app_data.get_log_title(), " 9/9 - container_1");
+    }
+
+    #[test]
+    /// Whilst the log stream is paused new lines are ignored, and last_updated is unchanged, so that they are fetched once resumed
+    fn test_app_data_update_log_by_id_stream_paused() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        assert!(!app_data.get_log_stream_paused());
+
+        let logs = (1..=3).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+        app_data.containers.items[0].last_updated = 0;
+
+        app_data.toggle_log_stream();
+        assert!(app_data.get_log_stream_paused());
+        let logs = (4..=5).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs.clone()), &ids[0]);
+        assert_eq!(app_data.get_log_title(), " 3/3 - container_1");
+        assert_eq!(app_data.containers.items[0].last_updated, 0);
+
+        app_data.toggle_log_stream();
+        assert!(!app_data.get_log_stream_paused());
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+        assert_eq!(app_data.get_log_title(), " 5/5 - container_1");
+        assert!(app_data.containers.items[0].last_updated > 0);
+    }
+
+    #[test]
+    /// The plain text of the buffered logs is returned, ansi formatting removed
+    fn test_app_data_get_logs_text_by_id() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert_eq!(app_data.get_logs_text_by_id(&ids[0]), Some(vec![]));
+
+        let logs = (1..=3)
+            .map(|i| format!("{i} \x1b[31mline {i}\x1b[0m"))
+            .collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+
+        let result = app_data.get_logs_text_by_id(&ids[0]);
+        assert_eq!(
+            result,
+            Some(vec![
+                "line 1".to_owned(),
+                "line 2".to_owned(),
+                "line 3".to_owned()
+            ])
+        );
+        assert!(app_data
+            .get_logs_text_by_id(&ContainerId::from("4"))
+            .is_none());
     }
 
-    // **** //
-    // Logs //
-    // **** //
-
     #[test]
-    /// log title string generated correctly
-    fn test_app_data_get_log_title() {
+    /// Toggling timestamps regenerates the buffered logs, without duplicating lines or changing the selected line
+    fn test_app_data_toggle_log_timestamps() {
         let (ids, containers) = gen_containers();
-
         let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        assert!(!app_data.get_logs_with_timestamps());
 
-        // No container selected select
-        let result = app_data.get_log_title();
-        assert_eq!(result, "");
+        let logs = (1..=3)
+            .map(|i| format!("2023-01-14T19:13:3{i}.783138328Z line {i}"))
+            .collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs.clone()), &ids[0]);
+        app_data.log_previous();
 
-        // No logs
-        app_data.containers.start();
-        let result = app_data.get_log_title();
-        assert_eq!(result, " - container_1");
+        let widths = |app_data: &mut AppData| {
+            app_data
+                .get_logs(None)
+                .iter()
+                .map(ListItem::width)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(widths(&mut app_data), vec![6, 6, 6]);
 
-        // On last line of logs
-        let logs = (1..=3).map(|i| format!("{i}")).collect::<Vec<_>>();
-        app_data.update_log_by_id(logs, &ids[0]);
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 3/3 - container_1");
+        app_data.toggle_log_timestamps();
+        assert!(app_data.get_logs_with_timestamps());
+        // The timestamp is shown in the time format, but the text keeps the timestamp as sent by Docker
+        assert_eq!(widths(&mut app_data), vec![26, 26, 26]);
+        assert_eq!(
+            app_data.get_logs_text_by_id(&ids[0]).unwrap()[0],
+            "2023-01-14T19:13:31.783138328Z line 1"
+        );
+        assert_eq!(app_data.get_log_state().unwrap().selected(), Some(1));
 
-        // Change log state to no longer be at the end
-        app_data.log_previous();
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 2/3 - container_1");
+        // Same logs received again aren't duplicated
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+        assert_eq!(app_data.get_logs(None).len(), 3);
+
+        app_data.toggle_log_timestamps();
+        assert_eq!(widths(&mut app_data), vec![6, 6, 6]);
+        assert_eq!(app_data.get_logs_text_by_id(&ids[0]).unwrap()[0], "line 1");
     }
 
     #[test]
-    /// log title string generated correctly after container change
-    fn test_app_data_get_log_title_after_container_change() {
+    /// Cycling the log streams filters the buffered logs of every container, and is shown in the log title
+    fn test_app_data_cycle_log_streams() {
         let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
-
-        // No container selected select
-        let result = app_data.get_log_title();
-        assert_eq!(result, "");
-
         app_data.containers_start();
 
-        let result = app_data.get_log_title();
-        assert_eq!(result, " - container_1");
+        let logs = (1..=4)
+            .map(|i| {
+                let stream = if i % 2 == 0 {
+                    LogStream::Stderr
+                } else {
+                    LogStream::Stdout
+                };
+                (stream, format!("2023-01-14T19:13:3{i}.783138328Z line {i}"))
+            })
+            .collect::<Vec<_>>();
+        app_data.update_log_by_id(logs, &ids[0]);
+        assert_eq!(app_data.get_logs(None).len(), 4);
+        assert_eq!(app_data.get_log_title(), " 4/4 - container_1");
 
-        // change container
-        app_data.containers_next();
-        let result = app_data.get_log_title();
-        assert_eq!(result, " - container_2");
+        assert_eq!(app_data.cycle_log_streams(), LogStreamFilter::Stdout);
+        assert_eq!(
+            app_data.get_logs_text_by_id(&ids[0]).unwrap(),
+            ["line 1", "line 3"]
+        );
+        assert_eq!(app_data.get_log_title(), " 2/2 stdout only - container_1");
 
-        // On last line of logs
-        let logs = (1..=3).map(|i| format!("{i}")).collect::<Vec<_>>();
-        app_data.update_log_by_id(logs, &ids[1]);
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 3/3 - container_2");
+        assert_eq!(app_data.cycle_log_streams(), LogStreamFilter::Stderr);
+        assert_eq!(
+            app_data.get_logs_text_by_id(&ids[0]).unwrap(),
+            ["line 2", "line 4"]
+        );
 
-        // Change log state to no longer be at the end
-        app_data.log_previous();
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 2/3 - container_2");
+        // A container that hasn't had any logs yet uses the current filter once its logs arrive
+        app_data.update_log_by_id(
+            vec![
+                (
+                    LogStream::Stdout,
+                    "2023-01-14T19:13:31.783138328Z line 1".to_owned(),
+                ),
+                (
+                    LogStream::Stderr,
+                    "2023-01-14T19:13:32.783138328Z line 2".to_owned(),
+                ),
+            ],
+            &ids[1],
+        );
+        assert_eq!(app_data.get_logs_text_by_id(&ids[1]).unwrap(), ["line 2"]);
+
+        assert_eq!(app_data.cycle_log_streams(), LogStreamFilter::All);
+        assert_eq!(app_data.get_logs(None).len(), 4);
+        assert_eq!(app_data.get_log_title(), " 4/4 - container_1");
     }
 
     #[test]
-    /// update logs by id works
-    fn test_app_data_update_log_by_id() {
+    /// Everything relative to now is read from the clock, so moving the clock moves the uptime, the sort by uptime, and when logs were last updated
+    fn test_app_data_clock() {
         let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        let clock = MockClock::new(NOW);
+        app_data.clock = Arc::new(clock.clone());
+        app_data.containers_start();
+        let uptime = |app_data: &AppData| {
+            app_data
+                .get_selected_detail()
+                .unwrap()
+                .into_iter()
+                .find(|(label, _)| *label == "uptime")
+                .map(|(_, value)| value)
+                .unwrap()
+        };
 
-        // No container selected select
-        let result = app_data.get_log_title();
-        assert_eq!(result, "");
+        app_data.update_uptime_by_id(
+            &ids[0],
+            Uptime {
+                started: Some(NOW),
+                finished: None,
+            },
+        );
+        app_data.update_uptime_by_id(
+            &ids[1],
+            Uptime {
+                started: Some(NOW - 30),
+                finished: None,
+            },
+        );
+        assert_eq!(uptime(&app_data), "0s");
+        clock.advance(59);
+        assert_eq!(uptime(&app_data), "59s");
+        clock.advance(1);
+        assert_eq!(uptime(&app_data), "1m");
+        clock.advance(86_400 * 2);
+        assert_eq!(uptime(&app_data), "2d");
+
+        // A clock that has moved backwards, e.g. clock skew, is unknown, so is sorted first
+        clock.advance(-(86_400 * 2 + 60 + 15));
+        assert_eq!(uptime(&app_data), "--");
+        app_data.set_sorted(Some((Header::Uptime, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        assert_eq!(result[0].id, ids[0]);
+        assert_eq!(result[2].id, ids[1]);
 
-        app_data.containers_start();
-        let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(vec![], &ids[0]);
+        assert_eq!(
+            app_data.get_container_by_id(&ids[0]).unwrap().last_updated,
+            u64::try_from(NOW - 15).unwrap()
+        );
+    }
 
-        app_data.update_log_by_id(logs, &ids[0]);
-        // app_data.log_start();
+    #[test]
+    /// Changing the since clears the buffered logs of every container, so they are refetched, and is shown in the log title
+    fn test_app_data_set_log_since() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        assert!(app_data.get_log_since().is_none());
+        assert!(app_data.get_log_since_timestamp().is_none());
 
-        let result = app_data.get_log_state();
-        assert!(result.is_some());
-        assert_eq!(result.as_ref().unwrap().selected(), Some(2));
-        assert_eq!(result.unwrap().offset(), 0);
+        for id in &ids[..2] {
+            app_data.update_log_by_id(
+                gen_stdout(vec!["2023-01-14T19:13:30.783138328Z line 1".to_owned()]),
+                id,
+            );
+        }
+        assert_eq!(app_data.get_log_title(), " 1/1 - container_1");
+
+        let since = Since::Relative(600);
+        app_data.set_log_since(Some(since));
+        assert_eq!(app_data.get_log_since(), Some(since));
+        assert_eq!(app_data.get_log_since_timestamp(), Some(NOW - 600));
+        assert!(app_data.get_logs(None).is_empty());
+        assert!(app_data.get_logs_text_by_id(&ids[1]).unwrap().is_empty());
+        assert!(app_data
+            .containers
+            .items
+            .iter()
+            .all(|i| i.last_updated == 0));
+        assert_eq!(app_data.get_log_title(), " since 10m - container_1");
 
-        let result = app_data.get_logs();
-        assert_eq!(result.len(), 3);
+        app_data.update_log_by_id(
+            gen_stdout(vec!["2023-01-14T19:13:31.783138328Z line 2".to_owned()]),
+            &ids[0],
+        );
+        assert_eq!(app_data.get_log_title(), " 1/1 since 10m - container_1");
 
-        let result = app_data.get_log_title();
-        assert_eq!(result, " 3/3 - container_1");
+        app_data.set_log_since(None);
+        assert!(app_data.get_log_since().is_none());
+        assert_eq!(app_data.get_log_title(), " - container_1");
     }
 
     #[test]
@@ -1532,7 +4745,7 @@ mod tests {
         let mut app_data = gen_appdata(&containers);
         let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
         app_data.containers_start();
-        app_data.update_log_by_id(logs, &ids[0]);
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
 
         app_data.log_start();
 
@@ -1542,7 +4755,7 @@ mod tests {
         assert_eq!(result.unwrap().offset(), 0);
 
         let result = app_data.get_log_title();
-        assert_eq!(result, " 1/3 - container_1");
+        assert_eq!(result, " 1/3 paused - container_1");
     }
 
     #[test]
@@ -1552,7 +4765,7 @@ mod tests {
         let mut app_data = gen_appdata(&containers);
         let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
         app_data.containers_start();
-        app_data.update_log_by_id(logs, &ids[0]);
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
 
         app_data.log_start();
 
@@ -1562,7 +4775,7 @@ mod tests {
         assert_eq!(result.unwrap().offset(), 0);
 
         let result = app_data.get_log_title();
-        assert_eq!(result, " 1/3 - container_1");
+        assert_eq!(result, " 1/3 paused - container_1");
 
         app_data.log_end();
         let result = app_data.get_log_state();
@@ -1582,7 +4795,7 @@ mod tests {
         let mut app_data = gen_appdata(&containers);
         let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
         app_data.containers_start();
-        app_data.update_log_by_id(logs, &ids[0]);
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
 
         app_data.log_start();
 
@@ -1592,7 +4805,7 @@ mod tests {
         assert_eq!(result.unwrap().offset(), 0);
 
         let result = app_data.get_log_title();
-        assert_eq!(result, " 1/3 - container_1");
+        assert_eq!(result, " 1/3 paused - container_1");
 
         app_data.log_next();
 
@@ -1602,7 +4815,7 @@ mod tests {
         assert_eq!(result.unwrap().offset(), 0);
 
         let result = app_data.get_log_title();
-        assert_eq!(result, " 2/3 - container_1");
+        assert_eq!(result, " 2/3 paused - container_1");
 
         app_data.log_next();
         let result = app_data.get_log_state();
@@ -1611,7 +4824,7 @@ mod tests {
         assert_eq!(result.unwrap().offset(), 0);
 
         let result = app_data.get_log_title();
-        assert_eq!(result, " 3/3 - container_1");
+        assert_eq!(result, " 3/3 paused - container_1");
         app_data.log_next();
 
         let result = app_data.get_log_state();
@@ -1620,7 +4833,7 @@ mod tests {
         assert_eq!(result.unwrap().offset(), 0);
 
         let result = app_data.get_log_title();
-        assert_eq!(result, " 3/3 - container_1");
+        assert_eq!(result, " 3/3 paused - container_1");
     }
 
     #[test]
@@ -1631,7 +4844,7 @@ mod tests {
         let mut app_data = gen_appdata(&containers);
         let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
         app_data.containers_start();
-        app_data.update_log_by_id(logs, &ids[0]);
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
 
         app_data.log_end();
 
@@ -1650,7 +4863,7 @@ mod tests {
         assert_eq!(result.as_ref().unwrap().selected(), Some(1));
         assert_eq!(result.unwrap().offset(), 0);
         let result = app_data.get_log_title();
-        assert_eq!(result, " 2/3 - container_1");
+        assert_eq!(result, " 2/3 paused - container_1");
 
         app_data.log_previous();
         let result = app_data.get_log_state();
@@ -1658,7 +4871,7 @@ mod tests {
         assert_eq!(result.as_ref().unwrap().selected(), Some(0));
         assert_eq!(result.unwrap().offset(), 0);
         let result = app_data.get_log_title();
-        assert_eq!(result, " 1/3 - container_1");
+        assert_eq!(result, " 1/3 paused - container_1");
 
         app_data.log_previous();
         let result = app_data.get_log_state();
@@ -1666,7 +4879,7 @@ mod tests {
         assert_eq!(result.as_ref().unwrap().selected(), Some(0));
         assert_eq!(result.unwrap().offset(), 0);
         let result = app_data.get_log_title();
-        assert_eq!(result, " 1/3 - container_1");
+        assert_eq!(result, " 1/3 paused - container_1");
     }
 
     // ********** //
@@ -1717,7 +4930,7 @@ mod tests {
         let (_ids, containers) = gen_containers();
         let app_data = gen_appdata(&containers);
 
-        let result = app_data.get_width();
+        let result = app_data.get_width(IdMode::Short);
         let expected = Columns {
             name: (Header::Name, 11),
             state: (Header::State, 11),
@@ -1726,8 +4939,15 @@ mod tests {
             mem: (Header::Memory, 7, 7),
             id: (Header::Id, 8),
             image: (Header::Image, 7),
+            image_size: (Header::ImageSize, 10),
             net_rx: (Header::Rx, 7),
             net_tx: (Header::Tx, 7),
+            net_io: (Header::NetIo, 9, 9),
+            blk_io: (Header::BlockIo, 9, 9),
+            uptime: (Header::Uptime, 6),
+            project: (Header::Project, 7),
+            service: (Header::Service, 7),
+            ip: (Header::Ip, 2),
         };
         assert_eq!(result, expected);
     }
@@ -1736,6 +4956,169 @@ mod tests {
     // Ports //
     // ***** //
 
+    fn gen_image(id: &str, created: i64) -> ImageItem {
+        ImageItem {
+            containers: 0,
+            created,
+            id: ImageId::from(id),
+            size: ByteStats::new(1000),
+            tags: format!("image_{id}:latest"),
+        }
+    }
+
+    #[test]
+    /// Images are sorted newest first, the selected image is kept selected after an update, and after another image is removed
+    fn test_app_data_update_images() {
+        let mut app_data = gen_appdata(&[]);
+        assert!(app_data.get_selected_image().is_none());
+
+        app_data.update_images(vec![gen_image("1", 1), gen_image("2", 2)]);
+        assert_eq!(
+            app_data.get_selected_image().unwrap().id,
+            ImageId::from("2")
+        );
+        assert_eq!(app_data.images_title(), " 1/2");
+
+        app_data.images_next();
+        assert_eq!(
+            app_data.get_selected_image().unwrap().id,
+            ImageId::from("1")
+        );
+
+        app_data.update_images(vec![
+            gen_image("1", 1),
+            gen_image("2", 2),
+            gen_image("3", 3),
+        ]);
+        assert_eq!(
+            app_data.get_selected_image().unwrap().id,
+            ImageId::from("1")
+        );
+        assert_eq!(app_data.images_title(), " 3/3");
+
+        app_data.remove_image(&ImageId::from("1"));
+        assert_eq!(
+            app_data.get_selected_image().unwrap().id,
+            ImageId::from("2")
+        );
+
+        app_data.remove_image(&ImageId::from("2"));
+        app_data.remove_image(&ImageId::from("3"));
+        assert!(app_data.get_selected_image().is_none());
+        assert_eq!(app_data.images_title(), "");
+    }
+
+    fn gen_network(id: &str, name: &str) -> NetworkItem {
+        NetworkItem {
+            containers: 0,
+            driver: String::from("bridge"),
+            id: NetworkId::from(id),
+            name: String::from(name),
+            scope: String::from("local"),
+        }
+    }
+
+    #[test]
+    /// Networks are sorted by name, the selected network is kept selected after an update, and the inspect output is only returned for the inspected network
+    fn test_app_data_update_networks() {
+        let mut app_data = gen_appdata(&[]);
+        assert!(app_data.get_selected_network().is_none());
+
+        app_data.update_networks(vec![gen_network("1", "b"), gen_network("2", "a")]);
+        assert_eq!(
+            app_data.get_selected_network().unwrap().id,
+            NetworkId::from("2")
+        );
+        assert_eq!(app_data.networks_title(), " 1/2");
+
+        app_data.networks_next();
+        app_data.update_networks(vec![
+            gen_network("1", "b"),
+            gen_network("2", "a"),
+            gen_network("3", "c"),
+        ]);
+        assert_eq!(
+            app_data.get_selected_network().unwrap().id,
+            NetworkId::from("1")
+        );
+
+        app_data.remove_network(&NetworkId::from("1"));
+        assert_eq!(
+            app_data.get_selected_network().unwrap().id,
+            NetworkId::from("3")
+        );
+
+        assert!(app_data
+            .get_network_inspect_by_id(&NetworkId::from("3"))
+            .is_none());
+        app_data.update_network_inspect(NetworkId::from("3"), String::from("{}"));
+        assert_eq!(
+            app_data.get_network_inspect_by_id(&NetworkId::from("3")),
+            Some(String::from("{}"))
+        );
+        assert!(app_data
+            .get_network_inspect_by_id(&NetworkId::from("2"))
+            .is_none());
+    }
+
+    fn gen_volume(name: &str) -> VolumeItem {
+        VolumeItem {
+            driver: String::from("local"),
+            mountpoint: format!("/var/lib/docker/volumes/{name}/_data"),
+            name: VolumeName::from(name),
+            size: None,
+        }
+    }
+
+    #[test]
+    /// Volumes are sorted by name, and the selected volume is kept selected after an update, and after another volume is removed
+    fn test_app_data_update_volumes() {
+        let mut app_data = gen_appdata(&[]);
+        assert!(app_data.get_selected_volume().is_none());
+
+        app_data.update_volumes(vec![gen_volume("b"), gen_volume("a")]);
+        assert_eq!(
+            app_data.get_selected_volume().unwrap().name,
+            VolumeName::from("a")
+        );
+        assert_eq!(app_data.volumes_title(), " 1/2");
+
+        app_data.volumes_next();
+        app_data.update_volumes(vec![gen_volume("c"), gen_volume("b"), gen_volume("a")]);
+        assert_eq!(
+            app_data.get_selected_volume().unwrap().name,
+            VolumeName::from("b")
+        );
+
+        app_data.remove_volume(&VolumeName::from("a"));
+        assert_eq!(
+            app_data.get_selected_volume().unwrap().name,
+            VolumeName::from("c")
+        );
+
+        app_data.remove_volume(&VolumeName::from("b"));
+        app_data.remove_volume(&VolumeName::from("c"));
+        assert!(app_data.get_selected_volume().is_none());
+        assert_eq!(app_data.volumes_title(), "");
+    }
+
+    #[test]
+    /// Stopped containers are counted, including those hidden by the filter
+    fn test_app_data_get_stopped_len() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert_eq!(app_data.get_stopped_len(), 0);
+
+        app_data.containers.items[0].state = State::Exited;
+        app_data.containers.items[1].state = State::Paused;
+        app_data.containers.items[2].state = State::Dead;
+        assert_eq!(app_data.get_stopped_len(), 2);
+
+        app_data.filter_push('1');
+        assert_eq!(app_data.get_container_len(), 1);
+        assert_eq!(app_data.get_stopped_len(), 2);
+    }
+
     #[test]
     /// Returns selected containers ports ordered by private ip
     fn test_app_data_get_selected_ports() {
@@ -1746,16 +5129,19 @@ mod tests {
             ip: None,
             private: 10,
             public: Some(1),
+            protocol: None,
         });
         app_data.containers.items[0].ports.push(ContainerPorts {
             ip: None,
             private: 11,
             public: Some(3),
+            protocol: None,
         });
         app_data.containers.items[0].ports.push(ContainerPorts {
             ip: None,
             private: 4,
             public: Some(2),
+            protocol: None,
         });
 
         // No containers selected
@@ -1773,22 +5159,26 @@ mod tests {
                     ContainerPorts {
                         ip: None,
                         private: 4,
-                        public: Some(2)
+                        public: Some(2),
+                        protocol: None
                     },
                     ContainerPorts {
                         ip: None,
                         private: 10,
-                        public: Some(1)
+                        public: Some(1),
+                        protocol: None
                     },
                     ContainerPorts {
                         ip: None,
                         private: 11,
-                        public: Some(3)
+                        public: Some(3),
+                        protocol: None
                     },
                     ContainerPorts {
                         ip: None,
                         private: 8001,
-                        public: None
+                        public: None,
+                        protocol: None
                     }
                 ],
                 State::Running
@@ -1803,6 +5193,38 @@ mod tests {
         assert_eq!(result, Some((vec![], State::Running)));
     }
 
+    #[test]
+    /// Ports are parsed with their protocol, each binding of the same port is kept, and duplicates are removed
+    fn test_app_data_get_selected_ports_bindings() {
+        let mut app_data = gen_appdata(&[]);
+        let port = |ip: Option<&str>, private_port, public_port, typ| Port {
+            ip: ip.map(std::borrow::ToOwned::to_owned),
+            private_port,
+            public_port,
+            typ: Some(typ),
+        };
+        let mut input = [gen_container_summary(1, "running")];
+        input[0].ports = Some(vec![
+            port(Some("::"), 80, Some(8080), PortTypeEnum::TCP),
+            port(Some("0.0.0.0"), 80, Some(8080), PortTypeEnum::TCP),
+            port(Some("0.0.0.0"), 53, Some(5353), PortTypeEnum::UDP),
+            port(None, 53, None, PortTypeEnum::TCP),
+            port(Some("0.0.0.0"), 80, Some(8080), PortTypeEnum::TCP),
+        ]);
+        app_data.update_containers(&mut input);
+
+        let (result, _) = app_data.get_selected_ports().unwrap();
+        let result = result.iter().map(ContainerPorts::print).collect::<Vec<_>>();
+        let expected = [
+            ("", "53/tcp", "--"),
+            ("0.0.0.0", "53/udp", "5353"),
+            ("0.0.0.0", "80/tcp", "8080"),
+            ("::", "80/tcp", "8080"),
+        ]
+        .map(|(ip, private, public)| (ip.to_owned(), private.to_owned(), public.to_owned()));
+        assert_eq!(result, expected);
+    }
+
     // ************** //
     // Update mtehods //
     // ************** //
@@ -1817,7 +5239,16 @@ mod tests {
         let result = app_data.get_container_items();
         assert_eq!(result[0], containers[0]);
 
-        app_data.update_stats_by_id(&ids[0], Some(10.0), Some(10), 10, 10, 10);
+        app_data.update_stats_by_id(
+            &ids[0],
+            Some((10.0, 4)),
+            Some(10),
+            10,
+            IoTotals {
+                net: Some((10, 10)),
+                ..IoTotals::default()
+            },
+        );
 
         let result = app_data.get_container_items();
         assert_ne!(result[0], containers[0]);
@@ -1828,6 +5259,32 @@ mod tests {
         assert_eq!(result[0].tx, ByteStats::new(10));
     }
 
+    #[test]
+    /// In all cores mode the cpu usage is divided by the online cpus, and toggling converts the existing history
+    fn test_app_data_cycle_cpu_mode() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.update_stats_by_id(&ids[0], Some((200.0, 4)), None, 0, IoTotals::default());
+        assert_eq!(app_data.get_width(IdMode::Short).cpu.1, 7);
+
+        assert_eq!(app_data.cycle_cpu_mode(), CpuMode::All);
+        app_data.update_stats_by_id(&ids[0], Some((100.0, 4)), None, 0, IoTotals::default());
+        let result = app_data.get_container_items();
+        assert_eq!(result[0].cpus, 4);
+        assert_eq!(
+            result[0].cpu_stats,
+            VecDeque::from([CpuStats::new(50.0), CpuStats::new(25.0)])
+        );
+        assert_eq!(app_data.get_width(IdMode::Short).cpu.1, 9);
+
+        assert_eq!(app_data.cycle_cpu_mode(), CpuMode::Core);
+        let result = app_data.get_container_items();
+        assert_eq!(
+            result[0].cpu_stats,
+            VecDeque::from([CpuStats::new(200.0), CpuStats::new(100.0)])
+        );
+    }
+
     #[test]
     /// Update stats functioning
     fn test_app_data_update_containers() {
@@ -1846,6 +5303,173 @@ mod tests {
         assert_eq!(result_post[1].state, State::Dead);
     }
 
+    #[test]
+    /// Removing every container clears the selection, and navigating an empty list selects nothing
+    fn test_app_data_update_containers_empty() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_end();
+        assert_eq!(app_data.get_container_state().selected(), Some(2));
+
+        app_data.update_containers(&mut []);
+        assert!(app_data.get_container_items().is_empty());
+        assert_eq!(app_data.get_container_state().selected(), None);
+        assert!(app_data.get_selected_container_id().is_none());
+
+        app_data.containers_start();
+        app_data.containers_next();
+        app_data.containers_previous();
+        app_data.containers_end();
+        assert_eq!(app_data.get_container_state().selected(), None);
+        assert_eq!(app_data.container_title(), "");
+
+        // The first container to appear is selected
+        let mut input = [gen_container_summary(1, "running")];
+        app_data.update_containers(&mut input);
+        assert_eq!(app_data.get_container_state().selected(), Some(0));
+    }
+
+    #[test]
+    /// The selection follows the selected container, by id, as the list is reordered, or as containers above it are removed, and only falls back to the same, clamped, index once it's gone
+    fn test_app_data_update_containers_selection_anchored() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let selected = |app_data: &AppData| {
+            (
+                app_data.containers.state.selected(),
+                app_data.get_selected_container_id(),
+            )
+        };
+        app_data.containers_start();
+
+        // Reordered, container_1 is now last
+        app_data.set_sorted(Some((Header::Name, SortedOrder::Desc)));
+        assert_eq!(selected(&app_data), (Some(2), Some(ContainerId::from("1"))));
+
+        // A container above the selection exits, and is removed from the list
+        app_data.containers_previous();
+        assert_eq!(selected(&app_data), (Some(1), Some(ContainerId::from("2"))));
+        let mut input = [
+            gen_container_summary(1, "running"),
+            gen_container_summary(2, "running"),
+        ];
+        app_data.update_containers(&mut input);
+        app_data.sort_containers();
+        assert_eq!(selected(&app_data), (Some(0), Some(ContainerId::from("2"))));
+
+        // A new container is sorted above the selection
+        let mut input = [
+            gen_container_summary(1, "running"),
+            gen_container_summary(2, "running"),
+            gen_container_summary(4, "running"),
+        ];
+        app_data.update_containers(&mut input);
+        app_data.sort_containers();
+        assert_eq!(selected(&app_data), (Some(1), Some(ContainerId::from("2"))));
+
+        // The selected container is removed, the container that takes its index is selected
+        let mut input = [
+            gen_container_summary(1, "running"),
+            gen_container_summary(4, "running"),
+        ];
+        app_data.update_containers(&mut input);
+        app_data.sort_containers();
+        assert_eq!(selected(&app_data), (Some(1), Some(ContainerId::from("1"))));
+
+        // The last container is selected, and removed, so the index is clamped
+        app_data.update_containers(&mut [gen_container_summary(4, "running")]);
+        assert_eq!(selected(&app_data), (Some(0), Some(ContainerId::from("4"))));
+    }
+
+    #[test]
+    /// Podman is detected from the components of the version response, anything else is Docker
+    fn test_app_data_engine() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert_eq!(app_data.get_engine(), Engine::Docker);
+
+        let component = |name: &str| bollard::system::VersionComponents {
+            name: name.to_owned(),
+            version: "4.9.3".to_owned(),
+            details: None,
+        };
+        assert_eq!(Engine::new(&Version::default()), Engine::Docker);
+        let version = Version {
+            components: Some(vec![component("Engine"), component("containerd")]),
+            ..Default::default()
+        };
+        assert_eq!(Engine::new(&version), Engine::Docker);
+        let version = Version {
+            components: Some(vec![component("Podman Engine")]),
+            ..Default::default()
+        };
+        assert_eq!(Engine::new(&version), Engine::Podman);
+
+        app_data.set_engine(Engine::Podman);
+        assert_eq!(app_data.get_engine(), Engine::Podman);
+        assert_eq!(Engine::Podman.to_string(), "podman");
+    }
+
+    #[test]
+    /// Slow pings, and failed pings, degrade the health, the consecutive failures are counted until a ping succeeds, and only a change to the shown ping needs a redraw
+    fn test_app_data_ping() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert!(app_data.get_ping().health().is_none());
+        assert_eq!(app_data.get_ping().to_string(), "");
+
+        app_data.take_redraw();
+        assert_eq!(app_data.set_ping(Some(Duration::from_millis(3))), 0);
+        assert_eq!(app_data.get_ping().health(), Some(PingHealth::Healthy));
+        assert_eq!(app_data.get_ping().to_string(), "● 3ms");
+        assert!(app_data.take_redraw());
+
+        assert_eq!(app_data.set_ping(Some(Duration::from_millis(3))), 0);
+        assert!(!app_data.take_redraw());
+
+        app_data.set_ping(Some(Duration::from_millis(300)));
+        assert_eq!(app_data.get_ping().health(), Some(PingHealth::Slow));
+        assert_eq!(app_data.get_ping().to_string(), "● 300ms");
+
+        assert_eq!(app_data.set_ping(None), 1);
+        assert_eq!(app_data.get_ping().health(), Some(PingHealth::Failing));
+        assert_eq!(app_data.get_ping().to_string(), "● no response");
+        assert!(app_data.take_redraw());
+        assert_eq!(app_data.set_ping(None), 2);
+        assert!(!app_data.take_redraw());
+
+        assert_eq!(app_data.set_ping(Some(Duration::from_millis(5))), 0);
+        assert_eq!(app_data.get_ping().health(), Some(PingHealth::Healthy));
+    }
+
+    #[test]
+    /// The selection is kept, by id, when the filter hides containers above it, and when they are shown again
+    fn test_app_data_filter_selection_anchored() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_end();
+
+        app_data.filter_start();
+        "container_3".chars().for_each(|c| app_data.filter_push(c));
+        assert_eq!(app_data.get_container_state().selected(), Some(0));
+        app_data.update_containers(&mut [
+            gen_container_summary(1, "running"),
+            gen_container_summary(2, "running"),
+            gen_container_summary(3, "running"),
+        ]);
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("3"))
+        );
+
+        app_data.filter_clear();
+        assert_eq!(app_data.get_container_state().selected(), Some(2));
+        assert_eq!(
+            app_data.get_selected_container_id(),
+            Some(ContainerId::from("3"))
+        );
+    }
+
     #[test]
     /// Update logs don't work if container is_oxker: true
     fn test_app_data_update_log_by_id_is_oxker() {
@@ -1854,7 +5478,7 @@ mod tests {
         let mut app_data = gen_appdata(&containers);
         let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
 
-        app_data.update_log_by_id(logs, &ids[0]);
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
         app_data.log_start();
 
         let result = app_data.get_log_state();
@@ -0,0 +1,109 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use bollard::service::Network;
+
+/// Networks created by docker itself, which can never be removed
+const PREDEFINED_NETWORKS: [&str; 3] = ["bridge", "host", "none"];
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct NetworkId(String);
+
+impl From<&str> for NetworkId {
+    fn from(x: &str) -> Self {
+        Self(x.to_owned())
+    }
+}
+
+impl NetworkId {
+    pub const fn get(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Only return the first 8 chars of the id, in the same way as a ContainerId
+    pub fn get_short(&self) -> String {
+        self.0.chars().take(8).collect::<String>()
+    }
+}
+
+impl Ord for NetworkId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for NetworkId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A network, as shown in the networks view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkItem {
+    pub containers: usize,
+    pub driver: String,
+    pub id: NetworkId,
+    pub name: String,
+    pub scope: String,
+}
+
+impl NetworkItem {
+    /// Create a network from the docker list output, containers is the number of containers, running or not, attached to each network id.
+    /// The list output doesn't include the attached containers, so they are counted from the container list instead
+    pub fn new(network: &Network, containers: &HashMap<String, usize>) -> Self {
+        let id = network.id.clone().unwrap_or_default();
+        Self {
+            containers: containers.get(&id).copied().unwrap_or_default(),
+            driver: network.driver.clone().unwrap_or_default(),
+            id: NetworkId::from(id.as_str()),
+            name: network.name.clone().unwrap_or_default(),
+            scope: network.scope.clone().unwrap_or_default(),
+        }
+    }
+
+    /// Only a network without any attached containers, and that wasn't created by docker itself, can be removed
+    pub fn removable(&self) -> bool {
+        self.containers == 0 && !PREDEFINED_NETWORKS.contains(&self.name.as_str())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::Network;
+
+    use super::{NetworkId, NetworkItem};
+
+    #[test]
+    /// Containers are counted by network id, and only unused, user created, networks are removable
+    fn test_network_state_new() {
+        assert_eq!(NetworkId::from("0123456789ab").get_short(), "01234567");
+
+        let network = Network {
+            id: Some("0123456789ab".to_owned()),
+            name: Some("network_1".to_owned()),
+            driver: Some("bridge".to_owned()),
+            scope: Some("local".to_owned()),
+            ..Default::default()
+        };
+        let containers = HashMap::from([("0123456789ab".to_owned(), 2)]);
+        let item = NetworkItem::new(&network, &containers);
+        assert_eq!(item.name, "network_1");
+        assert_eq!(item.driver, "bridge");
+        assert_eq!(item.scope, "local");
+        assert_eq!(item.containers, 2);
+        assert!(!item.removable());
+
+        let item = NetworkItem::new(&network, &HashMap::new());
+        assert_eq!(item.containers, 0);
+        assert!(item.removable());
+
+        let network = Network {
+            name: Some("bridge".to_owned()),
+            ..network
+        };
+        assert!(!NetworkItem::new(&network, &HashMap::new()).removable());
+    }
+}
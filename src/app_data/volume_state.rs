@@ -0,0 +1,108 @@
+use std::cmp::Ordering;
+
+use bollard::service::Volume;
+
+use super::ByteStats;
+
+/// Volumes are identified by their name, rather than an id
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct VolumeName(String);
+
+impl From<&str> for VolumeName {
+    fn from(x: &str) -> Self {
+        Self(x.to_owned())
+    }
+}
+
+impl VolumeName {
+    pub const fn get(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl Ord for VolumeName {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for VolumeName {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A volume, as shown in the volumes view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VolumeItem {
+    pub driver: String,
+    pub mountpoint: String,
+    pub name: VolumeName,
+    pub size: Option<ByteStats>,
+}
+
+impl VolumeItem {
+    /// Create a volume from the docker list output.
+    /// The size is only known if docker has calculated the usage data, and is `-1` for drivers other than `local`
+    pub fn new(volume: &Volume) -> Self {
+        Self {
+            driver: volume.driver.clone(),
+            mountpoint: volume.mountpoint.clone(),
+            name: VolumeName::from(volume.name.as_str()),
+            size: volume
+                .usage_data
+                .as_ref()
+                .and_then(|i| u64::try_from(i.size).ok())
+                .map(ByteStats::new),
+        }
+    }
+
+    /// The size of the volume, or a dash if not available
+    pub fn get_size(&self) -> String {
+        self.size
+            .as_ref()
+            .map_or_else(|| String::from("-"), ToString::to_string)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::service::{Volume, VolumeUsageData};
+
+    use super::{VolumeItem, VolumeName};
+
+    #[test]
+    /// The size is only shown if docker has calculated it, and isn't set to -1
+    fn test_volume_state_new() {
+        let volume = Volume {
+            name: "volume_1".to_owned(),
+            driver: "local".to_owned(),
+            mountpoint: "/var/lib/docker/volumes/volume_1/_data".to_owned(),
+            ..Default::default()
+        };
+        let item = VolumeItem::new(&volume);
+        assert_eq!(item.name, VolumeName::from("volume_1"));
+        assert_eq!(item.driver, "local");
+        assert_eq!(item.mountpoint, "/var/lib/docker/volumes/volume_1/_data");
+        assert_eq!(item.get_size(), "-");
+
+        let volume = Volume {
+            usage_data: Some(VolumeUsageData {
+                size: 2000,
+                ref_count: 1,
+            }),
+            ..volume
+        };
+        assert_eq!(VolumeItem::new(&volume).get_size(), "2.00 kB");
+
+        let volume = Volume {
+            usage_data: Some(VolumeUsageData {
+                size: -1,
+                ref_count: -1,
+            }),
+            ..volume
+        };
+        assert_eq!(VolumeItem::new(&volume).get_size(), "-");
+    }
+}
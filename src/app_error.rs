@@ -3,14 +3,32 @@ use std::fmt;
 
 /// app errors to set in global state
 #[allow(unused)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum AppError {
     DockerCommand(DockerControls),
     DockerExec,
+    DockerInspect,
+    DockerInspectNetwork,
+    DockerLimits,
+    DockerLimitsRejected(String),
     DockerLogs,
+    DockerPrune,
+    DockerPull,
+    DockerPullAuth,
+    DockerPullNetwork,
+    DockerPullNotFound,
+    DockerPullUntagged,
+    DockerRecreate(&'static str),
+    DockerRemoveImage,
+    DockerRemoveNetwork,
+    DockerRemoveVolume,
+    DockerRename,
+    DockerRenameConflict,
+    DockerRestartPolicy,
     DockerConnect,
     DockerInterval,
     InputPoll,
+    Metrics(u16),
     MouseCapture(bool),
     Terminal,
 }
@@ -21,10 +39,40 @@ impl fmt::Display for AppError {
         match self {
             Self::DockerCommand(s) => write!(f, "Unable to {s} container"),
             Self::DockerExec => write!(f, "Unable to exec into container"),
+            Self::DockerInspect => write!(f, "Unable to inspect container"),
+            Self::DockerInspectNetwork => write!(f, "Unable to inspect network"),
+            Self::DockerLimits => write!(f, "Unable to update limits"),
+            Self::DockerLimitsRejected(message) => {
+                write!(f, "Unable to update limits, {message}")
+            }
             Self::DockerLogs => write!(f, "Unable to save logs"),
+            Self::DockerPrune => write!(f, "Unable to prune"),
+            Self::DockerPull => write!(f, "Unable to pull image"),
+            Self::DockerPullAuth => write!(f, "Unable to pull image, access denied"),
+            Self::DockerPullNetwork => {
+                write!(f, "Unable to pull image, the registry couldn't be reached")
+            }
+            Self::DockerPullNotFound => write!(f, "Unable to pull image, not found"),
+            Self::DockerPullUntagged => {
+                write!(f, "Unable to pull image, the container has no image tag")
+            }
+            Self::DockerRecreate(step) => {
+                write!(f, "Unable to recreate container, failed to {step}")
+            }
+            Self::DockerRemoveImage => write!(f, "Unable to remove image"),
+            Self::DockerRemoveNetwork => write!(f, "Unable to remove network"),
+            Self::DockerRemoveVolume => write!(f, "Unable to remove volume, it may be in use"),
+            Self::DockerRename => write!(f, "Unable to rename container"),
+            Self::DockerRenameConflict => {
+                write!(f, "Unable to rename container, the name is already in use")
+            }
+            Self::DockerRestartPolicy => write!(f, "Unable to update restart policy"),
             Self::DockerConnect => write!(f, "Unable to access docker daemon"),
             Self::DockerInterval => write!(f, "Docker update interval needs to be greater than 0"),
             Self::InputPoll => write!(f, "Unable to poll user input"),
+            Self::Metrics(port) => {
+                write!(f, "Unable to serve metrics, port {port} may be in use")
+            }
             Self::MouseCapture(x) => {
                 let reason = if *x { "en" } else { "dis" };
                 write!(f, "Unable to {reason}able mouse capture")
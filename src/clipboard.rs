@@ -0,0 +1,46 @@
+use std::process::Stdio;
+
+use tokio::{io::AsyncWriteExt, process::Command};
+
+/// Clipboard programs, and their arguments, that read the text to copy from stdin, tried in order until one succeeds
+const PROGRAMS: [(&str, &[&str]); 6] = [
+    ("pbcopy", &[]),
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+    ("clip.exe", &[]),
+    ("clip", &[]),
+];
+
+/// Copy text to the system clipboard, using the first available clipboard program.
+/// Returns an Err if none are available, which is expected when running headless, or inside a container
+pub async fn copy(text: &str) -> Result<(), String> {
+    for (program, args) in PROGRAMS {
+        if run(program, args, text).await {
+            return Ok(());
+        }
+    }
+    Err(String::from("clipboard unavailable"))
+}
+
+/// Spawn a clipboard program, write the text to its stdin, and check that it exited successfully
+async fn run(program: &str, args: &[&str], text: &str) -> bool {
+    let child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn();
+    if let Ok(mut child) = child {
+        // stdin is dropped at the end of this block, closing it, so that the program knows the text is complete
+        if let Some(mut stdin) = child.stdin.take() {
+            if stdin.write_all(text.as_bytes()).await.is_err() {
+                return false;
+            }
+        }
+        child.wait().await.is_ok_and(|status| status.success())
+    } else {
+        false
+    }
+}
@@ -0,0 +1,554 @@
+use std::{collections::HashMap, fmt};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use serde::Deserialize;
+
+/// Actions, in the main view, that can be bound to keys via the config file
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Cheatsheet,
+    Clear,
+    ContainerSearch,
+    CopyAllLogs,
+    CopyId,
+    CopyLogs,
+    CopyName,
+    CpuMode,
+    Delete,
+    Detail,
+    Env,
+    Exec,
+    Filter,
+    GoTo,
+    Group,
+    GroupCollapse,
+    Help,
+    History,
+    IdColumn,
+    Images,
+    Inspect,
+    Kill,
+    Labels,
+    Limits,
+    LogCopy,
+    LogMarker,
+    LogPause,
+    LogSearch,
+    LogSeverity,
+    LogsFullscreen,
+    LogSince,
+    LogStreams,
+    LogWrap,
+    Mark,
+    MemoryUnit,
+    MouseCapture,
+    Mounts,
+    Networks,
+    NextPanel,
+    Pause,
+    PreviousPanel,
+    Prune,
+    PullImage,
+    Quit,
+    Recreate,
+    RefreshFaster,
+    RefreshSlower,
+    Rename,
+    Restart,
+    RestartPolicy,
+    RunCommand,
+    SaveLogs,
+    ScrollDown,
+    ScrollEnd,
+    ScrollHalfPageDown,
+    ScrollHalfPageUp,
+    ScrollPageDown,
+    ScrollPageUp,
+    ScrollStart,
+    ScrollUp,
+    SearchNext,
+    SearchPrevious,
+    Select,
+    SortCpu,
+    SortCycle,
+    SortId,
+    SortImage,
+    SortMemory,
+    SortName,
+    SortReset,
+    SortReverse,
+    SortRx,
+    SortState,
+    SortStatus,
+    SortTx,
+    Start,
+    StateFilter,
+    Stop,
+    Summary,
+    Timestamps,
+    Unpause,
+    Volumes,
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Use the same snake_case name as is used in the config file
+        let debug = format!("{self:?}");
+        let mut output = String::with_capacity(debug.len() + 4);
+        for (index, c) in debug.chars().enumerate() {
+            if c.is_uppercase() && index > 0 {
+                output.push('_');
+            }
+            output.push(c.to_ascii_lowercase());
+        }
+        write!(f, "{output}")
+    }
+}
+
+/// The groups of actions shown in the keybinding cheatsheet, in the order they are shown
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Category {
+    General,
+    Navigation,
+    Containers,
+    Logs,
+    Sort,
+    Display,
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::General => "general",
+            Self::Navigation => "navigation",
+            Self::Containers => "containers",
+            Self::Logs => "logs",
+            Self::Sort => "sort",
+            Self::Display => "display",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+impl Action {
+    /// The action changes a container, image, network, or volume, or execs into a container, so is ignored in read-only mode
+    pub const fn is_mutating(self) -> bool {
+        matches!(
+            self,
+            Self::Delete
+                | Self::Exec
+                | Self::Kill
+                | Self::Limits
+                | Self::Pause
+                | Self::Prune
+                | Self::PullImage
+                | Self::Recreate
+                | Self::Rename
+                | Self::Restart
+                | Self::RestartPolicy
+                | Self::Start
+                | Self::Stop
+                | Self::Unpause
+        )
+    }
+
+    /// The group that an action is shown in, in the keybinding cheatsheet
+    pub const fn category(self) -> Category {
+        match self {
+            Self::Cheatsheet
+            | Self::Clear
+            | Self::Help
+            | Self::MouseCapture
+            | Self::Quit
+            | Self::RefreshFaster
+            | Self::RefreshSlower => Category::General,
+            Self::Images
+            | Self::Networks
+            | Self::NextPanel
+            | Self::PreviousPanel
+            | Self::ScrollDown
+            | Self::ScrollEnd
+            | Self::ScrollHalfPageDown
+            | Self::ScrollHalfPageUp
+            | Self::ScrollPageDown
+            | Self::ScrollPageUp
+            | Self::ScrollStart
+            | Self::ScrollUp
+            | Self::Select
+            | Self::Volumes => Category::Navigation,
+            Self::ContainerSearch
+            | Self::CopyId
+            | Self::CopyName
+            | Self::Delete
+            | Self::Env
+            | Self::Exec
+            | Self::Filter
+            | Self::GoTo
+            | Self::Group
+            | Self::GroupCollapse
+            | Self::Inspect
+            | Self::Kill
+            | Self::Labels
+            | Self::Limits
+            | Self::Mark
+            | Self::Mounts
+            | Self::Pause
+            | Self::Prune
+            | Self::PullImage
+            | Self::Recreate
+            | Self::Rename
+            | Self::Restart
+            | Self::RestartPolicy
+            | Self::RunCommand
+            | Self::Start
+            | Self::StateFilter
+            | Self::Stop
+            | Self::Unpause => Category::Containers,
+            Self::CopyAllLogs
+            | Self::CopyLogs
+            | Self::LogCopy
+            | Self::LogMarker
+            | Self::LogPause
+            | Self::LogSearch
+            | Self::LogSeverity
+            | Self::LogsFullscreen
+            | Self::LogSince
+            | Self::LogStreams
+            | Self::LogWrap
+            | Self::SaveLogs
+            | Self::SearchNext
+            | Self::SearchPrevious
+            | Self::Timestamps => Category::Logs,
+            Self::SortCpu
+            | Self::SortCycle
+            | Self::SortId
+            | Self::SortImage
+            | Self::SortMemory
+            | Self::SortName
+            | Self::SortReset
+            | Self::SortReverse
+            | Self::SortRx
+            | Self::SortState
+            | Self::SortStatus
+            | Self::SortTx => Category::Sort,
+            Self::CpuMode
+            | Self::Detail
+            | Self::History
+            | Self::IdColumn
+            | Self::MemoryUnit
+            | Self::Summary => Category::Display,
+        }
+    }
+}
+
+/// The keys of every bound action, grouped by category, as shown in the keybinding cheatsheet
+pub type CheatsheetSections = Vec<(Category, Vec<(String, Action)>)>;
+
+/// The default keys for each action, start, stop, & restart are unbound, as they are available via the commands panel
+const DEFAULT_KEYS: [(Action, &[&str]); 82] = [
+    (Action::Cheatsheet, &["?"]),
+    (Action::Clear, &["esc"]),
+    (Action::ContainerSearch, &["ctrl+f"]),
+    (Action::CopyAllLogs, &["alt+Y"]),
+    (Action::CopyId, &["y"]),
+    (Action::CopyLogs, &["alt+y"]),
+    (Action::CopyName, &["Y"]),
+    (Action::CpuMode, &["ctrl+v"]),
+    (Action::Delete, &["delete"]),
+    (Action::Detail, &["d", "D"]),
+    (Action::Env, &["a", "A"]),
+    (Action::Exec, &["e", "E"]),
+    (Action::Filter, &["f"]),
+    (Action::GoTo, &[":"]),
+    (Action::Group, &["c", "C"]),
+    (Action::GroupCollapse, &["z", "Z"]),
+    (Action::Help, &["h", "H"]),
+    (Action::History, &["v", "V"]),
+    (Action::IdColumn, &["ctrl+g"]),
+    (Action::Images, &["b", "B"]),
+    (Action::Inspect, &["i", "I"]),
+    (Action::Kill, &["x", "X"]),
+    (Action::Labels, &["ctrl+t"]),
+    (Action::Limits, &["alt+l"]),
+    (Action::LogCopy, &["ctrl+y"]),
+    (Action::LogMarker, &["alt+m"]),
+    (Action::LogPause, &["ctrl+s"]),
+    (Action::LogSearch, &["/"]),
+    (Action::LogSeverity, &["ctrl+l"]),
+    (Action::LogsFullscreen, &["l", "L"]),
+    (Action::LogSince, &["ctrl+k"]),
+    (Action::LogStreams, &["ctrl+d"]),
+    (Action::LogWrap, &["w", "W"]),
+    (Action::Mark, &["space"]),
+    (Action::MemoryUnit, &["ctrl+b"]),
+    (Action::MouseCapture, &["m", "M"]),
+    (Action::Mounts, &["ctrl+u"]),
+    (Action::Networks, &["ctrl+w"]),
+    (Action::NextPanel, &["tab"]),
+    (Action::Pause, &["p", "P"]),
+    (Action::PreviousPanel, &["backtab"]),
+    (Action::Prune, &["ctrl+p"]),
+    (Action::PullImage, &["alt+p"]),
+    (Action::Quit, &["q", "Q"]),
+    (Action::Recreate, &["alt+r"]),
+    (Action::RefreshFaster, &["+"]),
+    (Action::RefreshSlower, &["-"]),
+    (Action::Rename, &["ctrl+e"]),
+    (Action::Restart, &[]),
+    (Action::RestartPolicy, &["alt+a"]),
+    (Action::RunCommand, &["ctrl+r"]),
+    (Action::SaveLogs, &["s", "S"]),
+    (Action::ScrollDown, &["down", "j", "J"]),
+    (Action::ScrollEnd, &["end", "G"]),
+    (Action::ScrollHalfPageDown, &["alt+d"]),
+    (Action::ScrollHalfPageUp, &["alt+u"]),
+    (Action::ScrollPageDown, &["pagedown", "alt+f"]),
+    (Action::ScrollPageUp, &["pageup", "alt+b"]),
+    (Action::ScrollStart, &["home", "g"]),
+    (Action::ScrollUp, &["up", "k", "K"]),
+    (Action::SearchNext, &["n"]),
+    (Action::SearchPrevious, &["N"]),
+    (Action::Select, &["enter"]),
+    (Action::SortCpu, &["4"]),
+    (Action::SortCycle, &["o", "O"]),
+    (Action::SortId, &["6"]),
+    (Action::SortImage, &["7"]),
+    (Action::SortMemory, &["5"]),
+    (Action::SortName, &["1"]),
+    (Action::SortReset, &["0"]),
+    (Action::SortReverse, &["r", "R"]),
+    (Action::SortRx, &["8"]),
+    (Action::SortState, &["2"]),
+    (Action::SortStatus, &["3"]),
+    (Action::SortTx, &["9"]),
+    (Action::Start, &[]),
+    (Action::StateFilter, &["F"]),
+    (Action::Stop, &[]),
+    (Action::Summary, &["ctrl+a"]),
+    (Action::Timestamps, &["t", "T"]),
+    (Action::Unpause, &["u", "U"]),
+    (Action::Volumes, &["ctrl+o"]),
+];
+
+/// A single key, with optional modifiers, shift is ignored as it's already reflected in the case of a char
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBind {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBind {
+    /// Check if a key press matches this key, only the control & alt modifiers are compared
+    fn matches(self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        let relevant = KeyModifiers::CONTROL | KeyModifiers::ALT;
+        self.code == code && self.modifiers & relevant == modifiers & relevant
+    }
+
+    /// Parse the name of key, named keys are case-insensitive, a single char is used as-is
+    fn parse_code(name: &str) -> Option<KeyCode> {
+        let mut chars = name.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Some(KeyCode::Char(c));
+        }
+        let name = name.to_lowercase();
+        let code = match name.as_str() {
+            "backspace" => KeyCode::Backspace,
+            "backtab" => KeyCode::BackTab,
+            "delete" | "del" => KeyCode::Delete,
+            "down" => KeyCode::Down,
+            "end" => KeyCode::End,
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "home" => KeyCode::Home,
+            "insert" => KeyCode::Insert,
+            "left" => KeyCode::Left,
+            "pagedown" => KeyCode::PageDown,
+            "pageup" => KeyCode::PageUp,
+            "right" => KeyCode::Right,
+            "space" => KeyCode::Char(' '),
+            "tab" => KeyCode::Tab,
+            "up" => KeyCode::Up,
+            _ => {
+                let number = name.strip_prefix('f')?.parse::<u8>().ok()?;
+                if (1..=12).contains(&number) {
+                    KeyCode::F(number)
+                } else {
+                    return None;
+                }
+            }
+        };
+        Some(code)
+    }
+}
+
+/// The key in the same format as is used in the config file, e.g. "q", "pagedown", or "ctrl+d"
+impl fmt::Display for KeyBind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "ctrl+"),
+            (KeyModifiers::ALT, "alt+"),
+            (KeyModifiers::SHIFT, "shift+"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}")?;
+            }
+        }
+        let name = match self.code {
+            KeyCode::Backspace => "backspace",
+            KeyCode::BackTab => "backtab",
+            KeyCode::Delete => "delete",
+            KeyCode::Down => "down",
+            KeyCode::End => "end",
+            KeyCode::Enter => "enter",
+            KeyCode::Esc => "esc",
+            KeyCode::Home => "home",
+            KeyCode::Insert => "insert",
+            KeyCode::Left => "left",
+            KeyCode::PageDown => "pagedown",
+            KeyCode::PageUp => "pageup",
+            KeyCode::Right => "right",
+            KeyCode::Char(' ') => "space",
+            KeyCode::Tab => "tab",
+            KeyCode::Up => "up",
+            KeyCode::F(number) => return write!(f, "f{number}"),
+            KeyCode::Char(c) => return write!(f, "{c}"),
+            _ => "?",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl TryFrom<&str> for KeyBind {
+    type Error = String;
+
+    /// Parse keys such as "q", "pagedown", "ctrl+d", or "alt+shift+tab"
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let err = || format!("invalid key \"{input}\"");
+        if input.chars().count() == 1 {
+            return Self::parse_code(input)
+                .map(|code| Self {
+                    code,
+                    modifiers: KeyModifiers::NONE,
+                })
+                .ok_or_else(err);
+        }
+        let mut parts = input.split('+').collect::<Vec<_>>();
+        let key = parts.pop().filter(|i| !i.is_empty()).ok_or_else(err)?;
+        let mut modifiers = KeyModifiers::NONE;
+        for part in parts {
+            modifiers |= match part.to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(err()),
+            };
+        }
+        let code = Self::parse_code(key).ok_or_else(err)?;
+        Ok(Self { code, modifiers })
+    }
+}
+
+/// Either a single key, or a list of keys, in the config file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyList {
+    fn keys(&self) -> Vec<&str> {
+        match self {
+            Self::One(key) => vec![key.as_str()],
+            Self::Many(keys) => keys.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+/// Map of actions to keys, used by the input handler instead of matching literal keys
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    keys: HashMap<Action, Vec<KeyBind>>,
+}
+
+#[allow(clippy::expect_used)]
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::new(&HashMap::new()).expect("default keys are valid")
+    }
+}
+
+impl Keymap {
+    /// Create a keymap, any action not in the user supplied map uses the default keys.
+    /// Returns an error if a key is invalid, or if a key is bound to more than one action
+    pub fn new(user: &HashMap<Action, KeyList>) -> Result<Self, String> {
+        let mut keys = HashMap::new();
+        for (action, default) in DEFAULT_KEYS {
+            let names = user
+                .get(&action)
+                .map_or_else(|| default.to_vec(), KeyList::keys);
+            let binds = names
+                .into_iter()
+                .map(KeyBind::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("{e} for \"{action}\""))?;
+            keys.insert(action, binds);
+        }
+
+        let mut sorted = keys.iter().collect::<Vec<_>>();
+        sorted.sort_by_key(|i| i.0);
+        for (index, (action, binds)) in sorted.iter().enumerate() {
+            for (other_action, other_binds) in sorted.iter().skip(index + 1) {
+                if binds.iter().any(|i| other_binds.contains(i)) {
+                    return Err(format!(
+                        "a key is bound to both \"{action}\" and \"{other_action}\""
+                    ));
+                }
+            }
+        }
+        Ok(Self { keys })
+    }
+
+    /// Find the action, if any, bound to a key press
+    pub fn get_action(&self, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        self.keys
+            .iter()
+            .find(|(_, binds)| binds.iter().any(|i| i.matches(code, modifiers)))
+            .map(|(action, _)| *action)
+    }
+
+    /// Every action that is bound to at least one key, grouped by category, with its keys comma separated, as shown in the keybinding cheatsheet.
+    /// The actions are sorted alphabetically within each category, and the keys are in the order they were set
+    pub fn cheatsheet(&self) -> CheatsheetSections {
+        let mut sorted = self
+            .keys
+            .iter()
+            .filter(|(_, binds)| !binds.is_empty())
+            .collect::<Vec<_>>();
+        sorted.sort_by_key(|(action, _)| (action.category(), **action));
+
+        let mut output: CheatsheetSections = vec![];
+        for (action, binds) in sorted {
+            let keys = binds
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            match output.last_mut() {
+                Some((category, actions)) if *category == action.category() => {
+                    actions.push((keys, *action));
+                }
+                _ => output.push((action.category(), vec![(keys, *action)])),
+            }
+        }
+        output
+    }
+
+    /// Check if a key press is bound to a given action
+    pub fn is_action(&self, action: Action, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        self.keys
+            .get(&action)
+            .is_some_and(|binds| binds.iter().any(|i| i.matches(code, modifiers)))
+    }
+}
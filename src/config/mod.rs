@@ -0,0 +1,535 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    process,
+};
+
+use serde::Deserialize;
+use tracing::{error, warn};
+
+use crate::{
+    app_data::{Header, MemoryUnit, TimeFormat, TimeZone, DEFAULT_COLUMNS},
+    parse_args::CliArgs,
+};
+
+mod keymap;
+mod theme;
+mod view;
+use keymap::KeyList;
+pub use keymap::{Action, CheatsheetSections, Keymap};
+pub use theme::{Theme, ThemeName};
+pub use view::ViewConfig;
+
+/// Name of the config file, inside the `oxker` directory in the users config directory
+const CONFIG_FILE: &str = "config.toml";
+
+/// The config file as written by the user, every section is optional.
+/// Unknown sections, or actions, are rejected, so that typos aren't silently ignored
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    columns: Option<Vec<String>>,
+    keymap: Option<HashMap<Action, KeyList>>,
+    log_severity: Option<bool>,
+    memory_unit: Option<MemoryUnit>,
+    mouse: Option<bool>,
+    shells: Option<Vec<String>>,
+    theme: Option<toml::Table>,
+    time_format: Option<String>,
+    timezone: Option<TimeZone>,
+    view: Option<ViewConfig>,
+}
+
+/// Config, read at startup, with defaults used for anything not set in the config file
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub columns: Vec<Header>,
+    pub keymap: Keymap,
+    pub log_severity: bool,
+    pub memory_unit: MemoryUnit,
+    pub mouse: bool,
+    /// Where the view settings are saved on exit, either the "--config" location, or the default location, even if it doesn't yet exist
+    pub path: Option<PathBuf>,
+    /// Shells to try, in order, when execing into a container
+    pub shells: Vec<String>,
+    pub theme: Theme,
+    pub time_format: TimeFormat,
+    pub view: ViewConfig,
+}
+
+/// Parse the column names from the config file, in the order given.
+/// An unknown, or repeated, name is ignored with a warning, rather than stopping oxker from starting, and if no valid names remain the default columns are used
+fn parse_columns(names: &[String]) -> Vec<Header> {
+    let mut columns = vec![];
+    for name in names {
+        match toml::Value::String(name.clone()).try_into::<Header>() {
+            Ok(header) if !columns.contains(&header) => columns.push(header),
+            Ok(_) => warn!("column \"{name}\" is repeated in the config file, ignoring"),
+            Err(_) => warn!("unknown column \"{name}\" in the config file, ignoring"),
+        }
+    }
+    if columns.is_empty() {
+        DEFAULT_COLUMNS.to_vec()
+    } else {
+        columns
+    }
+}
+
+impl Config {
+    /// Default config location, in the platform's config directory.
+    /// `$XDG_CONFIG_HOME/oxker/config.toml` on Linux, `~/Library/Application Support/oxker/config.toml` on macOS, and `%APPDATA%\oxker\config.toml` on Windows
+    fn default_path() -> Option<PathBuf> {
+        directories::BaseDirs::new().map(|i| {
+            i.config_dir()
+                .join(env!("CARGO_PKG_NAME"))
+                .join(CONFIG_FILE)
+        })
+    }
+
+    /// Parse the text of a config file, theme colors are applied on top of the given built-in theme
+    fn parse(text: &str, theme: ThemeName) -> Result<Self, String> {
+        let file = toml::from_str::<ConfigFile>(text).map_err(|e| e.message().to_owned())?;
+        let keymap = Keymap::new(&file.keymap.unwrap_or_default())?;
+        let theme = Theme::from_name(theme)
+            .merge(file.theme.unwrap_or_default())
+            .map_err(|e| format!("invalid theme: {e}"))?;
+        Ok(Self {
+            columns: file
+                .columns
+                .map_or_else(|| DEFAULT_COLUMNS.to_vec(), |i| parse_columns(&i)),
+            keymap,
+            log_severity: file.log_severity.unwrap_or_default(),
+            memory_unit: file.memory_unit.unwrap_or_default(),
+            mouse: file.mouse.unwrap_or(true),
+            path: None,
+            shells: file.shells.unwrap_or_default(),
+            theme,
+            time_format: TimeFormat::new(
+                file.time_format.as_deref(),
+                file.timezone.unwrap_or_default(),
+            ),
+            view: file.view.unwrap_or_default(),
+        })
+    }
+
+    /// Read the config file at the given path
+    fn read(path: &Path, theme: ThemeName) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        Self::parse(&text, theme)
+    }
+
+    /// Load the config file, the "--config" argument takes priority, and the default location is only used if it isn't set.
+    /// If the default config file doesn't exist then the defaults are used, but a missing "--config" file, or any parse error, will quit the program.
+    /// The directory of the default location is created when the view settings are first saved
+    pub fn new(args: &CliArgs) -> Self {
+        let path = args
+            .config
+            .clone()
+            .or_else(|| Self::default_path().filter(|i| i.exists()));
+        let config = path.map_or_else(
+            || Self {
+                columns: DEFAULT_COLUMNS.to_vec(),
+                keymap: Keymap::default(),
+                log_severity: false,
+                memory_unit: MemoryUnit::default(),
+                mouse: true,
+                path: None,
+                shells: vec![],
+                theme: Theme::from_name(args.theme),
+                time_format: TimeFormat::default(),
+                view: ViewConfig::default(),
+            },
+            |path| {
+                Self::read(&path, args.theme).unwrap_or_else(|e| {
+                    error!("unable to parse config file \"{}\": {e}", path.display());
+                    process::exit(1)
+                })
+            },
+        );
+        Self {
+            path: args.config.clone().or_else(Self::default_path),
+            ..config
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    use ratatui::style::Color;
+
+    use super::{
+        keymap::Category, view::replace_view_table, Action, Config, Theme, ThemeName, ViewConfig,
+    };
+    use crate::{
+        app_data::{
+            Header, MemoryUnit, SortedOrder, StateFilter, Summary, TimeFormat, TimeZone,
+            DEFAULT_COLUMNS,
+        },
+        parse_args::CliArgs,
+        tests::gen_args,
+    };
+
+    #[test]
+    /// An empty config file uses the default keys
+    fn test_config_parse_empty() {
+        let config = Config::parse("", ThemeName::Dark).unwrap();
+        let result = config
+            .keymap
+            .get_action(KeyCode::Char('q'), KeyModifiers::NONE);
+        assert_eq!(result, Some(Action::Quit));
+        let result = config
+            .keymap
+            .get_action(KeyCode::Char('N'), KeyModifiers::SHIFT);
+        assert_eq!(result, Some(Action::SearchPrevious));
+        let result = config
+            .keymap
+            .get_action(KeyCode::Char('q'), KeyModifiers::CONTROL);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    /// User keys replace the default keys for an action, other actions keep their defaults
+    fn test_config_parse_keymap() {
+        let config = Config::parse(
+            r#"
+            [keymap]
+            quit = "ctrl+x"
+            scroll_down = ["down", "ctrl+n"]
+            restart = "F5"
+            "#,
+            ThemeName::Dark,
+        )
+        .unwrap();
+        let keymap = config.keymap;
+
+        assert!(keymap.is_action(Action::Quit, KeyCode::Char('x'), KeyModifiers::CONTROL));
+        assert!(!keymap.is_action(Action::Quit, KeyCode::Char('q'), KeyModifiers::NONE));
+        assert_eq!(
+            keymap.get_action(KeyCode::Char('n'), KeyModifiers::CONTROL),
+            Some(Action::ScrollDown)
+        );
+        assert!(keymap
+            .get_action(KeyCode::Char('j'), KeyModifiers::NONE)
+            .is_none());
+        assert_eq!(
+            keymap.get_action(KeyCode::F(5), KeyModifiers::NONE),
+            Some(Action::Restart)
+        );
+        assert_eq!(
+            keymap.get_action(KeyCode::Char('h'), KeyModifiers::NONE),
+            Some(Action::Help)
+        );
+    }
+
+    #[test]
+    /// The cheatsheet lists every bound action, grouped by category, with the keys as set in the config file, unbound actions are left out
+    fn test_config_keymap_cheatsheet() {
+        let config = Config::parse(
+            r#"
+            [keymap]
+            quit = ["ctrl+x", "F5"]
+            help = []
+            restart = "alt+shift+tab"
+            "#,
+            ThemeName::Dark,
+        )
+        .unwrap();
+        let cheatsheet = config.keymap.cheatsheet();
+
+        let categories = cheatsheet.iter().map(|i| i.0).collect::<Vec<_>>();
+        assert_eq!(
+            categories,
+            vec![
+                Category::General,
+                Category::Navigation,
+                Category::Containers,
+                Category::Logs,
+                Category::Sort,
+                Category::Display
+            ]
+        );
+        assert_eq!(
+            cheatsheet[0].1,
+            vec![
+                ("?".to_owned(), Action::Cheatsheet),
+                ("esc".to_owned(), Action::Clear),
+                ("m, M".to_owned(), Action::MouseCapture),
+                ("ctrl+x, f5".to_owned(), Action::Quit),
+                ("+".to_owned(), Action::RefreshFaster),
+                ("-".to_owned(), Action::RefreshSlower),
+            ]
+        );
+        let containers = &cheatsheet[2].1;
+        assert!(containers.contains(&("alt+shift+tab".to_owned(), Action::Restart)));
+        assert!(containers.contains(&("space".to_owned(), Action::Mark)));
+        assert!(!containers.iter().any(|i| i.1 == Action::Start));
+    }
+
+    #[test]
+    /// Invalid keys, unknown actions, and duplicate keys all return an error
+    fn test_config_parse_errors() {
+        let result = Config::parse("[keymap]\nquit = \"ctrl+\"", ThemeName::Dark);
+        assert_eq!(
+            result.unwrap_err(),
+            "invalid key \"ctrl+\" for \"quit\"".to_owned()
+        );
+
+        let result = Config::parse("[keymap]\nnot_an_action = \"a\"", ThemeName::Dark);
+        assert!(result
+            .unwrap_err()
+            .contains("unknown variant `not_an_action`"));
+
+        let result = Config::parse("[keymaps]\nquit = \"a\"", ThemeName::Dark);
+        assert!(result.unwrap_err().contains("unknown field `keymaps`"));
+
+        let result = Config::parse("[keymap]\nquit = \"h\"", ThemeName::Dark);
+        assert_eq!(
+            result.unwrap_err(),
+            "a key is bound to both \"help\" and \"quit\"".to_owned()
+        );
+    }
+
+    #[test]
+    /// User theme colors are applied on top of the chosen built-in theme, unset colors keep the built-in value
+    fn test_config_parse_theme() {
+        let config = Config::parse("", ThemeName::Dark).unwrap();
+        assert_eq!(config.theme, Theme::default());
+
+        let config = Config::parse(
+            r##"
+            [theme]
+            header_bg = "blue"
+            state_running = "#00ff00"
+            "##,
+            ThemeName::Light,
+        )
+        .unwrap();
+        let light = Theme::from_name(ThemeName::Light);
+        assert_eq!(config.theme.header_bg, Color::Blue);
+        assert_eq!(config.theme.state_running, Color::Rgb(0, 255, 0));
+        assert_eq!(config.theme.ports_text, light.ports_text);
+        assert_ne!(config.theme.ports_text, Theme::default().ports_text);
+    }
+
+    #[test]
+    /// Log severity highlighting is off by default, and the user tokens replace the default tokens
+    fn test_config_parse_log_severity() {
+        let config = Config::parse("", ThemeName::Dark).unwrap();
+        assert!(!config.log_severity);
+        assert_eq!(config.theme.log_severity.get("error"), Some(&Color::Red));
+
+        let config = Config::parse(
+            r#"
+            log_severity = true
+            [theme.log_severity]
+            panic = "lightred"
+            "#,
+            ThemeName::Dark,
+        )
+        .unwrap();
+        assert!(config.log_severity);
+        assert_eq!(
+            config.theme.log_severity.into_iter().collect::<Vec<_>>(),
+            vec![("panic".to_owned(), Color::LightRed)]
+        );
+    }
+
+    #[test]
+    /// Mouse capture is enabled by default, and can be disabled at startup
+    fn test_config_parse_mouse() {
+        let config = Config::parse("", ThemeName::Dark).unwrap();
+        assert!(config.mouse);
+        let config = Config::parse("mouse = false", ThemeName::Dark).unwrap();
+        assert!(!config.mouse);
+    }
+
+    #[test]
+    /// Shells are tried in the order given, and default to none, so that only `sh` is tried
+    fn test_config_parse_shells() {
+        let config = Config::parse("", ThemeName::Dark).unwrap();
+        assert!(config.shells.is_empty());
+        let config = Config::parse(r#"shells = ["/bin/bash", "ash"]"#, ThemeName::Dark).unwrap();
+        assert_eq!(config.shells, ["/bin/bash", "ash"]);
+    }
+
+    #[test]
+    /// The memory unit defaults to auto, and an unknown unit is an error
+    fn test_config_parse_memory_unit() {
+        let config = Config::parse("", ThemeName::Dark).unwrap();
+        assert_eq!(config.memory_unit, MemoryUnit::Auto);
+        let config = Config::parse(r#"memory_unit = "mib""#, ThemeName::Dark).unwrap();
+        assert_eq!(config.memory_unit, MemoryUnit::Mib);
+        assert!(Config::parse(r#"memory_unit = "mb""#, ThemeName::Dark).is_err());
+    }
+
+    #[test]
+    /// The time format defaults to local time, an invalid format falls back to the default, but an unknown timezone is an error
+    fn test_config_parse_time_format() {
+        let config = Config::parse("", ThemeName::Dark).unwrap();
+        assert_eq!(config.time_format, TimeFormat::default());
+
+        let config = Config::parse(
+            r#"
+            time_format = "%H:%M:%S"
+            timezone = "utc"
+            "#,
+            ThemeName::Dark,
+        )
+        .unwrap();
+        assert_eq!(
+            config.time_format,
+            TimeFormat::new(Some("%H:%M:%S"), TimeZone::Utc)
+        );
+        assert_eq!(config.time_format.format(0), "00:00:00");
+
+        let config = Config::parse(r#"time_format = "%Q""#, ThemeName::Dark).unwrap();
+        assert_eq!(config.time_format, TimeFormat::default());
+        assert!(Config::parse(r#"timezone = "gmt""#, ThemeName::Dark).is_err());
+    }
+
+    #[test]
+    /// Unknown theme fields, and invalid colors, return an error
+    fn test_config_parse_theme_errors() {
+        let result = Config::parse(
+            "[theme]
+header = \"blue\"",
+            ThemeName::Dark,
+        );
+        assert!(result.unwrap_err().contains("unknown field `header`"));
+
+        let result = Config::parse(
+            "[theme]
+header_bg = \"not_a_color\"",
+            ThemeName::Dark,
+        );
+        assert!(result.unwrap_err().starts_with("invalid theme"));
+    }
+
+    #[test]
+    /// Each view setting is optional, so a partial view table still loads, unknown settings return an error
+    fn test_config_parse_view() {
+        let config = Config::parse("", ThemeName::Dark).unwrap();
+        assert_eq!(config.view, ViewConfig::default());
+
+        let config = Config::parse(
+            r#"
+            [view]
+            sort = "net_io"
+            log_wrap = true
+            "#,
+            ThemeName::Dark,
+        )
+        .unwrap();
+        assert_eq!(
+            config.view,
+            ViewConfig {
+                sort: Some(Header::NetIo),
+                log_wrap: Some(true),
+                ..ViewConfig::default()
+            }
+        );
+
+        let result = Config::parse("[view]\ncolumns = 1", ThemeName::Dark);
+        assert!(result.unwrap_err().contains("unknown field `columns`"));
+    }
+
+    #[test]
+    /// Columns are shown in the order given, unknown or repeated names are ignored, and an empty list uses the default columns
+    fn test_config_parse_columns() {
+        let config = Config::parse("", ThemeName::Dark).unwrap();
+        assert_eq!(config.columns, DEFAULT_COLUMNS.to_vec());
+
+        let config = Config::parse(
+            r#"columns = ["uptime", "name", "mem", "ports", "net", "name", "block", "service"]"#,
+            ThemeName::Dark,
+        )
+        .unwrap();
+        assert_eq!(
+            config.columns,
+            vec![
+                Header::Uptime,
+                Header::Name,
+                Header::Memory,
+                Header::NetIo,
+                Header::BlockIo,
+                Header::Service
+            ]
+        );
+
+        let config = Config::parse(r#"columns = ["not_a_column"]"#, ThemeName::Dark).unwrap();
+        assert_eq!(config.columns, DEFAULT_COLUMNS.to_vec());
+
+        let config = Config::parse("columns = []", ThemeName::Dark).unwrap();
+        assert_eq!(config.columns, DEFAULT_COLUMNS.to_vec());
+    }
+
+    #[test]
+    /// Only the view table is replaced, the rest of the config file, including comments, is unchanged
+    fn test_config_replace_view_table() {
+        let table = "sort = \"cpu\"\n";
+        assert_eq!(replace_view_table("", table), "[view]\nsort = \"cpu\"\n");
+
+        let text = "# my config\nmouse = false\n\n[view] # saved\nsort = \"name\"\n\n[theme]\nheader_bg = \"blue\"\n";
+        let result = replace_view_table(text, table);
+        assert_eq!(
+            result,
+            "# my config\nmouse = false\n\n[theme]\nheader_bg = \"blue\"\n\n[view]\nsort = \"cpu\"\n"
+        );
+        assert!(Config::parse(&result, ThemeName::Dark).is_ok());
+    }
+
+    #[test]
+    /// Saved view settings can be read back, the config file is created if missing
+    fn test_config_view_save() {
+        let dir = std::env::temp_dir().join(format!("oxker_view_{}", std::process::id()));
+        let path = dir.join("config.toml");
+        let view = ViewConfig {
+            sort: Some(Header::Memory),
+            sort_order: Some(SortedOrder::Desc),
+            state_filter: Some(StateFilter::Running),
+            grouped: Some(false),
+            summary: Some(Summary::Visible),
+            detail: Some(true),
+            history: Some(true),
+            log_timestamps: Some(false),
+            log_wrap: Some(true),
+        };
+        view.save(&path);
+        let config = Config::read(&path, ThemeName::Dark).unwrap();
+        assert_eq!(config.view, view);
+
+        std::fs::write(&path, "mouse = false\n").unwrap();
+        ViewConfig::default().save(&path);
+        let config = Config::read(&path, ThemeName::Dark).unwrap();
+        assert!(!config.mouse);
+        assert_eq!(config.view, ViewConfig::default());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    /// The default location is the oxker directory in the platform config directory, and "--config" takes priority over it
+    fn test_config_path() {
+        let default = Config::default_path().unwrap();
+        assert!(default.ends_with("oxker/config.toml"));
+        assert_eq!(
+            default.parent().unwrap().parent().unwrap(),
+            directories::BaseDirs::new().unwrap().config_dir()
+        );
+
+        let dir = std::env::temp_dir().join(format!("oxker_path_{}", std::process::id()));
+        let path = dir.join("custom.toml");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&path, "mouse = false\n").unwrap();
+        let args = CliArgs {
+            config: Some(path.clone()),
+            ..gen_args()
+        };
+        let config = Config::new(&args);
+        assert!(!config.mouse);
+        assert_eq!(config.path, Some(path));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
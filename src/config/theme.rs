@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+
+use clap::ValueEnum;
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::app_data::{DockerControls, Health, State};
+
+/// Built-in themes, selected with the "--theme" argument, individual colors can then be changed in the config file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ThemeName {
+    #[default]
+    Dark,
+    Light,
+}
+
+/// Every color used by the ui, colors are either a name, e.g. "red", "lightblue", or a hex code, e.g. "#ffb224".
+/// `log_severity` maps a token, matched case-insensitively as a whole word, to the color of the log lines that contain it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Theme {
+    pub alert: Color,
+    pub border_selected: Color,
+    pub chart_cpu: Color,
+    pub chart_max_running: Color,
+    pub chart_memory: Color,
+    pub chart_memory_limit: Color,
+    pub command_delete: Color,
+    pub command_kill: Color,
+    pub command_pause: Color,
+    pub command_restart: Color,
+    pub command_start: Color,
+    pub command_stop: Color,
+    pub command_unpause: Color,
+    pub container_text: Color,
+    pub error_bg: Color,
+    pub error_fg: Color,
+    pub fresh_start: Color,
+    pub header_bg: Color,
+    pub header_fg: Color,
+    pub header_selected_fg: Color,
+    pub health_healthy: Color,
+    pub health_starting: Color,
+    pub health_unhealthy: Color,
+    pub help_bg: Color,
+    pub help_fg: Color,
+    pub help_highlight: Color,
+    pub info_bg: Color,
+    pub info_fg: Color,
+    pub log_match_bg: Color,
+    pub log_match_fg: Color,
+    pub log_search_error: Color,
+    pub log_severity: BTreeMap<String, Color>,
+    pub log_stderr: Color,
+    pub log_timestamp: Color,
+    pub net_rx: Color,
+    pub net_tx: Color,
+    pub popup_bg: Color,
+    pub popup_fg: Color,
+    pub popup_highlight: Color,
+    pub ports_header: Color,
+    pub ports_text: Color,
+    pub selected_row_bg: Color,
+    pub state_paused: Color,
+    pub state_removing: Color,
+    pub state_restarting: Color,
+    pub state_running: Color,
+    pub state_stopped: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// The original oxker colors
+    fn dark() -> Self {
+        Self {
+            border_selected: Color::LightCyan,
+            alert: Color::LightRed,
+            chart_cpu: Color::Magenta,
+            chart_max_running: Color::Rgb(255, 178, 36),
+            chart_memory: Color::Cyan,
+            chart_memory_limit: Color::Red,
+            command_delete: Color::Gray,
+            command_kill: Color::LightRed,
+            command_pause: Color::Yellow,
+            command_restart: Color::Magenta,
+            command_start: Color::Green,
+            command_stop: Color::Red,
+            command_unpause: Color::Blue,
+            container_text: Color::Blue,
+            error_bg: Color::Red,
+            error_fg: Color::White,
+            fresh_start: Color::LightYellow,
+            header_bg: Color::Magenta,
+            header_fg: Color::Black,
+            header_selected_fg: Color::White,
+            health_healthy: Color::Green,
+            health_starting: Color::Yellow,
+            health_unhealthy: Color::LightRed,
+            help_bg: Color::Magenta,
+            help_fg: Color::Black,
+            help_highlight: Color::White,
+            info_bg: Color::Blue,
+            info_fg: Color::White,
+            log_match_bg: Color::Yellow,
+            log_match_fg: Color::Black,
+            log_search_error: Color::Red,
+            log_severity: BTreeMap::from([
+                ("error".to_owned(), Color::Red),
+                ("fatal".to_owned(), Color::Red),
+                ("info".to_owned(), Color::Green),
+                ("warn".to_owned(), Color::Yellow),
+                ("warning".to_owned(), Color::Yellow),
+            ]),
+            log_stderr: Color::LightRed,
+            log_timestamp: Color::DarkGray,
+            net_rx: Color::Rgb(255, 233, 193),
+            net_tx: Color::Rgb(205, 140, 140),
+            popup_bg: Color::White,
+            popup_fg: Color::Black,
+            popup_highlight: Color::Red,
+            ports_header: Color::Yellow,
+            ports_text: Color::White,
+            selected_row_bg: Color::Reset,
+            state_paused: Color::Yellow,
+            state_removing: Color::LightRed,
+            state_restarting: Color::LightGreen,
+            state_running: Color::Green,
+            state_stopped: Color::Red,
+        }
+    }
+
+    /// Darker variants of the colors that are hard to read on a light background
+    fn light() -> Self {
+        Self {
+            border_selected: Color::Blue,
+            chart_max_running: Color::Rgb(191, 112, 0),
+            chart_memory: Color::Blue,
+            command_delete: Color::DarkGray,
+            command_pause: Color::Rgb(160, 120, 0),
+            fresh_start: Color::Magenta,
+            health_healthy: Color::Rgb(0, 140, 60),
+            health_starting: Color::Rgb(160, 120, 0),
+            health_unhealthy: Color::Red,
+            net_rx: Color::Rgb(150, 100, 0),
+            net_tx: Color::Rgb(150, 60, 60),
+            popup_bg: Color::Black,
+            popup_fg: Color::White,
+            popup_highlight: Color::LightRed,
+            ports_header: Color::Rgb(160, 120, 0),
+            log_severity: BTreeMap::from([
+                ("error".to_owned(), Color::Red),
+                ("fatal".to_owned(), Color::Red),
+                ("info".to_owned(), Color::Rgb(0, 140, 60)),
+                ("warn".to_owned(), Color::Rgb(160, 120, 0)),
+                ("warning".to_owned(), Color::Rgb(160, 120, 0)),
+            ]),
+            ports_text: Color::Black,
+            log_stderr: Color::Red,
+            state_paused: Color::Rgb(160, 120, 0),
+            state_restarting: Color::Rgb(0, 140, 60),
+            ..Self::dark()
+        }
+    }
+
+    /// Get the built-in theme
+    pub fn from_name(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Dark => Self::dark(),
+            ThemeName::Light => Self::light(),
+        }
+    }
+
+    /// Apply the user set colors from the config file on top of a built-in theme, unset colors keep the built-in color
+    pub fn merge(self, user: toml::Table) -> Result<Self, String> {
+        let mut table = toml::Table::try_from(self).map_err(|e| e.to_string())?;
+        table.extend(user);
+        table
+            .try_into()
+            .map_err(|e: toml::de::Error| e.message().to_owned())
+    }
+
+    /// Color for a container state
+    pub const fn state(&self, state: State) -> Color {
+        match state {
+            State::Paused => self.state_paused,
+            State::Removing => self.state_removing,
+            State::Restarting => self.state_restarting,
+            State::Running => self.state_running,
+            _ => self.state_stopped,
+        }
+    }
+
+    /// Color for the health indicator of a container with a healthcheck
+    pub const fn health(&self, health: Health) -> Color {
+        match health {
+            Health::Healthy => self.health_healthy,
+            Health::Starting => self.health_starting,
+            Health::Unhealthy => self.health_unhealthy,
+        }
+    }
+
+    /// Color for a docker command, in the commands panel
+    pub const fn command(&self, control: DockerControls) -> Color {
+        match control {
+            DockerControls::Delete => self.command_delete,
+            DockerControls::Kill => self.command_kill,
+            DockerControls::Pause => self.command_pause,
+            DockerControls::Recreate | DockerControls::Restart => self.command_restart,
+            DockerControls::Start => self.command_start,
+            DockerControls::Stop => self.command_stop,
+            DockerControls::Unpause => self.command_unpause,
+        }
+    }
+}
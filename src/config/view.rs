@@ -0,0 +1,91 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app_data::{AppData, Header, SortedOrder, StateFilter, Summary},
+    ui::GuiState,
+};
+
+/// Name of the config file table that the view settings are saved in
+const VIEW_TABLE: &str = "[view]";
+
+/// The view settings, saved to the `[view]` table of the config file on exit, and restored on startup.
+/// Every field is optional, so that a partial, or hand written, table still loads
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ViewConfig {
+    pub sort: Option<Header>,
+    pub sort_order: Option<SortedOrder>,
+    pub state_filter: Option<StateFilter>,
+    pub grouped: Option<bool>,
+    pub summary: Option<Summary>,
+    pub detail: Option<bool>,
+    pub history: Option<bool>,
+    pub log_timestamps: Option<bool>,
+    pub log_wrap: Option<bool>,
+}
+
+impl ViewConfig {
+    /// Read the current view settings, to be saved on exit
+    pub fn new(app_data: &AppData, gui_state: &GuiState) -> Self {
+        let sorted = app_data.get_sorted();
+        Self {
+            sort: sorted.map(|i| i.0),
+            sort_order: sorted.map(|i| i.1),
+            state_filter: Some(app_data.get_state_filter()),
+            grouped: Some(app_data.is_grouped()),
+            summary: Some(app_data.get_summary()),
+            detail: Some(gui_state.get_detail_visible()),
+            history: Some(gui_state.get_history_visible()),
+            log_timestamps: Some(app_data.get_logs_with_timestamps()),
+            log_wrap: Some(gui_state.get_log_wrap()),
+        }
+    }
+
+    /// Write the view settings into the config file, creating it if it doesn't yet exist.
+    /// Only the `[view]` table is replaced, so the rest of the file, including any comments, is unchanged.
+    /// Any error, e.g. the file isn't writable, is ignored, as the view settings aren't important enough to stop oxker from closing
+    pub fn save(&self, path: &Path) {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(_) => return,
+        };
+        let Ok(table) = toml::to_string(self) else {
+            return;
+        };
+        if let Some(parent) = path.parent() {
+            if std::fs::create_dir_all(parent).is_err() {
+                return;
+            }
+        }
+        std::fs::write(path, replace_view_table(&text, &table)).ok();
+    }
+}
+
+/// Remove any existing `[view]` table from the text of a config file, and append the given table
+pub fn replace_view_table(text: &str, table: &str) -> String {
+    let mut in_view = false;
+    let mut lines = text
+        .lines()
+        .filter(|line| {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.starts_with('[') {
+                in_view = line == VIEW_TABLE;
+            }
+            !in_view
+        })
+        .collect::<Vec<_>>();
+    while lines.last().is_some_and(|i| i.trim().is_empty()) {
+        lines.pop();
+    }
+    let mut output = lines.join("\n");
+    if !output.is_empty() {
+        output.push_str("\n\n");
+    }
+    output.push_str(VIEW_TABLE);
+    output.push('\n');
+    output.push_str(table);
+    output
+}
@@ -0,0 +1,166 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::parse_args::TlsPaths;
+
+const DOCKER_CONFIG: &str = "DOCKER_CONFIG";
+/// The built-in context, which has no stored metadata, and uses the default socket, or DOCKER_HOST
+const DEFAULT_CONTEXT: &str = "default";
+
+/// The Docker cli config directory, `$DOCKER_CONFIG`, or `~/.docker`
+pub fn config_dir() -> Option<PathBuf> {
+    std::env::var(DOCKER_CONFIG).map_or_else(
+        |_| directories::BaseDirs::new().map(|i| i.home_dir().join(".docker")),
+        |i| Some(PathBuf::from(i)),
+    )
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Endpoint {
+    host: Option<String>,
+}
+
+/// The `meta.json` file of a stored context, only the name, and the docker endpoint, are used
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ContextMeta {
+    name: String,
+    #[serde(default)]
+    endpoints: HashMap<String, Endpoint>,
+}
+
+/// The Docker endpoint of a named Docker CLI context, as created by `docker context create`.
+/// Contexts are stored in `$DOCKER_CONFIG/contexts`, or `~/.docker/contexts`, each in a directory named after the sha256 of the context name,
+/// so every `meta.json` is read until the name matches
+#[derive(Debug, Clone)]
+pub struct DockerContext {
+    pub host: String,
+    pub tls: Option<TlsPaths>,
+}
+
+impl DockerContext {
+    /// Find a context by name, the `default` context has no stored endpoint, so returns None
+    pub fn read(name: &str) -> Result<Option<Self>, String> {
+        if name == DEFAULT_CONTEXT {
+            return Ok(None);
+        }
+        let Some(config_dir) = config_dir() else {
+            return Err(String::from("unable to find the Docker config directory"));
+        };
+        Self::read_from(&config_dir.join("contexts"), name).map(Some)
+    }
+
+    /// Search the `meta` directory of the given contexts directory, the TLS files, if any, are in the matching `tls` directory
+    fn read_from(contexts_dir: &Path, name: &str) -> Result<Self, String> {
+        let entries = std::fs::read_dir(contexts_dir.join("meta")).map_err(|_| {
+            format!(
+                "Docker context \"{name}\" not found, no contexts in \"{}\"",
+                contexts_dir.display()
+            )
+        })?;
+
+        for dir in entries.filter_map(Result::ok).map(|i| i.path()) {
+            let Ok(content) = std::fs::read_to_string(dir.join("meta.json")) else {
+                continue;
+            };
+            let Ok(meta) = serde_json::from_str::<ContextMeta>(&content) else {
+                continue;
+            };
+            if meta.name != name {
+                continue;
+            }
+            let host = meta
+                .endpoints
+                .get("docker")
+                .and_then(|i| i.host.clone())
+                .ok_or_else(|| format!("Docker context \"{name}\" has no docker endpoint"))?;
+
+            let tls_dir = dir
+                .file_name()
+                .map(|id| contexts_dir.join("tls").join(id).join("docker"));
+            let tls = tls_dir
+                .map(|dir| TlsPaths {
+                    ca: dir.join("ca.pem"),
+                    cert: dir.join("cert.pem"),
+                    key: dir.join("key.pem"),
+                })
+                .filter(|tls| [&tls.ca, &tls.cert, &tls.key].iter().all(|i| i.exists()));
+            return Ok(Self { host, tls });
+        }
+        Err(format!("Docker context \"{name}\" not found"))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::path::Path;
+
+    use super::DockerContext;
+
+    /// Create a stored context, in the same layout as the Docker cli
+    fn write_context(dir: &Path, id: &str, meta: &str, tls: bool) {
+        let meta_dir = dir.join("meta").join(id);
+        std::fs::create_dir_all(&meta_dir).unwrap();
+        std::fs::write(meta_dir.join("meta.json"), meta).unwrap();
+        if tls {
+            let tls_dir = dir.join("tls").join(id).join("docker");
+            std::fs::create_dir_all(&tls_dir).unwrap();
+            for file in ["ca.pem", "cert.pem", "key.pem"] {
+                std::fs::write(tls_dir.join(file), "").unwrap();
+            }
+        }
+    }
+
+    #[test]
+    /// A context is found by its name, rather than its directory, and the TLS files are only used if all three exist
+    fn test_docker_context_read() {
+        let dir = std::env::temp_dir().join(format!("oxker_context_{}", std::process::id()));
+        write_context(
+            &dir,
+            "aaa",
+            r#"{"Name":"remote","Metadata":{},"Endpoints":{"docker":{"Host":"ssh://user@host","SkipTLSVerify":false}}}"#,
+            false,
+        );
+        write_context(
+            &dir,
+            "bbb",
+            r#"{"Name":"secure","Metadata":{},"Endpoints":{"docker":{"Host":"tcp://host:2376","SkipTLSVerify":false}}}"#,
+            true,
+        );
+        write_context(&dir, "ccc", r#"{"Name":"empty","Endpoints":{}}"#, false);
+        write_context(&dir, "ddd", "not json", false);
+
+        let result = DockerContext::read_from(&dir, "remote").unwrap();
+        assert_eq!(result.host, "ssh://user@host");
+        assert!(result.tls.is_none());
+
+        let result = DockerContext::read_from(&dir, "secure").unwrap();
+        assert_eq!(result.host, "tcp://host:2376");
+        let tls = result.tls.unwrap();
+        assert_eq!(
+            tls.ca,
+            dir.join("tls").join("bbb").join("docker").join("ca.pem")
+        );
+
+        let result = DockerContext::read_from(&dir, "empty");
+        assert_eq!(
+            result.unwrap_err(),
+            "Docker context \"empty\" has no docker endpoint"
+        );
+
+        let result = DockerContext::read_from(&dir, "missing");
+        assert_eq!(result.unwrap_err(), "Docker context \"missing\" not found");
+
+        let result = DockerContext::read_from(&dir.join("missing"), "remote");
+        assert!(result.is_err());
+
+        assert!(DockerContext::read("default").unwrap().is_none());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,19 +1,103 @@
 use std::sync::Arc;
 
-use crate::app_data::ContainerId;
+use crate::app_data::{ContainerId, DockerControls, ImageId, NetworkId, VolumeName};
 use bollard::Docker;
 use tokio::sync::oneshot::Sender;
 
 #[derive(Debug)]
 pub enum DockerMessage {
+    /// Start, stop, or restart, every container in a compose project
+    Batch {
+        control: DockerControls,
+        ids: Vec<ContainerId>,
+    },
     ConfirmDelete(ContainerId),
-    Delete(ContainerId),
+    Env(ContainerId),
     Exec(Sender<Arc<Docker>>),
+    /// Refresh the images list
+    Images,
+    Inspect(ContainerId),
+    InspectNetwork(NetworkId),
+    Kill {
+        id: ContainerId,
+        signal: String,
+    },
+    Labels(ContainerId),
+    /// Refresh the networks list
+    Networks,
     Pause(ContainerId),
+    /// Stop, remove, and create & start a new copy of, a container, keeping the options that can be read back from its inspect output
+    Recreate(ContainerId),
+    /// Pull the image of a container, the progress is shown in the pull panel
+    PullImage {
+        id: ContainerId,
+        image: String,
+    },
+    /// Remove every stopped container, every dangling image, and/or every unused anonymous volume
+    Prune {
+        containers: bool,
+        images: bool,
+        volumes: bool,
+    },
     Quit,
+    Remove {
+        id: ContainerId,
+        force: bool,
+    },
+    RemoveImage {
+        id: ImageId,
+        force: bool,
+    },
+    RemoveNetwork(NetworkId),
+    RemoveVolume(VolumeName),
+    Rename {
+        id: ContainerId,
+        name: String,
+    },
     Restart(ContainerId),
+    /// Reconstruct the docker run command of a container from its inspect output
+    RunCommand(ContainerId),
     Start(ContainerId),
     Stop(ContainerId),
-    Resume(ContainerId),
+    Unpause(ContainerId),
     Update,
+    /// Change the cpu and memory limits of a running container, `None` leaves a limit unchanged
+    UpdateLimits {
+        id: ContainerId,
+        nano_cpus: Option<i64>,
+        memory: Option<i64>,
+    },
+    /// Change the restart policy of a container, without recreating it
+    UpdateRestartPolicy {
+        id: ContainerId,
+        policy: String,
+    },
+    /// Refresh the volumes list
+    Volumes,
+}
+
+impl DockerMessage {
+    /// A command that changes a container, image, network, or volume, rather than just reading data, quitting whilst one is still being processed asks for confirmation first
+    pub const fn is_action(&self) -> bool {
+        matches!(
+            self,
+            Self::Batch { .. }
+                | Self::Kill { .. }
+                | Self::Pause(_)
+                | Self::Prune { .. }
+                | Self::PullImage { .. }
+                | Self::Recreate(_)
+                | Self::Remove { .. }
+                | Self::RemoveImage { .. }
+                | Self::RemoveNetwork(_)
+                | Self::RemoveVolume(_)
+                | Self::Rename { .. }
+                | Self::Restart(_)
+                | Self::Start(_)
+                | Self::Stop(_)
+                | Self::Unpause(_)
+                | Self::UpdateLimits { .. }
+                | Self::UpdateRestartPolicy { .. }
+        )
+    }
 }
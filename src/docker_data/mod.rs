@@ -1,19 +1,26 @@
 use bollard::{
     container::{
-        ListContainersOptions, LogsOptions, MemoryStatsStats, RemoveContainerOptions,
-        StartContainerOptions, Stats, StatsOptions,
+        AttachContainerOptions, AttachContainerResults, KillContainerOptions,
+        ListContainersOptions, LogsOptions, MemoryStatsStats, PruneContainersOptions,
+        RemoveContainerOptions, RenameContainerOptions, StartContainerOptions, Stats, StatsOptions,
+        UpdateContainerOptions,
     },
-    service::ContainerSummary,
-    Docker,
+    image::{CreateImageOptions, ListImagesOptions, PruneImagesOptions, RemoveImageOptions},
+    network::{InspectNetworkOptions, ListNetworksOptions},
+    service::{ContainerSummary, RestartPolicy, RestartPolicyNameEnum},
+    system::EventsOptions,
+    volume::{ListVolumesOptions, PruneVolumesOptions, RemoveVolumeOptions},
+    Docker, API_DEFAULT_VERSION,
 };
 use futures_util::StreamExt;
 use parking_lot::Mutex;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     sync::{
         atomic::{AtomicBool, AtomicUsize},
         Arc,
     },
+    time::{SystemTime, UNIX_EPOCH},
 };
 use tokio::{
     sync::mpsc::{Receiver, Sender},
@@ -22,44 +29,92 @@ use tokio::{
 use uuid::Uuid;
 
 use crate::{
-    app_data::{AppData, ContainerId, DockerControls, State},
+    app_data::{
+        format_timestamp, parse_timestamp_nanos, AppData, ByteStats, ContainerAddress,
+        ContainerCommand, ContainerId, ContainerMount, DockerControls, Engine, Health, ImageItem,
+        IoTotals, LogStream, NetworkItem, Restarts, State, Uptime, VolumeItem, VolumeName,
+    },
     app_error::AppError,
-    parse_args::CliArgs,
-    ui::{GuiState, Status},
+    parse_args::{CliArgs, TlsPaths},
+    ui::{Batch, BatchStep, GuiState, Status, View},
     ENTRY_POINT,
 };
 mod message;
+mod pull;
+mod recreate;
+mod run_command;
 pub use message::DockerMessage;
 
-#[derive(Debug, Clone, Eq, Hash, PartialEq)]
-enum SpawnId {
-    Stats((ContainerId, Binate)),
-    Log(ContainerId),
-}
+/// How often the scheduler checks if the update interval has elapsed
+const SCHEDULER_POLL: std::time::Duration = std::time::Duration::from_millis(100);
+/// A remote host that doesn't respond shouldn't leave oxker hanging
+const PING_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+/// Number of pings in a row that can fail before reconnecting
+const PING_FAILURES: u8 = 3;
+/// Bounds of the delay between reconnection attempts, the delay is doubled after each failed attempt
+const RECONNECT_MIN: std::time::Duration = std::time::Duration::from_secs(1);
+const RECONNECT_MAX: std::time::Duration = std::time::Duration::from_secs(30);
+/// Container events often arrive in bursts, e.g. a compose project starting, so a single update is sent once the events have been quiet for this long
+const EVENT_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(100);
+/// Container events that change the containers list, or the state of a container
+const CONTAINER_EVENTS: [&str; 9] = [
+    "create", "destroy", "die", "pause", "rename", "restart", "start", "stop", "unpause",
+];
+/// Each stats stream is a long-lived connection to the daemon, so only this many containers have their stats streamed at once
+const MAX_STAT_STREAMS: usize = 64;
 
-/// Cpu & Mem stats take twice as long as the update interval to get a value, so will have two being executed at the same time
-/// SpawnId::Stats takes container_id and binate value to enable both cycles of the same container_id to be inserted into the hashmap
-/// Binate value is toggled when all handles have been spawned off
-/// Also effectively means that the docker_update interval minimum will be 1000ms
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
-enum Binate {
-    One,
-    Two,
+/// How to connect to the Docker daemon, kept so that the same connection can be re-created if the daemon restarts
+#[derive(Debug, Clone)]
+pub enum Connection {
+    Defaults,
+    Socket(String),
+    Ssl { host: String, tls: TlsPaths },
 }
 
-impl Binate {
-    const fn toggle(self) -> Self {
+impl Connection {
+    pub fn connect(&self) -> Result<Docker, bollard::errors::Error> {
         match self {
-            Self::One => Self::Two,
-            Self::Two => Self::One,
+            Self::Defaults => Docker::connect_with_socket_defaults(),
+            Self::Socket(host) => Docker::connect_with_socket(host, 120, API_DEFAULT_VERSION),
+            Self::Ssl { host, tls } => Docker::connect_with_ssl(
+                host,
+                &tls.key,
+                &tls.cert,
+                &tls.ca,
+                120,
+                API_DEFAULT_VERSION,
+            ),
+        }
+    }
+
+    /// Check that the daemon responds, within the PING_TIMEOUT
+    pub async fn ping(docker: &Docker) -> bool {
+        Self::round_trip(docker).await.is_some()
+    }
+
+    /// Time a ping of the daemon, `None` if it doesn't respond within the PING_TIMEOUT
+    pub async fn round_trip(docker: &Docker) -> Option<std::time::Duration> {
+        let now = std::time::Instant::now();
+        match tokio::time::timeout(PING_TIMEOUT, docker.ping()).await {
+            Ok(Ok(_)) => Some(now.elapsed()),
+            _ => None,
         }
     }
 }
 
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+enum SpawnId {
+    Attach(ContainerId),
+    Health(ContainerId),
+    Stats(ContainerId),
+    Log(ContainerId),
+}
+
 pub struct DockerData {
     app_data: Arc<Mutex<AppData>>,
     args: CliArgs,
-    binate: Binate,
+    attach_failed: Arc<Mutex<HashSet<ContainerId>>>,
+    connection: Connection,
     docker: Arc<Docker>,
     gui_state: Arc<Mutex<GuiState>>,
     is_running: Arc<AtomicBool>,
@@ -69,10 +124,29 @@ pub struct DockerData {
 }
 
 impl DockerData {
-    /// Use docker stats to calculate current cpu usage
+    /// The number of online cpus, older daemons don't send it, so fall back to the number of per cpu usages
+    fn online_cpus(stats: &Stats) -> u64 {
+        stats.cpu_stats.online_cpus.unwrap_or_else(|| {
+            stats
+                .cpu_stats
+                .cpu_usage
+                .percpu_usage
+                .as_ref()
+                .map_or(0, std::vec::Vec::len) as u64
+        })
+    }
+
+    /// Use docker stats to calculate current cpu usage.
+    /// Podman doesn't always send the system cpu usage, which would leave the usage at zero, so it's instead calculated against the time between the two readings, in the same way as `podman stats`
     #[allow(clippy::cast_precision_loss)]
     // TODO FIX: this can overflow
-    fn calculate_usage(stats: &Stats) -> f64 {
+    fn calculate_usage(stats: &Stats, engine: Engine) -> f64 {
+        if engine == Engine::Podman
+            && (stats.cpu_stats.system_cpu_usage.is_none()
+                || stats.precpu_stats.system_cpu_usage.is_none())
+        {
+            return Self::calculate_usage_elapsed(stats);
+        }
         let mut cpu_percentage = 0.0;
         let previous_cpu = stats.precpu_stats.cpu_usage.total_usage;
         let cpu_delta = stats.cpu_stats.cpu_usage.total_usage as f64 - previous_cpu as f64;
@@ -82,14 +156,7 @@ impl DockerData {
             stats.precpu_stats.system_cpu_usage,
         ) {
             let system_delta = (cpu_stats_usage - precpu_stats_usage) as f64;
-            let online_cpus = stats.cpu_stats.online_cpus.unwrap_or_else(|| {
-                stats
-                    .cpu_stats
-                    .cpu_usage
-                    .percpu_usage
-                    .as_ref()
-                    .map_or(0, std::vec::Vec::len) as u64
-            }) as f64;
+            let online_cpus = Self::online_cpus(stats) as f64;
             if system_delta > 0.0 && cpu_delta > 0.0 {
                 cpu_percentage = (cpu_delta / system_delta) * online_cpus * 100.0;
             }
@@ -97,111 +164,240 @@ impl DockerData {
         cpu_percentage
     }
 
-    /// Get a single docker stat in order to update mem and cpu usage
+    /// Calculate the cpu usage from the cpu time used between the `preread` and `read` timestamps, a container using two whole cpus is at 200%
+    #[allow(clippy::cast_precision_loss)]
+    fn calculate_usage_elapsed(stats: &Stats) -> f64 {
+        let cpu_delta = stats
+            .cpu_stats
+            .cpu_usage
+            .total_usage
+            .saturating_sub(stats.precpu_stats.cpu_usage.total_usage);
+        match (
+            parse_timestamp_nanos(&stats.read),
+            parse_timestamp_nanos(&stats.preread),
+        ) {
+            (Some(read), Some(preread)) if read > preread => {
+                cpu_delta as f64 / (read - preread) as f64 * 100.0
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Stream the docker stats of a single container, updating the cpu, mem, and io usage as each value arrives, rather than on the update interval.
+    /// The first value is skipped, as docker sends it before there is a previous cpu value to calculate the usage from.
+    /// The stream ends once the container stops, or the task is aborted, when the container is no longer visible.
     /// don't take &self, so that can tokio::spawn into it's own thread
     /// remove if from spawns hashmap when complete
-    async fn update_container_stat(
+    async fn stream_container_stats(
         app_data: Arc<Mutex<AppData>>,
         docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
         id: ContainerId,
-        init: Option<(Arc<AtomicUsize>, usize)>,
-        state: State,
-        spawn_id: SpawnId,
+        mut init: Option<Arc<AtomicUsize>>,
         spawns: Arc<Mutex<HashMap<SpawnId, JoinHandle<()>>>>,
     ) {
-        if state.is_alive() || init.is_some() {
-            let mut stream = docker
-                .stats(
-                    id.get(),
-                    Some(StatsOptions {
-                        stream: false,
-                        one_shot: false,
-                    }),
-                )
-                .take(1);
-
-            while let Some(Ok(stats)) = stream.next().await {
-                // Memory stats are only collected if the container is alive - is this the behaviour we want?
-                let mem_stat = if state.is_alive() {
-                    let mem_cache = stats.memory_stats.stats.map_or(0, |i| match i {
-                        MemoryStatsStats::V1(x) => x.inactive_file,
-                        MemoryStatsStats::V2(x) => x.inactive_file,
-                    });
-                    Some(
-                        stats
-                            .memory_stats
-                            .usage
-                            .unwrap_or_default()
-                            .saturating_sub(mem_cache),
-                    )
-                } else {
-                    None
-                };
+        let engine = app_data.lock().get_engine();
+        let mut stream = docker
+            .stats(
+                id.get(),
+                Some(StatsOptions {
+                    stream: true,
+                    one_shot: false,
+                }),
+            )
+            .skip(1);
 
-                let mem_limit = stats.memory_stats.limit.unwrap_or_default();
+        while let Some(Ok(stats)) = stream.next().await {
+            let mem_cache = stats.memory_stats.stats.map_or(0, |i| match i {
+                MemoryStatsStats::V1(x) => x.inactive_file,
+                MemoryStatsStats::V2(x) => x.inactive_file,
+            });
+            let mem_stat = stats
+                .memory_stats
+                .usage
+                .unwrap_or_default()
+                .saturating_sub(mem_cache);
 
-                let op_key = stats
-                    .networks
-                    .as_ref()
-                    .and_then(|networks| networks.keys().next().cloned());
+            let mem_limit = stats.memory_stats.limit.unwrap_or_default();
 
-                let cpu_stats = if state.is_alive() {
-                    Some(Self::calculate_usage(&stats))
-                } else {
-                    None
-                };
-                let (rx, tx) = if let Some(key) = op_key {
-                    stats
-                        .networks
-                        .unwrap_or_default()
-                        .get(&key)
-                        .map_or((0, 0), |f| (f.rx_bytes, f.tx_bytes))
-                } else {
-                    (0, 0)
-                };
+            let cpu_stats = Self::calculate_usage(&stats, engine);
 
-                app_data
-                    .lock()
-                    .update_stats_by_id(&id, cpu_stats, mem_stat, mem_limit, rx, tx);
+            // Sum every network interface, a container without any networks, e.g. host mode, has no network stats
+            let net = stats.networks.as_ref().map(|networks| {
+                networks
+                    .values()
+                    .fold((0, 0), |acc, i| (acc.0 + i.rx_bytes, acc.1 + i.tx_bytes))
+            });
+
+            // Docker uses both capitalised & lowercase op names, depending on the cgroup version
+            let (blk_read, blk_write) = stats
+                .blkio_stats
+                .io_service_bytes_recursive
+                .as_ref()
+                .map_or((0, 0), |entries| {
+                    entries
+                        .iter()
+                        .fold((0, 0), |acc, i| match i.op.to_lowercase().as_str() {
+                            "read" => (acc.0 + i.value, acc.1),
+                            "write" => (acc.0, acc.1 + i.value),
+                            _ => acc,
+                        })
+                });
+
+            app_data.lock().update_stats_by_id(
+                &id,
+                Some((cpu_stats, Self::online_cpus(&stats))),
+                Some(mem_stat),
+                mem_limit,
+                IoTotals {
+                    net,
+                    blk_read,
+                    blk_write,
+                },
+            );
+            let alert = app_data.lock().update_alert_by_id(&id);
+            if let Some(alert) = alert {
+                let mut gui_state = gui_state.lock();
+                gui_state.set_info_box(&alert);
+                gui_state.status_push(Status::Alert);
+            }
+            if let Some(target) = init.take() {
+                target.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
             }
         }
-        spawns.lock().remove(&spawn_id);
-        if let Some((target, _)) = init {
+        spawns.lock().remove(&SpawnId::Stats(id));
+        if let Some(target) = init {
             target.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
         }
     }
 
-    /// Update all stats, spawn each container into own tokio::spawn thread
-    fn update_all_container_stats(&mut self, all_ids: &[(State, ContainerId)]) {
-        for (state, id) in all_ids {
-            let docker = Arc::clone(&self.docker);
-            let app_data = Arc::clone(&self.app_data);
-            let spawns = Arc::clone(&self.spawns);
-            let spawn_id = SpawnId::Stats((id.clone(), self.binate));
+    /// Stream the stats of the visible, alive, containers, as well as the hidden alive containers whilst the summary bar totals every container, up to MAX_STAT_STREAMS, each in its own tokio::spawn thread.
+    /// Any stream of a container that has since been removed, or filtered out, is aborted.
+    /// Returns the number of streams, so that the initialisation can wait for each to receive its first value
+    fn update_all_container_stats(&self) -> usize {
+        let ids = self
+            .app_data
+            .lock()
+            .get_stat_stream_ids()
+            .into_iter()
+            .take(MAX_STAT_STREAMS)
+            .collect::<HashSet<_>>();
+        let mut spawns = self.spawns.lock();
+        spawns.retain(|key, handle| match key {
+            SpawnId::Stats(id) if !ids.contains(id) => {
+                handle.abort();
+                false
+            }
+            _ => true,
+        });
+        for id in &ids {
+            spawns.entry(SpawnId::Stats(id.clone())).or_insert_with(|| {
+                tokio::spawn(Self::stream_container_stats(
+                    Arc::clone(&self.app_data),
+                    Arc::clone(&self.docker),
+                    Arc::clone(&self.gui_state),
+                    id.clone(),
+                    self.init.as_ref().map(Arc::clone),
+                    Arc::clone(&self.spawns),
+                ))
+            });
+        }
+        drop(spawns);
+        ids.len()
+    }
+
+    /// Read the restart count & policy, the ip addresses, the mounts, the entrypoint & cmd, the logging driver, the started & finished times, and the healthcheck status, of a single container from its inspect data, show an info box if it has just become unhealthy.
+    /// The health is only read for alive containers, as a stopped container keeps its last health status
+    /// remove it from spawns hashmap when complete
+    async fn update_health(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        (state, id): (State, ContainerId),
+        spawns: Arc<Mutex<HashMap<SpawnId, JoinHandle<()>>>>,
+    ) {
+        if let Ok(inspect) = docker.inspect_container(id.get(), None).await {
+            app_data
+                .lock()
+                .update_restarts_by_id(&id, Restarts::from(&inspect));
+            app_data
+                .lock()
+                .update_address_by_id(&id, ContainerAddress::from(&inspect));
+            app_data.lock().update_mounts_by_id(
+                &id,
+                inspect
+                    .mounts
+                    .unwrap_or_default()
+                    .iter()
+                    .map(ContainerMount::from)
+                    .collect(),
+            );
+            if let Some(config) = inspect.config.as_ref() {
+                app_data
+                    .lock()
+                    .update_container_command_by_id(&id, ContainerCommand::from(config));
+            }
+            app_data.lock().update_log_driver_by_id(
+                &id,
+                inspect
+                    .host_config
+                    .as_ref()
+                    .and_then(|i| i.log_config.as_ref())
+                    .and_then(|i| i.typ.clone()),
+            );
+            let inspect_state = inspect.state.unwrap_or_default();
+            app_data.lock().update_uptime_by_id(
+                &id,
+                Uptime::new(
+                    inspect_state.started_at.as_deref(),
+                    inspect_state.finished_at.as_deref(),
+                ),
+            );
+            if state.is_alive() {
+                let health = inspect_state
+                    .health
+                    .and_then(|i| i.status)
+                    .and_then(Health::from_status);
+                let unhealthy = app_data.lock().update_health_by_id(&id, health);
+                if unhealthy {
+                    let name = app_data.lock().get_container_name_by_id(&id);
+                    if let Some(name) = name {
+                        gui_state
+                            .lock()
+                            .set_info_box(&format!("{} is unhealthy", name.get()));
+                    }
+                }
+            }
+        }
+        spawns.lock().remove(&SpawnId::Health(id));
+    }
 
-            let init = self.init.as_ref().map(|i| (Arc::clone(i), all_ids.len()));
+    /// Update the health, and started & finished times, of every running container, as well as the times of any stopped container that are yet to be read.
+    /// Spawn each container into own tokio::spawn thread
+    fn update_all_container_health(&self, all_ids: &[(State, ContainerId)]) {
+        for (state, id) in all_ids {
+            if !state.is_alive() && !self.app_data.lock().uptime_unknown(id) {
+                continue;
+            }
             self.spawns
                 .lock()
-                .entry(spawn_id.clone())
+                .entry(SpawnId::Health(id.clone()))
                 .or_insert_with(|| {
-                    tokio::spawn(Self::update_container_stat(
-                        app_data,
-                        docker,
-                        id.clone(),
-                        init,
-                        *state,
-                        spawn_id,
-                        spawns,
+                    tokio::spawn(Self::update_health(
+                        Arc::clone(&self.app_data),
+                        Arc::clone(&self.docker),
+                        Arc::clone(&self.gui_state),
+                        (*state, id.clone()),
+                        Arc::clone(&self.spawns),
                     ))
                 });
         }
-        self.binate = self.binate.toggle();
     }
 
     /// Get all current containers, handle into ContainerItem in the app_data struct rather than here
     /// Just make sure that items sent are guaranteed to have an id
     /// If in a containerised runtime, will ignore any container that uses the `/app/oxker` as an entry point, unless the `-s` flag is set
-    pub async fn update_all_containers(&mut self) -> Vec<(State, ContainerId)> {
+    pub async fn update_all_containers(&self) -> Vec<(State, ContainerId)> {
         let containers = self
             .docker
             .list_containers(Some(ListContainersOptions::<String> {
@@ -218,7 +414,7 @@ impl DockerData {
                     if self.args.in_container
                         && f.command
                             .as_ref()
-                            .map_or(false, |c| c.starts_with(ENTRY_POINT))
+                            .is_some_and(|c| c.starts_with(ENTRY_POINT))
                         && self.args.show_self
                     {
                         None
@@ -230,6 +426,10 @@ impl DockerData {
             })
             .collect::<Vec<ContainerSummary>>();
 
+        let image_sizes_needed = self.app_data.lock().image_sizes_needed();
+        if image_sizes_needed {
+            self.update_image_sizes().await;
+        }
         self.app_data.lock().update_containers(&mut output);
 
         // Just get the containers that are currently running, or being restarted, no point updating info on paused or dead containers
@@ -241,37 +441,165 @@ impl DockerData {
             .collect::<Vec<_>>()
     }
 
-    /// Update single container logs
+    /// Get the size of every image with a single request, rather than inspecting the image of each container, as many containers often share the same image
+    async fn update_image_sizes(&self) {
+        let Ok(images) = self
+            .docker
+            .list_images(None::<ListImagesOptions<String>>)
+            .await
+        else {
+            return;
+        };
+        let image_sizes = images
+            .into_iter()
+            .map(|i| {
+                let size = u64::try_from(i.size).unwrap_or_default();
+                (i.id, ByteStats::new(size))
+            })
+            .collect::<HashMap<_, _>>();
+        self.app_data.lock().update_image_sizes(image_sizes);
+    }
+
+    /// Update single container logs, the first read of a container's logs is limited to the "--log-tail" number of lines,
+    /// and to the lines written after the "--since" time, if set.
+    /// Both stdout & stderr are requested, and each line is kept with the stream it was written to,
     /// remove it from spawns hashmap when complete
     async fn update_log(
         app_data: Arc<Mutex<AppData>>,
         docker: Arc<Docker>,
         id: ContainerId,
-        since: u64,
+        (since, tail): (u64, Option<usize>),
         spawns: Arc<Mutex<HashMap<SpawnId, JoinHandle<()>>>>,
     ) {
+        let tail = tail
+            .filter(|_| since == 0)
+            .map_or_else(|| String::from("all"), |i| i.to_string());
+        let since = i64::try_from(since).unwrap_or_default().max(
+            app_data
+                .lock()
+                .get_log_since_timestamp()
+                .unwrap_or_default(),
+        );
         let options = Some(LogsOptions::<String> {
             stdout: true,
+            stderr: true,
             timestamps: true,
-            since: i64::try_from(since).unwrap_or_default(),
+            since,
+            tail,
             ..Default::default()
         });
 
         let mut logs = docker.logs(id.get(), options);
         let mut output = vec![];
+        let mut unreadable = false;
 
-        while let Some(Ok(value)) = logs.next().await {
-            let data = value.to_string();
-            if !data.trim().is_empty() {
-                output.push(data);
+        while let Some(value) = logs.next().await {
+            match value {
+                Ok(value) => {
+                    let data = value.to_string();
+                    if !data.trim().is_empty() {
+                        output.push((LogStream::from(&value), data));
+                    }
+                }
+                Err(e) => {
+                    // The daemon responds with a 501 if the logging driver doesn't support reading
+                    unreadable = matches!(
+                        e,
+                        bollard::errors::Error::DockerResponseServerError {
+                            status_code: 501,
+                            ..
+                        }
+                    );
+                    break;
+                }
             }
         }
         spawns.lock().remove(&SpawnId::Log(id.clone()));
-        app_data.lock().update_log_by_id(output, &id);
+        let mut app_data = app_data.lock();
+        app_data.set_logs_unreadable_by_id(&id, unreadable);
+        app_data.update_log_by_id(output, &id);
+    }
+
+    /// Stream the stdout & stderr of a container into its logs, until the stream ends, or the task is aborted.
+    /// The attached output has no timestamps, so each line is given the time it was received.
+    /// If unable to attach, the container is added to the failed set, so that its logs are polled instead
+    async fn attach_log(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        id: ContainerId,
+        attach_failed: Arc<Mutex<HashSet<ContainerId>>>,
+        spawns: Arc<Mutex<HashMap<SpawnId, JoinHandle<()>>>>,
+    ) {
+        let options = Some(AttachContainerOptions::<String> {
+            stdout: Some(true),
+            stderr: Some(true),
+            stream: Some(true),
+            ..Default::default()
+        });
+        match docker.attach_container(id.get(), options).await {
+            Ok(AttachContainerResults { mut output, .. }) => {
+                // Once attached the logs are streamed, so are no longer waiting to be fetched
+                app_data.lock().update_log_by_id(vec![], &id);
+                while let Some(Ok(value)) = output.next().await {
+                    let now = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map_or(0, |i| i.as_nanos());
+                    let stream = LogStream::from(&value);
+                    // Each line needs a unique timestamp, as duplicate timestamps are ignored
+                    let output = value
+                        .to_string()
+                        .lines()
+                        .filter(|i| !i.trim().is_empty())
+                        .zip(now..)
+                        .map(|(line, nanos)| {
+                            (stream, format!("{} {line}", format_timestamp(nanos)))
+                        })
+                        .collect::<Vec<_>>();
+                    app_data.lock().update_log_by_id(output, &id);
+                }
+            }
+            Err(_) => {
+                attach_failed.lock().insert(id.clone());
+            }
+        }
+        spawns.lock().remove(&SpawnId::Attach(id));
+    }
+
+    /// When attaching, stop streaming any container that is no longer selected, and attach to the selected container if it's alive.
+    /// Returns true if the selected container is attached, so its logs don't need to be polled
+    fn update_attach(&self, selected: Option<(ContainerId, State)>) -> bool {
+        let selected = selected
+            .filter(|(id, state)| state.is_alive() && !self.attach_failed.lock().contains(id))
+            .map(|(id, _)| id);
+        let mut spawns = self.spawns.lock();
+        spawns.retain(|key, handle| match key {
+            SpawnId::Attach(id) if Some(id) != selected.as_ref() => {
+                handle.abort();
+                false
+            }
+            _ => true,
+        });
+        let Some(id) = selected else {
+            return false;
+        };
+        if spawns.contains_key(&SpawnId::Attach(id.clone())) {
+            return true;
+        }
+        spawns.insert(
+            SpawnId::Attach(id.clone()),
+            tokio::spawn(Self::attach_log(
+                Arc::clone(&self.app_data),
+                Arc::clone(&self.docker),
+                id,
+                Arc::clone(&self.attach_failed),
+                Arc::clone(&self.spawns),
+            )),
+        );
+        false
     }
 
     /// Update all logs, spawn each container into own tokio::spawn thread
-    fn init_all_logs(&mut self, all_ids: &[(State, ContainerId)]) {
+    fn init_all_logs(&self, all_ids: &[(State, ContainerId)]) {
         for (_, id) in all_ids {
             let docker = Arc::clone(&self.docker);
             let app_data = Arc::clone(&self.app_data);
@@ -279,30 +607,175 @@ impl DockerData {
             let key = SpawnId::Log(id.clone());
             self.spawns.lock().insert(
                 key,
-                tokio::spawn(Self::update_log(app_data, docker, id.clone(), 0, spawns)),
+                tokio::spawn(Self::update_log(
+                    app_data,
+                    docker,
+                    id.clone(),
+                    (0, self.args.log_tail),
+                    spawns,
+                )),
             );
         }
     }
 
+    /// Update the images list, each image has the number of containers, running or not, that use it
+    async fn update_images(docker: &Docker, app_data: &Arc<Mutex<AppData>>) {
+        let Ok(images) = docker.list_images(None::<ListImagesOptions<String>>).await else {
+            return;
+        };
+        let mut containers = HashMap::new();
+        for container in docker
+            .list_containers(Some(ListContainersOptions::<String> {
+                all: true,
+                ..Default::default()
+            }))
+            .await
+            .unwrap_or_default()
+        {
+            if let Some(image_id) = container.image_id {
+                *containers.entry(image_id).or_insert(0) += 1;
+            }
+        }
+        let images = images
+            .iter()
+            .map(|i| ImageItem::new(i, &containers))
+            .collect::<Vec<_>>();
+        app_data.lock().update_images(images);
+    }
+
+    /// Update the networks list, each network has the number of containers, running or not, attached to it
+    async fn update_networks(docker: &Docker, app_data: &Arc<Mutex<AppData>>) {
+        let Ok(networks) = docker
+            .list_networks(None::<ListNetworksOptions<String>>)
+            .await
+        else {
+            return;
+        };
+        let mut containers = HashMap::new();
+        for container in docker
+            .list_containers(Some(ListContainersOptions::<String> {
+                all: true,
+                ..Default::default()
+            }))
+            .await
+            .unwrap_or_default()
+        {
+            for endpoint in container
+                .network_settings
+                .and_then(|i| i.networks)
+                .unwrap_or_default()
+                .into_values()
+            {
+                if let Some(network_id) = endpoint.network_id {
+                    *containers.entry(network_id).or_insert(0) += 1;
+                }
+            }
+        }
+        let networks = networks
+            .iter()
+            .map(|i| NetworkItem::new(i, &containers))
+            .collect::<Vec<_>>();
+        app_data.lock().update_networks(networks);
+    }
+
+    /// Update the volumes list
+    async fn update_volumes(docker: &Docker, app_data: &Arc<Mutex<AppData>>) {
+        let Ok(response) = docker
+            .list_volumes(None::<ListVolumesOptions<String>>)
+            .await
+        else {
+            return;
+        };
+        let volumes = response
+            .volumes
+            .unwrap_or_default()
+            .iter()
+            .map(VolumeItem::new)
+            .collect::<Vec<_>>();
+        app_data.lock().update_volumes(volumes);
+    }
+
+    /// Re-create the connection to the Docker daemon, e.g. after the daemon has been restarted.
+    /// Retries with an exponential backoff, whilst showing a reconnecting error, until either connected or oxker is closed
+    async fn reconnect(&mut self) {
+        self.app_data.lock().set_error(
+            AppError::DockerConnect,
+            &self.gui_state,
+            Status::DockerConnect,
+        );
+        let uuid = Uuid::new_v4();
+        let loading_handle = GuiState::start_loading_animation(&self.gui_state, uuid);
+        let mut delay = RECONNECT_MIN;
+        while self.is_running.load(std::sync::atomic::Ordering::SeqCst) {
+            tokio::time::sleep(delay).await;
+            if let Ok(docker) = self.connection.connect() {
+                if let Some(round_trip) = Connection::round_trip(&docker).await {
+                    self.docker = Arc::new(docker);
+                    self.app_data.lock().set_ping(Some(round_trip));
+                    self.app_data.lock().remove_error();
+                    self.gui_state.lock().status_del(Status::DockerConnect);
+                    break;
+                }
+            }
+            delay = (delay * 2).min(RECONNECT_MAX);
+        }
+        self.gui_state
+            .lock()
+            .stop_loading_animation(&loading_handle, uuid);
+    }
+
     /// Update all cpu_mem, and selected container log (if a log update join_handle isn't currently being executed)
+    /// The daemon is pinged before every update, and the round trip time shown in the header, so that a struggling connection is flagged early.
+    /// Only once PING_FAILURES pings in a row have failed is the connection treated as lost, and reconnected, before then the update is skipped, so that the containers aren't cleared by a failed update
     async fn update_everything(&mut self) {
+        let round_trip = Connection::round_trip(&self.docker).await;
+        if self.app_data.lock().set_ping(round_trip) >= PING_FAILURES {
+            self.reconnect().await;
+        } else if round_trip.is_none() {
+            return;
+        }
+        let view = self.gui_state.lock().get_view();
+        match view {
+            View::Images => Self::update_images(&self.docker, &self.app_data).await,
+            View::Networks => Self::update_networks(&self.docker, &self.app_data).await,
+            View::Volumes => Self::update_volumes(&self.docker, &self.app_data).await,
+            View::Containers => (),
+        }
         let all_ids = self.update_all_containers().await;
-        if let Some(container) = self.app_data.lock().get_selected_container() {
-            let last_updated = container.last_updated;
+        let selected = self
+            .app_data
+            .lock()
+            .get_selected_container()
+            .filter(|i| !i.logs.stream_paused())
+            .map(|i| (i.id.clone(), i.state, i.last_updated));
+        // Once attached, the logs of the selected container are streamed, so aren't polled
+        let attached =
+            self.args.attach && self.update_attach(selected.as_ref().map(|i| (i.0.clone(), i.1)));
+        if let Some((id, _, last_updated)) = selected.filter(|_| !attached) {
             self.spawns
                 .lock()
-                .entry(SpawnId::Log(container.id.clone()))
+                .entry(SpawnId::Log(id.clone()))
                 .or_insert_with(|| {
                     // MAYBE make a struct that can create this data?
                     let app_data = Arc::clone(&self.app_data);
                     let docker = Arc::clone(&self.docker);
-                    let id = container.id.clone();
                     let spawns = Arc::clone(&self.spawns);
-                    tokio::spawn(Self::update_log(app_data, docker, id, last_updated, spawns))
+                    tokio::spawn(Self::update_log(
+                        app_data,
+                        docker,
+                        id,
+                        (last_updated, self.args.log_tail),
+                        spawns,
+                    ))
                 });
-        };
-        self.update_all_container_stats(&all_ids);
+        }
+        self.update_all_container_stats();
+        self.update_all_container_health(&all_ids);
         self.app_data.lock().sort_containers();
+        // Alerts are raised as the stats are received, but only cleared once no container is above a threshold
+        if !self.app_data.lock().has_alert() {
+            self.gui_state.lock().status_del(Status::Alert);
+        }
     }
 
     /// Initialize docker container data, before any messages are received
@@ -310,16 +783,23 @@ impl DockerData {
         self.gui_state.lock().status_push(Status::Init);
         let loading_uuid = Uuid::new_v4();
         let loading_handle = GuiState::start_loading_animation(&self.gui_state, loading_uuid);
+        if let Ok(info) = self.docker.info().await {
+            self.app_data.lock().set_host_memory(
+                info.mem_total
+                    .map_or(0, |i| u64::try_from(i).unwrap_or_default()),
+            );
+        }
         let all_ids = self.update_all_containers().await;
 
-        self.update_all_container_stats(&all_ids);
+        let streams = self.update_all_container_stats();
+        self.update_all_container_health(&all_ids);
 
         self.init_all_logs(&all_ids);
 
         while let Some(x) = self.init.as_ref() {
             self.app_data.lock().sort_containers();
             tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-            if x.load(std::sync::atomic::Ordering::SeqCst) == all_ids.len() {
+            if x.load(std::sync::atomic::Ordering::SeqCst) >= streams {
                 self.init = None;
             }
         }
@@ -340,6 +820,21 @@ impl DockerData {
             .set_error(AppError::DockerCommand(error), gui_state, Status::Error);
     }
 
+    /// Display a short info box confirming that a docker command was successfully executed on a container
+    fn set_info(
+        app_data: &Arc<Mutex<AppData>>,
+        id: &ContainerId,
+        control: DockerControls,
+        gui_state: &Arc<Mutex<GuiState>>,
+    ) {
+        let name = app_data.lock().get_container_name_by_id(id);
+        if let Some(name) = name {
+            gui_state
+                .lock()
+                .set_info_box(&format!("{} {}", control.past_tense(), name.get()));
+        }
+    }
+
     /// Handle incoming messages, container controls & all container information update
     /// Spawn Docker commands off into own thread
     #[allow(clippy::too_many_lines)]
@@ -349,21 +844,123 @@ impl DockerData {
             let gui_state = Arc::clone(&self.gui_state);
             let app_data = Arc::clone(&self.app_data);
             let uuid = Uuid::new_v4();
+            // The input handler already ignores these in read-only mode, this makes sure that nothing else can send one
+            if message.is_action() && app_data.lock().args.read_only {
+                continue;
+            }
+            if message.is_action() {
+                self.gui_state.lock().insert_pending_action(uuid);
+            }
             // TODO need to refactor these
             match message {
+                DockerMessage::Batch { control, ids } => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        Self::batch(&docker, &app_data, &gui_state, control, &ids).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::Env(id) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        if let Ok(inspect) = docker.inspect_container(id.get(), None).await {
+                            let env = inspect.config.and_then(|i| i.env).unwrap_or_default();
+                            app_data.lock().update_env_by_id(&id, env);
+                        } else {
+                            gui_state
+                                .lock()
+                                .set_fetch_error(Status::Env, AppError::DockerInspect);
+                        }
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                }
+                DockerMessage::Labels(id) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        if let Ok(inspect) = docker.inspect_container(id.get(), None).await {
+                            let labels = inspect
+                                .config
+                                .and_then(|i| i.labels)
+                                .unwrap_or_default()
+                                .into_iter()
+                                .collect();
+                            app_data.lock().update_labels_by_id(&id, labels);
+                        } else {
+                            gui_state
+                                .lock()
+                                .set_fetch_error(Status::Labels, AppError::DockerInspect);
+                        }
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                }
                 DockerMessage::Exec(docker_tx) => {
                     docker_tx.send(Arc::clone(&self.docker)).ok();
                 }
+                DockerMessage::Inspect(id) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let inspect = docker
+                            .inspect_container(id.get(), None)
+                            .await
+                            .ok()
+                            .and_then(|i| serde_json::to_string_pretty(&i).ok());
+                        if let Some(inspect) = inspect {
+                            app_data.lock().update_inspect_by_id(&id, inspect);
+                        } else {
+                            gui_state
+                                .lock()
+                                .set_fetch_error(Status::Inspect, AppError::DockerInspect);
+                        }
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                }
+                DockerMessage::Kill { id, signal } => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        if docker
+                            .kill_container(id.get(), Some(KillContainerOptions { signal }))
+                            .await
+                            .is_err()
+                        {
+                            Self::set_error(&app_data, DockerControls::Kill, &gui_state);
+                        } else {
+                            Self::set_info(&app_data, &id, DockerControls::Kill, &gui_state);
+                        }
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                    self.update_everything().await;
+                }
                 DockerMessage::Pause(id) => {
                     tokio::spawn(async move {
                         let handle = GuiState::start_loading_animation(&gui_state, uuid);
                         if docker.pause_container(id.get()).await.is_err() {
                             Self::set_error(&app_data, DockerControls::Pause, &gui_state);
+                        } else {
+                            Self::set_info(&app_data, &id, DockerControls::Pause, &gui_state);
                         }
                         gui_state.lock().stop_loading_animation(&handle, uuid);
                     });
                     self.update_everything().await;
                 }
+                DockerMessage::Prune {
+                    containers,
+                    images,
+                    volumes,
+                } => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        Self::prune(
+                            &docker,
+                            &app_data,
+                            &gui_state,
+                            (containers, images, volumes),
+                        )
+                        .await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                    self.update_everything().await;
+                }
                 DockerMessage::Restart(id) => {
                     tokio::spawn(async move {
                         let handle = GuiState::start_loading_animation(&gui_state, uuid);
@@ -374,6 +971,36 @@ impl DockerData {
                     });
                     self.update_everything().await;
                 }
+                DockerMessage::PullImage { id, image } => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        Self::pull_image(&docker, &app_data, &gui_state, &id, &image).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                }
+                DockerMessage::Recreate(id) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        Self::recreate(&docker, &app_data, &gui_state, &id).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::RunCommand(id) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        if let Ok(inspect) = docker.inspect_container(id.get(), None).await {
+                            app_data
+                                .lock()
+                                .update_run_command_by_id(&id, run_command::run_command(&inspect));
+                        } else {
+                            gui_state
+                                .lock()
+                                .set_fetch_error(Status::RunCommand, AppError::DockerInspect);
+                        }
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                }
                 DockerMessage::Start(id) => {
                     tokio::spawn(async move {
                         let handle = GuiState::start_loading_animation(&gui_state, uuid);
@@ -398,17 +1025,19 @@ impl DockerData {
                     });
                     self.update_everything().await;
                 }
-                DockerMessage::Resume(id) => {
+                DockerMessage::Unpause(id) => {
                     tokio::spawn(async move {
                         let handle = GuiState::start_loading_animation(&gui_state, uuid);
                         if docker.unpause_container(id.get()).await.is_err() {
-                            Self::set_error(&app_data, DockerControls::Resume, &gui_state);
+                            Self::set_error(&app_data, DockerControls::Unpause, &gui_state);
+                        } else {
+                            Self::set_info(&app_data, &id, DockerControls::Unpause, &gui_state);
                         }
                         gui_state.lock().stop_loading_animation(&handle, uuid);
                     });
                     self.update_everything().await;
                 }
-                DockerMessage::Delete(id) => {
+                DockerMessage::Remove { id, force } => {
                     tokio::spawn(async move {
                         let handle = GuiState::start_loading_animation(&gui_state, uuid);
                         if docker
@@ -416,24 +1045,194 @@ impl DockerData {
                                 id.get(),
                                 Some(RemoveContainerOptions {
                                     v: false,
-                                    force: true,
+                                    force,
                                     link: false,
                                 }),
                             )
                             .await
                             .is_err()
                         {
-                            Self::set_error(&app_data, DockerControls::Stop, &gui_state);
+                            Self::set_error(&app_data, DockerControls::Delete, &gui_state);
+                        } else {
+                            Self::set_info(&app_data, &id, DockerControls::Delete, &gui_state);
+                            app_data.lock().remove_container(&id);
                         }
                         gui_state.lock().stop_loading_animation(&handle, uuid);
                     });
                     self.update_everything().await;
                     self.gui_state.lock().set_delete_container(None);
                 }
+                DockerMessage::Images => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        Self::update_images(&docker, &app_data).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                }
+                DockerMessage::Networks => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        Self::update_networks(&docker, &app_data).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                }
+                DockerMessage::InspectNetwork(id) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        let inspect = docker
+                            .inspect_network(id.get(), None::<InspectNetworkOptions<String>>)
+                            .await
+                            .ok()
+                            .and_then(|i| serde_json::to_string_pretty(&i).ok());
+                        if let Some(inspect) = inspect {
+                            app_data.lock().update_network_inspect(id, inspect);
+                        } else {
+                            gui_state.lock().set_fetch_error(
+                                Status::NetworkInspect,
+                                AppError::DockerInspectNetwork,
+                            );
+                        }
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                }
+                DockerMessage::RemoveNetwork(id) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        if docker.remove_network(id.get()).await.is_err() {
+                            app_data.lock().set_error(
+                                AppError::DockerRemoveNetwork,
+                                &gui_state,
+                                Status::Error,
+                            );
+                        } else {
+                            let name = app_data
+                                .lock()
+                                .get_network_by_id(&id)
+                                .map(|i| i.name.clone());
+                            if let Some(name) = name {
+                                gui_state.lock().set_info_box(&format!("deleted {name}"));
+                            }
+                            app_data.lock().remove_network(&id);
+                        }
+                        Self::update_networks(&docker, &app_data).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                    self.gui_state.lock().set_delete_network(None);
+                }
+                DockerMessage::Volumes => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        Self::update_volumes(&docker, &app_data).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                }
+                DockerMessage::RemoveVolume(name) => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        if docker
+                            .remove_volume(name.get(), Some(RemoveVolumeOptions { force: false }))
+                            .await
+                            .is_err()
+                        {
+                            app_data.lock().set_error(
+                                AppError::DockerRemoveVolume,
+                                &gui_state,
+                                Status::Error,
+                            );
+                        } else {
+                            gui_state
+                                .lock()
+                                .set_info_box(&format!("deleted {}", name.get()));
+                            app_data.lock().remove_volume(&name);
+                        }
+                        Self::update_volumes(&docker, &app_data).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                    self.gui_state.lock().set_delete_volume(None);
+                }
+                DockerMessage::Rename { id, name } => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        match docker
+                            .rename_container(id.get(), RenameContainerOptions { name: &name })
+                            .await
+                        {
+                            Ok(()) => {
+                                gui_state.lock().set_info_box(&format!("renamed to {name}"));
+                                app_data.lock().rename_container(&id, &name);
+                            }
+                            Err(e) => {
+                                // The daemon responds with a 409 if the name is used by another container
+                                let error = match e {
+                                    bollard::errors::Error::DockerResponseServerError {
+                                        status_code: 409,
+                                        ..
+                                    } => AppError::DockerRenameConflict,
+                                    _ => AppError::DockerRename,
+                                };
+                                app_data.lock().set_error(error, &gui_state, Status::Error);
+                            }
+                        }
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                    self.gui_state.lock().set_rename(None);
+                }
+                DockerMessage::RemoveImage { id, force } => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        if docker
+                            .remove_image(
+                                id.get(),
+                                Some(RemoveImageOptions {
+                                    force,
+                                    noprune: false,
+                                }),
+                                None,
+                            )
+                            .await
+                            .is_err()
+                        {
+                            app_data.lock().set_error(
+                                AppError::DockerRemoveImage,
+                                &gui_state,
+                                Status::Error,
+                            );
+                        } else {
+                            let tags = app_data.lock().get_image_by_id(&id).map(|i| i.tags.clone());
+                            if let Some(tags) = tags {
+                                gui_state.lock().set_info_box(&format!("deleted {tags}"));
+                            }
+                            app_data.lock().remove_image(&id);
+                        }
+                        Self::update_images(&docker, &app_data).await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                    self.gui_state.lock().set_delete_image(None);
+                }
                 DockerMessage::ConfirmDelete(id) => {
                     self.gui_state.lock().set_delete_container(Some(id));
                 }
                 DockerMessage::Update => self.update_everything().await,
+                DockerMessage::UpdateLimits {
+                    id,
+                    nano_cpus,
+                    memory,
+                } => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        Self::update_limits(&docker, &app_data, &gui_state, &id, nano_cpus, memory)
+                            .await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                }
+                DockerMessage::UpdateRestartPolicy { id, policy } => {
+                    tokio::spawn(async move {
+                        let handle = GuiState::start_loading_animation(&gui_state, uuid);
+                        Self::update_restart_policy(&docker, &app_data, &gui_state, &id, &policy)
+                            .await;
+                        gui_state.lock().stop_loading_animation(&handle, uuid);
+                    });
+                }
                 DockerMessage::Quit => {
                     self.spawns
                         .lock()
@@ -446,16 +1245,468 @@ impl DockerData {
         }
     }
 
-    /// Send an update message every x ms, where x is the args.docker_interval
-    fn scheduler(args: &CliArgs, docker_tx: Sender<DockerMessage>) {
-        let update_duration = std::time::Duration::from_millis(u64::from(args.docker_interval));
+    /// Prune stopped containers, dangling images, and/or unused anonymous volumes, removing the pruned containers & volumes from app_data.
+    /// A summary of what was removed, and the space reclaimed, is shown in the info box
+    async fn prune(
+        docker: &Docker,
+        app_data: &Arc<Mutex<AppData>>,
+        gui_state: &Arc<Mutex<GuiState>>,
+        (containers, images, volumes): (bool, bool, bool),
+    ) {
+        let mut reclaimed = 0;
+        let mut summary = vec![];
+        if containers {
+            let Ok(response) = docker
+                .prune_containers(None::<PruneContainersOptions<String>>)
+                .await
+            else {
+                app_data
+                    .lock()
+                    .set_error(AppError::DockerPrune, gui_state, Status::Error);
+                return;
+            };
+            let deleted = response.containers_deleted.unwrap_or_default();
+            for id in &deleted {
+                app_data
+                    .lock()
+                    .remove_container(&ContainerId::from(id.as_str()));
+            }
+            reclaimed += response.space_reclaimed.unwrap_or_default();
+            summary.push(format!("{} containers", deleted.len()));
+        }
+        if images {
+            let Ok(response) = docker
+                .prune_images(None::<PruneImagesOptions<String>>)
+                .await
+            else {
+                app_data
+                    .lock()
+                    .set_error(AppError::DockerPrune, gui_state, Status::Error);
+                return;
+            };
+            let deleted = response
+                .images_deleted
+                .unwrap_or_default()
+                .iter()
+                .filter(|i| i.deleted.is_some())
+                .count();
+            reclaimed += response.space_reclaimed.unwrap_or_default();
+            summary.push(format!("{deleted} images"));
+        }
+        if volumes {
+            let Ok(response) = docker
+                .prune_volumes(None::<PruneVolumesOptions<String>>)
+                .await
+            else {
+                app_data
+                    .lock()
+                    .set_error(AppError::DockerPrune, gui_state, Status::Error);
+                return;
+            };
+            let deleted = response.volumes_deleted.unwrap_or_default();
+            for name in &deleted {
+                app_data
+                    .lock()
+                    .remove_volume(&VolumeName::from(name.as_str()));
+            }
+            reclaimed += response.space_reclaimed.unwrap_or_default();
+            summary.push(format!("{} volumes", deleted.len()));
+        }
+        let reclaimed = ByteStats::new(u64::try_from(reclaimed).unwrap_or_default());
+        gui_state.lock().set_info_box(&format!(
+            "pruned {}, reclaimed {reclaimed}",
+            summary.join(" & ")
+        ));
+    }
+
+    /// Pull a tagged image, with the credentials from the Docker config file for its registry, or anonymously if there are none.
+    /// The status of each layer is passed to `progress`, the first error, either from the response or the pull stream, ends the pull
+    async fn pull(
+        docker: &Docker,
+        image: &str,
+        mut progress: impl FnMut(Option<String>, String),
+    ) -> Result<(), AppError> {
+        let credentials = pull::credentials(image).await;
+        let options = CreateImageOptions {
+            from_image: image,
+            ..Default::default()
+        };
+        let mut stream = docker.create_image(Some(options), None, credentials);
+        while let Some(item) = stream.next().await {
+            match item {
+                Ok(info) => {
+                    if let Some(message) = info.error {
+                        return Err(pull::error(None, &message));
+                    }
+                    let status = match (info.status, info.progress) {
+                        (Some(status), Some(progress)) => format!("{status} {progress}"),
+                        (Some(status), None) => status,
+                        (None, _) => continue,
+                    };
+                    progress(info.id, status);
+                }
+                Err(e) => {
+                    return Err(match e {
+                        bollard::errors::Error::DockerResponseServerError {
+                            status_code,
+                            message,
+                        } => pull::error(Some(status_code), &message),
+                        bollard::errors::Error::DockerStreamError { error } => {
+                            pull::error(None, &error)
+                        }
+                        _ => AppError::DockerPull,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Pull an image, with the latest tag if it has none, using the credentials from the Docker config file if there are any for its registry, streaming the progress of each layer into the pull panel.
+    /// If the panel has been closed before the pull finishes, the result is shown in the info box, or as an error, instead
+    async fn pull_image(
+        docker: &Docker,
+        app_data: &Arc<Mutex<AppData>>,
+        gui_state: &Arc<Mutex<GuiState>>,
+        id: &ContainerId,
+        image: &str,
+    ) {
+        let Some(image) = pull::tagged(image) else {
+            if !gui_state
+                .lock()
+                .pull_finished(id, Some(AppError::DockerPullUntagged))
+            {
+                app_data
+                    .lock()
+                    .set_error(AppError::DockerPullUntagged, gui_state, Status::Error);
+            }
+            return;
+        };
+        let error = Self::pull(docker, &image, |layer, status| {
+            gui_state.lock().pull_progress(id, layer, status);
+        })
+        .await
+        .err();
+        if !gui_state.lock().pull_finished(id, error.clone()) {
+            if let Some(error) = error {
+                app_data.lock().set_error(error, gui_state, Status::Error);
+            } else {
+                gui_state.lock().set_info_box(&format!("pulled {image}"));
+            }
+        }
+    }
+
+    /// Send a start, stop, restart, or delete, to each container in turn, the step each container is at is shown in the batch panel.
+    /// A restart is sent as a stop and then a start, so that the two steps can be shown separately.
+    /// A failed container doesn't stop the rest of the batch, once finished the panel is closed if every container succeeded, otherwise it's left open with a summary of the failures
+    async fn batch(
+        docker: &Docker,
+        app_data: &Arc<Mutex<AppData>>,
+        gui_state: &Arc<Mutex<GuiState>>,
+        control: DockerControls,
+        ids: &[ContainerId],
+    ) {
+        let containers = ids
+            .iter()
+            .map(|id| {
+                let name = app_data
+                    .lock()
+                    .get_container_name_by_id(id)
+                    .map_or_else(|| id.get().to_owned(), |i| i.get().to_owned());
+                (id.clone(), name)
+            })
+            .collect();
+        gui_state
+            .lock()
+            .set_batch(Some(Batch::new(control, containers)));
+
+        let step = |id: &ContainerId, step: BatchStep| gui_state.lock().batch_step(id, step);
+        let mut failed = false;
+        for id in ids {
+            let result = match control {
+                DockerControls::Start | DockerControls::Restart => {
+                    let stopped = control != DockerControls::Restart || {
+                        step(id, BatchStep::Stopping);
+                        docker.stop_container(id.get(), None).await.is_ok()
+                    };
+                    if stopped {
+                        step(id, BatchStep::Starting);
+                        docker
+                            .start_container(id.get(), None::<StartContainerOptions<String>>)
+                            .await
+                            .map_err(|_| "start")
+                    } else {
+                        Err("stop")
+                    }
+                }
+                DockerControls::Stop => {
+                    step(id, BatchStep::Stopping);
+                    docker
+                        .stop_container(id.get(), None)
+                        .await
+                        .map_err(|_| "stop")
+                }
+                // Running containers aren't removed, as a batch delete is never forced
+                DockerControls::Delete => {
+                    step(id, BatchStep::Removing);
+                    let removed = docker.remove_container(id.get(), None).await;
+                    if removed.is_ok() {
+                        app_data.lock().remove_container(id);
+                    }
+                    removed.map_err(|_| "remove")
+                }
+                // Not offered by the batch popup, so nothing is sent to Docker, and the container is shown as failed rather than done
+                DockerControls::Pause
+                | DockerControls::Unpause
+                | DockerControls::Kill
+                | DockerControls::Recreate => Err("unsupported"),
+            };
+            failed |= result.is_err();
+            step(
+                id,
+                result.map_or_else(BatchStep::Failed, |()| BatchStep::Done),
+            );
+        }
+
+        let is_open = gui_state.lock().get_batch().is_some();
+        if !failed {
+            let text = format!("{} {} containers", control.past_tense(), ids.len());
+            let mut gui_state = gui_state.lock();
+            gui_state.set_batch(None);
+            gui_state.set_info_box(&text);
+        } else if !is_open {
+            // The panel was closed before the batch finished, so the failure is shown as an error instead
+            Self::set_error(app_data, control, gui_state);
+        }
+    }
+
+    /// Change the cpu and/or memory limits of a container, as `docker update --cpus --memory` would.
+    /// The new memory limit is applied to the container straight away, so the memory gauge doesn't wait for the next stats update
+    #[allow(clippy::cast_precision_loss)]
+    async fn update_limits(
+        docker: &Docker,
+        app_data: &Arc<Mutex<AppData>>,
+        gui_state: &Arc<Mutex<GuiState>>,
+        id: &ContainerId,
+        nano_cpus: Option<i64>,
+        memory: Option<i64>,
+    ) {
+        let options = UpdateContainerOptions::<String> {
+            nano_cp_us: nano_cpus,
+            memory,
+            ..Default::default()
+        };
+        if let Err(e) = docker.update_container(id.get(), options).await {
+            // The daemon responds with an error if a limit is invalid, e.g. the memory limit is below its minimum, or the current usage, so show its reason
+            let error = match e {
+                bollard::errors::Error::DockerResponseServerError { message, .. } => {
+                    AppError::DockerLimitsRejected(message)
+                }
+                _ => AppError::DockerLimits,
+            };
+            app_data.lock().set_error(error, gui_state, Status::Error);
+            return;
+        }
+        let memory = memory.and_then(|i| u64::try_from(i).ok());
+        if let Some(memory) = memory {
+            app_data.lock().update_mem_limit_by_id(id, memory);
+        }
+        let limits = nano_cpus
+            .map(|i| format!("cpus {}", i as f64 / 1_000_000_000.0))
+            .into_iter()
+            .chain(memory.map(|i| format!("memory {}", ByteStats::new(i))))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let name = app_data
+            .lock()
+            .get_container_name_by_id(id)
+            .map_or_else(String::new, |i| format!(" of {}", i.get()));
+        gui_state
+            .lock()
+            .set_info_box(&format!("limits{name} set to {limits}"));
+    }
+
+    /// Change the restart policy of a container, an `on-failure` policy retries without limit.
+    /// The container is then inspected again, so that the shown policy is updated without waiting for the next inspect
+    async fn update_restart_policy(
+        docker: &Docker,
+        app_data: &Arc<Mutex<AppData>>,
+        gui_state: &Arc<Mutex<GuiState>>,
+        id: &ContainerId,
+        policy: &str,
+    ) {
+        let options = UpdateContainerOptions::<String> {
+            restart_policy: Some(RestartPolicy {
+                name: policy.parse::<RestartPolicyNameEnum>().ok(),
+                maximum_retry_count: None,
+            }),
+            ..Default::default()
+        };
+        if docker.update_container(id.get(), options).await.is_err() {
+            app_data
+                .lock()
+                .set_error(AppError::DockerRestartPolicy, gui_state, Status::Error);
+            return;
+        }
+        if let Ok(inspect) = docker.inspect_container(id.get(), None).await {
+            app_data
+                .lock()
+                .update_restarts_by_id(id, Restarts::from(&inspect));
+        }
+        let name = app_data
+            .lock()
+            .get_container_name_by_id(id)
+            .map_or_else(String::new, |i| format!(" of {}", i.get()));
+        gui_state
+            .lock()
+            .set_info_box(&format!("restart policy{name} set to {policy}"));
+    }
+
+    /// Recreate a container, inspect it, pull its image if it isn't present, stop it if running, remove it, and then create & start a new container from the inspect output.
+    /// Each step is shown in the info box, if any step fails the remaining steps are skipped, so a failed pull leaves the container untouched, but a failed create leaves it removed
+    async fn recreate(
+        docker: &Docker,
+        app_data: &Arc<Mutex<AppData>>,
+        gui_state: &Arc<Mutex<GuiState>>,
+        id: &ContainerId,
+    ) {
+        let set_error = |step| {
+            app_data
+                .lock()
+                .set_error(AppError::DockerRecreate(step), gui_state, Status::Error);
+        };
+        let Ok(inspect) = docker.inspect_container(id.get(), None).await else {
+            set_error("inspect");
+            return;
+        };
+        let Some((options, config)) = recreate::create_config(&inspect) else {
+            set_error("inspect");
+            return;
+        };
+        let name = options.name.clone();
+
+        if let Some(image) = config.image.as_deref() {
+            if docker.inspect_image(image).await.is_err() {
+                let Some(tagged) = pull::tagged(image) else {
+                    app_data.lock().set_error(
+                        AppError::DockerPullUntagged,
+                        gui_state,
+                        Status::Error,
+                    );
+                    return;
+                };
+                gui_state
+                    .lock()
+                    .set_info_box(&format!("recreate: pulling {tagged}"));
+                if let Err(e) = Self::pull(docker, &tagged, |_, _| ()).await {
+                    app_data.lock().set_error(e, gui_state, Status::Error);
+                    return;
+                }
+            }
+        }
+
+        if inspect.state.and_then(|i| i.running) == Some(true) {
+            gui_state
+                .lock()
+                .set_info_box(&format!("recreate: stopping {name}"));
+            if docker.stop_container(id.get(), None).await.is_err() {
+                set_error("stop");
+                return;
+            }
+        }
+
+        gui_state
+            .lock()
+            .set_info_box(&format!("recreate: removing {name}"));
+        if docker.remove_container(id.get(), None).await.is_err() {
+            set_error("remove");
+            return;
+        }
+        app_data.lock().remove_container(id);
+
+        gui_state
+            .lock()
+            .set_info_box(&format!("recreate: creating {name}"));
+        let Ok(response) = docker.create_container(Some(options), config).await else {
+            set_error("create");
+            return;
+        };
+
+        gui_state
+            .lock()
+            .set_info_box(&format!("recreate: starting {name}"));
+        if docker
+            .start_container(&response.id, None::<StartContainerOptions<String>>)
+            .await
+            .is_err()
+        {
+            set_error("start");
+            return;
+        }
+        gui_state
+            .lock()
+            .set_info_box(&format!("{} {name}", DockerControls::Recreate.past_tense()));
+    }
+
+    /// Send an update message every x ms, where x is the docker interval, which can be changed at runtime.
+    /// The interval is re-read at least every 100ms, so a shorter interval is applied without waiting for a long one to finish
+    fn scheduler(app_data: &Arc<Mutex<AppData>>, docker_tx: Sender<DockerMessage>) {
+        let app_data = Arc::clone(app_data);
         let mut now = std::time::Instant::now();
         tokio::spawn(async move {
             loop {
+                let update_duration = std::time::Duration::from_millis(u64::from(
+                    app_data.lock().get_docker_interval(),
+                ));
                 let to_sleep = update_duration.saturating_sub(now.elapsed());
-                tokio::time::sleep(to_sleep).await;
-                docker_tx.send(DockerMessage::Update).await.ok();
-                now = std::time::Instant::now();
+                if to_sleep.is_zero() {
+                    docker_tx.send(DockerMessage::Update).await.ok();
+                    now = std::time::Instant::now();
+                } else {
+                    tokio::time::sleep(to_sleep.min(SCHEDULER_POLL)).await;
+                }
+            }
+        });
+    }
+
+    /// Subscribe to the docker container events, and send an update message as soon as a container is created, started, stopped, or removed, rather than waiting for the next scheduled update.
+    /// The scheduled updates remain, in order to reconcile anything missed. If the events stream drops, it is re-created with an exponential backoff, using a new connection, until oxker is closed
+    fn events(
+        connection: Connection,
+        docker_tx: Sender<DockerMessage>,
+        is_running: Arc<AtomicBool>,
+    ) {
+        tokio::spawn(async move {
+            let mut delay = RECONNECT_MIN;
+            while is_running.load(std::sync::atomic::Ordering::SeqCst) {
+                if let Ok(docker) = connection.connect() {
+                    // Fused, as the stream can end whilst waiting for a burst to end
+                    let mut stream = docker
+                        .events(Some(EventsOptions::<String> {
+                            filters: HashMap::from([
+                                ("type".to_owned(), vec!["container".to_owned()]),
+                                (
+                                    "event".to_owned(),
+                                    CONTAINER_EVENTS.iter().map(|i| (*i).to_owned()).collect(),
+                                ),
+                            ]),
+                            ..Default::default()
+                        }))
+                        .fuse();
+                    while let Some(Ok(_)) = stream.next().await {
+                        delay = RECONNECT_MIN;
+                        // Wait for the burst to end, or for the stream to drop
+                        while let Ok(Some(Ok(_))) =
+                            tokio::time::timeout(EVENT_DEBOUNCE, stream.next()).await
+                        {
+                        }
+                        if docker_tx.send(DockerMessage::Update).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(RECONNECT_MAX);
             }
         });
     }
@@ -463,6 +1714,7 @@ impl DockerData {
     /// Initialise self, and start the message receiving loop
     pub async fn init(
         app_data: Arc<Mutex<AppData>>,
+        connection: Connection,
         docker: Docker,
         docker_rx: Receiver<DockerMessage>,
         docker_tx: Sender<DockerMessage>,
@@ -474,7 +1726,8 @@ impl DockerData {
             let mut inner = Self {
                 app_data,
                 args: args.clone(),
-                binate: Binate::One,
+                attach_failed: Arc::new(Mutex::new(HashSet::new())),
+                connection,
                 docker: Arc::new(docker),
                 gui_state,
                 init: Some(Arc::new(AtomicUsize::new(0))),
@@ -483,7 +1736,12 @@ impl DockerData {
                 spawns: Arc::new(Mutex::new(HashMap::new())),
             };
             inner.initialise_container_data().await;
-            Self::scheduler(&args, docker_tx);
+            Self::events(
+                inner.connection.clone(),
+                docker_tx.clone(),
+                Arc::clone(&inner.is_running),
+            );
+            Self::scheduler(&inner.app_data, docker_tx);
             inner.message_handler().await;
         }
     }
@@ -491,12 +1749,17 @@ impl DockerData {
 
 // tests, use redis-test container, check logs exists, and selector of logs, and that it increases, and matches end, when you run restart on the docker containers
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use bollard::container::{
         BlkioStats, CPUStats, CPUUsage, MemoryStats, PidsStats, StorageStats, ThrottlingData,
     };
 
     use super::*;
+    use crate::{
+        app_data::PingHealth,
+        tests::{gen_appdata, gen_args, gen_containers},
+    };
 
     #[allow(clippy::too_many_lines)]
     fn gen_stats(x: u64, y: u64) -> Stats {
@@ -615,20 +1878,69 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    /// A single failed ping skips the update, rather than clearing the containers with an empty list
+    async fn test_update_everything_failed_ping() {
+        let (_, containers) = gen_containers();
+        let app_data = Arc::new(Mutex::new(gen_appdata(&containers)));
+        let connection = Connection::Socket("/nonexistent/docker.sock".to_owned());
+        let (_docker_tx, docker_rx) = tokio::sync::mpsc::channel(1);
+        let mut docker_data = DockerData {
+            app_data: Arc::clone(&app_data),
+            args: gen_args(),
+            attach_failed: Arc::new(Mutex::new(HashSet::new())),
+            docker: Arc::new(connection.connect().unwrap()),
+            connection,
+            gui_state: Arc::new(Mutex::new(GuiState::default())),
+            init: None,
+            is_running: Arc::new(AtomicBool::new(true)),
+            receiver: docker_rx,
+            spawns: Arc::new(Mutex::new(HashMap::new())),
+        };
+
+        docker_data.update_everything().await;
+        assert_eq!(
+            app_data.lock().get_ping().health(),
+            Some(PingHealth::Failing)
+        );
+        assert_eq!(app_data.lock().get_container_len(), 3);
+    }
+
     #[test]
     #[allow(clippy::float_cmp)]
     /// Test the stats calculator, had to cheat here to get round input/outputs
     fn test_calculate_usage_no_previous_cpu() {
         let stats = gen_stats(1_000_000_000, 900_000_000);
-        let result = DockerData::calculate_usage(&stats);
+        let result = DockerData::calculate_usage(&stats, Engine::Docker);
         assert_eq!(result, 50.0);
 
         let stats = gen_stats(1_000_000_000, 800_000_000);
-        let result = DockerData::calculate_usage(&stats);
+        let result = DockerData::calculate_usage(&stats, Engine::Docker);
         assert_eq!(result, 25.0);
 
         let stats = gen_stats(1_000_000_000, 750_000_000);
-        let result = DockerData::calculate_usage(&stats);
+        let result = DockerData::calculate_usage(&stats, Engine::Docker);
         assert_eq!(result, 20.00);
     }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    /// Without the system cpu usage, Podman's usage is calculated against the time between the readings, rather than left at zero
+    fn test_calculate_usage_podman() {
+        let mut stats = gen_stats(1_000_000_000, 900_000_000);
+        assert_eq!(DockerData::calculate_usage(&stats, Engine::Podman), 50.0);
+
+        stats.cpu_stats.system_cpu_usage = None;
+        stats.precpu_stats.system_cpu_usage = None;
+        assert_eq!(DockerData::calculate_usage(&stats, Engine::Docker), 0.0);
+        assert_eq!(DockerData::calculate_usage(&stats, Engine::Podman), 0.0);
+
+        // 50_000_000ns of cpu time over 0.5s
+        stats.read = "2024-02-29T12:34:56.5Z".to_owned();
+        stats.preread = "2024-02-29T12:34:56Z".to_owned();
+        assert_eq!(DockerData::calculate_usage(&stats, Engine::Podman), 10.0);
+
+        stats.preread = "0001-01-01T00:00:00Z".to_owned();
+        assert_eq!(DockerData::calculate_usage(&stats, Engine::Podman), 0.0);
+    }
 }
@@ -0,0 +1,326 @@
+use std::{collections::HashMap, process::Stdio, time::Duration};
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use bollard::auth::DockerCredentials;
+use serde::Deserialize;
+use tokio::{io::AsyncWriteExt, process::Command};
+
+use crate::{app_error::AppError, docker_context};
+
+/// The key that the Docker cli uses for Docker Hub, in both the `auths` & `credHelpers` maps, and as the server url passed to a credential helper
+const DOCKER_HUB: &str = "https://index.docker.io/v1/";
+/// Hostnames that all refer to Docker Hub
+const DOCKER_HUB_HOSTS: [&str; 3] = ["index.docker.io", "docker.io", "registry-1.docker.io"];
+/// Returned as the username by a credential helper when the secret is an identity token, rather than a password
+const TOKEN_USERNAME: &str = "<token>";
+/// How long a credential helper has to respond, it can block, e.g. on a locked keychain, in which case the `auths` map is used instead
+const HELPER_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A single entry of the `auths` map, the `auth` is the base64 of `username:password`
+#[derive(Debug, Default, Deserialize)]
+struct AuthEntry {
+    auth: Option<String>,
+    identitytoken: Option<String>,
+}
+
+/// The parts of `$DOCKER_CONFIG/config.json`, or `~/.docker/config.json`, used to authenticate a pull
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DockerConfig {
+    #[serde(default)]
+    auths: HashMap<String, AuthEntry>,
+    #[serde(default)]
+    cred_helpers: HashMap<String, String>,
+    creds_store: Option<String>,
+}
+
+/// The output of `docker-credential-<helper> get`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct HelperOutput {
+    username: String,
+    secret: String,
+}
+
+/// The registry hostname of an image, None for Docker Hub.
+/// As with the Docker cli, the first part of the name is only a registry if it contains a `.` or a `:`, or is `localhost`
+pub fn registry(image: &str) -> Option<&str> {
+    let (first, _) = image.split_once('/')?;
+    let is_registry = first.contains(['.', ':']) || first == "localhost";
+    (is_registry && !DOCKER_HUB_HOSTS.contains(&first)).then_some(first)
+}
+
+/// Add the `latest` tag to an image without a tag or digest, else Docker would pull every tag.
+/// None if the image is only an id, e.g. when the tag the container was created from has since been removed, as there is nothing to pull
+pub fn tagged(image: &str) -> Option<String> {
+    let image = image.trim();
+    let is_id = image.starts_with("sha256:")
+        || (image.len() == 64 && image.chars().all(|c| c.is_ascii_hexdigit()));
+    if image.is_empty() || is_id {
+        return None;
+    }
+    let name = image.rsplit('/').next().unwrap_or(image);
+    if name.contains([':', '@']) {
+        Some(image.to_owned())
+    } else {
+        Some(format!("{image}:latest"))
+    }
+}
+
+/// Reduce an `auths` key, which can be a full url, e.g. `https://registry.example.com/v1/`, to just its hostname
+fn hostname(key: &str) -> &str {
+    let key = key
+        .strip_prefix("https://")
+        .or_else(|| key.strip_prefix("http://"))
+        .unwrap_or(key);
+    key.split('/').next().unwrap_or(key)
+}
+
+impl DockerConfig {
+    /// Check if a key of the `auths` or `credHelpers` maps is for the given registry, None being Docker Hub
+    fn matches(key: &str, registry: Option<&str>) -> bool {
+        let host = hostname(key);
+        registry.map_or_else(|| DOCKER_HUB_HOSTS.contains(&host), |i| i == host)
+    }
+
+    /// The credential helper for a registry, a registry specific helper takes priority over the credsStore
+    fn helper(&self, registry: Option<&str>) -> Option<&str> {
+        self.cred_helpers
+            .iter()
+            .find(|(key, _)| Self::matches(key, registry))
+            .map(|(_, helper)| helper.as_str())
+            .or(self.creds_store.as_deref())
+            .filter(|i| !i.is_empty())
+    }
+
+    /// Credentials stored directly in the `auths` map, None if there is no matching entry, or it can't be decoded
+    fn auth(&self, registry: Option<&str>) -> Option<DockerCredentials> {
+        let (key, entry) = self
+            .auths
+            .iter()
+            .find(|(key, _)| Self::matches(key, registry))?;
+        let serveraddress = Some(key.clone());
+        if let Some(token) = entry.identitytoken.as_ref().filter(|i| !i.is_empty()) {
+            return Some(DockerCredentials {
+                identitytoken: Some(token.clone()),
+                serveraddress,
+                ..Default::default()
+            });
+        }
+        let decoded = STANDARD.decode(entry.auth.as_ref()?.trim()).ok()?;
+        let decoded = String::from_utf8(decoded).ok()?;
+        let (username, password) = decoded.split_once(':')?;
+        Some(DockerCredentials {
+            username: Some(username.to_owned()),
+            password: Some(password.to_owned()),
+            serveraddress,
+            ..Default::default()
+        })
+    }
+}
+
+/// Ask a credential helper, e.g. `docker-credential-desktop`, for the credentials of a registry, the server url is written to its stdin.
+/// None if the helper fails, or doesn't respond within HELPER_TIMEOUT, the helper is killed when dropped
+async fn from_helper(helper: &str, registry: Option<&str>) -> Option<DockerCredentials> {
+    let server = registry.unwrap_or(DOCKER_HUB);
+    let mut child = Command::new(format!("docker-credential-{helper}"))
+        .arg("get")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn()
+        .ok()?;
+    // stdin is dropped at the end of this block, closing it, so that the helper knows the server url is complete
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(server.as_bytes()).await.ok()?;
+    }
+    let output = tokio::time::timeout(HELPER_TIMEOUT, child.wait_with_output())
+        .await
+        .ok()?
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let output = serde_json::from_slice::<HelperOutput>(&output.stdout).ok()?;
+    let serveraddress = Some(server.to_owned());
+    if output.username == TOKEN_USERNAME {
+        Some(DockerCredentials {
+            identitytoken: Some(output.secret),
+            serveraddress,
+            ..Default::default()
+        })
+    } else {
+        Some(DockerCredentials {
+            username: Some(output.username),
+            password: Some(output.secret),
+            serveraddress,
+            ..Default::default()
+        })
+    }
+}
+
+/// Find the credentials for the registry of an image, in the same way as the Docker cli, via a credential helper if one is configured, else the `auths` map.
+/// None if the config file doesn't exist, or has no credentials for the registry, in which case the image is pulled anonymously
+pub async fn credentials(image: &str) -> Option<DockerCredentials> {
+    let path = docker_context::config_dir()?.join("config.json");
+    let content = tokio::fs::read_to_string(path).await.ok()?;
+    let config = serde_json::from_str::<DockerConfig>(&content).ok()?;
+    let registry = registry(image);
+    if let Some(helper) = config.helper(registry) {
+        if let Some(credentials) = from_helper(helper, registry).await {
+            return Some(credentials);
+        }
+    }
+    config.auth(registry)
+}
+
+/// Classify a failed pull from the response status code, or the error message in the pull stream, which has no status code.
+/// The message is checked first, as Docker responds with a 404 when access to a private image is denied, and a 500 when the registry can't be reached
+pub fn error(status_code: Option<u16>, message: &str) -> AppError {
+    let message = message.to_lowercase();
+    let contains = |words: &[&str]| words.iter().any(|i| message.contains(i));
+    if matches!(status_code, Some(401 | 403))
+        || contains(&["unauthorized", "access denied", "authentication required"])
+    {
+        AppError::DockerPullAuth
+    } else if contains(&[
+        "no such host",
+        "i/o timeout",
+        "connection refused",
+        "network is unreachable",
+        "tls handshake timeout",
+        "request canceled while waiting for connection",
+    ]) {
+        AppError::DockerPullNetwork
+    } else if status_code == Some(404) || contains(&["not found", "manifest unknown"]) {
+        AppError::DockerPullNotFound
+    } else {
+        AppError::DockerPull
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::app_error::AppError;
+
+    use super::{error, registry, tagged, DockerConfig};
+
+    #[test]
+    /// Auth & not found errors are found from either the status code, or the stream error message
+    fn test_pull_error() {
+        assert!(matches!(error(Some(404), ""), AppError::DockerPullNotFound));
+        assert!(matches!(error(Some(401), ""), AppError::DockerPullAuth));
+        assert!(matches!(
+            error(None, "manifest for nginx:nope not found: manifest unknown"),
+            AppError::DockerPullNotFound
+        ));
+        assert!(matches!(
+            error(None, "pull access denied for private/app"),
+            AppError::DockerPullAuth
+        ));
+        assert!(matches!(
+            error(Some(500), "connection reset"),
+            AppError::DockerPull
+        ));
+        // A daemon side error, e.g. writing a layer, isn't an auth failure
+        assert!(matches!(
+            error(
+                None,
+                "failed to register layer: open /var/lib/docker/overlay2/l: permission denied"
+            ),
+            AppError::DockerPull
+        ));
+        // A private image that the credentials don't give access to is a 404, but isn't reported as not found
+        assert!(matches!(
+            error(
+                Some(404),
+                "pull access denied for private/app, repository does not exist or may require 'docker login'"
+            ),
+            AppError::DockerPullAuth
+        ));
+        assert!(matches!(
+            error(
+                Some(500),
+                "Get \"https://registry.example.com/v2/\": dial tcp: lookup registry.example.com: no such host"
+            ),
+            AppError::DockerPullNetwork
+        ));
+        assert!(matches!(
+            error(None, "net/http: TLS handshake timeout"),
+            AppError::DockerPullNetwork
+        ));
+    }
+
+    #[test]
+    /// Only a first part containing a `.` or `:`, or `localhost`, is a registry, Docker Hub hostnames are treated as no registry
+    fn test_pull_registry() {
+        assert_eq!(registry("nginx:latest"), None);
+        assert_eq!(registry("library/nginx"), None);
+        assert_eq!(registry("docker.io/library/nginx"), None);
+        assert_eq!(
+            registry("ghcr.io/mrjackwills/oxker:latest"),
+            Some("ghcr.io")
+        );
+        assert_eq!(registry("localhost:5000/app"), Some("localhost:5000"));
+        assert_eq!(registry("localhost/app"), Some("localhost"));
+    }
+
+    #[test]
+    /// The latest tag is only added when there isn't a tag or digest, and an image id can't be pulled
+    fn test_pull_tagged() {
+        assert_eq!(tagged("nginx"), Some(String::from("nginx:latest")));
+        assert_eq!(tagged("nginx:1.25"), Some(String::from("nginx:1.25")));
+        assert_eq!(
+            tagged("localhost:5000/app"),
+            Some(String::from("localhost:5000/app:latest"))
+        );
+        assert_eq!(
+            tagged("nginx@sha256:abc"),
+            Some(String::from("nginx@sha256:abc"))
+        );
+        assert_eq!(tagged("sha256:abc"), None);
+        assert_eq!(tagged(&"a".repeat(64)), None);
+        assert_eq!(tagged(""), None);
+    }
+
+    #[test]
+    /// Credentials are decoded from the auths map, matched by hostname, and a credential helper takes priority
+    fn test_pull_config() {
+        // "user:pass"
+        let config = serde_json::from_str::<DockerConfig>(
+            r#"{
+                "auths": {
+                    "https://index.docker.io/v1/": {"auth": "dXNlcjpwYXNz"},
+                    "ghcr.io": {"identitytoken": "token"}
+                },
+                "credHelpers": {"registry.example.com": "ecr-login"}
+            }"#,
+        )
+        .unwrap();
+
+        let hub = config.auth(None).unwrap();
+        assert_eq!(hub.username, Some(String::from("user")));
+        assert_eq!(hub.password, Some(String::from("pass")));
+        assert_eq!(
+            hub.serveraddress,
+            Some(String::from("https://index.docker.io/v1/"))
+        );
+
+        let ghcr = config.auth(Some("ghcr.io")).unwrap();
+        assert_eq!(ghcr.identitytoken, Some(String::from("token")));
+        assert!(ghcr.username.is_none());
+
+        assert!(config.auth(Some("quay.io")).is_none());
+        assert_eq!(
+            config.helper(Some("registry.example.com")),
+            Some("ecr-login")
+        );
+        assert_eq!(config.helper(None), None);
+
+        let config = serde_json::from_str::<DockerConfig>(r#"{"credsStore": "desktop"}"#).unwrap();
+        assert_eq!(config.helper(None), Some("desktop"));
+        assert!(config.auth(None).is_none());
+    }
+}
@@ -0,0 +1,126 @@
+use bollard::{
+    container::{Config, CreateContainerOptions},
+    service::{ContainerInspectResponse, HostConfig},
+};
+
+/// Rebuild the options needed to create a copy of a container from its inspect output, `None` if the name or image is missing.
+/// Only the name, image, env, exposed & published ports, binds, mounts, network, restart policy, and auto remove setting are kept.
+/// Everything else, such as the command, entrypoint, labels, healthcheck, and resource limits, falls back to the defaults of the image, or docker
+pub fn create_config(
+    inspect: &ContainerInspectResponse,
+) -> Option<(CreateContainerOptions<String>, Config<String>)> {
+    let name = inspect
+        .name
+        .as_ref()
+        .map(|i| i.trim_start_matches('/').to_owned())
+        .filter(|i| !i.is_empty())?;
+    let config = inspect.config.clone().unwrap_or_default();
+    let image = config.image.or_else(|| inspect.image.clone())?;
+    let host_config = inspect.host_config.clone().unwrap_or_default();
+
+    Some((
+        CreateContainerOptions {
+            name,
+            platform: None,
+        },
+        Config {
+            image: Some(image),
+            env: config.env,
+            exposed_ports: config.exposed_ports,
+            host_config: Some(HostConfig {
+                auto_remove: host_config.auto_remove,
+                binds: host_config.binds,
+                mounts: host_config.mounts,
+                network_mode: host_config.network_mode,
+                port_bindings: host_config.port_bindings,
+                restart_policy: host_config.restart_policy,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    ))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{
+        ContainerConfig, ContainerInspectResponse, HostConfig, PortBinding, RestartPolicy,
+        RestartPolicyNameEnum,
+    };
+
+    use super::create_config;
+
+    #[test]
+    /// Without a name, or an image, a container can't be recreated
+    fn test_recreate_missing() {
+        let inspect = ContainerInspectResponse {
+            config: Some(ContainerConfig {
+                image: Some(String::from("image_1")),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(create_config(&inspect).is_none());
+
+        let inspect = ContainerInspectResponse {
+            name: Some(String::from("/container_1")),
+            ..Default::default()
+        };
+        assert!(create_config(&inspect).is_none());
+    }
+
+    #[test]
+    /// The name, image, env, ports, binds, network, and restart policy are copied, anything else isn't
+    fn test_recreate_config() {
+        let port_bindings = HashMap::from([(
+            String::from("80/tcp"),
+            Some(vec![PortBinding {
+                host_ip: Some(String::from("0.0.0.0")),
+                host_port: Some(String::from("8080")),
+            }]),
+        )]);
+        let inspect = ContainerInspectResponse {
+            name: Some(String::from("/container_1")),
+            config: Some(ContainerConfig {
+                image: Some(String::from("image_1")),
+                env: Some(vec![String::from("KEY=value")]),
+                cmd: Some(vec![String::from("sleep")]),
+                ..Default::default()
+            }),
+            host_config: Some(HostConfig {
+                binds: Some(vec![String::from("/host:/container:ro")]),
+                network_mode: Some(String::from("network_1")),
+                port_bindings: Some(port_bindings.clone()),
+                restart_policy: Some(RestartPolicy {
+                    name: Some(RestartPolicyNameEnum::ALWAYS),
+                    maximum_retry_count: None,
+                }),
+                memory: Some(1024),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let (options, config) = create_config(&inspect).unwrap();
+        assert_eq!(options.name, "container_1");
+        assert_eq!(config.image, Some(String::from("image_1")));
+        assert_eq!(config.env, Some(vec![String::from("KEY=value")]));
+        assert!(config.cmd.is_none());
+
+        let host_config = config.host_config.unwrap();
+        assert_eq!(
+            host_config.binds,
+            Some(vec![String::from("/host:/container:ro")])
+        );
+        assert_eq!(host_config.network_mode, Some(String::from("network_1")));
+        assert_eq!(host_config.port_bindings, Some(port_bindings));
+        assert_eq!(
+            host_config.restart_policy.and_then(|i| i.name),
+            Some(RestartPolicyNameEnum::ALWAYS)
+        );
+        assert!(host_config.memory.is_none());
+    }
+}
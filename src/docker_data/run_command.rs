@@ -0,0 +1,264 @@
+use bollard::service::{ContainerInspectResponse, MountPointTypeEnum};
+
+/// First line of the generated command, as a comment so that the copied text can still be pasted into a shell
+const BEST_EFFORT: &str =
+    "# best-effort, omits the command, entrypoint, labels, healthcheck, resource limits, and any other options";
+
+/// Network modes that don't need to be passed to `docker run`
+const DEFAULT_NETWORKS: [&str; 2] = ["default", "bridge"];
+
+/// Quote an argument for a POSIX shell, only if it contains any chars that would need to be escaped
+fn quote(arg: &str) -> String {
+    let safe = |c: char| c.is_ascii_alphanumeric() || "-_./:=@,+%".contains(c);
+    if !arg.is_empty() && arg.chars().all(safe) {
+        arg.to_owned()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
+
+/// Reconstruct an approximate `docker run` command from the inspect output of a container, one option per line.
+/// Only the name, restart policy, network, published ports, mounts, env, and image are included, as they can be reliably read back.
+/// The env includes any variables set by the image itself, as the two can't be told apart
+pub fn run_command(inspect: &ContainerInspectResponse) -> String {
+    let mut args = vec![];
+    let host_config = inspect.host_config.clone().unwrap_or_default();
+    let config = inspect.config.clone().unwrap_or_default();
+
+    if let Some(name) = inspect.name.as_ref() {
+        args.push(format!("--name {}", quote(name.trim_start_matches('/'))));
+    }
+    if host_config.auto_remove == Some(true) {
+        args.push(String::from("--rm"));
+    }
+    if let Some(policy) = host_config.restart_policy {
+        let name = policy.name.map(|i| i.to_string()).unwrap_or_default();
+        match (name.as_str(), policy.maximum_retry_count) {
+            ("" | "no", _) => (),
+            ("on-failure", Some(count)) if count > 0 => {
+                args.push(format!("--restart on-failure:{count}"));
+            }
+            (name, _) => args.push(format!("--restart {name}")),
+        }
+    }
+    if let Some(network) = host_config
+        .network_mode
+        .filter(|i| !i.is_empty() && !DEFAULT_NETWORKS.contains(&i.as_str()))
+    {
+        args.push(format!("--network {}", quote(&network)));
+    }
+
+    let mut ports = host_config
+        .port_bindings
+        .unwrap_or_default()
+        .into_iter()
+        .flat_map(|(container_port, bindings)| {
+            let container_port = container_port
+                .strip_suffix("/tcp")
+                .map_or_else(|| container_port.clone(), ToOwned::to_owned);
+            bindings
+                .unwrap_or_default()
+                .into_iter()
+                .map(move |binding| {
+                    let host_ip = binding
+                        .host_ip
+                        .filter(|i| !i.is_empty() && i != "0.0.0.0" && i != "::");
+                    let host_port = binding.host_port.filter(|i| !i.is_empty());
+                    match (host_ip, host_port) {
+                        (Some(ip), Some(port)) => format!("{ip}:{port}:{container_port}"),
+                        (Some(ip), None) => format!("{ip}::{container_port}"),
+                        (None, Some(port)) => format!("{port}:{container_port}"),
+                        (None, None) => container_port.clone(),
+                    }
+                })
+        })
+        .collect::<Vec<_>>();
+    ports.sort();
+    ports.dedup();
+    args.extend(ports.into_iter().map(|i| format!("-p {}", quote(&i))));
+
+    for mount in inspect.mounts.clone().unwrap_or_default() {
+        let Some(destination) = mount.destination else {
+            continue;
+        };
+        let read_only = if mount.rw == Some(false) { ":ro" } else { "" };
+        let source = match mount.typ {
+            Some(MountPointTypeEnum::BIND) => mount.source,
+            Some(MountPointTypeEnum::VOLUME) => mount.name,
+            Some(MountPointTypeEnum::TMPFS) => {
+                args.push(format!("--tmpfs {}", quote(&destination)));
+                continue;
+            }
+            _ => None,
+        };
+        if let Some(source) = source {
+            args.push(format!(
+                "-v {}",
+                quote(&format!("{source}:{destination}{read_only}"))
+            ));
+        }
+    }
+
+    args.extend(
+        config
+            .env
+            .unwrap_or_default()
+            .iter()
+            .map(|i| format!("-e {}", quote(i))),
+    );
+
+    if let Some(image) = config.image.or_else(|| inspect.image.clone()) {
+        args.push(quote(&image));
+    }
+
+    let mut output = format!("{BEST_EFFORT}\ndocker run -d");
+    for arg in args {
+        output.push_str(" \\\n  ");
+        output.push_str(&arg);
+    }
+    output
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{
+        ContainerConfig, ContainerInspectResponse, HostConfig, MountPoint, MountPointTypeEnum,
+        PortBinding, RestartPolicy, RestartPolicyNameEnum,
+    };
+
+    use super::{quote, run_command, BEST_EFFORT};
+
+    #[test]
+    /// Args are only quoted when needed, and single quotes are escaped
+    fn test_run_command_quote() {
+        assert_eq!(quote("nginx:latest"), "nginx:latest");
+        assert_eq!(quote("KEY=value"), "KEY=value");
+        assert_eq!(quote("KEY=two words"), "'KEY=two words'");
+        assert_eq!(quote("it's"), r"'it'\''s'");
+        assert_eq!(quote(""), "''");
+    }
+
+    #[test]
+    /// A container with only an image produces a minimal command
+    fn test_run_command_minimal() {
+        let inspect = ContainerInspectResponse {
+            name: Some(String::from("/container_1")),
+            config: Some(ContainerConfig {
+                image: Some(String::from("image_1")),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            run_command(&inspect),
+            format!("{BEST_EFFORT}\ndocker run -d \\\n  --name container_1 \\\n  image_1")
+        );
+    }
+
+    #[test]
+    /// Name, restart policy, network, ports, mounts, and env are all reconstructed, default values are omitted
+    fn test_run_command_full() {
+        let inspect = ContainerInspectResponse {
+            name: Some(String::from("/container_1")),
+            image: Some(String::from("sha256:abc")),
+            host_config: Some(HostConfig {
+                auto_remove: Some(false),
+                restart_policy: Some(RestartPolicy {
+                    name: Some(RestartPolicyNameEnum::ON_FAILURE),
+                    maximum_retry_count: Some(3),
+                }),
+                network_mode: Some(String::from("my_network")),
+                port_bindings: Some(HashMap::from([
+                    (
+                        String::from("80/tcp"),
+                        Some(vec![PortBinding {
+                            host_ip: Some(String::from("0.0.0.0")),
+                            host_port: Some(String::from("8080")),
+                        }]),
+                    ),
+                    (
+                        String::from("53/udp"),
+                        Some(vec![PortBinding {
+                            host_ip: Some(String::from("127.0.0.1")),
+                            host_port: Some(String::from("5353")),
+                        }]),
+                    ),
+                    (String::from("443/tcp"), None),
+                ])),
+                ..Default::default()
+            }),
+            mounts: Some(vec![
+                MountPoint {
+                    typ: Some(MountPointTypeEnum::BIND),
+                    source: Some(String::from("/home/user/data")),
+                    destination: Some(String::from("/data")),
+                    rw: Some(false),
+                    ..Default::default()
+                },
+                MountPoint {
+                    typ: Some(MountPointTypeEnum::VOLUME),
+                    name: Some(String::from("volume_1")),
+                    source: Some(String::from("/var/lib/docker/volumes/volume_1/_data")),
+                    destination: Some(String::from("/var/lib/db")),
+                    rw: Some(true),
+                    ..Default::default()
+                },
+                MountPoint {
+                    typ: Some(MountPointTypeEnum::TMPFS),
+                    destination: Some(String::from("/tmp")),
+                    ..Default::default()
+                },
+            ]),
+            config: Some(ContainerConfig {
+                image: Some(String::from("image_1:latest")),
+                env: Some(vec![
+                    String::from("PATH=/usr/bin"),
+                    String::from("GREETING=hello world"),
+                ]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let expected = [
+            BEST_EFFORT,
+            "docker run -d \\",
+            "  --name container_1 \\",
+            "  --restart on-failure:3 \\",
+            "  --network my_network \\",
+            "  -p 127.0.0.1:5353:53/udp \\",
+            "  -p 8080:80 \\",
+            "  -v /home/user/data:/data:ro \\",
+            "  -v volume_1:/var/lib/db \\",
+            "  --tmpfs /tmp \\",
+            "  -e PATH=/usr/bin \\",
+            "  -e 'GREETING=hello world' \\",
+            "  image_1:latest",
+        ];
+        assert_eq!(run_command(&inspect), expected.join("\n"));
+    }
+
+    #[test]
+    /// The bridge network and a "no" restart policy are omitted, and the image id is used if the config has no image
+    fn test_run_command_defaults() {
+        let inspect = ContainerInspectResponse {
+            image: Some(String::from("sha256:abc")),
+            host_config: Some(HostConfig {
+                auto_remove: Some(true),
+                restart_policy: Some(RestartPolicy {
+                    name: Some(RestartPolicyNameEnum::NO),
+                    maximum_retry_count: Some(0),
+                }),
+                network_mode: Some(String::from("bridge")),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            run_command(&inspect),
+            format!("{BEST_EFFORT}\ndocker run -d \\\n  --rm \\\n  sha256:abc")
+        );
+    }
+}
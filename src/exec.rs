@@ -40,6 +40,7 @@ mod command {
     pub const DOCKER: &str = "docker";
     pub const EXEC: &str = "exec";
     pub const SH: &str = "sh";
+    pub const C: &str = "-c";
     pub const IT: &str = "-it";
 }
 
@@ -141,74 +142,103 @@ impl TerminalSize {
     }
 }
 
+/// The shells to try, in order, the user supplied shells, without any repeats, and then `sh` as a fallback
+fn shell_candidates(shells: Vec<String>) -> Vec<String> {
+    let mut output = vec![];
+    for shell in shells.into_iter().chain([command::SH.to_owned()]) {
+        if !output.contains(&shell) {
+            output.push(shell);
+        }
+    }
+    output
+}
+
 #[derive(Debug, Clone)]
 pub enum ExecMode {
-    // use Bollard Rust library
-    Internal((ContainerId, Arc<Docker>)),
-    // use the external `docker-cli`
-    External(ContainerId),
+    // use Bollard Rust library, with the shell to execute
+    Internal((ContainerId, Arc<Docker>, String)),
+    // use the external `docker-cli`, with the shell to execute
+    External((ContainerId, String)),
 }
 
 impl ExecMode {
+    /// Check if the shell exists in the container, using the Bollard library, by executing `pwd` via the shell
+    async fn shell_valid_internal(docker: &Arc<Docker>, id: &ContainerId, shell: &str) -> bool {
+        if let Ok(exec) = docker
+            .create_exec(
+                id.get(),
+                CreateExecOptions {
+                    attach_stdout: Some(true),
+                    attach_stderr: Some(true),
+                    cmd: Some(vec![shell, command::C, command::PWD]),
+                    ..Default::default()
+                },
+            )
+            .await
+        {
+            if let Ok(StartExecResults::Attached { mut output, .. }) =
+                docker.start_exec(&exec.id, None).await
+            {
+                if let Some(Ok(msg)) = output.next().await {
+                    return !msg.to_string().starts_with(OCI_ERROR);
+                }
+            }
+        }
+        false
+    }
+
+    /// Check if the shell exists in the container, using the external docker cli
+    fn shell_valid_external(id: &ContainerId, shell: &str) -> bool {
+        std::process::Command::new(command::DOCKER)
+            .args([command::EXEC, id.get(), shell, command::C, command::PWD])
+            .output()
+            .is_ok_and(|output| {
+                output.status.success()
+                    && String::from_utf8(output.stdout).is_ok_and(|i| !i.starts_with(OCI_ERROR))
+            })
+    }
+
     /// Test if we can exec into the selected container, first via the Internal methods, then by the External
-    /// If the container is oxker, it will always return None
-    pub async fn new(app_data: &Arc<Mutex<AppData>>, docker: &Arc<Docker>) -> Option<Self> {
+    /// The shells from the container label, `--shell`, and config file, are tried in order, if none exist in the container, then fallback to `sh`
+    /// If no shell exists, the error contains the shells that were tried, the error is empty if the container is oxker, or isn't running
+    pub async fn new(
+        app_data: &Arc<Mutex<AppData>>,
+        docker: &Arc<Docker>,
+    ) -> Result<Self, Vec<String>> {
         let is_oxker = app_data.lock().is_oxker();
         if is_oxker {
-            return None;
+            return Err(vec![]);
         }
 
         let use_cli = app_data.lock().args.use_cli;
+        let shells = shell_candidates(app_data.lock().get_exec_shells());
         let container = app_data.lock().get_selected_container_id_state_name();
 
-        if let Some((id, state, _)) = container {
-            if state == State::Running {
-                if tty_readable() && !use_cli {
-                    if let Ok(exec) = docker
-                        .create_exec(
-                            id.get(),
-                            CreateExecOptions {
-                                attach_stdout: Some(true),
-                                attach_stderr: Some(true),
-                                cmd: Some(vec![command::PWD]),
-                                ..Default::default()
-                            },
-                        )
-                        .await
-                    {
-                        if let Ok(StartExecResults::Attached { mut output, .. }) =
-                            docker.start_exec(&exec.id, None).await
-                        {
-                            if let Some(Ok(msg)) = output.next().await {
-                                if !msg.to_string().starts_with(OCI_ERROR) {
-                                    return Some(Self::Internal((id.clone(), Arc::clone(docker))));
-                                }
-                            }
-                        }
-                    }
+        let Some((id, State::Running, _)) = container else {
+            return Err(vec![]);
+        };
+        if tty_readable() && !use_cli {
+            for shell in &shells {
+                if Self::shell_valid_internal(docker, &id, shell).await {
+                    return Ok(Self::Internal((id, Arc::clone(docker), shell.clone())));
                 }
+            }
+        }
 
-                if let Ok(output) = std::process::Command::new(command::DOCKER)
-                    .args([command::EXEC, id.get(), command::PWD])
-                    .output()
-                {
-                    if let Ok(output) = String::from_utf8(output.stdout) {
-                        if !output.starts_with(OCI_ERROR) {
-                            return Some(Self::External(id.clone()));
-                        }
-                    }
-                }
+        for shell in &shells {
+            if Self::shell_valid_external(&id, shell) {
+                return Ok(Self::External((id, shell.clone())));
             }
         }
-        None
+        Err(shells)
     }
 
     /// exec into the container using the external docker cli, the result it just piped into oxker
-    fn exec_external(id: &ContainerId) {
+    fn exec_external(id: &ContainerId, shell: &str) {
         let mut stdout = std::io::stdout();
         stdout.write_all(CURSOR_POS.as_bytes()).ok();
         if let Ok(mut child) = std::process::Command::new(command::DOCKER)
-            .args([command::EXEC, command::IT, id.get(), command::SH])
+            .args([command::EXEC, command::IT, id.get(), shell])
             .stdin(std::process::Stdio::inherit())
             .stdout(std::process::Stdio::inherit())
             .stderr(std::process::Stdio::inherit())
@@ -227,6 +257,7 @@ impl ExecMode {
         &self,
         id: &ContainerId,
         docker: &Arc<Docker>,
+        shell: &str,
         terminal_size: Option<TerminalSize>,
     ) -> Result<(), AppError> {
         let cancel_token = CancellationToken::new();
@@ -239,7 +270,7 @@ impl ExecMode {
                     attach_stderr: Some(false),
                     attach_stdin: Some(true),
                     tty: Some(true),
-                    cmd: Some(vec![command::SH]),
+                    cmd: Some(vec![shell]),
                     ..Default::default()
                 },
             )
@@ -319,7 +350,7 @@ impl ExecMode {
                             if byte_sequence_valid(&bytes) {
                                 waiting_thread.store(false, std::sync::atomic::Ordering::SeqCst);
                             }
-                        };
+                        }
                     }
                 });
 
@@ -342,12 +373,14 @@ impl ExecMode {
 
     pub async fn run(&self, tty_size: Option<TerminalSize>) -> Result<(), AppError> {
         match self {
-            Self::External(id) => {
-                Self::exec_external(id);
+            Self::External((id, shell)) => {
+                Self::exec_external(id, shell);
                 Ok(())
             }
 
-            Self::Internal((id, docker)) => self.exec_internal(id, docker, tty_size).await,
+            Self::Internal((id, docker, shell)) => {
+                self.exec_internal(id, docker, shell, tty_size).await
+            }
         }
     }
 }
@@ -1,20 +1,18 @@
 use std::{
     fs::OpenOptions,
     io::{BufWriter, Write},
+    path::{Path, PathBuf},
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
-    time::SystemTime,
 };
 
-use bollard::{container::LogsOptions, Docker};
-use cansi::v3::categorise_text;
+use bollard::Docker;
 use crossterm::{
     event::{DisableMouseCapture, KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
     execute,
 };
-use futures_util::StreamExt;
 use parking_lot::Mutex;
 use ratatui::layout::Rect;
 use tokio::sync::mpsc::{Receiver, Sender};
@@ -22,14 +20,27 @@ use uuid::Uuid;
 
 mod message;
 use crate::{
-    app_data::{AppData, DockerControls, Header},
+    app_data::{AppData, ContainerId, DockerControls, Header, ImageItem, LogMarker, Since, State},
     app_error::AppError,
+    clipboard,
+    config::{Action, Keymap},
     docker_data::DockerMessage,
     exec::{tty_readable, ExecMode},
-    ui::{DeleteButton, GuiState, SelectablePanel, Status, Ui},
+    ui::{
+        validate_container_name, ConfirmControl, DeleteButton, GuiState, KillSignal, Limits,
+        LogSince, Prune, PruneTarget, Pull, PullState, Rename, RestartPolicy, SaveLogs,
+        SelectablePanel, Status, Ui, View,
+    },
 };
 pub use message::InputMessages;
 
+/// Shown instead of sending a command to oxker's own container, as the command could stop, or freeze, the ui
+const SELF_WARNING: &str = "✖ oxker is running in this container";
+/// Shown when oxker's own container is removed from a batch command
+const SELF_SKIPPED: &str = "✖ skipped the container oxker is running in";
+/// Shown in the log since popup when the input is neither a duration nor a timestamp
+const LOG_SINCE_ERROR: &str = "invalid, e.g. 10m, 1h30m, or 2024-03-01T12:00:00Z";
+
 /// Handle all input events
 #[derive(Debug)]
 pub struct InputHandler {
@@ -37,6 +48,7 @@ pub struct InputHandler {
     docker_tx: Sender<DockerMessage>,
     gui_state: Arc<Mutex<GuiState>>,
     is_running: Arc<AtomicBool>,
+    keymap: Keymap,
     mouse_capture: bool,
     rec: Receiver<InputMessages>,
 }
@@ -49,14 +61,17 @@ impl InputHandler {
         docker_tx: Sender<DockerMessage>,
         gui_state: Arc<Mutex<GuiState>>,
         is_running: Arc<AtomicBool>,
+        keymap: Keymap,
+        mouse_capture: bool,
     ) {
         let mut inner = Self {
             app_data,
             docker_tx,
             gui_state,
             is_running,
+            keymap,
+            mouse_capture,
             rec,
-            mouse_capture: true,
         };
         inner.start().await;
     }
@@ -68,10 +83,23 @@ impl InputHandler {
                 InputMessages::ButtonPress(key) => self.button_press(key.0, key.1).await,
                 InputMessages::MouseEvent(mouse_event) => {
                     if !self.gui_state.lock().status_contains(&[
+                        Status::DockerConnect,
                         Status::Error,
                         Status::Help,
+                        Status::Cheatsheet,
+                        Status::ConfirmControl,
                         Status::DeleteConfirm,
-                    ]) {
+                        Status::KillSignal,
+                        Status::Limits,
+                        Status::LogCopy,
+                        Status::LogSince,
+                        Status::Prune,
+                        Status::QuitConfirm,
+                        Status::Rename,
+                        Status::RestartPolicy,
+                        Status::SaveLogs,
+                    ]) && self.gui_state.lock().get_view() == View::Containers
+                    {
                         self.mouse_press(mouse_event);
                     }
                     let delete_confirm = self
@@ -83,6 +111,8 @@ impl InputHandler {
                     }
                 }
             }
+            // Any input can change what is shown, so the next frame always gets drawn
+            self.gui_state.lock().set_redraw();
             if !self.is_running.load(Ordering::SeqCst) {
                 break;
             }
@@ -95,58 +125,605 @@ impl InputHandler {
     }
 
     /// Send a quit message to docker, to abort all spawns, if an error is returned, set is_running to false here instead
-    /// If gui_status is Error or Init, then just set the is_running to false immediately, for a quicker exit
-    async fn quit(&self) {
-        let error_init = self
-            .gui_state
-            .lock()
-            .status_contains(&[Status::Error, Status::Init]);
+    /// If gui_status is Error or Init, then just set the is_running to false immediately, for a quicker exit.
+    /// Unless forced, quitting whilst a Docker command is still being processed opens the quit confirmation instead
+    async fn quit(&self, force: bool) {
+        let error_init = self.gui_state.lock().status_contains(&[
+            Status::DockerConnect,
+            Status::Error,
+            Status::Init,
+        ]);
+        if !force && !error_init && self.gui_state.lock().pending_actions() > 0 {
+            self.gui_state.lock().open_quit_confirm();
+            return;
+        }
         if error_init || self.docker_tx.send(DockerMessage::Quit).await.is_err() {
             self.is_running
                 .store(false, std::sync::atomic::Ordering::SeqCst);
         }
     }
 
+    /// Handle the quit confirmation, quit now, wait for the pending commands to finish then quit, or cancel
+    async fn quit_confirm_key(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('y' | 'Y') => self.quit(true).await,
+            KeyCode::Char('w' | 'W') => {
+                if self.gui_state.lock().get_quit_waiting() {
+                    return;
+                }
+                self.gui_state.lock().set_quit_waiting();
+                let gui_state = Arc::clone(&self.gui_state);
+                let docker_tx = self.docker_tx.clone();
+                tokio::spawn(async move {
+                    loop {
+                        // Cancelled from the quit confirmation
+                        if !gui_state.lock().get_quit_waiting() {
+                            break;
+                        }
+                        if gui_state.lock().pending_actions() == 0 {
+                            docker_tx.send(DockerMessage::Quit).await.ok();
+                            break;
+                        }
+                        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                    }
+                });
+            }
+            KeyCode::Esc | KeyCode::Char('n' | 'N') => self.gui_state.lock().close_quit_confirm(),
+            _ => (),
+        }
+    }
+
+    /// Check if the container in the Delete Confirm dialog can only be removed with force, i.e. it is still running.
+    /// An image can only be removed with force if it is used by any container, a network or volume is never removed with force
+    fn delete_requires_force(&self) -> bool {
+        if self.gui_state.lock().get_delete_network().is_some()
+            || self.gui_state.lock().get_delete_volume().is_some()
+        {
+            return false;
+        }
+        let image = self.gui_state.lock().get_delete_image();
+        if let Some(image) = image {
+            return self
+                .app_data
+                .lock()
+                .get_image_by_id(&image)
+                .is_some_and(ImageItem::in_use);
+        }
+        let id = self.gui_state.lock().get_delete_container();
+        id.is_some_and(|id| {
+            self.app_data
+                .lock()
+                .get_container_state_by_id(&id)
+                .is_some_and(State::requires_force)
+        })
+    }
+
     /// This is executed from the Delete Confirm dialog, and will send an internal message to actually remove the given container
-    async fn confirm_delete(&self) {
+    /// A running container can only be removed with force, and a stopped container only without
+    async fn confirm_delete(&self, force: bool) {
+        let volume = self.gui_state.lock().get_delete_volume();
+        if let Some(name) = volume {
+            if !force {
+                self.docker_tx
+                    .send(DockerMessage::RemoveVolume(name))
+                    .await
+                    .ok();
+            }
+            return;
+        }
+        let network = self.gui_state.lock().get_delete_network();
+        if let Some(id) = network {
+            if !force {
+                self.docker_tx
+                    .send(DockerMessage::RemoveNetwork(id))
+                    .await
+                    .ok();
+            }
+            return;
+        }
+        let image = self.gui_state.lock().get_delete_image();
+        if let Some(id) = image {
+            if self.delete_requires_force() == force {
+                self.docker_tx
+                    .send(DockerMessage::RemoveImage { id, force })
+                    .await
+                    .ok();
+            }
+            return;
+        }
         let id = self.gui_state.lock().get_delete_container();
         if let Some(id) = id {
-            self.docker_tx.send(DockerMessage::Delete(id)).await.ok();
+            if self.delete_requires_force() == force {
+                self.docker_tx
+                    .send(DockerMessage::Remove { id, force })
+                    .await
+                    .ok();
+            }
+        }
+    }
+
+    /// Open the Delete Confirm dialog for the selected container, or delete every marked container
+    async fn delete_key(&self) {
+        if self.send_marked(DockerControls::Delete).await || self.selected_is_oxker() {
+            return;
+        }
+        let id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = id {
+            self.docker_tx
+                .send(DockerMessage::ConfirmDelete(id))
+                .await
+                .ok();
         }
     }
 
     /// This is executed from the Delete Confirm dialog, and will clear the delete_container information (removes id and closes panel)
     fn clear_delete(&self) {
         self.gui_state.lock().set_delete_container(None);
+        self.gui_state.lock().set_delete_image(None);
+        self.gui_state.lock().set_delete_network(None);
+        self.gui_state.lock().set_delete_volume(None);
+    }
+
+    /// Switch between the containers & images views, the images are refreshed when the images view is opened
+    async fn images_key(&self) {
+        self.gui_state.lock().toggle_view(View::Images);
+        if self.gui_state.lock().get_view() == View::Images {
+            self.docker_tx.send(DockerMessage::Images).await.ok();
+        }
+    }
+
+    /// Switch between the containers & networks views, the networks are refreshed when the networks view is opened
+    async fn networks_key(&self) {
+        self.gui_state.lock().toggle_view(View::Networks);
+        if self.gui_state.lock().get_view() == View::Networks {
+            self.docker_tx.send(DockerMessage::Networks).await.ok();
+        }
+    }
+
+    /// Switch between the containers & volumes views, the volumes are refreshed when the volumes view is opened
+    async fn volumes_key(&self) {
+        self.gui_state.lock().toggle_view(View::Volumes);
+        if self.gui_state.lock().get_view() == View::Volumes {
+            self.docker_tx.send(DockerMessage::Volumes).await.ok();
+        }
+    }
+
+    /// Open the Delete Confirm dialog for the selected volume, a volume in use can't be removed, which is reported by docker
+    fn delete_volume_key(&self) {
+        let name = self
+            .app_data
+            .lock()
+            .get_selected_volume()
+            .map(|i| i.name.clone());
+        if let Some(name) = name {
+            self.gui_state.lock().set_delete_volume(Some(name));
+        }
+    }
+
+    /// Open the Delete Confirm dialog for the selected network, a network in use, or created by docker, can't be removed
+    fn delete_network_key(&self) {
+        let network = self
+            .app_data
+            .lock()
+            .get_selected_network()
+            .map(|i| (i.id.clone(), i.removable()));
+        match network {
+            Some((id, true)) => self.gui_state.lock().set_delete_network(Some(id)),
+            Some((_, false)) => self
+                .gui_state
+                .lock()
+                .set_info_box("network is in use, or can't be removed"),
+            None => (),
+        }
+    }
+
+    /// Open the keybinding cheatsheet, read from the keymap, so that any keys set in the config file are shown
+    fn cheatsheet_open(&self) {
+        self.gui_state
+            .lock()
+            .set_cheatsheet(Some(self.keymap.cheatsheet()));
+    }
+
+    /// Handle keys whilst the keybinding cheatsheet is visible, the scroll keys scroll it by line or page, esc or the cheatsheet key closes it
+    fn cheatsheet_key(&self, key_code: KeyCode, key_modifier: KeyModifiers) {
+        let mut gui_state = self.gui_state.lock();
+        if key_code == KeyCode::Esc {
+            gui_state.set_cheatsheet(None);
+            return;
+        }
+        match self.keymap.get_action(key_code, key_modifier) {
+            Some(Action::Cheatsheet | Action::Clear) => gui_state.set_cheatsheet(None),
+            Some(Action::ScrollUp) => gui_state.cheatsheet_scroll_up(1),
+            Some(Action::ScrollDown) => gui_state.cheatsheet_scroll_down(1),
+            Some(Action::ScrollHalfPageUp) => gui_state.cheatsheet_scroll_up(5),
+            Some(Action::ScrollHalfPageDown) => gui_state.cheatsheet_scroll_down(5),
+            Some(Action::ScrollPageUp) => gui_state.cheatsheet_scroll_up(10),
+            Some(Action::ScrollPageDown) => gui_state.cheatsheet_scroll_down(10),
+            Some(Action::ScrollStart) => gui_state.cheatsheet_scroll_up(u16::MAX),
+            Some(Action::ScrollEnd) => gui_state.cheatsheet_scroll_down(u16::MAX),
+            _ => (),
+        }
+    }
+
+    /// Open the inspect panel for the selected network, the inspect output is always refreshed when opened
+    async fn inspect_network_key(&self) {
+        let id = self
+            .app_data
+            .lock()
+            .get_selected_network()
+            .map(|i| i.id.clone());
+        if let Some(id) = id {
+            self.gui_state.lock().set_network_inspect(Some(id.clone()));
+            self.docker_tx
+                .send(DockerMessage::InspectNetwork(id))
+                .await
+                .ok();
+        }
+    }
+
+    /// Handle keys whilst the network inspect panel is visible, scrolling by line or page, esc or i closes the panel
+    fn network_inspect_key(&self, key_code: KeyCode) {
+        let inspect = self.gui_state.lock().get_network_inspect();
+        if let Some(inspect) = inspect {
+            let max = self
+                .app_data
+                .lock()
+                .get_network_inspect_by_id(&inspect.id)
+                .map_or(0, |i| i.lines().count());
+            let mut gui_state = self.gui_state.lock();
+            match key_code {
+                KeyCode::Esc | KeyCode::Char('i' | 'I') => gui_state.set_network_inspect(None),
+                KeyCode::Up | KeyCode::Char('k' | 'K') => gui_state.network_inspect_scroll_up(1),
+                KeyCode::Down | KeyCode::Char('j' | 'J') => {
+                    gui_state.network_inspect_scroll_down(1, max);
+                }
+                KeyCode::PageUp => gui_state.network_inspect_scroll_up(10),
+                KeyCode::PageDown => gui_state.network_inspect_scroll_down(10, max),
+                KeyCode::Home => gui_state.network_inspect_scroll_up(u16::MAX),
+                KeyCode::End => gui_state.network_inspect_scroll_down(u16::MAX, max),
+                _ => (),
+            }
+        }
+    }
+
+    /// Open the Delete Confirm dialog for the selected image
+    fn delete_image_key(&self) {
+        let id = self
+            .app_data
+            .lock()
+            .get_selected_image()
+            .map(|i| i.id.clone());
+        if let Some(id) = id {
+            self.gui_state.lock().set_delete_image(Some(id));
+        }
     }
 
-    /// Validate that one can exec into a Docker container
+    /// Validate that one can exec into a Docker container, only running containers can be exec'd into
     async fn e_key(&self) {
         let is_oxker = self.app_data.lock().is_oxker();
-        if !is_oxker && tty_readable() {
+        let state = self
+            .app_data
+            .lock()
+            .get_selected_container_id_state_name()
+            .map(|i| i.1);
+        if state.is_some_and(|i| i != State::Running) {
+            self.gui_state
+                .lock()
+                .set_info_box("✖ can only exec into a running container");
+        } else if !is_oxker && tty_readable() {
             let uuid = Uuid::new_v4();
             let handle = GuiState::start_loading_animation(&self.gui_state, uuid);
             let (sx, rx) = tokio::sync::oneshot::channel::<Arc<Docker>>();
             self.docker_tx.send(DockerMessage::Exec(sx)).await.ok();
 
             if let Ok(docker) = rx.await {
-                (ExecMode::new(&self.app_data, &docker).await).map_or_else(
-                    || {
+                match ExecMode::new(&self.app_data, &docker).await {
+                    Ok(mode) => self.gui_state.lock().set_exec_mode(mode),
+                    Err(shells) if !shells.is_empty() => {
+                        self.gui_state.lock().set_info_box(&format!(
+                            "✖ no shell found, tried {}",
+                            shells.join(", ")
+                        ));
+                    }
+                    Err(_) => {
                         self.app_data.lock().set_error(
                             AppError::DockerExec,
                             &self.gui_state,
                             Status::Error,
                         );
-                    },
-                    |mode| {
-                        self.gui_state.lock().set_exec_mode(mode);
-                    },
-                );
+                    }
+                }
             }
             self.gui_state.lock().stop_loading_animation(&handle, uuid);
         }
     }
 
+    /// Copy the full id, or the name, of the selected container to the clipboard.
+    /// The clipboard is often unavailable, such as when running headless or in a container, which is shown in the info box
+    async fn copy_key(&self, name: bool) {
+        let container = self.app_data.lock().get_selected_container_id_state_name();
+        if let Some((id, _, container_name)) = container {
+            let (text, description) = if name {
+                (container_name, "name")
+            } else {
+                (id.get().to_owned(), "id")
+            };
+            let info = match clipboard::copy(&text).await {
+                Ok(()) => format!("✓ copied {description} to clipboard"),
+                Err(e) => format!("✖ {e}"),
+            };
+            self.gui_state.lock().set_info_box(&info);
+        }
+    }
+
+    /// Copy the log lines shown in the logs panel, or every buffered line, of the selected container to the clipboard.
+    /// Only the lines of the shown streams are copied, with timestamps if shown, so that the copy matches what's on screen
+    async fn copy_logs(&self, all: bool) {
+        let lines = if all {
+            self.app_data.lock().get_log_text()
+        } else {
+            let log_page = self.gui_state.lock().get_log_page();
+            log_page.map_or(vec![], |(rows, width)| {
+                self.app_data.lock().get_log_visible_text(rows, width)
+            })
+        };
+        if lines.is_empty() {
+            return;
+        }
+        let suffix = if lines.len() == 1 { "" } else { "s" };
+        let info = match clipboard::copy(&lines.join("\n")).await {
+            Ok(()) => format!("✓ copied {} log line{suffix} to clipboard", lines.len()),
+            Err(e) => format!("✖ {e}"),
+        };
+        self.gui_state.lock().set_info_box(&info);
+    }
+
+    /// Start the log copy mode for the selected container, if it has any logs
+    fn log_copy_open(&self) {
+        let id = self
+            .app_data
+            .lock()
+            .get_log_position()
+            .and_then(|_| self.app_data.lock().get_selected_container_id());
+        if id.is_some() {
+            self.gui_state.lock().set_log_copy(id);
+        }
+    }
+
+    /// Handle keys whilst in the log copy mode, moving the cursor removes the range selection, whereas moving with shift extends it.
+    /// The selected lines are copied to the clipboard, which also exits the copy mode
+    async fn log_copy_key(&self, key_code: KeyCode, key_modifier: KeyModifiers) {
+        let Some(log_copy) = self.gui_state.lock().get_log_copy() else {
+            return;
+        };
+        // The selected container can change if it's removed, in which case the copy mode is no longer valid
+        if self.app_data.lock().get_selected_container_id() != Some(log_copy.id) {
+            self.gui_state.lock().set_log_copy(None);
+            return;
+        }
+        let shift = key_modifier.contains(KeyModifiers::SHIFT);
+        let select = |extend: bool| {
+            let anchor = if extend {
+                log_copy
+                    .anchor
+                    .or_else(|| self.app_data.lock().get_log_position())
+            } else {
+                None
+            };
+            self.gui_state.lock().set_log_copy_anchor(anchor);
+        };
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                select(shift);
+                self.app_data.lock().log_next();
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                select(shift);
+                self.app_data.lock().log_previous();
+            }
+            KeyCode::Char('J') => {
+                select(true);
+                self.app_data.lock().log_next();
+            }
+            KeyCode::Char('K') => {
+                select(true);
+                self.app_data.lock().log_previous();
+            }
+            KeyCode::Home | KeyCode::Char('g') => {
+                select(shift);
+                self.app_data.lock().log_start();
+            }
+            KeyCode::End | KeyCode::Char('G') => {
+                select(shift);
+                self.app_data.lock().log_end();
+            }
+            KeyCode::Char('y' | 'Y') | KeyCode::Enter => {
+                let lines = self.app_data.lock().get_log_selected_text(log_copy.anchor);
+                let suffix = if lines.len() == 1 { "" } else { "s" };
+                let info = match clipboard::copy(&lines.join("\n")).await {
+                    Ok(()) => format!("✓ copied {} log line{suffix} to clipboard", lines.len()),
+                    Err(e) => format!("✖ {e}"),
+                };
+                self.gui_state.lock().set_info_box(&info);
+                self.gui_state.lock().set_log_copy(None);
+            }
+            KeyCode::Esc => self.gui_state.lock().set_log_copy(None),
+            _ => (),
+        }
+    }
+
+    /// Open the inspect panel for the selected container, the inspect output is always refreshed when opened
+    async fn i_key(&self) {
+        let id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = id {
+            self.gui_state.lock().set_inspect(Some(id.clone()));
+            self.docker_tx.send(DockerMessage::Inspect(id)).await.ok();
+        }
+    }
+
+    /// Handle keys whilst the inspect panel is visible
+    fn inspect_key(&self, key_code: KeyCode) {
+        let inspect = self.gui_state.lock().get_inspect();
+        if let Some(inspect) = inspect {
+            let max = self
+                .app_data
+                .lock()
+                .get_inspect_by_id(&inspect.id)
+                .map_or(0, |i| i.lines().count());
+            let mut gui_state = self.gui_state.lock();
+            match key_code {
+                KeyCode::Esc | KeyCode::Char('i' | 'I') => gui_state.set_inspect(None),
+                KeyCode::Up | KeyCode::Char('k' | 'K') => gui_state.inspect_scroll_up(1),
+                KeyCode::Down | KeyCode::Char('j' | 'J') => gui_state.inspect_scroll_down(1, max),
+                KeyCode::PageUp => gui_state.inspect_scroll_up(10),
+                KeyCode::PageDown => gui_state.inspect_scroll_down(10, max),
+                KeyCode::Home => gui_state.inspect_scroll_up(u16::MAX),
+                KeyCode::End => gui_state.inspect_scroll_down(u16::MAX, max),
+                _ => (),
+            }
+        }
+    }
+
+    /// Open the environment variables panel for the selected container, the variables are always refreshed when opened
+    async fn a_key(&self) {
+        let id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = id {
+            self.gui_state.lock().set_env(Some(id.clone()));
+            self.docker_tx.send(DockerMessage::Env(id)).await.ok();
+        }
+    }
+
+    /// Handle keys whilst the environment variables panel is visible
+    fn env_key(&self, key_code: KeyCode) {
+        let env = self.gui_state.lock().get_env();
+        if let Some(env) = env {
+            let max = self
+                .app_data
+                .lock()
+                .get_env_by_id(&env.id)
+                .map_or(0, |i| i.len());
+            let mut gui_state = self.gui_state.lock();
+            match key_code {
+                KeyCode::Esc | KeyCode::Char('a' | 'A') => gui_state.set_env(None),
+                KeyCode::Char('m' | 'M') => gui_state.env_toggle_mask(),
+                KeyCode::Up | KeyCode::Char('k' | 'K') => gui_state.env_scroll_up(1),
+                KeyCode::Down | KeyCode::Char('j' | 'J') => gui_state.env_scroll_down(1, max),
+                KeyCode::PageUp => gui_state.env_scroll_up(10),
+                KeyCode::PageDown => gui_state.env_scroll_down(10, max),
+                KeyCode::Home => gui_state.env_scroll_up(u16::MAX),
+                KeyCode::End => gui_state.env_scroll_down(u16::MAX, max),
+                _ => (),
+            }
+        }
+    }
+
+    /// Open the labels panel for the selected container, the labels are always refreshed when opened
+    async fn labels_open(&self) {
+        let id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = id {
+            self.gui_state.lock().set_labels(Some(id.clone()));
+            self.docker_tx.send(DockerMessage::Labels(id)).await.ok();
+        }
+    }
+
+    /// Handle keys whilst the labels panel is visible
+    fn labels_key(&self, key_code: KeyCode) {
+        let labels = self.gui_state.lock().get_labels();
+        if let Some(labels) = labels {
+            let max = self
+                .app_data
+                .lock()
+                .get_labels_by_id(&labels.id)
+                .map_or(0, |i| i.len());
+            let mut gui_state = self.gui_state.lock();
+            match key_code {
+                KeyCode::Esc => gui_state.set_labels(None),
+                KeyCode::Up | KeyCode::Char('k' | 'K') => gui_state.labels_scroll_up(1),
+                KeyCode::Down | KeyCode::Char('j' | 'J') => gui_state.labels_scroll_down(1, max),
+                KeyCode::PageUp => gui_state.labels_scroll_up(10),
+                KeyCode::PageDown => gui_state.labels_scroll_down(10, max),
+                KeyCode::Home => gui_state.labels_scroll_up(u16::MAX),
+                KeyCode::End => gui_state.labels_scroll_down(u16::MAX, max),
+                _ => (),
+            }
+        }
+    }
+
+    /// Open the mounts panel for the selected container
+    fn mounts_open(&self) {
+        let id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = id {
+            self.gui_state.lock().set_mounts(Some(id));
+        }
+    }
+
+    /// Handle keys whilst the mounts panel is visible
+    fn mounts_key(&self, key_code: KeyCode) {
+        let mounts = self.gui_state.lock().get_mounts();
+        if let Some(mounts) = mounts {
+            let max = self
+                .app_data
+                .lock()
+                .get_mounts_by_id(&mounts.id)
+                .map_or(0, |i| i.len());
+            let mut gui_state = self.gui_state.lock();
+            match key_code {
+                KeyCode::Esc => gui_state.set_mounts(None),
+                KeyCode::Up | KeyCode::Char('k' | 'K') => gui_state.mounts_scroll_up(1),
+                KeyCode::Down | KeyCode::Char('j' | 'J') => gui_state.mounts_scroll_down(1, max),
+                KeyCode::PageUp => gui_state.mounts_scroll_up(10),
+                KeyCode::PageDown => gui_state.mounts_scroll_down(10, max),
+                KeyCode::Home => gui_state.mounts_scroll_up(u16::MAX),
+                KeyCode::End => gui_state.mounts_scroll_down(u16::MAX, max),
+                _ => (),
+            }
+        }
+    }
+
+    /// Open the docker run command panel for the selected container, the command is always rebuilt when opened
+    async fn run_command_open(&self) {
+        let id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = id {
+            self.gui_state.lock().set_run_command(Some(id.clone()));
+            self.docker_tx
+                .send(DockerMessage::RunCommand(id))
+                .await
+                .ok();
+        }
+    }
+
+    /// Handle keys whilst the docker run command panel is visible, c copies the command to the clipboard
+    async fn run_command_key(&self, key_code: KeyCode) {
+        let run_command = self.gui_state.lock().get_run_command();
+        if let Some(run_command) = run_command {
+            let text = self.app_data.lock().get_run_command_by_id(&run_command.id);
+            if matches!(key_code, KeyCode::Char('c' | 'C')) {
+                if let Some(text) = text {
+                    let info = match clipboard::copy(&text).await {
+                        Ok(()) => String::from("✓ copied run command to clipboard"),
+                        Err(e) => format!("✖ {e}"),
+                    };
+                    self.gui_state.lock().set_info_box(&info);
+                }
+                return;
+            }
+            let max = text.map_or(0, |i| i.lines().count());
+            let mut gui_state = self.gui_state.lock();
+            match key_code {
+                KeyCode::Esc => gui_state.set_run_command(None),
+                KeyCode::Up | KeyCode::Char('k' | 'K') => gui_state.run_command_scroll_up(1),
+                KeyCode::Down | KeyCode::Char('j' | 'J') => {
+                    gui_state.run_command_scroll_down(1, max);
+                }
+                KeyCode::PageUp => gui_state.run_command_scroll_up(10),
+                KeyCode::PageDown => gui_state.run_command_scroll_down(10, max),
+                KeyCode::Home => gui_state.run_command_scroll_up(u16::MAX),
+                KeyCode::End => gui_state.run_command_scroll_down(u16::MAX, max),
+                _ => (),
+            }
+        }
+    }
+
     /// Toggle the mouse capture (via input of the 'm' key)
     fn m_key(&mut self) {
         if self.mouse_capture {
@@ -171,142 +748,777 @@ impl InputHandler {
                 &self.gui_state,
                 Status::Error,
             );
-        };
+        }
 
         self.mouse_capture = !self.mouse_capture;
     }
 
-    /// Save the currently selected containers logs into a `[container_name]_[timestamp].log` file
-    async fn s_key(&mut self) {
-        /// This is the inner workings, *inlined* here to return a Result
-        async fn save_logs(
-            app_data: &Arc<Mutex<AppData>>,
-            gui_state: &Arc<Mutex<GuiState>>,
-            docker_tx: &Sender<DockerMessage>,
-        ) -> Result<(), Box<dyn std::error::Error>> {
-            let args = app_data.lock().args.clone();
-            let container = app_data.lock().get_selected_container_id_state_name();
-            if let Some((id, _, name)) = container {
-                if let Some(log_path) = args.save_dir {
-                    let (sx, rx) = tokio::sync::oneshot::channel::<Arc<Docker>>();
-                    docker_tx.send(DockerMessage::Exec(sx)).await?;
-
-                    let now = SystemTime::now()
-                        .duration_since(SystemTime::UNIX_EPOCH)
-                        .map_or(0, |i| i.as_secs());
-
-                    let path = log_path.join(format!("{name}_{now}.log"));
-
-                    let docker = rx.await?;
-                    let options = Some(LogsOptions::<String> {
-                        stdout: true,
-                        timestamps: args.timestamp,
-                        since: 0,
-                        ..Default::default()
-                    });
-                    let mut logs = docker.logs(id.get(), options);
-                    let mut output = vec![];
-
-                    while let Some(Ok(value)) = logs.next().await {
-                        let data = value.to_string();
-                        if !data.trim().is_empty() {
-                            output.push(
-                                categorise_text(&data)
-                                    .into_iter()
-                                    .map(|i| i.text)
-                                    .collect::<String>(),
-                            );
-                        }
-                    }
-                    if !output.is_empty() {
-                        let mut stream = BufWriter::new(
-                            OpenOptions::new()
-                                .read(true)
-                                .write(true)
-                                .create(true)
-                                .truncate(true)
-                                .open(&path)?,
-                        );
-
-                        for line in &output {
-                            stream.write_all(line.as_bytes())?;
-                        }
-                        stream.flush()?;
+    /// Open the save logs popup, with a default path of `[save_dir]/[container_name]_[timestamp].log`
+    fn s_key(&self) {
+        let save_dir = self.app_data.lock().args.save_dir.clone();
+        let container = self.app_data.lock().get_selected_container_id_state_name();
+        if let Some((id, _, name)) = container {
+            let now = self.app_data.lock().now();
+            let file_name = format!("{name}_{now}.log");
+            let path = save_dir.map_or_else(|| PathBuf::from(&file_name), |i| i.join(&file_name));
+            self.gui_state.lock().set_save_logs(Some(SaveLogs {
+                id,
+                path: path.display().to_string(),
+            }));
+        }
+    }
 
-                        gui_state
-                            .lock()
-                            .set_info_box(&format!("saved to {}", path.display()));
-                    }
+    /// Write the buffered logs of the container in the save logs popup to the chosen path
+    fn confirm_save_logs(&self) {
+        /// This is the inner workings, *inlined* here to return a Result
+        fn write_logs(path: &Path, logs: &[String]) -> std::io::Result<()> {
+            let mut stream = BufWriter::new(
+                OpenOptions::new()
+                    .write(true)
+                    .create(true)
+                    .truncate(true)
+                    .open(path)?,
+            );
+            for line in logs {
+                stream.write_all(line.as_bytes())?;
+                if !line.ends_with('\n') {
+                    stream.write_all(b"\n")?;
                 }
             }
-            Ok(())
+            stream.flush()
         }
 
-        let log_status = Status::Logs;
-        let status = self.gui_state.lock().status_contains(&[log_status]);
-        if !status {
-            self.gui_state.lock().status_push(log_status);
-
-            let uuid = Uuid::new_v4();
-            let handle = GuiState::start_loading_animation(&self.gui_state, uuid);
-            if save_logs(&self.app_data, &self.gui_state, &self.docker_tx)
-                .await
-                .is_err()
-            {
+        let save_logs = self.gui_state.lock().get_save_logs();
+        if let Some(save_logs) = save_logs {
+            if save_logs.path.trim().is_empty() {
+                return;
+            }
+            self.gui_state.lock().set_save_logs(None);
+            let logs = self
+                .app_data
+                .lock()
+                .get_logs_text_by_id(&save_logs.id)
+                .unwrap_or_default();
+            if logs.is_empty() {
+                self.gui_state.lock().set_info_box("no logs to save");
+                return;
+            }
+            let path = PathBuf::from(save_logs.path.trim());
+            if write_logs(&path, &logs).is_ok() {
+                self.gui_state
+                    .lock()
+                    .set_info_box(&format!("saved to {}", path.display()));
+            } else {
                 self.app_data.lock().set_error(
                     AppError::DockerLogs,
                     &self.gui_state,
                     Status::Error,
                 );
             }
-            self.gui_state.lock().status_del(log_status);
-            self.gui_state.lock().stop_loading_animation(&handle, uuid);
+        }
+    }
+
+    /// Handle keys whilst the save logs popup is visible
+    fn save_logs_key(&self, key_code: KeyCode, key_modifier: KeyModifiers) {
+        match key_code {
+            KeyCode::Enter => self.confirm_save_logs(),
+            KeyCode::Esc => self.gui_state.lock().set_save_logs(None),
+            KeyCode::Backspace => self.gui_state.lock().save_logs_pop(),
+            KeyCode::Char(c) if key_modifier != KeyModifiers::CONTROL => {
+                self.gui_state.lock().save_logs_push(c);
+            }
+            _ => (),
+        }
+    }
+
+    /// Open the rename popup, prefilled with the current name of the selected container
+    fn rename_open(&self) {
+        let container = self.app_data.lock().get_selected_container_id_state_name();
+        if let Some((id, _, name)) = container {
+            self.gui_state.lock().set_rename(Some(Rename { id, name }));
+        }
+    }
+
+    /// Open the restart policy popup, with the current policy of the selected container selected, if it's known.
+    /// Oxker's own container is never changed
+    fn restart_policy_open(&self) {
+        if self.selected_is_oxker() {
+            return;
+        }
+        let id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = id {
+            let current = self.app_data.lock().get_selected_restarts();
+            self.gui_state
+                .lock()
+                .set_restart_policy(Some(RestartPolicy::new(
+                    id,
+                    current.as_ref().map(|i| i.policy.as_str()),
+                )));
+        }
+    }
+
+    /// Send the selected restart policy, and close the popup
+    async fn confirm_restart_policy(&self) {
+        let restart_policy = self.gui_state.lock().get_restart_policy();
+        if let Some(restart_policy) = restart_policy {
+            if let Some(policy) = restart_policy.get_policy() {
+                self.gui_state.lock().set_restart_policy(None);
+                self.docker_tx
+                    .send(DockerMessage::UpdateRestartPolicy {
+                        id: restart_policy.id,
+                        policy: policy.to_owned(),
+                    })
+                    .await
+                    .ok();
+            }
+        }
+    }
+
+    /// Open the limits popup for the selected container, oxker's own container is never changed
+    fn limits_open(&self) {
+        if self.selected_is_oxker() {
+            return;
+        }
+        let id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = id {
+            self.gui_state.lock().set_limits(Some(Limits::new(id)));
+        }
+    }
+
+    /// Send the new limits, and close the popup, an invalid limit is shown as an error in the popup instead
+    async fn confirm_limits(&self) {
+        let Some(limits) = self.gui_state.lock().get_limits() else {
+            return;
+        };
+        match limits.parse() {
+            Ok((nano_cpus, memory)) => {
+                self.gui_state.lock().set_limits(None);
+                self.docker_tx
+                    .send(DockerMessage::UpdateLimits {
+                        id: limits.id,
+                        nano_cpus,
+                        memory,
+                    })
+                    .await
+                    .ok();
+            }
+            Err(error) => self.gui_state.lock().limits_error(String::from(error)),
+        }
+    }
+
+    /// Handle keys whilst the limits popup is visible, tab switches between the cpus and memory inputs
+    async fn limits_key(&self, key_code: KeyCode, key_modifier: KeyModifiers) {
+        match key_code {
+            KeyCode::Enter => self.confirm_limits().await,
+            KeyCode::Esc => self.gui_state.lock().set_limits(None),
+            KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => {
+                self.gui_state.lock().limits_toggle_field();
+            }
+            KeyCode::Backspace => self.gui_state.lock().limits_pop(),
+            KeyCode::Char(c) if key_modifier != KeyModifiers::CONTROL => {
+                self.gui_state.lock().limits_push(c);
+            }
+            _ => (),
+        }
+    }
+
+    /// Handle keys whilst the restart policy popup is visible, the scroll keys also change the selected policy
+    async fn restart_policy_key(&self, key_code: KeyCode, key_modifier: KeyModifiers) {
+        match key_code {
+            KeyCode::Enter => self.confirm_restart_policy().await,
+            KeyCode::Esc => self.gui_state.lock().set_restart_policy(None),
+            KeyCode::Up => self.gui_state.lock().restart_policy_previous(),
+            KeyCode::Down => self.gui_state.lock().restart_policy_next(),
+            _ => match self.keymap.get_action(key_code, key_modifier) {
+                Some(Action::ScrollUp) => self.gui_state.lock().restart_policy_previous(),
+                Some(Action::ScrollDown) => self.gui_state.lock().restart_policy_next(),
+                Some(Action::Clear) => self.gui_state.lock().set_restart_policy(None),
+                _ => (),
+            },
+        }
+    }
+
+    /// Ask to confirm recreating the selected container
+    fn recreate_open(&self) {
+        let id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = id {
+            self.recreate_confirm(id);
+        }
+    }
+
+    /// Ask to confirm recreating a container, regardless of "--confirm-destructive", as any options that can't be read back from the inspect output are lost.
+    /// Oxker's own container is never recreated
+    fn recreate_confirm(&self, id: ContainerId) {
+        if self.app_data.lock().is_oxker_in_container_by_id(&id) {
+            self.gui_state.lock().set_info_box(SELF_WARNING);
+            return;
+        }
+        self.gui_state
+            .lock()
+            .set_confirm_control(Some(ConfirmControl {
+                batch: false,
+                control: DockerControls::Recreate,
+                ids: vec![id],
+                signal: None,
+            }));
+    }
+
+    /// Open the image pull panel, and start pulling the image of the selected container
+    async fn pull_open(&self) {
+        let container = self
+            .app_data
+            .lock()
+            .get_selected_container()
+            .map(|i| (i.id.clone(), i.image.get().to_owned()));
+        if let Some((id, image)) = container {
+            self.gui_state
+                .lock()
+                .set_pull(Some(Pull::new(id.clone(), image.clone())));
+            self.docker_tx
+                .send(DockerMessage::PullImage { id, image })
+                .await
+                .ok();
+        }
+    }
+
+    /// Handle keys whilst the image pull panel is visible, once pulled r closes the panel, and asks to recreate the container to use the new image.
+    /// Closing the panel doesn't cancel the pull
+    fn pull_key(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Esc => self.gui_state.lock().set_pull(None),
+            KeyCode::Char('r' | 'R') => {
+                let pull = self.gui_state.lock().get_pull();
+                if let Some(pull) = pull.filter(|i| matches!(i.state, PullState::Pulled)) {
+                    self.gui_state.lock().set_pull(None);
+                    self.recreate_confirm(pull.id);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Send the new name to Docker, an invalid, or unchanged, name is ignored, so the popup stays open until fixed, or cancelled
+    async fn confirm_rename(&self) {
+        let rename = self.gui_state.lock().get_rename();
+        if let Some(rename) = rename {
+            let name = rename.name.trim();
+            if !validate_container_name(name) {
+                return;
+            }
+            let current = self.app_data.lock().get_container_name_by_id(&rename.id);
+            if current.is_some_and(|i| i.get() == name) {
+                self.gui_state.lock().set_rename(None);
+                return;
+            }
+            self.docker_tx
+                .send(DockerMessage::Rename {
+                    id: rename.id,
+                    name: name.to_owned(),
+                })
+                .await
+                .ok();
+        }
+    }
+
+    /// Handle keys whilst the rename popup is visible
+    async fn rename_key(&self, key_code: KeyCode, key_modifier: KeyModifiers) {
+        match key_code {
+            KeyCode::Enter => self.confirm_rename().await,
+            KeyCode::Esc => self.gui_state.lock().set_rename(None),
+            KeyCode::Backspace => self.gui_state.lock().rename_pop(),
+            KeyCode::Char(c) if key_modifier != KeyModifiers::CONTROL => {
+                self.gui_state.lock().rename_push(c);
+            }
+            _ => (),
+        }
+    }
+
+    /// Open the log since popup, prefilled with the current since, if one is set
+    fn log_since_open(&self) {
+        let input = self
+            .app_data
+            .lock()
+            .get_log_since()
+            .map(|i| i.to_string())
+            .unwrap_or_default();
+        self.gui_state
+            .lock()
+            .set_log_since(Some(LogSince { input, error: None }));
+    }
+
+    /// Apply the since from the popup, an empty input clears it, an invalid input keeps the popup open with the expected formats shown
+    fn confirm_log_since(&self) {
+        let Some(log_since) = self.gui_state.lock().get_log_since() else {
+            return;
+        };
+        let input = log_since.input.trim();
+        let since = if input.is_empty() {
+            None
+        } else if let Ok(since) = input.parse::<Since>() {
+            Some(since)
+        } else {
+            self.gui_state
+                .lock()
+                .log_since_error(String::from(LOG_SINCE_ERROR));
+            return;
+        };
+        self.app_data.lock().set_log_since(since);
+        let text = since.map_or_else(
+            || String::from("logs since: cleared"),
+            |i| format!("logs since: {i}"),
+        );
+        let mut gui_state = self.gui_state.lock();
+        gui_state.set_log_since(None);
+        gui_state.set_info_box(&text);
+    }
+
+    /// Handle keys whilst the log since popup is visible
+    fn log_since_key(&self, key_code: KeyCode, key_modifier: KeyModifiers) {
+        match key_code {
+            KeyCode::Enter => self.confirm_log_since(),
+            KeyCode::Esc => self.gui_state.lock().set_log_since(None),
+            KeyCode::Backspace => self.gui_state.lock().log_since_pop(),
+            KeyCode::Char(c) if key_modifier != KeyModifiers::CONTROL => {
+                self.gui_state.lock().log_since_push(c);
+            }
+            _ => (),
+        }
+    }
+
+    /// Toggle the timestamps of the logs (via input of the 't' key)
+    fn t_key(&self) {
+        let timestamps = {
+            let mut app_data = self.app_data.lock();
+            app_data.toggle_log_timestamps();
+            app_data.get_logs_with_timestamps()
+        };
+        let text = if timestamps {
+            "✓ log timestamps shown"
+        } else {
+            "✖ log timestamps hidden"
+        };
+        self.gui_state.lock().set_info_box(text);
+    }
+
+    /// Check if the selected container is oxker's own container, or another containerised oxker, showing a warning if so
+    fn selected_is_oxker(&self) -> bool {
+        let is_oxker = self.app_data.lock().is_oxker_in_container();
+        if is_oxker {
+            self.gui_state.lock().set_info_box(SELF_WARNING);
+        }
+        is_oxker
+    }
+
+    /// Pause the selected container, is a no-op if the container isn't currently running
+    async fn p_key(&self) {
+        if self.selected_is_oxker() {
+            return;
+        }
+        let container = self.app_data.lock().get_selected_container_id_state_name();
+        if let Some((id, state, _)) = container {
+            if state == State::Running {
+                self.docker_tx.send(DockerMessage::Pause(id)).await.ok();
+            }
+        }
+    }
+
+    /// Unpause the selected container, is a no-op if the container isn't currently paused
+    async fn u_key(&self) {
+        if self.selected_is_oxker() {
+            return;
+        }
+        let container = self.app_data.lock().get_selected_container_id_state_name();
+        if let Some((id, state, _)) = container {
+            if state == State::Paused {
+                self.docker_tx.send(DockerMessage::Unpause(id)).await.ok();
+            }
+        }
+    }
+
+    /// Open the kill popup for the selected container, is a no-op if the container isn't alive
+    fn x_key(&self) {
+        if self.selected_is_oxker() {
+            return;
+        }
+        let container = self.app_data.lock().get_selected_container_id_state_name();
+        if let Some((id, state, _)) = container {
+            if state.is_alive() {
+                self.gui_state
+                    .lock()
+                    .set_kill_signal(Some(KillSignal::new(id)));
+            }
+        }
+    }
+
+    /// Send the kill message with the selected, or typed, signal, the popup will stay open if the typed signal is invalid
+    async fn confirm_kill(&self) {
+        let kill_signal = self.gui_state.lock().get_kill_signal();
+        if let Some(kill_signal) = kill_signal {
+            if let Some(signal) = kill_signal.get_signal() {
+                self.gui_state.lock().set_kill_signal(None);
+                self.send_or_confirm(ConfirmControl {
+                    batch: false,
+                    control: DockerControls::Kill,
+                    ids: vec![kill_signal.id],
+                    signal: Some(signal),
+                })
+                .await;
+            }
+        }
+    }
+
+    /// Handle keys whilst the kill popup is visible
+    async fn kill_key(&self, key_code: KeyCode, key_modifier: KeyModifiers) {
+        match key_code {
+            KeyCode::Enter => self.confirm_kill().await,
+            KeyCode::Esc => self.gui_state.lock().set_kill_signal(None),
+            KeyCode::Up => self.gui_state.lock().kill_signal_previous(),
+            KeyCode::Down => self.gui_state.lock().kill_signal_next(),
+            KeyCode::Backspace => self.gui_state.lock().kill_signal_pop(),
+            KeyCode::Char(c) if key_modifier != KeyModifiers::CONTROL => {
+                self.gui_state.lock().kill_signal_push(c);
+            }
+            _ => (),
+        }
+    }
+
+    /// Open the prune popup, showing the number of stopped containers that would be removed
+    fn prune_open(&self) {
+        let stopped = self.app_data.lock().get_stopped_len();
+        self.gui_state.lock().set_prune(Some(Prune::new(stopped)));
+    }
+
+    /// Send the prune message, the popup stays open if nothing is selected to prune
+    async fn confirm_prune(&self) {
+        let prune = self.gui_state.lock().get_prune();
+        if let Some(prune) = prune.filter(|i| i.is_selected()) {
+            self.gui_state.lock().set_prune(None);
+            self.docker_tx
+                .send(DockerMessage::Prune {
+                    containers: prune.containers,
+                    images: prune.images,
+                    volumes: prune.volumes,
+                })
+                .await
+                .ok();
+        }
+    }
+
+    /// Handle keys whilst the prune popup is visible
+    async fn prune_key(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Enter => self.confirm_prune().await,
+            KeyCode::Esc => self.gui_state.lock().set_prune(None),
+            KeyCode::Char('c' | 'C') => self.gui_state.lock().prune_toggle(PruneTarget::Containers),
+            KeyCode::Char('i' | 'I') => self.gui_state.lock().prune_toggle(PruneTarget::Images),
+            KeyCode::Char('v' | 'V') => self.gui_state.lock().prune_toggle(PruneTarget::Volumes),
+            _ => (),
+        }
+    }
+
+    /// Handle keys whilst the log search query is being typed, Tab toggles between a plain and a regex search
+    fn search_key(&self, key_code: KeyCode, key_modifier: KeyModifiers) {
+        match key_code {
+            KeyCode::Enter => {
+                self.gui_state.lock().finish_log_search();
+                self.search_jump(true);
+            }
+            KeyCode::Esc => self.gui_state.lock().clear_log_search(),
+            KeyCode::Tab => {
+                if let Some(search) = self.gui_state.lock().get_log_search_mut() {
+                    search.toggle_regex();
+                }
+            }
+            KeyCode::Backspace => {
+                if let Some(search) = self.gui_state.lock().get_log_search_mut() {
+                    search.pop();
+                }
+            }
+            KeyCode::Char(c) if key_modifier != KeyModifiers::CONTROL => {
+                if let Some(search) = self.gui_state.lock().get_log_search_mut() {
+                    search.push(c);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Open the container filter input, an existing filter term can then be edited
+    fn f_key(&self) {
+        self.app_data.lock().filter_start();
+        self.gui_state.lock().status_push(Status::FilterContainers);
+    }
+
+    /// Open the container search input, an existing search term can then be edited
+    fn container_search_open(&self) {
+        self.app_data.lock().search_start();
+        self.gui_state.lock().status_push(Status::SearchContainers);
+    }
+
+    /// Handle keys whilst the container search is being typed, the selection jumps to the first match as each char is typed.
+    /// Enter keeps the search, so that the matches can be cycled through, Esc removes it
+    fn container_search_key(&self, key_code: KeyCode, key_modifier: KeyModifiers) {
+        match key_code {
+            KeyCode::Enter => {
+                if !self.app_data.lock().has_search() {
+                    self.app_data.lock().search_clear();
+                }
+                self.gui_state.lock().status_del(Status::SearchContainers);
+            }
+            KeyCode::Esc => {
+                self.app_data.lock().search_clear();
+                self.gui_state.lock().status_del(Status::SearchContainers);
+            }
+            KeyCode::Backspace => self.app_data.lock().search_pop(),
+            KeyCode::Char(c) if key_modifier != KeyModifiers::CONTROL => {
+                self.app_data.lock().search_push(c);
+            }
+            _ => (),
+        }
+    }
+
+    /// Handle keys whilst the go to input is open, Enter selects the container at the typed row, Esc closes the input without moving
+    fn go_to_key(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Enter => {
+                let row = self.gui_state.lock().go_to_finish();
+                if let Some(row) = row {
+                    self.app_data.lock().containers_go_to(row);
+                }
+            }
+            KeyCode::Esc => {
+                self.gui_state.lock().go_to_finish();
+            }
+            KeyCode::Backspace => self.gui_state.lock().go_to_pop(),
+            KeyCode::Char(c) => self.gui_state.lock().go_to_push(c),
+            _ => (),
+        }
+    }
+
+    /// Handle keys whilst the container filter is being typed, Enter keeps the filter, Esc removes it
+    fn filter_key(&self, key_code: KeyCode, key_modifier: KeyModifiers) {
+        match key_code {
+            KeyCode::Enter => {
+                let is_empty = self
+                    .app_data
+                    .lock()
+                    .get_filter_term()
+                    .is_none_or(String::is_empty);
+                if is_empty {
+                    self.app_data.lock().filter_clear();
+                }
+                self.gui_state.lock().status_del(Status::FilterContainers);
+            }
+            KeyCode::Esc => {
+                self.app_data.lock().filter_clear();
+                self.gui_state.lock().status_del(Status::FilterContainers);
+            }
+            KeyCode::Backspace => self.app_data.lock().filter_pop(),
+            KeyCode::Char(c) if key_modifier != KeyModifiers::CONTROL => {
+                self.app_data.lock().filter_push(c);
+            }
+            _ => (),
+        }
+    }
+
+    /// Jump to the next, or previous, log line that matches the current search.
+    /// A container search takes priority, in which case the next, or previous, matching container is selected instead
+    fn search_jump(&self, next: bool) {
+        if self.app_data.lock().has_search() {
+            self.app_data.lock().search_next(next);
+            return;
+        }
+        let search = self.gui_state.lock().get_log_search();
+        if let Some(search) = search {
+            if next {
+                self.app_data.lock().log_next_match(&search);
+            } else {
+                self.app_data.lock().log_previous_match(&search);
+            }
         }
     }
 
     /// Send docker command, if the Commands panel is selected
-    async fn enter_key(&mut self) {
+    async fn enter_key(&self) {
         // This isn't great, just means you can't send docker commands before full initialization of the program
         let panel = self.gui_state.lock().get_selected_panel();
-        if panel == SelectablePanel::Commands {
+        if panel == SelectablePanel::Commands && !self.reject_if_read_only() {
             let option_command = self.app_data.lock().selected_docker_controls();
 
             if let Some(command) = option_command {
                 // Poor way of disallowing commands to be sent to a containerised okxer
-                if self.app_data.lock().is_oxker_in_container() {
+                if self.send_marked(command).await || self.selected_is_oxker() {
                     return;
-                };
+                }
+                // A collapsed group only has the start, stop, & restart commands, which are applied to every container in the group
+                let group_ids = self.app_data.lock().get_selected_group_ids();
+                if let Some(ids) = group_ids {
+                    self.send_or_confirm(ConfirmControl {
+                        batch: true,
+                        control: command,
+                        ids,
+                        signal: None,
+                    })
+                    .await;
+                    return;
+                }
                 let option_id = self.app_data.lock().get_selected_container_id();
                 if let Some(id) = option_id {
                     match command {
-                        DockerControls::Delete => self
-                            .docker_tx
-                            .send(DockerMessage::ConfirmDelete(id))
-                            .await
-                            .ok(),
-                        DockerControls::Pause => {
-                            self.docker_tx.send(DockerMessage::Pause(id)).await.ok()
-                        }
-                        DockerControls::Resume => {
-                            self.docker_tx.send(DockerMessage::Resume(id)).await.ok()
+                        DockerControls::Kill => {
+                            self.gui_state
+                                .lock()
+                                .set_kill_signal(Some(KillSignal::new(id)));
                         }
-                        DockerControls::Start => {
-                            self.docker_tx.send(DockerMessage::Start(id)).await.ok()
+                        DockerControls::Delete => {
+                            self.docker_tx
+                                .send(DockerMessage::ConfirmDelete(id))
+                                .await
+                                .ok();
                         }
-                        DockerControls::Stop => {
-                            self.docker_tx.send(DockerMessage::Stop(id)).await.ok()
+                        control => {
+                            self.send_or_confirm(ConfirmControl {
+                                batch: false,
+                                control,
+                                ids: vec![id],
+                                signal: None,
+                            })
+                            .await;
                         }
-                        DockerControls::Restart => {
-                            self.docker_tx.send(DockerMessage::Restart(id)).await.ok()
-                        }
-                    };
+                    }
                 }
             }
         }
     }
 
+    /// If any containers are marked, send a start, stop, restart, or delete, command to all of them, and then unmark them.
+    /// Returns false if nothing was sent, so the command should be applied to the selected container instead
+    async fn send_marked(&self, control: DockerControls) -> bool {
+        if !matches!(
+            control,
+            DockerControls::Start
+                | DockerControls::Stop
+                | DockerControls::Restart
+                | DockerControls::Delete
+        ) {
+            return false;
+        }
+        let marked_ids = self.app_data.lock().get_marked_ids();
+        if let Some(ids) = marked_ids {
+            self.app_data.lock().clear_marked();
+            self.send_or_confirm(ConfirmControl {
+                batch: true,
+                control,
+                ids,
+                signal: None,
+            })
+            .await;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Send a command to one or more containers, when "--confirm-destructive" is set a stop, restart, kill, or delete, is instead held in the confirmation popup.
+    /// Oxker's own container is always removed from the containers, e.g. when it's marked, or in a collapsed group
+    async fn send_or_confirm(&self, mut pending: ConfirmControl) {
+        let len = pending.ids.len();
+        pending
+            .ids
+            .retain(|id| !self.app_data.lock().is_oxker_in_container_by_id(id));
+        if pending.ids.len() != len {
+            self.gui_state.lock().set_info_box(SELF_SKIPPED);
+        }
+        if pending.ids.is_empty() {
+            return;
+        }
+        if pending.control.is_destructive() && self.app_data.lock().args.confirm_destructive {
+            self.gui_state.lock().set_confirm_control(Some(pending));
+        } else {
+            self.send_confirmed(pending).await;
+        }
+    }
+
+    /// Send a command, either as a batch, or to a single container, a single delete is never sent here, as it goes via the Delete Confirm dialog
+    async fn send_confirmed(&self, pending: ConfirmControl) {
+        let ConfirmControl {
+            batch,
+            control,
+            ids,
+            signal,
+        } = pending;
+        let message = match (batch, ids.as_slice()) {
+            (false, [id]) => {
+                let id = id.clone();
+                match control {
+                    DockerControls::Kill => DockerMessage::Kill {
+                        id,
+                        signal: signal.unwrap_or_default(),
+                    },
+                    DockerControls::Pause => DockerMessage::Pause(id),
+                    DockerControls::Unpause => DockerMessage::Unpause(id),
+                    DockerControls::Start => DockerMessage::Start(id),
+                    DockerControls::Stop => DockerMessage::Stop(id),
+                    DockerControls::Restart => DockerMessage::Restart(id),
+                    DockerControls::Delete => DockerMessage::ConfirmDelete(id),
+                    DockerControls::Recreate => DockerMessage::Recreate(id),
+                }
+            }
+            _ => DockerMessage::Batch { control, ids },
+        };
+        self.docker_tx.send(message).await.ok();
+    }
+
+    /// Handle keys whilst the confirmation popup is visible
+    async fn confirm_control_key(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('y' | 'Y') => {
+                let pending = self.gui_state.lock().get_confirm_control();
+                self.gui_state.lock().set_confirm_control(None);
+                if let Some(pending) = pending {
+                    self.send_confirmed(pending).await;
+                }
+            }
+            KeyCode::Esc | KeyCode::Char('n' | 'N') => {
+                self.gui_state.lock().set_confirm_control(None);
+            }
+            _ => (),
+        }
+    }
+
+    /// Send a start, stop, or restart, command to the marked containers, the selected container, or collapsed group, directly from a key press.
+    /// Is a no-op if the command isn't available for the current state of the container
+    async fn send_control(&self, control: DockerControls) {
+        if self.send_marked(control).await || self.selected_is_oxker() {
+            return;
+        }
+        let group_ids = self.app_data.lock().get_selected_group_ids();
+        if let Some(ids) = group_ids {
+            self.send_or_confirm(ConfirmControl {
+                batch: true,
+                control,
+                ids,
+                signal: None,
+            })
+            .await;
+            return;
+        }
+        let container = self.app_data.lock().get_selected_container_id_state_name();
+        if let Some((id, state, _)) = container {
+            if DockerControls::gen_vec(state).contains(&control) {
+                self.send_or_confirm(ConfirmControl {
+                    batch: false,
+                    control,
+                    ids: vec![id],
+                    signal: None,
+                })
+                .await;
+            }
+        }
+    }
+
     /// Change the the "next" selectable panel
-    fn tab_key(&mut self) {
+    fn tab_key(&self) {
         let is_containers =
             self.gui_state.lock().get_selected_panel() == SelectablePanel::Containers;
         let count = if self.app_data.lock().get_container_len() == 0 && is_containers {
@@ -320,7 +1532,7 @@ impl InputHandler {
     }
 
     /// Change to previously selected panel
-    fn back_tab_key(&mut self) {
+    fn back_tab_key(&self) {
         let is_containers = self.gui_state.lock().get_selected_panel() == SelectablePanel::Logs;
         let count = if self.app_data.lock().get_container_len() == 0 && is_containers {
             2
@@ -332,7 +1544,7 @@ impl InputHandler {
         }
     }
 
-    fn home_key(&mut self) {
+    fn home_key(&self) {
         let mut locked_data = self.app_data.lock();
         let selected_panel = self.gui_state.lock().get_selected_panel();
         match selected_panel {
@@ -343,7 +1555,7 @@ impl InputHandler {
     }
 
     /// Go to end of the list of the currently selected panel
-    fn end_key(&mut self) {
+    fn end_key(&self) {
         let mut locked_data = self.app_data.lock();
         let selected_panel = self.gui_state.lock().get_selected_panel();
         match selected_panel {
@@ -354,6 +1566,7 @@ impl InputHandler {
     }
 
     /// Handle keyboard button events
+    #[allow(clippy::too_many_lines)]
     async fn button_press(&mut self, key_code: KeyCode, key_modifier: KeyModifiers) {
         let contains_delete = self
             .gui_state
@@ -362,16 +1575,58 @@ impl InputHandler {
 
         let contains = |s: Status| self.gui_state.lock().status_contains(&[s]);
 
+        let contains_connect = contains(Status::DockerConnect);
         let contains_error = contains(Status::Error);
         let contains_help = contains(Status::Help);
+        let contains_cheatsheet = contains(Status::Cheatsheet);
         let contains_exec = contains(Status::Exec);
+        let contains_kill = contains(Status::KillSignal);
+        let contains_search = contains(Status::SearchLogs);
+        let contains_save = contains(Status::SaveLogs);
+        let contains_rename = contains(Status::Rename);
+        let contains_restart_policy = contains(Status::RestartPolicy);
+        let contains_log_since = contains(Status::LogSince);
+        let contains_limits = contains(Status::Limits);
+        let contains_filter = contains(Status::FilterContainers);
+        let contains_container_search = contains(Status::SearchContainers);
+        let contains_go_to = contains(Status::GoTo);
+        let contains_inspect = contains(Status::Inspect);
+        let contains_network_inspect = contains(Status::NetworkInspect);
+        let contains_env = contains(Status::Env);
+        let contains_labels = contains(Status::Labels);
+        let contains_confirm = contains(Status::ConfirmControl);
+        let contains_mounts = contains(Status::Mounts);
+        let contains_run_command = contains(Status::RunCommand);
+        let contains_pull = contains(Status::Pull);
+        let contains_batch = contains(Status::Batch);
+        let contains_prune = contains(Status::Prune);
+        let contains_log_copy = contains(Status::LogCopy);
+        let contains_quit_confirm = contains(Status::QuitConfirm);
 
         if !contains_exec {
-            // Always just quit on Ctrl + c/C or q/Q
+            // Always just quit on Ctrl + c/C, or the quit key, the quit key is ignored when text can be typed.
+            // The quit key asks for confirmation if a Docker command is still being processed, pressing it again quits anyway
             let is_c = || key_code == KeyCode::Char('c') || key_code == KeyCode::Char('C');
-            let is_q = || key_code == KeyCode::Char('q') || key_code == KeyCode::Char('Q');
-            if key_modifier == KeyModifiers::CONTROL && is_c() || is_q() {
-                self.quit().await;
+            let is_quit = self.keymap.is_action(Action::Quit, key_code, key_modifier);
+            if key_modifier == KeyModifiers::CONTROL && is_c() {
+                self.quit(true).await;
+            } else if is_quit
+                && !contains_kill
+                && !contains_search
+                && !contains_save
+                && !contains_rename
+                && !contains_log_since
+                && !contains_limits
+                && !contains_filter
+                && !contains_container_search
+                && !contains_go_to
+            {
+                self.quit(contains_quit_confirm).await;
+            }
+
+            // Whilst reconnecting to Docker, only quitting is possible
+            if contains_connect {
+                return;
             }
 
             if contains_error {
@@ -383,60 +1638,320 @@ impl InputHandler {
                     _ => (),
                 }
             } else if contains_help {
-                match key_code {
-                    KeyCode::Esc | KeyCode::Char('h' | 'H') => {
+                match self.keymap.get_action(key_code, key_modifier) {
+                    Some(Action::Clear | Action::Help) => {
                         self.gui_state.lock().status_del(Status::Help);
                     }
-                    KeyCode::Char('m' | 'M') => self.m_key(),
+                    Some(Action::MouseCapture) => self.m_key(),
                     _ => (),
                 }
+            } else if contains_cheatsheet {
+                self.cheatsheet_key(key_code, key_modifier);
+            } else if contains_quit_confirm {
+                self.quit_confirm_key(key_code).await;
+            } else if contains_confirm {
+                self.confirm_control_key(key_code).await;
             } else if contains_delete {
                 match key_code {
-                    KeyCode::Char('y' | 'Y') => self.confirm_delete().await,
+                    KeyCode::Char('y' | 'Y') => self.confirm_delete(false).await,
+                    KeyCode::Char('f' | 'F') => self.confirm_delete(true).await,
                     KeyCode::Esc | KeyCode::Char('n' | 'N') => self.clear_delete(),
                     _ => (),
                 }
-            } else {
-                match key_code {
-                    KeyCode::Char('0') => self.app_data.lock().reset_sorted(),
-                    KeyCode::Char('1') => self.sort(Header::Name),
-                    KeyCode::Char('2') => self.sort(Header::State),
-                    KeyCode::Char('3') => self.sort(Header::Status),
-                    KeyCode::Char('4') => self.sort(Header::Cpu),
-                    KeyCode::Char('5') => self.sort(Header::Memory),
-                    KeyCode::Char('6') => self.sort(Header::Id),
-                    KeyCode::Char('7') => self.sort(Header::Image),
-                    KeyCode::Char('8') => self.sort(Header::Rx),
-                    KeyCode::Char('9') => self.sort(Header::Tx),
-                    KeyCode::Char('e' | 'E') => self.e_key().await,
-                    KeyCode::Char('h' | 'H') => self.gui_state.lock().status_push(Status::Help),
-                    KeyCode::Char('m' | 'M') => self.m_key(),
-                    KeyCode::Char('s' | 'S') => self.s_key().await,
-                    KeyCode::Tab => self.tab_key(),
-                    KeyCode::BackTab => self.back_tab_key(),
-                    KeyCode::Home => self.home_key(),
-                    KeyCode::End => self.end_key(),
-                    KeyCode::Up | KeyCode::Char('k' | 'K') => self.previous(),
-                    KeyCode::PageUp => {
-                        for _ in 0..=6 {
-                            self.previous();
-                        }
-                    }
-                    KeyCode::Down | KeyCode::Char('j' | 'J') => self.next(),
-                    KeyCode::PageDown => {
-                        for _ in 0..=6 {
-                            self.next();
-                        }
-                    }
-                    KeyCode::Enter => self.enter_key().await,
-                    _ => (),
+            } else if contains_inspect {
+                self.inspect_key(key_code);
+            } else if contains_network_inspect {
+                self.network_inspect_key(key_code);
+            } else if contains_env {
+                self.env_key(key_code);
+            } else if contains_labels {
+                self.labels_key(key_code);
+            } else if contains_mounts {
+                self.mounts_key(key_code);
+            } else if contains_run_command {
+                self.run_command_key(key_code).await;
+            } else if contains_pull {
+                self.pull_key(key_code);
+            } else if contains_batch {
+                // Closing the panel doesn't stop the batch, any failure is then shown as an error once it's finished
+                if key_code == KeyCode::Esc {
+                    self.gui_state.lock().set_batch(None);
+                }
+            } else if contains_prune {
+                self.prune_key(key_code).await;
+            } else if contains_restart_policy {
+                self.restart_policy_key(key_code, key_modifier).await;
+            } else if contains_log_copy {
+                self.log_copy_key(key_code, key_modifier).await;
+            } else if contains_container_search {
+                self.container_search_key(key_code, key_modifier);
+            } else if contains_go_to {
+                self.go_to_key(key_code);
+            } else if contains_filter {
+                self.filter_key(key_code, key_modifier);
+            } else if contains_save {
+                self.save_logs_key(key_code, key_modifier);
+            } else if contains_rename {
+                self.rename_key(key_code, key_modifier).await;
+            } else if contains_log_since {
+                self.log_since_key(key_code, key_modifier);
+            } else if contains_limits {
+                self.limits_key(key_code, key_modifier).await;
+            } else if contains_search {
+                self.search_key(key_code, key_modifier);
+            } else if contains_kill {
+                self.kill_key(key_code, key_modifier).await;
+            } else if let Some(action) = self.keymap.get_action(key_code, key_modifier) {
+                if action.is_mutating() && self.reject_if_read_only() {
+                    return;
+                }
+                let view = self.gui_state.lock().get_view();
+                match view {
+                    View::Containers => self.action(action).await,
+                    View::Images => self.image_action(action).await,
+                    View::Networks => self.network_action(action).await,
+                    View::Volumes => self.volume_action(action).await,
                 }
             }
         }
     }
 
+    /// In read-only mode no command that changes Docker is sent, so show a warning, and return true, to reject it
+    fn reject_if_read_only(&self) -> bool {
+        let read_only = self.app_data.lock().args.read_only;
+        if read_only {
+            self.gui_state
+                .lock()
+                .set_info_box("✖ read-only, unable to change containers");
+        }
+        read_only
+    }
+
+    /// Execute an action, triggered by key presses when no popup or text input is visible
+    #[allow(clippy::too_many_lines)]
+    async fn action(&mut self, action: Action) {
+        match action {
+            Action::SortReset => self.app_data.lock().reset_sorted(),
+            Action::SortName => self.sort(Header::Name),
+            Action::SortState => self.sort(Header::State),
+            Action::SortStatus => self.sort(Header::Status),
+            Action::SortCpu => self.sort(Header::Cpu),
+            Action::SortMemory => self.sort(Header::Memory),
+            Action::SortId => self.sort(Header::Id),
+            Action::SortImage => self.sort(Header::Image),
+            Action::SortRx => self.sort(Header::Rx),
+            Action::SortTx => self.sort(Header::Tx),
+            Action::SortCycle => self.app_data.lock().cycle_sort_header(),
+            Action::SortReverse => self.app_data.lock().reverse_sort_order(),
+            Action::Exec => self.e_key().await,
+            Action::Filter => self.f_key(),
+            Action::ContainerSearch => self.container_search_open(),
+            Action::StateFilter => self.app_data.lock().cycle_state_filter(),
+            Action::Group => self.app_data.lock().toggle_grouped(),
+            Action::GroupCollapse => self.app_data.lock().toggle_group_collapsed(),
+            Action::Help => self.gui_state.lock().status_push(Status::Help),
+            Action::Cheatsheet => self.cheatsheet_open(),
+            Action::CopyId => self.copy_key(false).await,
+            Action::CopyName => self.copy_key(true).await,
+            Action::CopyLogs => self.copy_logs(false).await,
+            Action::CopyAllLogs => self.copy_logs(true).await,
+            Action::Detail => self.gui_state.lock().toggle_detail(),
+            Action::History => self.gui_state.lock().toggle_history(),
+            Action::Summary => self.app_data.lock().cycle_summary(),
+            Action::LogsFullscreen => self.gui_state.lock().toggle_logs_fullscreen(),
+            Action::LogWrap => self.gui_state.lock().toggle_log_wrap(),
+            Action::LogPause => self.app_data.lock().toggle_log_stream(),
+            Action::LogCopy => self.log_copy_open(),
+            Action::LogMarker => {
+                if self.app_data.lock().get_selected_container_id().is_none() {
+                    return;
+                }
+                let marker = self.app_data.lock().cycle_log_marker();
+                let info = match marker {
+                    Some(LogMarker::Set(_)) => "log marker set",
+                    Some(LogMarker::Only(_)) => "showing only the logs since the marker",
+                    None => "log marker cleared",
+                };
+                self.gui_state.lock().set_info_box(info);
+            }
+            Action::LogSeverity => self.gui_state.lock().toggle_log_severity(),
+            Action::LogSince => self.log_since_open(),
+            Action::Inspect => self.i_key().await,
+            Action::Images => self.images_key().await,
+            Action::Networks => self.networks_key().await,
+            Action::Volumes => self.volumes_key().await,
+            Action::Env => self.a_key().await,
+            Action::Labels => self.labels_open().await,
+            Action::Mounts => self.mounts_open(),
+            Action::RunCommand => self.run_command_open().await,
+            Action::MouseCapture => self.m_key(),
+            Action::Pause => self.p_key().await,
+            Action::SaveLogs => self.s_key(),
+            Action::Timestamps => self.t_key(),
+            Action::Unpause => self.u_key().await,
+            Action::Kill => self.x_key(),
+            Action::Prune => self.prune_open(),
+            Action::Rename => self.rename_open(),
+            Action::RestartPolicy => self.restart_policy_open(),
+            Action::Limits => self.limits_open(),
+            Action::GoTo => self.gui_state.lock().go_to_start(),
+            Action::Recreate => self.recreate_open(),
+            Action::PullImage => self.pull_open().await,
+            Action::Start => self.send_control(DockerControls::Start).await,
+            Action::Stop => self.send_control(DockerControls::Stop).await,
+            Action::Restart => self.send_control(DockerControls::Restart).await,
+            Action::LogSearch => self.gui_state.lock().start_log_search(),
+            Action::SearchNext => self.search_jump(true),
+            Action::SearchPrevious => self.search_jump(false),
+            Action::Clear => {
+                self.gui_state.lock().clear_log_search();
+                self.app_data.lock().search_clear();
+                self.app_data.lock().filter_clear();
+                self.app_data.lock().clear_marked();
+            }
+            Action::Mark => self.app_data.lock().toggle_marked(),
+            Action::MemoryUnit => {
+                let memory_unit = self.app_data.lock().cycle_memory_unit();
+                self.gui_state
+                    .lock()
+                    .set_info_box(&format!("memory unit: {memory_unit}"));
+            }
+            Action::CpuMode => {
+                let cpu_mode = self.app_data.lock().cycle_cpu_mode();
+                self.gui_state
+                    .lock()
+                    .set_info_box(&format!("cpu: {cpu_mode}"));
+            }
+            Action::IdColumn => {
+                let id_mode = self.gui_state.lock().cycle_id_mode();
+                self.gui_state
+                    .lock()
+                    .set_info_box(&format!("id column: {id_mode}"));
+            }
+            Action::LogStreams => {
+                let log_streams = self.app_data.lock().cycle_log_streams();
+                self.gui_state
+                    .lock()
+                    .set_info_box(&format!("log streams: {log_streams}"));
+            }
+            Action::RefreshFaster => self.app_data.lock().interval_faster(),
+            Action::RefreshSlower => self.app_data.lock().interval_slower(),
+            Action::NextPanel => self.tab_key(),
+            Action::PreviousPanel => self.back_tab_key(),
+            Action::ScrollStart => self.home_key(),
+            Action::ScrollEnd => self.end_key(),
+            Action::ScrollUp => self.previous(),
+            Action::ScrollPageUp => self.page(false, false),
+            Action::ScrollHalfPageUp => self.page(false, true),
+            Action::ScrollDown => self.next(),
+            Action::ScrollPageDown => self.page(true, false),
+            Action::ScrollHalfPageDown => self.page(true, true),
+            Action::Select => self.enter_key().await,
+            Action::Delete => self.delete_key().await,
+            // Quit is handled before any popup, in button_press()
+            Action::Quit => (),
+        }
+    }
+
+    /// Execute an action in the images view, actions that only apply to containers are ignored
+    async fn image_action(&mut self, action: Action) {
+        match action {
+            Action::Images => self.images_key().await,
+            Action::Networks => self.networks_key().await,
+            Action::Volumes => self.volumes_key().await,
+            Action::Help => self.gui_state.lock().status_push(Status::Help),
+            Action::Cheatsheet => self.cheatsheet_open(),
+            Action::MouseCapture => self.m_key(),
+            Action::RefreshFaster => self.app_data.lock().interval_faster(),
+            Action::RefreshSlower => self.app_data.lock().interval_slower(),
+            Action::ScrollStart => self.app_data.lock().images_start(),
+            Action::ScrollEnd => self.app_data.lock().images_end(),
+            Action::ScrollUp => self.app_data.lock().images_previous(),
+            Action::ScrollPageUp => {
+                for _ in 0..=6 {
+                    self.app_data.lock().images_previous();
+                }
+            }
+            Action::ScrollDown => self.app_data.lock().images_next(),
+            Action::ScrollPageDown => {
+                for _ in 0..=6 {
+                    self.app_data.lock().images_next();
+                }
+            }
+            Action::Delete => self.delete_image_key(),
+            _ => (),
+        }
+    }
+
+    /// Execute an action in the networks view, actions that only apply to containers are ignored
+    async fn network_action(&mut self, action: Action) {
+        match action {
+            Action::Images => self.images_key().await,
+            Action::Networks => self.networks_key().await,
+            Action::Volumes => self.volumes_key().await,
+            Action::Help => self.gui_state.lock().status_push(Status::Help),
+            Action::Cheatsheet => self.cheatsheet_open(),
+            Action::Inspect => self.inspect_network_key().await,
+            Action::MouseCapture => self.m_key(),
+            Action::RefreshFaster => self.app_data.lock().interval_faster(),
+            Action::RefreshSlower => self.app_data.lock().interval_slower(),
+            Action::ScrollStart => self.app_data.lock().networks_start(),
+            Action::ScrollEnd => self.app_data.lock().networks_end(),
+            Action::ScrollUp => self.app_data.lock().networks_previous(),
+            Action::ScrollPageUp => {
+                for _ in 0..=6 {
+                    self.app_data.lock().networks_previous();
+                }
+            }
+            Action::ScrollDown => self.app_data.lock().networks_next(),
+            Action::ScrollPageDown => {
+                for _ in 0..=6 {
+                    self.app_data.lock().networks_next();
+                }
+            }
+            Action::Delete => self.delete_network_key(),
+            _ => (),
+        }
+    }
+
+    /// Execute an action in the volumes view, prune only removes unused volumes, actions that only apply to containers are ignored
+    async fn volume_action(&mut self, action: Action) {
+        match action {
+            Action::Images => self.images_key().await,
+            Action::Networks => self.networks_key().await,
+            Action::Volumes => self.volumes_key().await,
+            Action::Help => self.gui_state.lock().status_push(Status::Help),
+            Action::Cheatsheet => self.cheatsheet_open(),
+            Action::MouseCapture => self.m_key(),
+            Action::Prune => {
+                let stopped = self.app_data.lock().get_stopped_len();
+                self.gui_state
+                    .lock()
+                    .set_prune(Some(Prune::volumes(stopped)));
+            }
+            Action::RefreshFaster => self.app_data.lock().interval_faster(),
+            Action::RefreshSlower => self.app_data.lock().interval_slower(),
+            Action::ScrollStart => self.app_data.lock().volumes_start(),
+            Action::ScrollEnd => self.app_data.lock().volumes_end(),
+            Action::ScrollUp => self.app_data.lock().volumes_previous(),
+            Action::ScrollPageUp => {
+                for _ in 0..=6 {
+                    self.app_data.lock().volumes_previous();
+                }
+            }
+            Action::ScrollDown => self.app_data.lock().volumes_next(),
+            Action::ScrollPageDown => {
+                for _ in 0..=6 {
+                    self.app_data.lock().volumes_next();
+                }
+            }
+            Action::Delete => self.delete_volume_key(),
+            _ => (),
+        }
+    }
+
     /// Check if a button press interacts with either the yes or no buttons in the delete container confirm window
-    async fn button_intersect(&mut self, mouse_event: MouseEvent) {
+    async fn button_intersect(&self, mouse_event: MouseEvent) {
         if mouse_event.kind == MouseEventKind::Down(MouseButton::Left) {
             let intersect = self.gui_state.lock().button_intersect(Rect::new(
                 mouse_event.column,
@@ -447,7 +1962,7 @@ impl InputHandler {
 
             if let Some(button) = intersect {
                 match button {
-                    DeleteButton::Yes => self.confirm_delete().await,
+                    DeleteButton::Yes => self.confirm_delete(self.delete_requires_force()).await,
                     DeleteButton::No => self.clear_delete(),
                 }
             }
@@ -455,50 +1970,91 @@ impl InputHandler {
     }
 
     /// Handle mouse button events
-    fn mouse_press(&mut self, mouse_event: MouseEvent) {
+    /// Scrolling changes the selected line of the panel under the mouse, or the selected panel if not over any panel.
+    /// Clicking on a container row selects that container
+    fn mouse_press(&self, mouse_event: MouseEvent) {
+        let point = Rect::new(mouse_event.column, mouse_event.row, 1, 1);
         match mouse_event.kind {
-            MouseEventKind::ScrollUp => self.previous(),
-            MouseEventKind::ScrollDown => self.next(),
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => {
+                let panel = {
+                    let gui_state = self.gui_state.lock();
+                    gui_state
+                        .panel_at(point)
+                        .map_or_else(|| gui_state.get_selected_panel(), |(panel, _)| panel)
+                };
+                self.scroll(panel, mouse_event.kind == MouseEventKind::ScrollDown);
+            }
             MouseEventKind::Down(MouseButton::Left) => {
-                if let Some(header) = self.gui_state.lock().header_intersect(Rect::new(
-                    mouse_event.column,
-                    mouse_event.row,
-                    1,
-                    1,
-                )) {
+                let header = self.gui_state.lock().header_intersect(point);
+                if let Some(header) = header {
                     self.sort(header);
                 }
 
-                self.gui_state.lock().panel_intersect(Rect::new(
-                    mouse_event.column,
-                    mouse_event.row,
-                    1,
-                    1,
-                ));
+                self.gui_state.lock().panel_intersect(point);
+                let panel = self.gui_state.lock().panel_at(point);
+                if let Some((SelectablePanel::Containers, area)) = panel {
+                    // The first, and last, rows are the borders
+                    if mouse_event.row > area.y && mouse_event.row < area.bottom().saturating_sub(1)
+                    {
+                        self.app_data
+                            .lock()
+                            .containers_select_row(usize::from(mouse_event.row - area.y - 1));
+                    }
+                }
             }
             _ => (),
         }
     }
 
-    /// Change state to next, depending which panel is currently in focus
-    fn next(&mut self) {
+    /// Change the selected line of a given panel, down or up
+    fn scroll(&self, panel: SelectablePanel, down: bool) {
         let mut locked_data = self.app_data.lock();
+        match (panel, down) {
+            (SelectablePanel::Containers, true) => locked_data.containers_next(),
+            (SelectablePanel::Containers, false) => locked_data.containers_previous(),
+            (SelectablePanel::Logs, true) => locked_data.log_next(),
+            (SelectablePanel::Logs, false) => locked_data.log_previous(),
+            (SelectablePanel::Commands, true) => locked_data.docker_controls_next(),
+            (SelectablePanel::Commands, false) => locked_data.docker_controls_previous(),
+        }
+    }
+
+    /// Change state to next, depending which panel is currently in focus
+    fn next(&self) {
         let selected_panel = self.gui_state.lock().get_selected_panel();
-        match selected_panel {
-            SelectablePanel::Containers => locked_data.containers_next(),
-            SelectablePanel::Logs => locked_data.log_next(),
-            SelectablePanel::Commands => locked_data.docker_controls_next(),
+        self.scroll(selected_panel, true);
+    }
+
+    /// Move a page, or half a page, up or down, depending which panel is currently in focus, a page being 7 items, and half a page 3.
+    /// The logs panel instead moves by the number of visible rows, or half of them, where a soft-wrapped line counts as each of its rows.
+    /// The selected log line is clamped to the first and last lines
+    fn page(&self, down: bool, half: bool) {
+        let log_page = {
+            let gui_state = self.gui_state.lock();
+            if gui_state.get_selected_panel() == SelectablePanel::Logs {
+                gui_state.get_log_page()
+            } else {
+                None
+            }
         };
+        if let Some((rows, width)) = log_page {
+            let rows = if half { (rows / 2).max(1) } else { rows };
+            self.app_data.lock().log_page(down, rows, width);
+        } else {
+            let lines = if half { 3 } else { 7 };
+            for _ in 0..lines {
+                if down {
+                    self.next();
+                } else {
+                    self.previous();
+                }
+            }
+        }
     }
 
     /// Change state to previous, depending which panel is currently in focus
-    fn previous(&mut self) {
-        let mut locked_data = self.app_data.lock();
+    fn previous(&self) {
         let selected_panel = self.gui_state.lock().get_selected_panel();
-        match selected_panel {
-            SelectablePanel::Containers => locked_data.containers_previous(),
-            SelectablePanel::Logs => locked_data.log_previous(),
-            SelectablePanel::Commands => locked_data.docker_controls_previous(),
-        }
+        self.scroll(selected_panel, false);
     }
 }
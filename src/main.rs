@@ -1,13 +1,14 @@
 // Only allow when debugging
 // #![allow(unused)]
 
-use app_data::AppData;
+use app_data::{AppData, Engine, Header};
 use app_error::AppError;
-use bollard::{Docker, API_DEFAULT_VERSION};
-use docker_data::DockerData;
+use config::{Config, Keymap, ViewConfig};
+use docker_data::{Connection, DockerData};
 use input_handler::InputMessages;
 use parking_lot::Mutex;
-use parse_args::CliArgs;
+use parse_args::{CliArgs, TlsPaths};
+use ssh::SshTunnel;
 use std::{
     process,
     sync::{
@@ -16,17 +17,22 @@ use std::{
     },
 };
 use tokio::sync::mpsc::{Receiver, Sender};
-use tracing::{error, info, Level};
+use tracing::{error, Level};
 
 mod app_data;
 mod app_error;
+mod clipboard;
+mod config;
+mod docker_context;
 mod docker_data;
 mod exec;
 mod input_handler;
+mod metrics;
 mod parse_args;
+mod ssh;
 mod ui;
 
-use ui::{GuiState, Status, Ui};
+use ui::{GuiState, IdMode, Status, Ui};
 
 use crate::docker_data::DockerMessage;
 
@@ -39,17 +45,21 @@ const DOCKER_HOST: &str = "DOCKER_HOST";
 /// Enable tracing, only really used in debug mode, for now
 /// write to file if `-g` is set?
 fn setup_tracing() {
-    tracing_subscriber::fmt().with_max_level(Level::INFO).init();
+    tracing_subscriber::fmt()
+        .with_max_level(Level::INFO)
+        .with_writer(std::io::stderr)
+        .init();
 }
 
 /// Read the optional docker_host path, the cli args take priority over the DOCKER_HOST env
 fn read_docker_host(args: &CliArgs) -> Option<String> {
     args.host
         .as_ref()
-        .map_or_else(|| std::env::var(DOCKER_HOST).ok(), |x| Some(x.to_string()))
+        .map_or_else(|| std::env::var(DOCKER_HOST).ok(), |x| Some(x.clone()))
 }
 
-/// Create docker daemon handler, and only spawn up the docker data handler if a ping returns non-error
+/// Create docker daemon handler, and only spawn up the docker data handler if a ping returns non-error, the version response is used to detect if the socket is served by Podman
+/// If the host is an `ssh://` address, the Docker socket is forwarded via ssh, and the returned tunnel must be kept alive for as long as Docker is used
 async fn docker_init(
     app_data: &Arc<Mutex<AppData>>,
     docker_rx: Receiver<DockerMessage>,
@@ -57,30 +67,52 @@ async fn docker_init(
     gui_state: &Arc<Mutex<GuiState>>,
     is_running: &Arc<AtomicBool>,
     host: Option<String>,
-) {
-    let connection = host.map_or_else(Docker::connect_with_socket_defaults, |host| {
-        Docker::connect_with_socket(&host, 120, API_DEFAULT_VERSION)
-    });
+    tls: Option<TlsPaths>,
+) -> Option<SshTunnel> {
+    let set_connect_error = || {
+        app_data
+            .lock()
+            .set_error(AppError::DockerConnect, gui_state, Status::DockerConnect);
+    };
+
+    let tunnel = match host.as_deref().and_then(SshTunnel::destination) {
+        Some(destination) => match SshTunnel::new(destination).await {
+            Ok(tunnel) => Some(tunnel),
+            Err(e) => {
+                error!("{e}");
+                set_connect_error();
+                return None;
+            }
+        },
+        None => None,
+    };
+
+    let connection = match (tunnel.as_ref(), host, tls) {
+        (Some(tunnel), _, _) => Connection::Socket(tunnel.socket().to_string_lossy().into_owned()),
+        (None, Some(host), Some(tls)) => Connection::Ssl { host, tls },
+        (None, Some(host), None) => Connection::Socket(host),
+        (None, None, _) => Connection::Defaults,
+    };
 
-    if let Ok(docker) = connection {
-        if docker.ping().await.is_ok() {
+    if let Ok(docker) = connection.connect() {
+        if Connection::ping(&docker).await {
+            if let Ok(version) = docker.version().await {
+                app_data.lock().set_engine(Engine::new(&version));
+            }
             let app_data = Arc::clone(app_data);
             let gui_state = Arc::clone(gui_state);
             let is_running = Arc::clone(is_running);
 
             tokio::spawn(DockerData::init(
-                app_data, docker, docker_rx, docker_tx, gui_state, is_running,
+                app_data, connection, docker, docker_rx, docker_tx, gui_state, is_running,
             ));
         } else {
-            app_data
-                .lock()
-                .set_error(AppError::DockerConnect, gui_state, Status::DockerConnect);
+            set_connect_error();
         }
     } else {
-        app_data
-            .lock()
-            .set_error(AppError::DockerConnect, gui_state, Status::DockerConnect);
+        set_connect_error();
     }
+    tunnel
 }
 
 /// Create data for, and then spawn a tokio thread, for the input handler
@@ -90,6 +122,8 @@ fn handler_init(
     gui_state: &Arc<Mutex<GuiState>>,
     input_rx: Receiver<InputMessages>,
     is_running: &Arc<AtomicBool>,
+    keymap: Keymap,
+    mouse: bool,
 ) {
     let app_data = Arc::clone(app_data);
     let gui_state = Arc::clone(gui_state);
@@ -100,6 +134,8 @@ fn handler_init(
         docker_sx.clone(),
         gui_state,
         is_running,
+        keymap,
+        mouse,
     ));
 }
 
@@ -108,6 +144,7 @@ async fn main() {
     setup_tracing();
 
     let args = CliArgs::new();
+    let config = Config::new(&args);
 
     // If running via Docker image, need to sleep else program will just quit straight away, no real idea why
     // So just sleep for small while
@@ -116,75 +153,166 @@ async fn main() {
     }
     let host = read_docker_host(&args);
 
-    let app_data = Arc::new(Mutex::new(AppData::default(args.clone())));
-    let gui_state = Arc::new(Mutex::new(GuiState::default()));
+    let app_data = Arc::new(Mutex::new(AppData::default(args.clone(), config.theme)));
+    let gui_state = Arc::new(Mutex::new(GuiState::new(config.log_severity)));
+    app_data.lock().restore_view(&config.view);
+    if !config.columns.contains(&Header::Id) {
+        gui_state.lock().set_id_mode(IdMode::Hidden);
+    }
+    app_data.lock().set_visible_columns(config.columns);
+    app_data
+        .lock()
+        .set_memory_unit(args.memory_unit.unwrap_or(config.memory_unit));
+    gui_state.lock().restore_view(&config.view);
+    app_data
+        .lock()
+        .set_shells(args.shell.iter().cloned().chain(config.shells).collect());
+    app_data.lock().set_time_format(config.time_format);
     let is_running = Arc::new(AtomicBool::new(true));
     let (docker_tx, docker_rx) = tokio::sync::mpsc::channel(32);
 
-    docker_init(
+    // The docker data handler sets this itself, but the json output mustn't print before the handler has started
+    if !args.gui {
+        gui_state.lock().status_push(Status::Init);
+    }
+
+    let tunnel = docker_init(
         &app_data,
         docker_rx,
         docker_tx.clone(),
         &gui_state,
         &is_running,
         host,
+        args.tls.clone(),
     )
     .await;
 
+    if let Some(port) = args.metrics_port {
+        tokio::spawn(metrics::serve(
+            Arc::clone(&app_data),
+            Arc::clone(&gui_state),
+            Arc::clone(&is_running),
+            port,
+            args.in_container,
+        ));
+    }
+
     if args.gui {
         let (input_tx, input_rx) = tokio::sync::mpsc::channel(32);
-        handler_init(&app_data, &docker_tx, &gui_state, input_rx, &is_running);
-        Ui::create(app_data, gui_state, input_tx, is_running).await;
+        handler_init(
+            &app_data,
+            &docker_tx,
+            &gui_state,
+            input_rx,
+            &is_running,
+            config.keymap,
+            config.mouse,
+        );
+        Ui::create(
+            Arc::clone(&app_data),
+            Arc::clone(&gui_state),
+            input_tx,
+            is_running,
+            config.mouse,
+        )
+        .await;
+        if let Some(path) = config.path.as_ref() {
+            ViewConfig::new(&app_data.lock(), &gui_state.lock()).save(path);
+        }
     } else {
-        info!("in debug mode\n");
-        // Debug mode for testing, less pointless now, will display some basic information
-        while is_running.load(Ordering::SeqCst) {
-            if let Some(err) = app_data.lock().get_error() {
-                error!("{}", err);
-                process::exit(1);
-            }
-            tokio::time::sleep(std::time::Duration::from_millis(u64::from(
-                args.docker_interval,
-            )))
-            .await;
-            let containers = app_data
-                .lock()
-                .get_container_items()
-                .iter()
-                .map(|i| format!("{i}"))
-                .collect::<Vec<_>>();
-
-            if !containers.is_empty() {
-                for item in containers {
-                    info!("{item}");
-                }
-                println!();
-            }
+        print_containers(&app_data, &gui_state, &is_running, args.watch, tunnel).await;
+    }
+}
+
+/// The `--format json` output, wait for the initial container data to be loaded, then print every container, and its current stats, as a JSON array.
+/// With `--watch` a new array is printed, one per line, every docker update interval, until oxker is killed
+async fn print_containers(
+    app_data: &Arc<Mutex<AppData>>,
+    gui_state: &Arc<Mutex<GuiState>>,
+    is_running: &Arc<AtomicBool>,
+    watch: bool,
+    tunnel: Option<SshTunnel>,
+) {
+    let interval = u64::from(app_data.lock().args.docker_interval);
+    while is_running.load(Ordering::SeqCst) {
+        let err = app_data.lock().get_error();
+        if let Some(err) = err {
+            error!("{}", err);
+            // process::exit() doesn't run destructors, so close the ssh tunnel first
+            drop(tunnel);
+            process::exit(1);
         }
+        if gui_state.lock().status_contains(&[Status::Init]) {
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            continue;
+        }
+        let output = app_data.lock().get_container_output();
+        match serde_json::to_string(&output) {
+            Ok(json) => println!("{json}"),
+            Err(e) => error!("unable to serialize containers: {e}"),
+        }
+        if !watch {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(interval)).await;
     }
 }
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::many_single_char_names, unused)]
 mod tests {
+    use std::{
+        collections::{HashMap, HashSet},
+        sync::Arc,
+    };
+
     use bollard::service::{ContainerSummary, Port};
 
     use crate::{
-        app_data::{AppData, ContainerId, ContainerItem, ContainerPorts, State, StatefulList},
+        app_data::{
+            AppData, ContainerId, ContainerItem, ContainerPorts, CpuMode, Engine, LogStream,
+            LogStreamFilter, MemoryUnit, MockClock, Ping, State, StateFilter, StatefulList,
+            Summary, TimeFormat, TimeZone, DEFAULT_COLUMNS,
+        },
+        config::{Theme, ThemeName},
         parse_args::CliArgs,
     };
 
-    pub const fn gen_args() -> CliArgs {
+    /// The time of the mock clock of the generated AppData, 2023-11-14T22:13:20Z
+    pub const NOW: i64 = 1_700_000_000;
+
+    pub fn gen_args() -> CliArgs {
         CliArgs {
+            attach: false,
             color: false,
+            config: None,
+            confirm_destructive: false,
+            cpu_alert: None,
             docker_interval: 1000,
+            fresh: 30,
             gui: true,
+            watch: false,
+            history: 60,
             host: None,
             in_container: false,
+            self_id: None,
+            log_max_lines: 5000,
+            log_tail: Some(1000),
+            mem_alert: None,
+            memory_unit: None,
+            metrics_port: None,
             save_dir: None,
             raw: false,
+            read_only: false,
+            shell: None,
             show_self: false,
+            since: None,
+            sort: None,
+            sort_desc: false,
+            state: None,
+            theme: ThemeName::Dark,
             timestamp: false,
+            tls: None,
             use_cli: false,
         }
     }
@@ -200,17 +328,52 @@ mod tests {
                 ip: None,
                 private: u16::try_from(index).unwrap_or(1) + 8000,
                 public: None,
+                protocol: None,
             }],
             State::Running,
             format!("Up {index} hour"),
         )
     }
 
+    /// Log lines, as if they were all written to stdout
+    pub fn gen_stdout(logs: Vec<String>) -> Vec<(LogStream, String)> {
+        logs.into_iter().map(|i| (LogStream::Stdout, i)).collect()
+    }
+
     pub fn gen_appdata(containers: &[ContainerItem]) -> AppData {
         AppData {
             containers: StatefulList::new(containers.to_vec()),
+            hidden_containers: vec![],
+            selected_container: None,
+            filter_term: None,
+            search_term: None,
+            state_filter: StateFilter::All,
+            summary: Summary::Hidden,
+            grouped: false,
+            collapsed_groups: HashSet::new(),
+            marked: HashSet::new(),
+            images: StatefulList::new(vec![]),
+            image_sizes: HashMap::new(),
+            networks: StatefulList::new(vec![]),
+            network_inspect: None,
+            volumes: StatefulList::new(vec![]),
+            docker_interval: 1000,
+            host_memory: 0,
+            engine: Engine::Docker,
+            ping: Ping::default(),
+            time_format: TimeFormat::new(None, TimeZone::Utc),
+            redraw: true,
             error: None,
             sorted_by: None,
+            logs_with_timestamps: false,
+            log_streams: LogStreamFilter::All,
+            log_since: None,
+            cpu_mode: CpuMode::default(),
+            memory_unit: MemoryUnit::default(),
+            shells: vec![],
+            theme: Theme::default(),
+            visible_columns: DEFAULT_COLUMNS.to_vec(),
+            clock: Arc::new(MockClock::new(NOW)),
             args: gen_args(),
         }
     }
@@ -0,0 +1,296 @@
+use std::{
+    fmt::Write,
+    net::{Ipv4Addr, SocketAddr},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use parking_lot::Mutex;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+use tracing::error;
+
+use crate::{
+    app_data::{AppData, ContainerItem, Stats},
+    app_error::AppError,
+    ui::{GuiState, Status},
+};
+
+const PATH: &str = "/metrics";
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+/// How often the accept loop checks if oxker is still running
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+/// How long a client has to send its request, before the connection is dropped
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The response to a request, only a GET of the metrics path is served
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Route {
+    Metrics,
+    NotFound,
+    MethodNotAllowed,
+}
+
+impl Route {
+    /// Route from the request line, e.g. `GET /metrics HTTP/1.1`, any query string is ignored
+    fn new(request: &str) -> Self {
+        let mut parts = request
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .split_whitespace();
+        let method = parts.next().unwrap_or_default();
+        let path = parts
+            .next()
+            .unwrap_or_default()
+            .split('?')
+            .next()
+            .unwrap_or_default();
+        if path != PATH {
+            Self::NotFound
+        } else if method == "GET" {
+            Self::Metrics
+        } else {
+            Self::MethodNotAllowed
+        }
+    }
+
+    const fn status(self) -> &'static str {
+        match self {
+            Self::Metrics => "200 OK",
+            Self::NotFound => "404 Not Found",
+            Self::MethodNotAllowed => "405 Method Not Allowed",
+        }
+    }
+}
+
+/// A metric family, the name, type, help text, and the sample of a container, if known
+type Family = (
+    &'static str,
+    &'static str,
+    &'static str,
+    fn(&ContainerItem) -> Option<f64>,
+);
+
+/// Escape a label value, as required by the Prometheus text format
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Render the most recent stats of every container in the Prometheus text format.
+/// A sample is left out if it's not yet known, e.g. the cpu of a container that has never been running
+pub fn render<'a>(containers: impl Iterator<Item = &'a ContainerItem>) -> String {
+    let containers = containers
+        .map(|i| {
+            (
+                format!(
+                    "id=\"{}\",name=\"{}\",image=\"{}\"",
+                    escape(i.id.get()),
+                    escape(i.name.get()),
+                    escape(i.image.get())
+                ),
+                i,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let families: [Family; 7] = [
+        (
+            "oxker_container_cpu_percent",
+            "gauge",
+            "Cpu usage of the container, where each core is 100",
+            |i| i.cpu_stats.back().map(Stats::get_value),
+        ),
+        (
+            "oxker_container_memory_bytes",
+            "gauge",
+            "Memory usage of the container",
+            |i| i.mem_stats.back().map(Stats::get_value),
+        ),
+        (
+            "oxker_container_memory_limit_bytes",
+            "gauge",
+            "Memory limit of the container, the host memory if unlimited",
+            |i| i.mem_stats.back().map(|_| i.mem_limit.get_value()),
+        ),
+        (
+            "oxker_container_network_receive_bytes_total",
+            "counter",
+            "Bytes received by the container, across every network interface",
+            |i| Some(i.rx.get_value()),
+        ),
+        (
+            "oxker_container_network_transmit_bytes_total",
+            "counter",
+            "Bytes sent by the container, across every network interface",
+            |i| Some(i.tx.get_value()),
+        ),
+        (
+            "oxker_container_block_read_bytes_total",
+            "counter",
+            "Bytes read by the container from block devices",
+            #[allow(clippy::cast_precision_loss)]
+            |i| i.last_io.as_ref().map(|(_, io)| io.blk_read as f64),
+        ),
+        (
+            "oxker_container_block_write_bytes_total",
+            "counter",
+            "Bytes written by the container to block devices",
+            #[allow(clippy::cast_precision_loss)]
+            |i| i.last_io.as_ref().map(|(_, io)| io.blk_write as f64),
+        ),
+    ];
+
+    let mut output = String::new();
+    for (name, kind, help, value) in families {
+        let _ = writeln!(output, "# HELP {name} {help}");
+        let _ = writeln!(output, "# TYPE {name} {kind}");
+        for (labels, item) in &containers {
+            if let Some(value) = value(item) {
+                let _ = writeln!(output, "{name}{{{labels}}} {value}");
+            }
+        }
+    }
+    output
+}
+
+/// Read a single request, and write the response, the connection is always closed afterwards
+async fn respond(mut stream: TcpStream, app_data: Arc<Mutex<AppData>>) {
+    let mut buf = [0; 1024];
+    let Ok(Ok(len)) = tokio::time::timeout(REQUEST_TIMEOUT, stream.read(&mut buf)).await else {
+        return;
+    };
+    let route = Route::new(&String::from_utf8_lossy(&buf[..len]));
+    let body = if route == Route::Metrics {
+        render(app_data.lock().all_containers())
+    } else {
+        format!("{}\n", route.status())
+    };
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {CONTENT_TYPE}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        route.status(),
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await.ok();
+    stream.shutdown().await.ok();
+}
+
+/// Serve the metrics until oxker quits, each connection is handled in its own task, so a slow client can't hold up the others.
+/// Only bound to localhost, unless running in a container, where the port has to be published to be reachable anyway
+pub async fn serve(
+    app_data: Arc<Mutex<AppData>>,
+    gui_state: Arc<Mutex<GuiState>>,
+    is_running: Arc<AtomicBool>,
+    port: u16,
+    in_container: bool,
+) {
+    let ip = if in_container {
+        Ipv4Addr::UNSPECIFIED
+    } else {
+        Ipv4Addr::LOCALHOST
+    };
+    let listener = match TcpListener::bind(SocketAddr::from((ip, port))).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            error!("unable to bind metrics port {port}: {e}");
+            app_data
+                .lock()
+                .set_error(AppError::Metrics(port), &gui_state, Status::Error);
+            return;
+        }
+    };
+    while is_running.load(Ordering::SeqCst) {
+        if let Ok(Ok((stream, _))) = tokio::time::timeout(POLL_INTERVAL, listener.accept()).await {
+            tokio::spawn(respond(stream, Arc::clone(&app_data)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use crate::{
+        app_data::{ByteStats, ContainerId, CpuStats, IoTotals, State},
+        tests::gen_item,
+    };
+
+    use super::{render, Route};
+
+    #[test]
+    /// Only a GET of the metrics path is served, any query string is ignored
+    fn test_metrics_route() {
+        assert_eq!(Route::new("GET /metrics HTTP/1.1\r\n"), Route::Metrics);
+        assert_eq!(
+            Route::new("GET /metrics?name=oxker HTTP/1.1\r\nHost: localhost\r\n"),
+            Route::Metrics
+        );
+        assert_eq!(
+            Route::new("POST /metrics HTTP/1.1\r\n"),
+            Route::MethodNotAllowed
+        );
+        assert_eq!(Route::new("GET / HTTP/1.1\r\n"), Route::NotFound);
+        assert_eq!(Route::new("GET /metricsx HTTP/1.1\r\n"), Route::NotFound);
+        assert_eq!(Route::new(""), Route::NotFound);
+    }
+
+    #[test]
+    /// Every family has a help & type line, samples that aren't known yet are left out, including the memory limit until stats have arrived, and label values are escaped
+    fn test_metrics_render() {
+        let mut item_1 = gen_item(&ContainerId::from("1"), 1);
+        item_1.cpu_stats.push_back(CpuStats::new(12.5));
+        item_1.mem_stats.push_back(ByteStats::new(1024));
+        item_1.mem_limit = ByteStats::new(4096);
+        item_1.rx = ByteStats::new(100);
+        item_1.tx = ByteStats::new(200);
+        item_1.last_io = Some((
+            Instant::now(),
+            IoTotals {
+                net: Some((100, 200)),
+                blk_read: 300,
+                blk_write: 400,
+            },
+        ));
+        let mut item_2 = gen_item(&ContainerId::from("2"), 2);
+        item_2.name = "quote\"d".into();
+        item_2.state = State::Exited;
+
+        let output = render([item_1, item_2].iter());
+        let labels_1 = r#"id="1",name="container_1",image="image_1""#;
+        let labels_2 = r#"id="2",name="quote\"d",image="image_2""#;
+        let expected = [
+            "# HELP oxker_container_cpu_percent Cpu usage of the container, where each core is 100".to_owned(),
+            "# TYPE oxker_container_cpu_percent gauge".to_owned(),
+            format!("oxker_container_cpu_percent{{{labels_1}}} 12.5"),
+            "# HELP oxker_container_memory_bytes Memory usage of the container".to_owned(),
+            "# TYPE oxker_container_memory_bytes gauge".to_owned(),
+            format!("oxker_container_memory_bytes{{{labels_1}}} 1024"),
+            "# HELP oxker_container_memory_limit_bytes Memory limit of the container, the host memory if unlimited".to_owned(),
+            "# TYPE oxker_container_memory_limit_bytes gauge".to_owned(),
+            format!("oxker_container_memory_limit_bytes{{{labels_1}}} 4096"),
+            "# HELP oxker_container_network_receive_bytes_total Bytes received by the container, across every network interface".to_owned(),
+            "# TYPE oxker_container_network_receive_bytes_total counter".to_owned(),
+            format!("oxker_container_network_receive_bytes_total{{{labels_1}}} 100"),
+            format!("oxker_container_network_receive_bytes_total{{{labels_2}}} 0"),
+            "# HELP oxker_container_network_transmit_bytes_total Bytes sent by the container, across every network interface".to_owned(),
+            "# TYPE oxker_container_network_transmit_bytes_total counter".to_owned(),
+            format!("oxker_container_network_transmit_bytes_total{{{labels_1}}} 200"),
+            format!("oxker_container_network_transmit_bytes_total{{{labels_2}}} 0"),
+            "# HELP oxker_container_block_read_bytes_total Bytes read by the container from block devices".to_owned(),
+            "# TYPE oxker_container_block_read_bytes_total counter".to_owned(),
+            format!("oxker_container_block_read_bytes_total{{{labels_1}}} 300"),
+            "# HELP oxker_container_block_write_bytes_total Bytes written by the container to block devices".to_owned(),
+            "# TYPE oxker_container_block_write_bytes_total counter".to_owned(),
+            format!("oxker_container_block_write_bytes_total{{{labels_1}}} 400"),
+        ];
+        assert_eq!(output.lines().collect::<Vec<_>>(), expected);
+    }
+}
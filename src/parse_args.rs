@@ -1,18 +1,43 @@
 use std::{path::PathBuf, process};
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use tracing::error;
 
-use crate::{ENV_KEY, ENV_VALUE};
+use crate::{
+    app_data::{Header, MemoryUnit, Since, StateFilter},
+    config::ThemeName,
+    docker_context::DockerContext,
+    DOCKER_HOST, ENV_KEY, ENV_VALUE,
+};
 
 #[derive(Parser, Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 #[command(version, about)]
 pub struct Args {
+    /// Attach to the stdout & stderr of the selected container, instead of polling its logs, falls back to polling if unable to attach
+    #[clap(long, short = None)]
+    pub attach: bool,
+
     /// Docker update interval in ms, minimum effectively 1000
     #[clap(short = 'd', value_name = "ms", default_value_t = 1000)]
     pub docker_interval: u32,
 
+    /// Number of cpu & memory samples kept for each container, used by the charts and the history panel
+    #[clap(long, short = None, value_name = "samples", default_value_t = 60)]
+    pub history: usize,
+
+    /// Maximum number of log lines kept for each container, the oldest lines are removed once exceeded
+    #[clap(long="log-max-lines", short = None, value_name = "lines", default_value_t = 5000)]
+    pub log_max_lines: usize,
+
+    /// Number of log lines fetched when a container's logs are first read, "all" fetches the full history
+    #[clap(long="log-tail", short = None, value_name = "lines", default_value = "1000")]
+    pub log_tail: String,
+
+    /// Only fetch the logs written since this time, either a duration, e.g. "10m" or "1h30m", or an RFC 3339 timestamp
+    #[clap(long, short = None, value_name = "since")]
+    pub since: Option<Since>,
+
     /// Remove timestamps from Docker logs
     #[clap(short = 't')]
     pub timestamp: bool,
@@ -25,40 +50,169 @@ pub struct Args {
     #[clap(short = 'r', conflicts_with = "color")]
     pub raw: bool,
 
+    /// Always ask for confirmation before a container is stopped, restarted, killed, or removed, intended for shared or production Docker daemons
+    #[clap(long="confirm-destructive", short = None)]
+    pub confirm_destructive: bool,
+
+    /// Don't send any command that changes a container, image, network, or volume, or exec into a container, for safely browsing a sensitive Docker daemon
+    #[clap(long="read-only", short = None)]
+    pub read_only: bool,
+
     /// Show self when running as a docker container
     #[clap(short = 's')]
     pub show_self: bool,
 
-    /// Don't draw gui - for debugging - mostly pointless
-    #[clap(short = 'g')]
-    pub gui: bool,
+    /// Don't draw the gui, instead print every container, and its current stats, to stdout, then exit
+    #[clap(long, short = None, value_enum, value_name = "format")]
+    pub format: Option<OutputFormat>,
+
+    /// Keep printing the containers, once every docker update interval, instead of exiting, requires "--format"
+    #[clap(long, short = None, requires = "format")]
+    pub watch: bool,
 
-    /// Docker host, defaults to `/var/run/docker.sock`
+    /// Docker host, defaults to `/var/run/docker.sock`, an `ssh://[user@]host[:port]` host is connected to via ssh
     #[clap(long, short = None)]
     pub host: Option<String>,
 
+    /// Docker cli context, the host, and any TLS files, are read from the stored context, conflicts with "--host" & the TLS arguments
+    #[clap(long, short = None, value_name = "name", conflicts_with_all = ["host", "tls_cert", "tls_key", "tls_ca"])]
+    pub context: Option<String>,
+
     /// Force use of docker cli when execing into containers
     #[clap(long="use-cli", short = None)]
     pub use_cli: bool,
 
+    /// Client certificate for connecting to a Docker daemon via TLS, requires "--tls-key" & "--tls-ca"
+    #[clap(long="tls-cert", short = None, value_name = "path", requires_all = ["tls_key", "tls_ca"])]
+    pub tls_cert: Option<String>,
+
+    /// Client key for connecting to a Docker daemon via TLS, requires "--tls-cert" & "--tls-ca"
+    #[clap(long="tls-key", short = None, value_name = "path", requires_all = ["tls_cert", "tls_ca"])]
+    pub tls_key: Option<String>,
+
+    /// Certificate authority for connecting to a Docker daemon via TLS, requires "--tls-cert" & "--tls-key"
+    #[clap(long="tls-ca", short = None, value_name = "path", requires_all = ["tls_cert", "tls_key"])]
+    pub tls_ca: Option<String>,
+
+    /// Shell to use when execing into containers, tried before the config file shells, falls back to `sh` if not found in the container
+    #[clap(long, short = None, value_name = "shell")]
+    pub shell: Option<String>,
+
+    /// Config file location, defaults to `oxker/config.toml` in the platform config directory, e.g. `$XDG_CONFIG_HOME` on Linux
+    #[clap(long, short = None, value_name = "path")]
+    pub config: Option<String>,
+
+    /// Built-in color theme, individual colors can be changed in the config file
+    #[clap(long, short = None, value_enum, default_value_t = ThemeName::Dark)]
+    pub theme: ThemeName,
+
     /// Directory for saving exported logs, defaults to `$HOME`
     #[clap(long="save-dir", short = None)]
     pub save_dir: Option<String>,
+
+    /// Unit that memory is shown in, defaults to the config file setting, or auto, which picks kB, MB, or GB for each value
+    #[clap(long="memory-unit", short = None, value_enum, value_name = "unit")]
+    pub memory_unit: Option<MemoryUnit>,
+
+    /// Sort the containers by this column at startup, takes priority over the saved sort
+    #[clap(long, short = None, value_enum, value_name = "column")]
+    pub sort: Option<Header>,
+
+    /// Sort in descending order, requires "--sort"
+    #[clap(long="sort-desc", short = None, requires = "sort")]
+    pub sort_desc: bool,
+
+    /// Only show the containers in this state at startup, takes priority over the saved state filter
+    #[clap(long, short = None, value_enum, value_name = "state")]
+    pub state: Option<StateFilter>,
+
+    /// Highlight the name of a container that was started within this many seconds, to spot fresh restarts, 0 disables
+    #[clap(long, short = None, value_name = "seconds", default_value_t = 30)]
+    pub fresh: u64,
+
+    /// Alert when the cpu usage of a container rises above this percentage
+    #[clap(long="cpu-alert", short = None, value_name = "percent")]
+    pub cpu_alert: Option<f64>,
+
+    /// Alert when the memory usage of a container rises above this percentage of its memory limit, or of the host memory if unlimited
+    #[clap(long="mem-alert", short = None, value_name = "percent")]
+    pub mem_alert: Option<f64>,
+
+    /// Serve the container stats, in the Prometheus text format, at `/metrics` on this port, only bound to localhost unless running in a container
+    #[clap(long="metrics-port", short = None, value_name = "port")]
+    pub metrics_port: Option<u16>,
+}
+
+/// The structured output formats, for when the gui isn't drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Json,
+}
+
+/// Paths to the files needed to connect to a Docker daemon via mutual TLS
+#[derive(Debug, Clone)]
+pub struct TlsPaths {
+    pub ca: PathBuf,
+    pub cert: PathBuf,
+    pub key: PathBuf,
+}
+
+impl TlsPaths {
+    /// Only returns Some if all three paths are given, clap makes sure that they are either all set, or all unset
+    fn new(ca: Option<String>, cert: Option<String>, key: Option<String>) -> Option<Self> {
+        match (ca, cert, key) {
+            (Some(ca), Some(cert), Some(key)) => Some(Self {
+                ca: PathBuf::from(ca),
+                cert: PathBuf::from(cert),
+                key: PathBuf::from(key),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Check that all three files exist and are readable, returns the first path that is not
+    fn unreadable(&self) -> Option<&PathBuf> {
+        [&self.ca, &self.cert, &self.key]
+            .into_iter()
+            .find(|i| std::fs::File::open(i).is_err())
+    }
 }
 
 #[derive(Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct CliArgs {
+    pub attach: bool,
     pub color: bool,
+    pub config: Option<PathBuf>,
+    pub confirm_destructive: bool,
+    pub cpu_alert: Option<f64>,
     pub docker_interval: u32,
+    pub fresh: u64,
     pub gui: bool,
+    pub history: usize,
     pub host: Option<String>,
     pub in_container: bool,
+    /// The id, or the short id, of the container that oxker is running in, only searched for when running in a container
+    pub self_id: Option<String>,
+    pub log_max_lines: usize,
+    pub log_tail: Option<usize>,
+    pub mem_alert: Option<f64>,
+    pub memory_unit: Option<MemoryUnit>,
+    pub metrics_port: Option<u16>,
     pub save_dir: Option<PathBuf>,
     pub raw: bool,
+    pub read_only: bool,
+    pub shell: Option<String>,
     pub show_self: bool,
+    pub since: Option<Since>,
+    pub sort: Option<Header>,
+    pub sort_desc: bool,
+    pub state: Option<StateFilter>,
+    pub theme: ThemeName,
     pub timestamp: bool,
+    pub tls: Option<TlsPaths>,
     pub use_cli: bool,
+    pub watch: bool,
 }
 
 impl CliArgs {
@@ -73,6 +227,31 @@ impl CliArgs {
         false
     }
 
+    /// Find the first full length container id in the text of a cgroup or mountinfo file, e.g. `0::/docker/<id>`, or `/var/lib/docker/containers/<id>/hostname`
+    fn parse_container_id(text: &str) -> Option<String> {
+        text.lines()
+            .filter(|line| line.contains("docker") || line.contains("containerd"))
+            .flat_map(|line| line.split(['/', '-', '.', ':', ' ']))
+            .find(|i| i.len() == 64 && i.chars().all(|c| c.is_ascii_hexdigit()))
+            .map(ToOwned::to_owned)
+    }
+
+    /// Find the id of the container that oxker is running in, from the cgroup, or mountinfo, of the process.
+    /// If neither contain the id, then fallback to the hostname, which docker sets to the short container id, unless `--hostname` is used
+    fn find_self_id() -> Option<String> {
+        ["/proc/self/cgroup", "/proc/self/mountinfo"]
+            .iter()
+            .filter_map(|path| std::fs::read_to_string(path).ok())
+            .find_map(|text| Self::parse_container_id(&text))
+            .or_else(|| {
+                std::env::var("HOSTNAME")
+                    .ok()
+                    .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+                    .map(|i| i.trim().to_owned())
+                    .filter(|i| i.len() == 12 && i.chars().all(|c| c.is_ascii_hexdigit()))
+            })
+    }
+
     /// Parse cli arguments
     pub fn new() -> Self {
         let args = Args::parse();
@@ -88,17 +267,168 @@ impl CliArgs {
             error!("\"-d\" argument needs to be greater than 0");
             process::exit(1)
         }
+
+        if args.history == 0 {
+            error!("\"--history\" argument needs to be greater than 0");
+            process::exit(1)
+        }
+
+        if args.log_max_lines == 0 {
+            error!("\"--log-max-lines\" argument needs to be greater than 0");
+            process::exit(1)
+        }
+
+        // None fetches every log line
+        let log_tail = if args.log_tail == "all" {
+            None
+        } else if let Some(tail) = args.log_tail.parse::<usize>().ok().filter(|i| *i > 0) {
+            Some(tail)
+        } else {
+            error!("\"--log-tail\" argument needs to be greater than 0, or \"all\"");
+            process::exit(1)
+        };
+
+        for (arg, value) in [
+            ("--cpu-alert", args.cpu_alert),
+            ("--mem-alert", args.mem_alert),
+        ] {
+            if value.is_some_and(|i| i <= 0.0 || i.is_nan()) {
+                error!("\"{arg}\" argument needs to be greater than 0");
+                process::exit(1)
+            }
+        }
+
+        if args.metrics_port == Some(0) {
+            error!("\"--metrics-port\" argument needs to be greater than 0");
+            process::exit(1)
+        }
+
+        let (host, tls) = match args.context.as_deref().map(DockerContext::read) {
+            Some(Ok(Some(context))) => (Some(context.host), context.tls),
+            Some(Err(e)) => {
+                error!("{e}");
+                process::exit(1)
+            }
+            Some(Ok(None)) | None => (
+                args.host,
+                TlsPaths::new(args.tls_ca, args.tls_cert, args.tls_key),
+            ),
+        };
+        if let Some(tls) = tls.as_ref() {
+            if let Some(path) = tls.unreadable() {
+                error!("unable to read TLS file \"{}\"", path.display());
+                process::exit(1)
+            }
+            if host.is_none() && std::env::var(DOCKER_HOST).is_err() {
+                error!("\"--host\" argument, or the DOCKER_HOST env, is required when using TLS");
+                process::exit(1)
+            }
+        }
+
+        let in_container = Self::check_if_in_container();
+
         Self {
+            attach: args.attach,
             color: args.color,
+            config: args.config.map(PathBuf::from),
+            confirm_destructive: args.confirm_destructive,
+            cpu_alert: args.cpu_alert,
             docker_interval: args.docker_interval,
+            fresh: args.fresh,
             use_cli: args.use_cli,
-            gui: !args.gui,
-            host: args.host,
-            in_container: Self::check_if_in_container(),
+            gui: args.format.is_none(),
+            history: args.history,
+            host,
+            in_container,
+            self_id: if in_container {
+                Self::find_self_id()
+            } else {
+                None
+            },
+            log_max_lines: args.log_max_lines,
+            log_tail,
+            mem_alert: args.mem_alert,
+            memory_unit: args.memory_unit,
+            metrics_port: args.metrics_port,
             save_dir: logs_dir,
             raw: args.raw,
+            read_only: args.read_only,
+            shell: args.shell,
             show_self: !args.show_self,
+            since: args.since,
+            sort: args.sort,
+            sort_desc: args.sort_desc,
+            state: args.state,
+            theme: args.theme,
             timestamp: !args.timestamp,
+            tls,
+            watch: args.watch,
         }
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use clap::Parser;
+
+    use super::{Args, CliArgs};
+    use crate::app_data::{Header, Since, StateFilter};
+
+    #[test]
+    /// The sort column uses the same names as the config file, and invalid values, or "--sort-desc" without "--sort", are rejected
+    fn test_parse_args_sort_state() {
+        let args = Args::try_parse_from([
+            "oxker",
+            "--sort",
+            "net_io",
+            "--sort-desc",
+            "--state",
+            "running",
+        ])
+        .unwrap();
+        assert_eq!(args.sort, Some(Header::NetIo));
+        assert!(args.sort_desc);
+        assert_eq!(args.state, Some(StateFilter::Running));
+
+        let args = Args::try_parse_from(["oxker", "--sort", "mem"]).unwrap();
+        assert_eq!(args.sort, Some(Header::Memory));
+
+        assert!(Args::try_parse_from(["oxker", "--sort", "size"]).is_err());
+        assert!(Args::try_parse_from(["oxker", "--state", "stopped"]).is_err());
+        assert!(Args::try_parse_from(["oxker", "--sort-desc"]).is_err());
+    }
+
+    #[test]
+    /// The since is either a duration or an RFC 3339 timestamp, anything else is rejected
+    fn test_parse_args_since() {
+        let args = Args::try_parse_from(["oxker", "--since", "1h30m"]).unwrap();
+        assert_eq!(args.since, Some(Since::Relative(5400)));
+
+        let args = Args::try_parse_from(["oxker", "--since", "2023-01-14T19:13:30Z"]).unwrap();
+        assert_eq!(args.since, Some(Since::Absolute(1_673_723_610)));
+
+        assert!(Args::try_parse_from(["oxker", "--since", "yesterday"]).is_err());
+    }
+
+    #[test]
+    /// The container id is found in both cgroup v1 & v2, and mountinfo, formats, other files are ignored
+    fn test_parse_args_parse_container_id() {
+        let id = "4a3b2c1d".repeat(8);
+        assert_eq!(
+            CliArgs::parse_container_id(&format!("12:memory:/docker/{id}\n0::/")),
+            Some(id.clone())
+        );
+        assert_eq!(
+            CliArgs::parse_container_id(&format!("0::/system.slice/docker-{id}.scope")),
+            Some(id.clone())
+        );
+        assert_eq!(
+            CliArgs::parse_container_id(&format!(
+                "1 2 8:1 /var/lib/docker/containers/{id}/hostname /etc/hostname rw"
+            )),
+            Some(id)
+        );
+        assert_eq!(CliArgs::parse_container_id("0::/user.slice"), None);
+    }
+}
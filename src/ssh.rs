@@ -0,0 +1,139 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Stdio,
+    time::{Duration, Instant},
+};
+
+use tokio::{
+    io::{AsyncBufReadExt, BufReader},
+    process::{Child, Command},
+};
+use tracing::warn;
+
+const SSH_PREFIX: &str = "ssh://";
+const REMOTE_SOCKET: &str = "/var/run/docker.sock";
+/// How long to wait for ssh to connect, authenticate, and create the forwarded socket
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// An ssh process forwarding the remote Docker socket to a local socket, the process is killed, and the local socket removed, on drop
+#[derive(Debug)]
+pub struct SshTunnel {
+    child: Child,
+    socket: PathBuf,
+}
+
+impl SshTunnel {
+    /// Return the ssh destination, `ssh://[user@]host[:port]`, if the Docker host is an ssh address.
+    /// Any path after the host is removed, as ssh doesn't accept it
+    pub fn destination(host: &str) -> Option<&str> {
+        host.strip_prefix(SSH_PREFIX).and_then(|rest| {
+            let end = rest.find('/').map_or(host.len(), |i| i + SSH_PREFIX.len());
+            let destination = &host[..end];
+            (destination.len() > SSH_PREFIX.len()).then_some(destination)
+        })
+    }
+
+    /// Spawn ssh, and wait until the local socket has been created.
+    /// ssh is run in batch mode, so a key or agent is required, as a password prompt would break the terminal
+    pub async fn new(destination: &str) -> Result<Self, String> {
+        let socket = std::env::temp_dir().join(format!(
+            "{}-{}.sock",
+            env!("CARGO_PKG_NAME"),
+            std::process::id()
+        ));
+        // A socket left over from a previous run would make ssh fail to bind
+        std::fs::remove_file(&socket).ok();
+
+        let child = Command::new("ssh")
+            .args([
+                "-nNT",
+                "-o",
+                "BatchMode=yes",
+                "-o",
+                "ExitOnForwardFailure=yes",
+                "-o",
+                "StreamLocalBindUnlink=yes",
+                "-L",
+                &format!("{}:{REMOTE_SOCKET}", socket.display()),
+                destination,
+            ])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| format!("unable to run ssh: {e}"))?;
+
+        let mut tunnel = Self { child, socket };
+        tunnel.wait_for_socket().await?;
+        Ok(tunnel)
+    }
+
+    /// Poll until either the local socket exists, or ssh exits, most likely due to an authentication or connection error
+    async fn wait_for_socket(&mut self) -> Result<(), String> {
+        let start = Instant::now();
+        while start.elapsed() < CONNECT_TIMEOUT {
+            if let Ok(Some(status)) = self.child.try_wait() {
+                let mut stderr = String::new();
+                if let Some(mut pipe) = self.child.stderr.take() {
+                    tokio::io::AsyncReadExt::read_to_string(&mut pipe, &mut stderr)
+                        .await
+                        .ok();
+                }
+                return Err(format!("ssh exited with {status}: {}", stderr.trim()));
+            }
+            if self.socket.exists() {
+                self.drain_stderr();
+                return Ok(());
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        Err(String::from("timed out waiting for ssh to connect"))
+    }
+
+    /// Once connected, ssh still writes warnings to stderr, e.g. when a forwarded connection fails to open.
+    /// These are logged, as left unread the pipe would fill up, and ssh would block, freezing the tunnel
+    fn drain_stderr(&mut self) {
+        if let Some(pipe) = self.child.stderr.take() {
+            tokio::spawn(async move {
+                let mut lines = BufReader::new(pipe).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    warn!("ssh: {line}");
+                }
+            });
+        }
+    }
+
+    /// The local socket, that is forwarded to the remote Docker socket
+    pub fn socket(&self) -> &Path {
+        &self.socket
+    }
+}
+
+impl Drop for SshTunnel {
+    fn drop(&mut self) {
+        self.child.start_kill().ok();
+        std::fs::remove_file(&self.socket).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SshTunnel;
+
+    #[test]
+    /// Only ssh hosts return a destination, with any path removed
+    fn test_ssh_destination() {
+        assert_eq!(
+            SshTunnel::destination("ssh://user@host"),
+            Some("ssh://user@host")
+        );
+        assert_eq!(
+            SshTunnel::destination("ssh://user@host:2222/var/run/docker.sock"),
+            Some("ssh://user@host:2222")
+        );
+        assert_eq!(SshTunnel::destination("ssh://"), None);
+        assert_eq!(SshTunnel::destination("tcp://host:2376"), None);
+        assert_eq!(SshTunnel::destination("/var/run/docker.sock"), None);
+    }
+}
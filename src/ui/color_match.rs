@@ -41,10 +41,15 @@ pub mod log_sanitizer {
 
     /// Remove all ansi formatting from a given string and create ratatui Lines
     pub fn remove_ansi<'a>(input: &str) -> Vec<Line<'a>> {
-        raw(&categorise_text(input)
+        raw(&plain(input))
+    }
+
+    /// Remove all ansi formatting from a given string
+    pub fn plain(input: &str) -> String {
+        categorise_text(input)
             .into_iter()
             .map(|i| i.text)
-            .collect::<String>())
+            .collect::<String>()
     }
 
     /// create ratatui Lines that exactly match the given strings
@@ -1,26 +1,37 @@
 use parking_lot::Mutex;
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style, Stylize},
     symbols,
     text::{Line, Span},
     widgets::{
-        Axis, Block, BorderType, Borders, Chart, Clear, Dataset, GraphType, List, ListItem,
-        Paragraph,
+        block::{Position, Title},
+        Axis, Block, BorderType, Borders, Chart, Clear, Dataset, GraphType, LineGauge, List,
+        ListItem, Padding, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Sparkline,
+        Wrap,
     },
     Frame,
 };
+use std::{collections::BTreeMap, sync::Arc};
 use std::{default::Default, time::Instant};
-use std::{fmt::Display, sync::Arc};
 
-use crate::app_data::{ContainerItem, ContainerName, Header, SortedOrder};
+use crate::app_data::{
+    ContainerItem, ContainerMount, ContainerName, DockerControls, Engine, Header, Health,
+    MemoryUnit, PingHealth, SortedOrder, StateFilter,
+};
 use crate::{
     app_data::{AppData, ByteStats, Columns, CpuStats, State, Stats},
     app_error::AppError,
+    config::Theme,
 };
 
 use super::{
-    gui_state::{BoxLocation, DeleteButton, Region},
+    gui_state::{
+        validate_container_name, validate_signal, Batch, BatchStep, BoxLocation, Cheatsheet,
+        ConfirmControl, DeleteButton, EnvVars, Fetch, IdMode, Inspect, KillSignal, Labels, Limits,
+        LimitsField, LogSearch, LogSince, Mounts, NetworkInspect, Prune, Pull, PullState, Region,
+        Rename, RestartPolicy, RunCommand, SaveLogs, View,
+    },
     FrameData,
 };
 use super::{GuiState, SelectablePanel};
@@ -39,10 +50,27 @@ const NAME: &str = env!("CARGO_PKG_NAME");
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 const REPO: &str = env!("CARGO_PKG_REPOSITORY");
 const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
-const ORANGE: Color = Color::Rgb(255, 178, 36);
 const MARGIN: &str = "   ";
 const RIGHT_ARROW: &str = "▶ ";
+const HEALTH: &str = "●";
 const CIRCLE: &str = "⚪ ";
+const MARK: &str = "✚";
+/// Drawn after the name of the container that oxker is running in, so that it isn't stopped by mistake
+const SELF: &str = " (self)";
+/// Label prefixes set by docker compose, swarm, and the OCI image spec, which are highlighted in the labels panel
+const KNOWN_LABELS: [&str; 4] = [
+    "com.docker.compose.",
+    "com.docker.stack.",
+    "com.docker.swarm.",
+    "org.opencontainers.",
+];
+/// Shown when confirming a recreate, as only the options that can be read back from the inspect output are kept
+const RECREATE_WARNING: &str =
+    "only the image, name, ports, env, mounts, network, & restart policy are kept";
+/// Detail panel values that are wrapped, rather than cut off, as the full value is needed to know what the container is running
+const WRAPPED_DETAIL: [&str; 4] = ["image", "entrypoint", "cmd", "ip"];
+/// Percentage of the memory limit at which the memory gauge changes to the limit color
+const MEMORY_LIMIT_WARNING: f64 = 90.0;
 
 const CONSTRAINT_50_50: [Constraint; 2] = [Constraint::Percentage(50), Constraint::Percentage(50)];
 const CONSTRAINT_100: [Constraint; 1] = [Constraint::Percentage(100)];
@@ -99,7 +127,7 @@ fn generate_block<'a>(
         .border_type(BorderType::Rounded)
         .title(title);
     if fd.selected_panel == panel {
-        block = block.border_style(Style::default().fg(Color::LightCyan));
+        block = block.border_style(Style::default().fg(fd.theme.border_selected));
     }
     block
 }
@@ -118,7 +146,7 @@ pub fn commands(
             .map(|c| {
                 let lines = Line::from(vec![Span::styled(
                     c.to_string(),
-                    Style::default().fg(c.get_color()),
+                    Style::default().fg(fd.theme.command(*c)),
                 )]);
                 ListItem::new(lines)
             })
@@ -137,85 +165,238 @@ pub fn commands(
     }
 }
 
-/// Format the container data to display nicely on the screen
-fn format_containers<'a>(i: &ContainerItem, widths: &Columns) -> Line<'a> {
-    let state_style = Style::default().fg(i.state.get_color());
-    let blue = Style::default().fg(Color::Blue);
+/// Format the state of a container, a container with a healthcheck has a colored health indicator after its state.
+/// An unhealthy container uses the unhealthy color, rather than the state color, so that it stands out
+fn format_state<'a>(i: &ContainerItem, width: u8, theme: &Theme) -> (Style, [Span<'a>; 2]) {
+    let health = i.get_health();
+    let state_style = if health == Some(Health::Unhealthy) {
+        Style::default()
+            .fg(theme.health(Health::Unhealthy))
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.state(i.state))
+    };
+    let state = i.state.to_string();
+    // The column is padded after the health indicator
+    let indicator = format!(
+        "{:<width$}",
+        health.map_or_else(String::new, |_| format!(" {HEALTH}")),
+        width = usize::from(width).saturating_sub(state.chars().count())
+    );
+    let indicator_style = health.map_or(state_style, |health| {
+        Style::default().fg(theme.health(health))
+    });
+    (
+        state_style,
+        [
+            Span::styled(state, state_style),
+            Span::styled(indicator, indicator_style),
+        ],
+    )
+}
 
-    // Truncate?
-    Line::from(vec![
-        Span::styled(
-            format!(
-                "{:>width$}",
-                i.name.to_string(),
-                width = widths.name.1.into()
-            ),
-            blue,
-        ),
-        Span::styled(
-            format!(
-                "{MARGIN}{:<width$}",
-                i.state.to_string(),
-                width = widths.state.1.into()
-            ),
-            state_style,
-        ),
-        Span::styled(
-            format!(
-                "{MARGIN}{:>width$}",
-                i.status,
-                width = &widths.status.1.into()
-            ),
-            state_style,
-        ),
-        Span::styled(
-            format!(
-                "{}{:>width$}",
-                MARGIN,
-                i.cpu_stats.back().unwrap_or(&CpuStats::default()),
-                width = &widths.cpu.1.into()
-            ),
-            state_style,
-        ),
-        Span::styled(
-            format!(
-                "{MARGIN}{:>width_current$} / {:>width_limit$}",
-                i.mem_stats.back().unwrap_or(&ByteStats::default()),
-                i.mem_limit,
-                width_current = &widths.mem.1.into(),
-                width_limit = &widths.mem.2.into()
-            ),
-            state_style,
-        ),
-        Span::styled(
-            format!(
-                "{}{:>width$}",
-                MARGIN,
-                i.id.get_short(),
-                width = &widths.id.1.into()
-            ),
-            blue,
-        ),
-        Span::styled(
-            format!(
-                "{MARGIN}{:>width$}",
-                i.image.to_string(),
-                width = widths.image.1.into()
-            ),
-            blue,
-        ),
-        Span::styled(
-            format!("{MARGIN}{:>width$}", i.rx, width = widths.net_rx.1.into()),
-            Style::default().fg(Color::Rgb(255, 233, 193)),
-        ),
-        Span::styled(
-            format!("{MARGIN}{:>width$}", i.tx, width = widths.net_tx.1.into()),
-            Style::default().fg(Color::Rgb(205, 140, 140)),
-        ),
-    ])
+/// Format the container data to display nicely on the screen, only the visible columns are included, in their configured order
+#[allow(clippy::too_many_lines, clippy::too_many_arguments)]
+fn format_containers<'a>(
+    i: &ContainerItem,
+    widths: &Columns,
+    visible_columns: &[Header],
+    theme: &Theme,
+    marked: bool,
+    memory_unit: MemoryUnit,
+    fresh: u64,
+    id_mode: IdMode,
+    now: i64,
+) -> Line<'a> {
+    let (state_style, [state, health]) = format_state(i, widths.state.1, theme);
+    let blue = Style::default().fg(theme.container_text);
+    // A freshly started container's name is highlighted, in bold for the first half of the window, then fades to just the color until the window has passed
+    let name_style = i.started_within(now, fresh).map_or(blue, |seconds| {
+        let style = Style::default().fg(theme.fresh_start);
+        if u64::try_from(seconds.saturating_mul(2)).unwrap_or_default() < fresh {
+            style.add_modifier(Modifier::BOLD)
+        } else {
+            style
+        }
+    });
+    let rx = Style::default().fg(theme.net_rx);
+    let tx = Style::default().fg(theme.net_tx);
+    // The cpu & memory are highlighted whilst above the alert threshold
+    let alert_style = |alert: bool| {
+        if alert {
+            Style::default()
+                .fg(theme.alert)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            state_style
+        }
+    };
+
+    let name = if marked {
+        format!("{MARK} {}", i.name)
+    } else {
+        i.name.to_string()
+    };
+    let name = if i.is_self {
+        format!("{name}{SELF}")
+    } else {
+        name
+    };
+
+    let mut spans = vec![];
+    for (index, header) in visible_columns.iter().enumerate() {
+        // Every column, apart from the first, is separated by a margin
+        let margin = if index == 0 { "" } else { MARGIN };
+        match header {
+            Header::Name => spans.push(Span::styled(
+                format!("{margin}{name:>width$}", width = widths.name.1.into()),
+                name_style,
+            )),
+            Header::State => {
+                spans.push(Span::styled(
+                    format!("{margin}{}", state.content),
+                    state.style,
+                ));
+                spans.push(health.clone());
+            }
+            Header::Status => spans.push(Span::styled(
+                format!(
+                    "{margin}{:>width$}",
+                    i.status,
+                    width = &widths.status.1.into()
+                ),
+                state_style,
+            )),
+            Header::Cpu => spans.push(Span::styled(
+                format!(
+                    "{margin}{:>width$}",
+                    i.cpu_stats.back().copied().unwrap_or_default(),
+                    width = &widths.cpu.1.into()
+                ),
+                alert_style(i.alert.cpu),
+            )),
+            Header::Memory => spans.push(Span::styled(
+                format!(
+                    "{margin}{:>width_current$} / {:>width_limit$}",
+                    i.mem_stats
+                        .back()
+                        .copied()
+                        .unwrap_or_default()
+                        .format(memory_unit),
+                    i.mem_limit.format(memory_unit),
+                    width_current = &widths.mem.1.into(),
+                    width_limit = &widths.mem.2.into()
+                ),
+                alert_style(i.alert.mem),
+            )),
+            Header::Id => spans.push(Span::styled(
+                format!(
+                    "{margin}{:>width$}",
+                    id_mode.format(&i.id),
+                    width = &widths.id.1.into()
+                ),
+                blue,
+            )),
+            Header::Image => spans.push(Span::styled(
+                format!(
+                    "{margin}{:>width$}",
+                    i.image.to_string(),
+                    width = widths.image.1.into()
+                ),
+                blue,
+            )),
+            Header::Rx => spans.push(Span::styled(
+                format!("{margin}{:>width$}", i.rx, width = widths.net_rx.1.into()),
+                rx,
+            )),
+            Header::Tx => spans.push(Span::styled(
+                format!("{margin}{:>width$}", i.tx, width = widths.net_tx.1.into()),
+                tx,
+            )),
+            Header::NetIo => spans.push(Span::styled(
+                format!(
+                    "{margin}{:>width_rx$} / {:>width_tx$}",
+                    i.io_rates.rx,
+                    i.io_rates.tx,
+                    width_rx = widths.net_io.1.into(),
+                    width_tx = widths.net_io.2.into()
+                ),
+                rx,
+            )),
+            Header::BlockIo => spans.push(Span::styled(
+                format!(
+                    "{margin}{:>width_read$} / {:>width_write$}",
+                    i.io_rates.blk_read,
+                    i.io_rates.blk_write,
+                    width_read = widths.blk_io.1.into(),
+                    width_write = widths.blk_io.2.into()
+                ),
+                tx,
+            )),
+            Header::Uptime => spans.push(Span::styled(
+                format!(
+                    "{margin}{:>width$}",
+                    i.format_uptime(now),
+                    width = widths.uptime.1.into()
+                ),
+                state_style,
+            )),
+            Header::ImageSize => spans.push(Span::styled(
+                format!(
+                    "{margin}{:>width$}",
+                    i.format_image_size(),
+                    width = widths.image_size.1.into()
+                ),
+                blue,
+            )),
+            Header::Project => spans.push(Span::styled(
+                format!(
+                    "{margin}{:>width$}",
+                    i.format_project(),
+                    width = widths.project.1.into()
+                ),
+                blue,
+            )),
+            Header::Service => spans.push(Span::styled(
+                format!(
+                    "{margin}{:>width$}",
+                    i.format_service(),
+                    width = widths.service.1.into()
+                ),
+                blue,
+            )),
+            Header::Ip => spans.push(Span::styled(
+                format!(
+                    "{margin}{:>width$}",
+                    i.format_ip(),
+                    width = widths.ip.1.into()
+                ),
+                blue,
+            )),
+        }
+    }
+    Line::from(spans)
+}
+
+/// Format the heading of a compose project group, containers without a project are in the "ungrouped" group
+fn format_group<'a>(
+    project: Option<&String>,
+    (total, running): (usize, usize),
+    collapsed: bool,
+    theme: &Theme,
+) -> Line<'a> {
+    let icon = if collapsed { "▸" } else { "▾" };
+    let name = project.map_or("ungrouped", String::as_str);
+    Line::from(Span::styled(
+        format!("{icon} {name} - {running}/{total} running"),
+        Style::default()
+            .fg(theme.container_text)
+            .add_modifier(Modifier::BOLD),
+    ))
 }
 
 /// Draw the containers panel
+#[allow(clippy::too_many_lines)]
 pub fn containers(
     app_data: &Arc<Mutex<AppData>>,
     area: Rect,
@@ -223,27 +404,288 @@ pub fn containers(
     fd: &FrameData,
     gui_state: &Arc<Mutex<GuiState>>,
 ) {
-    let block = generate_block(app_data, area, fd, gui_state, SelectablePanel::Containers);
+    let mut block = generate_block(app_data, area, fd, gui_state, SelectablePanel::Containers);
+    if fd.alert {
+        block = block.title(
+            Title::from(Span::styled(
+                " ALERT ",
+                Style::default()
+                    .fg(fd.theme.alert)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+            ))
+            .alignment(Alignment::Right),
+        );
+    }
+    // The restart count & policy of the selected container, flagged if it looks to be crash looping
+    let restarts = app_data.lock().get_selected_restarts();
+    if let Some(restarts) = restarts {
+        let style = if restarts.is_flapping() {
+            Style::default()
+                .fg(fd.theme.alert)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        block = block.title(
+            Title::from(Span::styled(format!(" {restarts} "), style))
+                .position(Position::Bottom)
+                .alignment(Alignment::Right),
+        );
+    }
 
-    let items = app_data
-        .lock()
-        .get_container_items()
-        .iter()
-        .map(|i| ListItem::new(format_containers(i, &fd.columns)))
-        .collect::<Vec<_>>();
+    let items = {
+        let app_data = app_data.lock();
+        let grouped = app_data.is_grouped();
+        let mut previous_project = None;
+        // Whilst a row number is being typed, each container is prefixed with its row number
+        let row_width = app_data.get_container_items().len().to_string().len();
+        let now = app_data.now();
+        app_data
+            .get_container_items()
+            .iter()
+            .enumerate()
+            .map(|(index, i)| {
+                let mut line = format_containers(
+                    i,
+                    &fd.columns,
+                    &fd.visible_columns,
+                    &fd.theme,
+                    app_data.is_marked(&i.id),
+                    app_data.get_memory_unit(),
+                    app_data.args.fresh,
+                    fd.id_mode,
+                    now,
+                );
+                if fd.go_to.is_some() {
+                    line.spans.insert(
+                        0,
+                        Span::styled(
+                            format!("{:>row_width$} ", index + 1),
+                            Style::default().add_modifier(Modifier::DIM),
+                        ),
+                    );
+                }
+                // The first container of each group has the group heading, a collapsed group is only the heading
+                if grouped && previous_project != Some(&i.project) {
+                    previous_project = Some(&i.project);
+                    let project = i.project.as_ref();
+                    let collapsed = app_data.is_group_collapsed(project);
+                    let heading = format_group(
+                        project,
+                        app_data.get_group_count(project),
+                        collapsed,
+                        &fd.theme,
+                    );
+                    if collapsed {
+                        ListItem::new(heading)
+                    } else {
+                        ListItem::new(vec![heading, line])
+                    }
+                } else {
+                    ListItem::new(line)
+                }
+            })
+            .collect::<Vec<_>>()
+    };
 
     if items.is_empty() {
-        let paragraph = Paragraph::new("no containers running")
+        let text = if fd.filter_term.is_some() || fd.state_filter != StateFilter::All {
+            "no containers match the filter"
+        } else {
+            "no containers found"
+        };
+        let paragraph = Paragraph::new(text)
             .block(block)
             .alignment(Alignment::Center);
         f.render_widget(paragraph, area);
     } else {
+        let heights = items.iter().map(ListItem::height).collect::<Vec<_>>();
         let items = List::new(items)
             .block(block)
-            .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+            .highlight_style(
+                Style::default()
+                    .bg(fd.theme.selected_row_bg)
+                    .add_modifier(Modifier::BOLD),
+            )
             .highlight_symbol(CIRCLE);
         f.render_stateful_widget(items, area, app_data.lock().get_container_state());
+        let offset = app_data.lock().get_container_state().offset();
+        list_scrollbar(f, area, &heights, offset);
+    }
+}
+
+/// Draw a scrollbar over the right border of a list, using the offset set by the list when it was rendered.
+/// Isn't drawn if every item fits in the list
+fn list_scrollbar(f: &mut Frame, area: Rect, heights: &[usize], offset: usize) {
+    let viewport = usize::from(area.height.saturating_sub(2));
+    let total = heights.iter().sum::<usize>();
+    if total <= viewport {
+        return;
+    }
+    // The thumb is at the bottom of the track once the last line is at the bottom of the list
+    let mut state = ScrollbarState::new(total - viewport + 1)
+        .position(heights.iter().take(offset).sum())
+        .viewport_content_length(viewport);
+    f.render_stateful_widget(
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some(symbols::line::VERTICAL)),
+        area.inner(&Margin {
+            vertical: 1,
+            horizontal: 0,
+        }),
+        &mut state,
+    );
+}
+
+/// Draw the images list, the column headings are drawn inside the panel, as the heading bar only has the container columns
+pub fn images(f: &mut Frame, area: Rect, app_data: &Arc<Mutex<AppData>>, fd: &FrameData) {
+    let title = format!(" Images{} ", app_data.lock().images_title());
+    let mut app_data = app_data.lock();
+    let time_format = app_data.get_time_format();
+    let rows = app_data
+        .get_image_items()
+        .iter()
+        .map(|i| {
+            vec![
+                i.tags.clone(),
+                i.id.get_short(),
+                i.size.to_string(),
+                i.get_created(time_format),
+                i.containers.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let headings = ["repo:tag", "id", "size", "created", "containers"];
+    if let Some((list, area)) = table_list(f, area, (&title, "no images"), &headings, &rows, fd) {
+        f.render_stateful_widget(list, area, app_data.get_image_state());
+    }
+}
+
+/// Draw the networks list, in the same layout as the images list
+pub fn networks(f: &mut Frame, area: Rect, app_data: &Arc<Mutex<AppData>>, fd: &FrameData) {
+    let title = format!(" Networks{} ", app_data.lock().networks_title());
+    let mut app_data = app_data.lock();
+    let rows = app_data
+        .get_network_items()
+        .iter()
+        .map(|i| {
+            vec![
+                i.name.clone(),
+                i.id.get_short(),
+                i.driver.clone(),
+                i.scope.clone(),
+                i.containers.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let headings = ["name", "id", "driver", "scope", "containers"];
+    if let Some((list, area)) = table_list(f, area, (&title, "no networks"), &headings, &rows, fd) {
+        f.render_stateful_widget(list, area, app_data.get_network_state());
+    }
+}
+
+/// Draw the volumes list, in the same layout as the images list, the size is only known if docker has calculated it
+pub fn volumes(f: &mut Frame, area: Rect, app_data: &Arc<Mutex<AppData>>, fd: &FrameData) {
+    let title = format!(" Volumes{} ", app_data.lock().volumes_title());
+    let mut app_data = app_data.lock();
+    let rows = app_data
+        .get_volume_items()
+        .iter()
+        .map(|i| {
+            vec![
+                i.name.get().to_owned(),
+                i.driver.clone(),
+                i.get_size(),
+                i.mountpoint.clone(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let headings = ["name", "driver", "size", "mountpoint"];
+    if let Some((list, area)) = table_list(f, area, (&title, "no volumes"), &headings, &rows, fd) {
+        f.render_stateful_widget(list, area, app_data.get_volume_state());
+    }
+}
+
+/// Draw the block, and column headings, of a full screen list, the first column is left aligned, and every other column right aligned.
+/// Returns the list, and the area to render it in, so that the caller can render it with its own ListState, or None if there are no rows
+fn table_list<'a>(
+    f: &mut Frame,
+    area: Rect,
+    (title, empty): (&str, &'a str),
+    headings: &[&str],
+    rows: &[Vec<String>],
+    fd: &FrameData,
+) -> Option<(List<'a>, Rect)> {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(fd.theme.border_selected))
+        .title(title.to_owned());
+
+    if rows.is_empty() {
+        let paragraph = Paragraph::new(empty)
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return None;
+    }
+
+    let mut widths = headings
+        .iter()
+        .map(|i| i.chars().count())
+        .collect::<Vec<_>>();
+    for row in rows {
+        for (width, text) in widths.iter_mut().zip(row) {
+            *width = (*width).max(text.chars().count());
+        }
     }
+    let format_row = |row: &[String]| {
+        row.iter().zip(&widths).skip(1).fold(
+            format!("{:<width$}", row[0], width = widths[0]),
+            |line, (text, width)| format!("{line}{MARGIN}{text:>width$}"),
+        )
+    };
+
+    let heading = Paragraph::new(format_row(
+        &headings.iter().map(|i| (*i).to_owned()).collect::<Vec<_>>(),
+    ))
+    .style(Style::default().fg(fd.theme.ports_header));
+    let items = rows
+        .iter()
+        .map(|i| {
+            ListItem::new(Span::styled(
+                format_row(i),
+                Style::default().fg(fd.theme.container_text),
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(block.inner(area));
+
+    let list = List::new(items)
+        .highlight_style(
+            Style::default()
+                .bg(fd.theme.selected_row_bg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(CIRCLE);
+
+    f.render_widget(block, area);
+    // Indent the heading by the width of the highlight symbol, so it aligns with the list
+    f.render_widget(
+        heading,
+        Rect {
+            x: split[0].x + 3,
+            width: split[0].width.saturating_sub(3),
+            ..split[0]
+        },
+    );
+    Some((list, split[1]))
 }
 
 /// Draw the logs panel
@@ -254,7 +696,16 @@ pub fn logs(
     fd: &FrameData,
     gui_state: &Arc<Mutex<GuiState>>,
 ) {
-    let block = generate_block(app_data, area, fd, gui_state, SelectablePanel::Logs);
+    let mut block = generate_block(app_data, area, fd, gui_state, SelectablePanel::Logs);
+    if fd.log_stream_paused {
+        block = block.title(
+            Title::from(Span::styled(
+                " PAUSED ",
+                Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED),
+            ))
+            .alignment(Alignment::Right),
+        );
+    }
     if fd.init {
         let paragraph = Paragraph::new(format!("parsing logs {}", fd.loading_icon))
             .style(Style::default())
@@ -262,14 +713,80 @@ pub fn logs(
             .alignment(Alignment::Center);
         f.render_widget(paragraph, area);
     } else {
-        let logs = app_data.lock().get_logs();
+        // When wrapped, the width excludes both borders and the highlight symbol
+        let wrap_width = fd
+            .log_wrap
+            .then(|| usize::from(area.width.saturating_sub(4)));
+        let mut logs = app_data.lock().get_logs(wrap_width);
+        // Severity colors are only applied to the drawn lines, the stored lines are unchanged
+        if fd.log_severity {
+            let severity = app_data.lock().get_log_severity(&fd.theme.log_severity);
+            for (index, color) in severity {
+                if let Some(line) = logs.get_mut(index) {
+                    *line = line.clone().style(Style::default().fg(color));
+                }
+            }
+        }
+        let block = if let Some(search) = fd.log_search.as_ref() {
+            let matches = app_data.lock().get_log_matches(search);
+            for index in &matches {
+                if let Some(line) = logs.get_mut(*index) {
+                    *line = line.clone().style(
+                        Style::default()
+                            .bg(fd.theme.log_match_bg)
+                            .fg(fd.theme.log_match_fg),
+                    );
+                }
+            }
+            block.title(log_search_title(
+                search,
+                matches.len(),
+                fd.log_search_editing,
+                &fd.theme,
+            ))
+        } else {
+            block
+        };
+
+        // The lines selected in the log copy mode are reversed, as the highlight symbol only marks the cursor
+        let block = if let Some((start, end)) = fd.log_copy {
+            for line in logs.iter_mut().take(end + 1).skip(start) {
+                *line = line
+                    .clone()
+                    .style(Style::default().add_modifier(Modifier::REVERSED));
+            }
+            block.title(log_copy_title(end - start + 1))
+        } else {
+            block
+        };
 
         if logs.is_empty() {
-            let paragraph = Paragraph::new("no logs found")
+            // A logging driver that can't be read is explained, rather than leaving the panel looking as if the logs are still to come
+            let text = fd.logs_unavailable.clone().unwrap_or_else(|| {
+                if fd.log_fetching {
+                    format!("fetching logs {}", fd.loading_icon)
+                } else {
+                    String::from("no logs found")
+                }
+            });
+            let paragraph = Paragraph::new(text)
                 .block(block)
+                .wrap(Wrap { trim: true })
                 .alignment(Alignment::Center);
             f.render_widget(paragraph, area);
         } else {
+            // Whether new lines are followed, once scrolled up the view stays on the selected line until following is resumed
+            let follow = if fd.log_follow {
+                " following "
+            } else {
+                " ( G ) to follow "
+            };
+            let block = block.title(
+                Title::from(follow)
+                    .position(Position::Bottom)
+                    .alignment(Alignment::Right),
+            );
+            let heights = logs.iter().map(ListItem::height).collect::<Vec<_>>();
             let items = List::new(logs)
                 .block(block)
                 .highlight_symbol(RIGHT_ARROW)
@@ -277,19 +794,53 @@ pub fn logs(
             // This should always return Some, as logs is not empty
             if let Some(log_state) = app_data.lock().get_log_state() {
                 f.render_stateful_widget(items, area, log_state);
+                list_scrollbar(f, area, &heights, log_state.offset());
             }
         }
     }
 }
 
+/// Generate the title, positioned at the bottom of the logs panel, showing the number of lines selected in the log copy mode
+fn log_copy_title<'a>(lines: usize) -> Title<'a> {
+    let suffix = if lines == 1 { "" } else { "s" };
+    Title::from(Line::from(vec![
+        Span::from(format!(" copy {lines} line{suffix} ")).add_modifier(Modifier::BOLD),
+        Span::from("( y ) copy ( J / K ) select ( esc ) exit "),
+    ]))
+    .position(Position::Bottom)
+}
+
+/// Generate the title, positioned at the bottom of the logs panel, showing the current search query
+/// An invalid regex is shown in red, in place of the number of matches
+fn log_search_title<'a>(
+    search: &LogSearch,
+    matches: usize,
+    editing: bool,
+    theme: &Theme,
+) -> Title<'a> {
+    let cursor = if editing { "▏" } else { "" };
+    let mode = if search.is_regex() { "regex" } else { "text" };
+    let result = search.get_error().map_or_else(
+        || Span::from(format!(" {matches} matches ")),
+        |err| Span::from(format!(" {err} ")).fg(theme.log_search_error),
+    );
+    Title::from(Line::from(vec![
+        Span::from(format!(" {mode} /{}{cursor}", search.get_query())).add_modifier(Modifier::BOLD),
+        result,
+    ]))
+    .position(Position::Bottom)
+}
+
 // Display the ports in a formatted list
 pub fn ports(
     f: &mut Frame,
     area: Rect,
     app_data: &Arc<Mutex<AppData>>,
     max_lens: (usize, usize, usize),
+    theme: &Theme,
 ) {
-    if let Some(ports) = app_data.lock().get_selected_ports() {
+    let ports = app_data.lock().get_selected_ports();
+    if let Some(ports) = ports {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
@@ -297,7 +848,7 @@ pub fn ports(
             .title(Span::styled(
                 " ports ",
                 Style::default()
-                    .fg(ports.1.get_color())
+                    .fg(theme.state(ports.1))
                     .add_modifier(Modifier::BOLD),
             ));
 
@@ -318,10 +869,10 @@ pub fn ports(
                     "{:>ip$}{:>private$}{:>public$}",
                     "ip", "private", "public"
                 ))
-                .fg(Color::Yellow),
+                .fg(theme.ports_header),
             )];
             for item in &ports.0 {
-                let fg = Color::White;
+                let fg = theme.ports_text;
                 let strings = item.print();
 
                 let line = vec![
@@ -338,8 +889,12 @@ pub fn ports(
 }
 
 /// Draw the cpu + mem charts
-pub fn chart(f: &mut Frame, area: Rect, app_data: &Arc<Mutex<AppData>>) {
-    if let Some((cpu, mem)) = app_data.lock().get_chart_data() {
+pub fn chart(f: &mut Frame, area: Rect, app_data: &Arc<Mutex<AppData>>, theme: &Theme) {
+    let chart_data = app_data.lock().get_chart_data();
+    #[allow(clippy::cast_precision_loss)]
+    let history = app_data.lock().args.history as f64;
+    let memory_unit = app_data.lock().get_memory_unit();
+    if let Some((cpu, mem)) = chart_data {
         let area = Layout::default()
             .direction(Direction::Horizontal)
             .constraints(CONSTRAINT_50_50)
@@ -347,38 +902,94 @@ pub fn chart(f: &mut Frame, area: Rect, app_data: &Arc<Mutex<AppData>>) {
 
         let cpu_dataset = vec![Dataset::default()
             .marker(symbols::Marker::Dot)
-            .style(Style::default().fg(Color::Magenta))
+            .style(Style::default().fg(theme.chart_cpu))
             .graph_type(GraphType::Line)
             .data(&cpu.0)];
         let mem_dataset = vec![Dataset::default()
             .marker(symbols::Marker::Dot)
-            .style(Style::default().fg(Color::Cyan))
+            .style(Style::default().fg(theme.chart_memory))
             .graph_type(GraphType::Line)
             .data(&mem.0)];
 
         let cpu_stats = CpuStats::new(cpu.0.last().map_or(0.00, |f| f.1));
         #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
         let mem_stats = ByteStats::new(mem.0.last().map_or(0, |f| f.1 as u64));
-        let cpu_chart = make_chart(cpu.2, "cpu", cpu_dataset, &cpu_stats, &cpu.1);
-        let mem_chart = make_chart(mem.2, "memory", mem_dataset, &mem_stats, &mem.1);
+        let cpu_chart = make_chart(
+            cpu.2,
+            "cpu",
+            cpu_dataset,
+            &cpu_stats.to_string(),
+            (cpu.1.to_string(), cpu.1.get_value()),
+            history,
+            theme,
+        );
+        let mem_chart = make_chart(
+            mem.2,
+            "memory",
+            mem_dataset,
+            &mem_stats.format(memory_unit),
+            (mem.1.format(memory_unit), mem.1.get_value()),
+            history,
+            theme,
+        );
 
         f.render_widget(cpu_chart, area[0]);
         f.render_widget(mem_chart, area[1]);
+        // The memory gauge is drawn over the bottom row of the memory chart, if the memory limit is known
+        let mem_gauge_data = app_data.lock().get_mem_gauge();
+        if let Some(gauge) = mem_gauge_data {
+            let inner = area[1].inner(&Margin {
+                vertical: 1,
+                horizontal: 1,
+            });
+            if inner.height > 1 {
+                let gauge_area = Rect {
+                    y: inner.bottom() - 1,
+                    height: 1,
+                    ..inner
+                };
+                f.render_widget(mem_gauge(gauge, memory_unit, theme), gauge_area);
+            }
+        }
     }
 }
 
-/// Create charts
-fn make_chart<'a, T: Stats + Display>(
+/// Memory usage as a percentage of the containers memory limit, or of the host memory if unlimited, changes color once close to the limit.
+/// The percentage is calculated from the bytes, so only the limit text depends on the memory unit
+fn mem_gauge<'a>(
+    (percent, limit): (f64, ByteStats),
+    memory_unit: MemoryUnit,
+    theme: &Theme,
+) -> LineGauge<'a> {
+    let color = if percent >= MEMORY_LIMIT_WARNING {
+        theme.chart_memory_limit
+    } else {
+        theme.chart_memory
+    };
+    LineGauge::default()
+        .ratio((percent / 100.0).clamp(0.0, 1.0))
+        .label(Span::styled(
+            format!("{percent:.2}% of {}", limit.format(memory_unit)),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ))
+        .line_set(symbols::line::THICK)
+        .gauge_style(Style::default().fg(color))
+}
+
+/// Create charts, the current value, and the max value, are already formatted, the max value is also needed as a number for the y axis bounds
+fn make_chart<'a>(
     state: State,
     name: &'a str,
     dataset: Vec<Dataset<'a>>,
-    current: &'a T,
-    max: &'a T,
+    current: &str,
+    (max, max_value): (String, f64),
+    history: f64,
+    theme: &Theme,
 ) -> Chart<'a> {
-    let title_color = state.get_color();
+    let title_color = theme.state(state);
     let label_color = match state {
-        State::Running => ORANGE,
-        _ => state.get_color(),
+        State::Running => theme.chart_max_running,
+        _ => title_color,
     };
     Chart::new(dataset)
         .block(
@@ -396,24 +1007,160 @@ fn make_chart<'a, T: Stats + Display>(
         .x_axis(
             Axis::default()
                 .style(Style::default().fg(title_color))
-                .bounds([0.00, 60.0]),
+                .bounds([0.00, history]),
         )
         .y_axis(
             Axis::default()
                 .labels(vec![
                     Span::styled("", Style::default().fg(label_color)),
                     Span::styled(
-                        format!("{max}"),
+                        max,
                         Style::default()
                             .add_modifier(Modifier::BOLD)
                             .fg(label_color),
                     ),
                 ])
                 // Add 0.01, so that max point is always visible?
-                .bounds([0.0, max.get_value() + 0.01]),
+                .bounds([0.0, max_value + 0.01]),
         )
 }
 
+/// Draw the detail sidebar, the key facts of the selected container, one per line, any value too long for the panel is cut off
+pub fn detail(f: &mut Frame, area: Rect, app_data: &Arc<Mutex<AppData>>) {
+    let detail = app_data.lock().get_selected_detail();
+    if let Some(detail) = detail {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title_alignment(Alignment::Center)
+            .title(Span::styled(
+                " detail ",
+                Style::default().add_modifier(Modifier::BOLD),
+            ));
+        let label_width = detail
+            .iter()
+            .map(|(label, _)| label.len())
+            .max()
+            .unwrap_or_default();
+        // The image, entrypoint, & cmd are wrapped onto as many lines as needed, so that the full value is shown, every other value is cut off
+        let value_width = usize::from(area.width.saturating_sub(2))
+            .saturating_sub(label_width + 2)
+            .max(1);
+        let lines = detail
+            .into_iter()
+            .flat_map(|(label, value)| {
+                let chunks = if WRAPPED_DETAIL.contains(&label) {
+                    value
+                        .chars()
+                        .collect::<Vec<_>>()
+                        .chunks(value_width)
+                        .map(|i| i.iter().collect::<String>())
+                        .collect::<Vec<_>>()
+                } else {
+                    vec![value]
+                };
+                chunks.into_iter().enumerate().map(move |(index, chunk)| {
+                    let label = if index == 0 { label } else { "" };
+                    Line::from(vec![
+                        Span::styled(
+                            format!(" {label:>label_width$} "),
+                            Style::default().add_modifier(Modifier::BOLD),
+                        ),
+                        Span::from(chunk),
+                    ])
+                })
+            })
+            .collect::<Vec<_>>();
+        f.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+/// Draw the cpu & memory history of the selected container as sparklines, one line each
+pub fn history(f: &mut Frame, area: Rect, app_data: &Arc<Mutex<AppData>>, theme: &Theme) {
+    let history_data = app_data.lock().get_history_data();
+    let memory_unit = app_data.lock().get_memory_unit();
+    if let Some(((cpu, cpu_current), (mem, mem_current), state)) = history_data {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title_alignment(Alignment::Center)
+            .title(Span::styled(
+                " history ",
+                Style::default()
+                    .fg(theme.state(state))
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        let labels = [
+            format!(" cpu {cpu_current} "),
+            format!(" memory {} ", mem_current.format(memory_unit)),
+        ];
+        let label_width = labels.iter().map(String::len).max().unwrap_or_default();
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Length(1)])
+            .split(block.inner(area));
+
+        f.render_widget(block, area);
+        for ((label, data, color), row) in [
+            (&labels[0], &cpu, theme.chart_cpu),
+            (&labels[1], &mem, theme.chart_memory),
+        ]
+        .into_iter()
+        .zip(rows.iter())
+        {
+            let split = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Length(u16::try_from(label_width).unwrap_or_default()),
+                    Constraint::Min(1),
+                ])
+                .split(*row);
+
+            // A Sparkline draws from the start of the data, so only use the most recent samples that fit
+            let width = usize::from(split[1].width);
+            let sparkline = Sparkline::default()
+                .data(&data[data.len().saturating_sub(width)..])
+                .style(Style::default().fg(color));
+
+            f.render_widget(
+                Paragraph::new(format!("{label:>label_width$}")).fg(color),
+                split[0],
+            );
+            f.render_widget(sparkline, split[1]);
+        }
+    }
+}
+
+/// Draw the summary bar, the number of running & stopped containers, and their total cpu & memory usage.
+/// The scope is always shown, so that it's clear if the totals exclude any filtered containers
+pub fn summary(f: &mut Frame, area: Rect, app_data: &Arc<Mutex<AppData>>, data: &FrameData) {
+    let (totals, memory_unit) = {
+        let app_data = app_data.lock();
+        (app_data.get_totals(), app_data.get_memory_unit())
+    };
+    let Some(totals) = totals else {
+        return;
+    };
+    let text = format!(
+        "  {}: {} running, {} stopped, {} total{MARGIN}cpu {}{MARGIN}memory {}",
+        data.summary.title(),
+        totals.running,
+        totals.stopped,
+        totals.total,
+        totals.cpu,
+        totals.mem.format(memory_unit),
+    );
+    f.render_widget(
+        Paragraph::new(text).style(
+            Style::default()
+                .bg(data.theme.header_bg)
+                .fg(data.theme.header_fg),
+        ),
+        area,
+    );
+}
+
 /// Draw heading bar at top of program, always visible
 /// TODO Should separate into loading icon/headers/help functions
 #[allow(clippy::too_many_lines)]
@@ -423,13 +1170,14 @@ pub fn heading_bar(
     data: &FrameData,
     gui_state: &Arc<Mutex<GuiState>>,
 ) {
-    let block = |fg: Color| Block::default().style(Style::default().bg(Color::Magenta).fg(fg));
+    let theme = &data.theme;
+    let block = |fg: Color| Block::default().style(Style::default().bg(theme.header_bg).fg(fg));
 
-    frame.render_widget(block(Color::Black), area);
+    frame.render_widget(block(theme.header_fg), area);
 
     // Generate a block for the header, if the header is currently being used to sort a column, then highlight it white
     let header_block = |x: &Header| {
-        let mut color = Color::Black;
+        let mut color = theme.header_fg;
         let mut prefix = "";
         let mut prefix_margin = 0;
         if let Some((a, b)) = &data.sorted_by {
@@ -439,45 +1187,32 @@ pub fn heading_bar(
                     SortedOrder::Desc => prefix = "▼ ",
                 }
                 prefix_margin = 2;
-                color = Color::White;
-            };
-        };
+                color = theme.header_selected_fg;
+            }
+        }
         (
-            Block::default().style(Style::default().bg(Color::Magenta).fg(color)),
+            Block::default().style(Style::default().bg(theme.header_bg).fg(color)),
             prefix,
             prefix_margin,
         )
     };
 
-    // Generate block for the headers, state and status has a specific layout, others all equal
+    // Generate block for the headers, the first column is offset to line up with the selected container marker, others are separated by a margin
     // width is dependant on it that column is selected to sort - or not
-    let gen_header = |header: &Header, width: usize| {
+    let gen_header = |header: &Header, width: usize, first: bool| {
         let block = header_block(header);
-        // Yes this is a mess, needs documenting correctly
-        let text = match header {
-            Header::State => format!(
-                " {x:>width$}",
-                x = format!("{ic}{header}", ic = block.1),
-                width = width
-            ),
-            Header::Name => format!(
-                "  {x:>width$}",
-                x = format!("{ic}{header}", ic = block.1),
-                width = width
-            ),
-            Header::Status => format!(
-                "{}  {x:>width$}",
-                MARGIN,
-                x = format!("{ic}{header}", ic = block.1),
-                width = width
-            ),
-            _ => format!(
-                "{}{x:>width$}",
-                MARGIN,
-                x = format!("{ic}{header}", ic = block.1),
-                width = width
-            ),
+        let margin = if first { "  " } else { MARGIN };
+        // The cpu heading shows which cpu mode is in use
+        let label = if header == &Header::Cpu {
+            data.cpu_mode.label().to_owned()
+        } else {
+            header.to_string()
         };
+        let text = format!(
+            "{margin}{x:>width$}",
+            x = format!("{ic}{label}", ic = block.1),
+            width = width
+        );
         let count = u16::try_from(text.chars().count()).unwrap_or_default();
         let status = Paragraph::new(text)
             .block(block.0)
@@ -485,28 +1220,70 @@ pub fn heading_bar(
         (status, count)
     };
 
-    // Meta data to iterate over to create blocks with correct widths
-    let header_meta = [
-        (Header::Name, data.columns.name.1),
-        (Header::State, data.columns.state.1),
-        (Header::Status, data.columns.status.1),
-        (Header::Cpu, data.columns.cpu.1),
-        (Header::Memory, data.columns.mem.1 + data.columns.mem.2 + 3),
-        (Header::Id, data.columns.id.1),
-        (Header::Image, data.columns.image.1),
-        (Header::Rx, data.columns.net_rx.1),
-        (Header::Tx, data.columns.net_tx.1),
-    ];
+    // Width of each column, to create blocks with correct widths
 
     // Need to add widths to this
 
     let suffix = if data.help_visible { "exit" } else { "show" };
-    let info_text = format!("( h ) {suffix} help {MARGIN}",);
-    let info_width = info_text.chars().count();
+    // Always show the filter term, so that it's clear that some containers are hidden
+    let filter_text = data.filter_term.as_ref().map_or(String::new(), |term| {
+        let cursor = if data.filter_editing { "▏" } else { "" };
+        format!("filter: {term}{cursor}{MARGIN}")
+    });
+    let search_text = data.search_term.as_ref().map_or(String::new(), |term| {
+        let cursor = if data.search_editing { "▏" } else { "" };
+        format!("search: {term}{cursor}{MARGIN}")
+    });
+    let go_to_text = data
+        .go_to
+        .as_ref()
+        .map_or(String::new(), |row| format!("go to: {row}▏{MARGIN}"));
+    let state_filter_text = if data.state_filter == StateFilter::All {
+        String::new()
+    } else {
+        format!("state: {}{MARGIN}", data.state_filter)
+    };
+    // The update interval is only shown once it's been changed from the `-d` argument
+    let interval_text = data
+        .changed_interval
+        .map_or(String::new(), |ms| format!("refresh: {ms}ms{MARGIN}"));
+    // Docker is expected, so the engine is only shown when connected to Podman
+    let engine_text = if data.engine == Engine::Podman {
+        format!("{}{MARGIN}", data.engine)
+    } else {
+        String::new()
+    };
+    // The round trip time of the last ping, colored by the health of the connection, once the daemon has been pinged
+    // Always shown, so that it's clear why commands are ignored
+    let read_only_text = if data.read_only {
+        format!("read-only{MARGIN}")
+    } else {
+        String::new()
+    };
+    let ping_span = data.ping.health().map_or_else(
+        || Span::raw(""),
+        |health| {
+            let color = match health {
+                PingHealth::Healthy => theme.health_healthy,
+                PingHealth::Slow => theme.health_starting,
+                PingHealth::Failing => theme.health_unhealthy,
+            };
+            Span::styled(format!("{}{MARGIN}", data.ping), Style::default().fg(color))
+        },
+    );
+    let info_text = format!(
+        "{filter_text}{search_text}{go_to_text}{state_filter_text}{interval_text}{engine_text}{read_only_text}"
+    );
+    let help_text = format!("( h ) {suffix} help {MARGIN}");
+    let info_width =
+        info_text.chars().count() + ping_span.content.chars().count() + help_text.chars().count();
 
+    // The column headers are hidden in full screen logs mode, and in the images view, as the containers panel isn't drawn
+    let show_headers =
+        data.has_containers && !data.logs_fullscreen && data.view == View::Containers;
     let column_width = usize::from(area.width).saturating_sub(info_width);
     let column_width = if column_width > 0 { column_width } else { 1 };
-    let splits = if data.has_containers {
+    let splits = if show_headers {
         vec![
             Constraint::Max(2),
             Constraint::Min(column_width.try_into().unwrap_or_default()),
@@ -521,19 +1298,21 @@ pub fn heading_bar(
         .constraints(splits)
         .split(area);
 
-    if data.has_containers {
+    if show_headers {
         let header_section_width = split_bar[1].width;
 
         let mut counter = 0;
 
         // Only show a header if the header cumulative header width is less than the header section width
-        let header_data = header_meta
+        let header_data = data
+            .visible_columns
             .iter()
-            .filter_map(|i| {
-                let header_block = gen_header(&i.0, i.1.into());
+            .enumerate()
+            .filter_map(|(index, header)| {
+                let header_block = gen_header(header, data.columns.width(*header), index == 0);
                 counter += header_block.1;
                 if counter <= header_section_width {
-                    Some((header_block.0, i.0, Constraint::Max(header_block.1)))
+                    Some((header_block.0, *header, Constraint::Max(header_block.1)))
                 } else {
                     None
                 }
@@ -542,7 +1321,7 @@ pub fn heading_bar(
 
         // Draw loading icon, or not, and a prefix with a single space
         let loading_paragraph = Paragraph::new(format!("{:>2}", data.loading_icon))
-            .block(block(Color::White))
+            .block(block(theme.header_selected_fg))
             .alignment(Alignment::Center);
         frame.render_widget(loading_paragraph, split_bar[0]);
 
@@ -563,16 +1342,20 @@ pub fn heading_bar(
 
     // show/hide help
     let color = if data.help_visible {
-        Color::Black
+        theme.header_fg
     } else {
-        Color::White
+        theme.header_selected_fg
     };
-    let help_paragraph = Paragraph::new(info_text)
-        .block(block(color))
-        .alignment(Alignment::Right);
+    let help_paragraph = Paragraph::new(Line::from(vec![
+        Span::raw(info_text),
+        ping_span,
+        Span::raw(help_text),
+    ]))
+    .block(block(color))
+    .alignment(Alignment::Right);
 
     // If no containers, don't display the headers, could maybe do this first?
-    let help_index = if data.has_containers { 2 } else { 0 };
+    let help_index = if show_headers { 2 } else { 0 };
     frame.render_widget(help_paragraph, split_bar[help_index]);
 }
 
@@ -604,21 +1387,21 @@ impl HelpInfo {
         Span::styled(input.to_owned(), Style::default().fg(color))
     }
 
-    /// &str to black text span
-    fn black_span<'a>(input: &str) -> Span<'a> {
-        Self::span(input, Color::Black)
+    /// &str to a span in the help text color
+    fn text_span<'a>(input: &str, theme: &Theme) -> Span<'a> {
+        Self::span(input, theme.help_fg)
     }
 
-    /// &str to white text span
-    fn white_span<'a>(input: &str) -> Span<'a> {
-        Self::span(input, Color::White)
+    /// &str to a span in the help highlight color
+    fn highlight_span<'a>(input: &str, theme: &Theme) -> Span<'a> {
+        Self::span(input, theme.help_highlight)
     }
 
     /// Generate the `oxker` name span + metadata
-    fn gen_name() -> Self {
+    fn gen_name(theme: &Theme) -> Self {
         let mut lines = NAME_TEXT
             .lines()
-            .map(|i| Line::from(Self::white_span(i)))
+            .map(|i| Line::from(Self::highlight_span(i, theme)))
             .collect::<Vec<_>>();
         lines.insert(0, Self::empty_span());
         let width = Self::calc_width(&lines);
@@ -631,10 +1414,10 @@ impl HelpInfo {
     }
 
     /// Generate the description span + metadata
-    fn gen_description() -> Self {
+    fn gen_description(theme: &Theme) -> Self {
         let lines = [
             Self::empty_span(),
-            Line::from(Self::white_span(DESCRIPTION)),
+            Line::from(Self::highlight_span(DESCRIPTION, theme)),
             Self::empty_span(),
         ];
         let width = Self::calc_width(&lines);
@@ -647,9 +1430,10 @@ impl HelpInfo {
     }
 
     /// Generate the button information span + metadata
-    fn gen_button() -> Self {
-        let button_item = |x: &str| Self::white_span(&format!(" ( {x} ) "));
-        let button_desc = |x: &str| Self::black_span(x);
+    #[allow(clippy::too_many_lines)]
+    fn gen_button(theme: &Theme) -> Self {
+        let button_item = |x: &str| Self::highlight_span(&format!(" ( {x} ) "), theme);
+        let button_desc = |x: &str| Self::text_span(x, theme);
         let or = || button_desc("or");
         let space = || button_desc(" ");
 
@@ -672,6 +1456,13 @@ impl HelpInfo {
                 button_item("Home End"),
                 button_desc("change selected line"),
             ]),
+            Line::from(vec![
+                space(),
+                button_item("alt+d alt+u"),
+                or(),
+                button_item("alt+f alt+b"),
+                button_desc("scroll the logs by half, or a whole, page"),
+            ]),
             Line::from(vec![
                 space(),
                 button_item("enter"),
@@ -686,20 +1477,269 @@ impl HelpInfo {
             ]),
             Line::from(vec![
                 space(),
-                button_item("h"),
-                button_desc("toggle this help information"),
+                button_item("i"),
+                button_desc("inspect a container - esc closes"),
             ]),
             Line::from(vec![
                 space(),
-                button_item("s"),
-                button_desc("save logs to file"),
+                button_item("a"),
+                button_desc("show environment variables, values are masked - m toggles"),
             ]),
             Line::from(vec![
                 space(),
-                button_item("m"),
-                button_desc(
-                    "toggle mouse capture - if disabled, text on screen can be selected & copied",
-                ),
+                button_item("ctrl+r"),
+                button_desc("show the docker run command, best-effort - c copies"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+u"),
+                button_desc("show the mounts of the selected container"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+t"),
+                button_desc("show the labels of the selected container"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("y"),
+                or(),
+                button_item("Y"),
+                button_desc("copy the id or name of the selected container"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("h"),
+                button_desc("toggle this help information"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("?"),
+                button_desc("show every keybinding, including any set in the config file"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("p"),
+                or(),
+                button_item("u"),
+                button_desc("pause or unpause the selected container"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("x"),
+                button_desc("kill the selected container with a chosen signal"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+e"),
+                button_desc("rename the selected container"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("alt+r"),
+                button_desc("recreate the selected container, best-effort - confirm first"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("alt+p"),
+                button_desc("pull the image of the selected container - r then recreates"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("alt+a"),
+                button_desc("change the restart policy of the selected container"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("alt+l"),
+                button_desc("change the cpu and memory limits of the selected container"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("delete"),
+                button_desc("delete the selected container, with force if running"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("b"),
+                button_desc("toggle the images view, delete removes the selected image"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+w"),
+                button_desc("toggle the networks view, i inspects, delete removes if unused"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+o"),
+                button_desc("toggle the volumes view, delete removes, ctrl+p prunes"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+p"),
+                button_desc("prune stopped containers, dangling images, and/or volumes"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("/"),
+                button_desc("search logs - tab toggles regex,"),
+                button_item("n"),
+                or(),
+                button_item("N"),
+                button_desc("next or previous match"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("f"),
+                button_desc("filter containers by name, image, or compose service - esc clears"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+f"),
+                button_desc("search container names,"),
+                button_item("n"),
+                or(),
+                button_item("N"),
+                button_desc("next or previous match"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item(":"),
+                button_desc("go to a container by its row number - type the number, then enter"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("F"),
+                button_desc("cycle the state filter - all, running, exited, paused"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("s"),
+                button_desc("save logs to file"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("t"),
+                button_desc("toggle log timestamps"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("v"),
+                button_desc("toggle the cpu & memory history panel"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("d"),
+                button_desc("toggle the container detail panel"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("l"),
+                button_desc("toggle full screen logs"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("w"),
+                button_desc("toggle wrapping of long log lines"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("g"),
+                or(),
+                button_item("G"),
+                button_desc("jump to the first or last line, G resumes following logs"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+s"),
+                button_desc("pause or resume the log stream of the selected container"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+l"),
+                button_desc("toggle coloring log lines by severity"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+d"),
+                button_desc("cycle the log streams - all, stdout, stderr"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("alt+m"),
+                button_desc("set a log marker, again shows only the newer lines, again clears"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+k"),
+                button_desc("only fetch logs since a duration, e.g. 10m, or a timestamp"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+b"),
+                button_desc("cycle the memory unit - auto, bytes, KiB, MiB, GiB"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+v"),
+                button_desc("toggle cpu usage between per core, and relative to every core"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+g"),
+                button_desc("cycle the id column - hidden, short, full"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+a"),
+                button_desc("cycle the summary bar - hidden, all, or visible containers"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("ctrl+y"),
+                button_desc("copy log lines,"),
+                button_item("J"),
+                or(),
+                button_item("K"),
+                button_desc("select a range,"),
+                button_item("y"),
+                button_desc("copies"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("alt+y"),
+                button_desc("copy the visible log lines,"),
+                button_item("alt+Y"),
+                button_desc("every buffered line"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("c"),
+                button_desc("group containers by compose project"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("z"),
+                button_desc("collapse or expand the selected group"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("space"),
+                button_desc("mark a container, commands apply to every marked container"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("+"),
+                or(),
+                button_item("-"),
+                button_desc("refresh docker information faster or slower"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("m"),
+                button_desc(
+                    "toggle mouse capture - if disabled, text on screen can be selected & copied",
+                ),
             ]),
             Line::from(vec![space(), button_item("0"), button_desc("stop sort")]),
             Line::from(vec![
@@ -707,6 +1747,14 @@ impl HelpInfo {
                 button_item("1 - 9"),
                 button_desc("sort by header - or click header"),
             ]),
+            Line::from(vec![
+                space(),
+                button_item("o"),
+                button_desc("cycle the sorted column "),
+                or(),
+                button_item("r"),
+                button_desc("reverse the sort order"),
+            ]),
             Line::from(vec![
                 space(),
                 button_item("esc"),
@@ -729,16 +1777,17 @@ impl HelpInfo {
     }
 
     /// Generate the final lines, GitHub link etc, + metadata
-    fn gen_final() -> Self {
+    fn gen_final(theme: &Theme) -> Self {
         let lines = [
             Self::empty_span(),
-            Line::from(vec![Self::black_span(
+            Line::from(vec![Self::text_span(
                 "currently an early work in progress, all and any input appreciated",
+                theme,
             )]),
             Line::from(vec![Span::styled(
                 REPO.to_owned(),
                 Style::default()
-                    .fg(Color::White)
+                    .fg(theme.help_highlight)
                     .add_modifier(Modifier::UNDERLINED),
             )]),
         ];
@@ -753,13 +1802,13 @@ impl HelpInfo {
 }
 
 /// Draw the help box in the centre of the screen
-pub fn help_box(f: &mut Frame) {
+pub fn help_box(f: &mut Frame, theme: &Theme) {
     let title = format!(" {VERSION} ");
 
-    let name_info = HelpInfo::gen_name();
-    let description_info = HelpInfo::gen_description();
-    let button_info = HelpInfo::gen_button();
-    let final_info = HelpInfo::gen_final();
+    let name_info = HelpInfo::gen_name(theme);
+    let description_info = HelpInfo::gen_description(theme);
+    let button_info = HelpInfo::gen_button(theme);
+    let final_info = HelpInfo::gen_final(theme);
 
     // have to add 10, but shouldn't need to, is an error somewhere
     let max_line_width = [
@@ -793,22 +1842,22 @@ pub fn help_box(f: &mut Frame) {
         .split(area);
 
     let name_paragraph = Paragraph::new(name_info.lines)
-        .style(Style::default().bg(Color::Magenta).fg(Color::White))
+        .style(Style::default().bg(theme.help_bg).fg(theme.help_highlight))
         .block(Block::default())
         .alignment(Alignment::Center);
 
     let description_paragraph = Paragraph::new(description_info.lines)
-        .style(Style::default().bg(Color::Magenta).fg(Color::Black))
+        .style(Style::default().bg(theme.help_bg).fg(theme.help_fg))
         .block(Block::default())
         .alignment(Alignment::Center);
 
     let help_paragraph = Paragraph::new(button_info.lines)
-        .style(Style::default().bg(Color::Magenta).fg(Color::Black))
+        .style(Style::default().bg(theme.help_bg).fg(theme.help_fg))
         .block(Block::default())
         .alignment(Alignment::Left);
 
     let final_paragraph = Paragraph::new(final_info.lines)
-        .style(Style::default().bg(Color::Magenta).fg(Color::Black))
+        .style(Style::default().bg(theme.help_bg).fg(theme.help_fg))
         .block(Block::default())
         .alignment(Alignment::Center);
 
@@ -816,7 +1865,7 @@ pub fn help_box(f: &mut Frame) {
         .title(title)
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(Color::Black).bg(Color::Magenta));
+        .border_style(Style::default().fg(theme.help_fg).bg(theme.help_bg));
 
     // Order is important here
     f.render_widget(Clear, area);
@@ -827,28 +1876,101 @@ pub fn help_box(f: &mut Frame) {
     f.render_widget(block, area);
 }
 
+/// Draw the keybinding cheatsheet, every bound key grouped by category, scrolled by its offset if taller than the screen
+pub fn cheatsheet(f: &mut Frame, cheatsheet: &Cheatsheet, theme: &Theme) {
+    let key_width = cheatsheet
+        .sections
+        .iter()
+        .flat_map(|(_, actions)| actions.iter())
+        .map(|(keys, _)| keys.chars().count())
+        .max()
+        .unwrap_or_default();
+
+    let mut lines = vec![];
+    for (index, (category, actions)) in cheatsheet.sections.iter().enumerate() {
+        if index > 0 {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(Span::styled(
+            category.to_string(),
+            Style::default()
+                .fg(theme.help_highlight)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for (keys, action) in actions {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{keys:>key_width$}  "),
+                    Style::default().fg(theme.help_highlight),
+                ),
+                Span::styled(action.to_string(), Style::default().fg(theme.help_fg)),
+            ]));
+        }
+    }
+    let line_count = lines.len();
+    let max_width = lines.iter().map(Line::width).max().unwrap_or_default();
+
+    let block = Block::default()
+        .title(" Keybindings ")
+        .title(
+            Title::from(format!(
+                " {}/{line_count} ( esc ) to close ",
+                usize::from(cheatsheet.offset) + usize::from(line_count > 0)
+            ))
+            .position(Position::Bottom),
+        )
+        .border_type(BorderType::Rounded)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.help_fg).bg(theme.help_bg));
+
+    let area = f.size();
+    let area = popup(
+        (line_count + 2).min(usize::from(area.height.saturating_sub(2))),
+        (max_width + 6).max(32),
+        area,
+        BoxLocation::MiddleCentre,
+    );
+
+    let paragraph = Paragraph::new(lines)
+        .style(Style::default().bg(theme.help_bg).fg(theme.help_fg))
+        .block(block.padding(Padding::horizontal(1)))
+        .scroll((cheatsheet.offset, 0));
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
 /// Draw the delete confirm box in the centre of the screen
 /// take in container id and container name here?
-pub fn delete_confirm(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, name: &ContainerName) {
+/// If the container is still running, the yes button is replaced with a force button
+pub fn delete_confirm(
+    f: &mut Frame,
+    gui_state: &Arc<Mutex<GuiState>>,
+    kind: &str,
+    name: &str,
+    force: bool,
+    theme: &Theme,
+) {
     let block = Block::default()
         .title(" Confirm Delete ")
         .border_type(BorderType::Rounded)
-        .style(Style::default().bg(Color::White).fg(Color::Black))
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_fg))
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL);
 
     let confirm = Line::from(vec![
-        Span::from("Are you sure you want to delete container: "),
+        Span::from(format!("Are you sure you want to delete {kind}: ")),
         Span::styled(
-            name.get(),
+            name,
             Style::default()
-                .fg(Color::Red)
-                .bg(Color::White)
+                .fg(theme.popup_highlight)
+                .bg(theme.popup_bg)
                 .add_modifier(Modifier::BOLD),
         ),
     ]);
 
-    let yes_text = " (Y)es ";
+    let yes_text = if force { " (F)orce " } else { " (Y)es " };
     let no_text = " (N)o ";
 
     // Find the maximum line width & height, and add some padding
@@ -861,7 +1983,7 @@ pub fn delete_confirm(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, name: &Co
         Block::default()
             .border_type(BorderType::Rounded)
             .borders(Borders::ALL)
-            .style(Style::default().bg(Color::White))
+            .style(Style::default().bg(theme.popup_bg))
     };
 
     let yes_para = Paragraph::new(yes_text)
@@ -907,242 +2029,3022 @@ pub fn delete_confirm(f: &mut Frame, gui_state: &Arc<Mutex<GuiState>>, name: &Co
         .update_region_map(Region::Delete(DeleteButton::Yes), yes_area);
 }
 
-/// Draw an error popup over whole screen
-pub fn error(f: &mut Frame, error: AppError, seconds: Option<u8>) {
+/// Draw the confirmation popup for a stop, restart, kill, or delete, only used when "--confirm-destructive" is set
+pub fn confirm_control(f: &mut Frame, confirm_control: &ConfirmControl, name: &str, theme: &Theme) {
     let block = Block::default()
-        .title(" Error ")
+        .title(" Confirm ")
         .border_type(BorderType::Rounded)
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_fg))
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL);
 
-    let to_push = match error {
-        AppError::DockerConnect => {
-            format!(
-                "\n\n {}::v{} closing in {:02} seconds",
-                NAME,
-                VERSION,
-                seconds.unwrap_or(5)
-            )
-        }
-        _ => String::from("\n\n ( c ) clear error\n ( q ) quit oxker "),
-    };
-
-    let mut text = format!("\n{error}");
-
-    text.push_str(to_push.as_str());
+    let mut spans = vec![
+        Span::from(format!(
+            "Are you sure you want to {}: ",
+            confirm_control.control
+        )),
+        Span::styled(
+            name,
+            Style::default()
+                .fg(theme.popup_highlight)
+                .bg(theme.popup_bg)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ];
+    if let Some(signal) = confirm_control.signal.as_ref() {
+        spans.push(Span::from(format!(" with {signal}")));
+    }
+    let confirm = Line::from(spans);
+    // Recreating is best-effort, so warn that anything other than the kept options is lost
+    let warning = (confirm_control.control == DockerControls::Recreate).then(|| {
+        Line::from(Span::styled(
+            RECREATE_WARNING,
+            Style::default().fg(theme.alert),
+        ))
+    });
 
-    // Find the maximum line width & height
-    let mut max_line_width = max_line_width(&text);
-    let mut lines = text.lines().count();
+    let max_line_width =
+        u16::try_from(warning.as_ref().map_or(0, Line::width).max(confirm.width())).unwrap_or(64)
+            + 12;
+    let area = popup(
+        8,
+        max_line_width.into(),
+        f.size(),
+        BoxLocation::MiddleCentre,
+    );
 
-    // Add some horizontal & vertical margins
-    max_line_width += 8;
-    lines += 3;
+    let split_popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(CONSTRAINT_POPUP)
+        .split(area);
 
-    let paragraph = Paragraph::new(text)
-        .style(Style::default().bg(Color::Red).fg(Color::White))
-        .block(block)
-        .alignment(Alignment::Center);
+    let split_buttons = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(CONSTRAINT_BUTTONS)
+        .split(split_popup[3]);
 
-    let area = popup(lines, max_line_width, f.size(), BoxLocation::MiddleCentre);
+    let button = |text| {
+        Paragraph::new(text).alignment(Alignment::Center).block(
+            Block::default()
+                .border_type(BorderType::Rounded)
+                .borders(Borders::ALL)
+                .style(Style::default().bg(theme.popup_bg)),
+        )
+    };
 
-    // let (paragraph, area) = gen_error(f, error, seconds);
     f.render_widget(Clear, area);
-    f.render_widget(paragraph, area);
+    f.render_widget(block, area);
+    f.render_widget(
+        Paragraph::new(confirm).alignment(Alignment::Center),
+        split_popup[1],
+    );
+    if let Some(warning) = warning {
+        f.render_widget(
+            Paragraph::new(warning).alignment(Alignment::Center),
+            split_popup[2],
+        );
+    }
+    f.render_widget(button(" (N)o "), split_buttons[1]);
+    f.render_widget(button(" (Y)es "), split_buttons[3]);
 }
 
-/// Draw info box in one of the 9 BoxLocations
-// TODO is this broken?
-pub fn info(f: &mut Frame, text: &str, instant: Instant, gui_state: &Arc<Mutex<GuiState>>) {
+/// Draw the quit confirmation, shown when quitting whilst Docker commands are still being processed.
+/// Whilst waiting for the commands to finish the count, and loading icon, are shown instead of the wait key
+pub fn quit_confirm(
+    f: &mut Frame,
+    pending: usize,
+    waiting: bool,
+    loading_icon: &str,
+    theme: &Theme,
+) {
     let block = Block::default()
-        .title("")
+        .title(" Quit ")
+        .border_type(BorderType::Rounded)
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_fg))
         .title_alignment(Alignment::Center)
-        .borders(Borders::NONE);
+        .borders(Borders::ALL);
 
-    let mut max_line_width = max_line_width(text);
-    let mut lines = text.lines().count();
+    let actions = if pending == 1 {
+        String::from("1 action")
+    } else {
+        format!("{pending} actions")
+    };
+    let highlight = Style::default()
+        .fg(theme.popup_highlight)
+        .bg(theme.popup_bg)
+        .add_modifier(Modifier::BOLD);
+    let (text, hint) = if waiting {
+        (
+            Line::from(vec![
+                Span::from("Waiting for "),
+                Span::styled(actions, highlight),
+                Span::from(format!(" to finish {loading_icon}")),
+            ]),
+            Line::from(""),
+        )
+    } else {
+        (
+            Line::from(vec![
+                Span::styled(actions, highlight),
+                Span::from(" still in progress, quit anyway?"),
+            ]),
+            Line::from("( w ) wait for them to finish, then quit"),
+        )
+    };
 
-    // Add some horizontal & vertical margins
-    max_line_width += 8;
-    lines += 2;
+    let max_line_width = u16::try_from(text.width().max(hint.width())).unwrap_or(64) + 12;
+    let area = popup(
+        8,
+        max_line_width.into(),
+        f.size(),
+        BoxLocation::MiddleCentre,
+    );
 
-    let paragraph = Paragraph::new(text)
-        .style(Style::default().bg(Color::Blue).fg(Color::White))
-        .block(block)
-        .alignment(Alignment::Center);
+    let split_popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(CONSTRAINT_POPUP)
+        .split(area);
+
+    let split_buttons = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(CONSTRAINT_BUTTONS)
+        .split(split_popup[3]);
+
+    let button = |text| {
+        Paragraph::new(text).alignment(Alignment::Center).block(
+            Block::default()
+                .border_type(BorderType::Rounded)
+                .borders(Borders::ALL)
+                .style(Style::default().bg(theme.popup_bg)),
+        )
+    };
 
-    let area = popup(lines, max_line_width, f.size(), BoxLocation::BottomRight);
     f.render_widget(Clear, area);
-    f.render_widget(paragraph, area);
-    if instant.elapsed().as_millis() > 4000 {
-        gui_state.lock().reset_info_box();
-    }
+    f.render_widget(block, area);
+    f.render_widget(
+        Paragraph::new(text).alignment(Alignment::Center),
+        split_popup[1],
+    );
+    f.render_widget(
+        Paragraph::new(hint).alignment(Alignment::Center),
+        split_popup[2],
+    );
+    f.render_widget(button(" (N)o "), split_buttons[1]);
+    f.render_widget(button(" (Y)es "), split_buttons[3]);
 }
 
-/// draw a box in the one of the BoxLocations, based on max line width + number of lines
-fn popup(text_lines: usize, text_width: usize, r: Rect, box_location: BoxLocation) -> Rect {
-    // Make sure blank_space can't be an negative, as will crash
-    let calc = |x: u16, y: usize| usize::from(x).saturating_sub(y).saturating_div(2);
+/// Draw the kill popup, a list of common signals, and a line to type a custom signal
+pub fn kill_signal(
+    f: &mut Frame,
+    kill_signal: &mut KillSignal,
+    name: &ContainerName,
+    theme: &Theme,
+) {
+    let block = Block::default()
+        .title(format!(" Kill {} ", name.get()))
+        .border_type(BorderType::Rounded)
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_fg))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
 
-    let blank_vertical = calc(r.height, text_lines);
-    let blank_horizontal = calc(r.width, text_width);
+    let items = kill_signal
+        .signals
+        .items
+        .iter()
+        .map(|i| ListItem::new(*i))
+        .collect::<Vec<_>>();
 
-    let (h_constraints, v_constraints) = box_location.get_constraints(
-        blank_horizontal.try_into().unwrap_or_default(),
-        blank_vertical.try_into().unwrap_or_default(),
-        text_lines.try_into().unwrap_or_default(),
-        text_width.try_into().unwrap_or_default(),
+    let list_len = u16::try_from(items.len()).unwrap_or(6);
+
+    // typed signal is shown in red until it's valid
+    let input_color = if validate_signal(&kill_signal.input).is_some() {
+        theme.popup_fg
+    } else {
+        theme.popup_highlight
+    };
+    let input = Line::from(vec![
+        Span::from("signal: "),
+        Span::styled(
+            kill_signal.input.as_str(),
+            Style::default()
+                .fg(input_color)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]);
+    let instructions =
+        Line::from("( ↑ ↓ ) select or type a signal, ( enter ) to send, ( esc ) to cancel");
+
+    let max_line_width = u16::try_from(instructions.width()).unwrap_or(64) + 4;
+    let area = popup(
+        usize::from(list_len + 5),
+        max_line_width.into(),
+        f.size(),
+        BoxLocation::MiddleCentre,
     );
 
-    let indexes = box_location.get_indexes();
+    let split_popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(list_len),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(block.inner(area));
 
-    let popup_layout = Layout::default()
+    // Only highlight the list item if a signal isn't being typed
+    let list = List::new(items).highlight_symbol(RIGHT_ARROW);
+    let list = if kill_signal.input.is_empty() {
+        list.highlight_style(Style::default().add_modifier(Modifier::BOLD))
+    } else {
+        list
+    };
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_stateful_widget(list, split_popup[0], &mut kill_signal.signals.state);
+    f.render_widget(Paragraph::new(input), split_popup[2]);
+    f.render_widget(
+        Paragraph::new(instructions).alignment(Alignment::Center),
+        split_popup[3],
+    );
+}
+
+/// Draw the restart policy popup, a list of the restart policies, with the current policy of the container selected when opened
+pub fn restart_policy(
+    f: &mut Frame,
+    restart_policy: &mut RestartPolicy,
+    name: &ContainerName,
+    theme: &Theme,
+) {
+    let title = format!(" Restart policy {} ", name.get());
+    let title_width = title.chars().count();
+    let block = Block::default()
+        .title(title)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_fg))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let items = restart_policy
+        .policies
+        .items
+        .iter()
+        .map(|i| ListItem::new(*i))
+        .collect::<Vec<_>>();
+    let list_len = u16::try_from(items.len()).unwrap_or(4);
+
+    let instructions = Line::from("( ↑ ↓ ) select a policy, ( enter ) to set, ( esc ) to cancel");
+    // Wide enough for the title, as a long container name could be wider than the instructions
+    let max_line_width = instructions.width().max(title_width) + 4;
+    let area = popup(
+        usize::from(list_len + 4),
+        max_line_width,
+        f.size(),
+        BoxLocation::MiddleCentre,
+    );
+
+    let split_popup = Layout::default()
         .direction(Direction::Vertical)
-        .constraints(v_constraints)
-        .split(r);
+        .constraints([
+            Constraint::Length(list_len),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(block.inner(area));
 
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints(h_constraints)
-        .split(popup_layout[indexes.0])[indexes.1]
+    let list = List::new(items)
+        .highlight_symbol(RIGHT_ARROW)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_stateful_widget(list, split_popup[0], &mut restart_policy.policies.state);
+    f.render_widget(
+        Paragraph::new(instructions).alignment(Alignment::Center),
+        split_popup[2],
+    );
 }
 
-#[cfg(test)]
-#[allow(clippy::unwrap_used, clippy::many_single_char_names)]
-mod tests {
+/// Draw the prune popup, showing what will be removed, each target can be toggled
+pub fn prune(f: &mut Frame, prune: Prune, theme: &Theme) {
+    let block = Block::default()
+        .title(" Prune ")
+        .border_type(BorderType::Rounded)
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_fg))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let target = |selected: bool, key: &str, text: String| {
+        let check = if selected { "[x]" } else { "[ ]" };
+        let style = if selected {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        Line::from(vec![
+            Span::styled(format!("{check} "), style),
+            Span::from(format!("( {key} ) ")),
+            Span::styled(text, style),
+        ])
+    };
+    let containers = target(
+        prune.containers,
+        "c",
+        format!("remove {} stopped containers", prune.stopped),
+    );
+    let images = target(
+        prune.images,
+        "i",
+        String::from("remove all dangling images"),
+    );
+    let volumes = target(
+        prune.volumes,
+        "v",
+        String::from("remove all unused anonymous volumes"),
+    );
+    let instructions = Line::from("( enter ) to prune, ( esc ) to cancel");
+
+    let max_line_width = containers
+        .width()
+        .max(images.width())
+        .max(volumes.width())
+        .max(instructions.width())
+        + 4;
+    let area = popup(7, max_line_width, f.size(), BoxLocation::MiddleCentre);
+
+    let split_popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(block.inner(area));
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(containers), split_popup[0]);
+    f.render_widget(Paragraph::new(images), split_popup[1]);
+    f.render_widget(Paragraph::new(volumes), split_popup[2]);
+    f.render_widget(
+        Paragraph::new(instructions).alignment(Alignment::Center),
+        split_popup[4],
+    );
+}
+
+/// Draw the save logs popup, with the editable path the logs will be written to
+pub fn save_logs(f: &mut Frame, save_logs: &SaveLogs, theme: &Theme) {
+    let block = Block::default()
+        .title(" Save Logs ")
+        .border_type(BorderType::Rounded)
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_fg))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let path = Line::from(vec![
+        Span::from("path: "),
+        Span::styled(
+            format!("{}▏", save_logs.path),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+    ]);
+    let instructions = Line::from("( enter ) to save, ( esc ) to cancel");
+
+    let max_line_width = path.width().max(instructions.width()) + 4;
+    let area = popup(4, max_line_width, f.size(), BoxLocation::MiddleCentre);
+
+    let split_popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Length(1)])
+        .split(block.inner(area));
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(path), split_popup[0]);
+    f.render_widget(
+        Paragraph::new(instructions).alignment(Alignment::Center),
+        split_popup[1],
+    );
+}
+
+/// Draw the rename popup, the new name is shown in the highlight color until it's a valid container name
+pub fn rename(f: &mut Frame, rename: &Rename, name: &ContainerName, theme: &Theme) {
+    let block = Block::default()
+        .title(format!(" Rename {} ", name.get()))
+        .border_type(BorderType::Rounded)
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_fg))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let input_color = if validate_container_name(rename.name.trim()) {
+        theme.popup_fg
+    } else {
+        theme.popup_highlight
+    };
+    let input = Line::from(vec![
+        Span::from("name: "),
+        Span::styled(
+            format!("{}▏", rename.name),
+            Style::default()
+                .fg(input_color)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]);
+    let instructions = Line::from("( enter ) to rename, ( esc ) to cancel");
+
+    let max_line_width = input.width().max(instructions.width()) + 4;
+    let area = popup(4, max_line_width, f.size(), BoxLocation::MiddleCentre);
+
+    let split_popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Length(1)])
+        .split(block.inner(area));
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(input), split_popup[0]);
+    f.render_widget(
+        Paragraph::new(instructions).alignment(Alignment::Center),
+        split_popup[1],
+    );
+}
+
+/// Draw the log since popup, with the reason the input is invalid, if it failed to parse
+pub fn log_since(f: &mut Frame, log_since: &LogSince, theme: &Theme) {
+    let block = Block::default()
+        .title(" Logs Since ")
+        .border_type(BorderType::Rounded)
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_fg))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let mut lines = vec![Line::from(vec![
+        Span::from("since: "),
+        Span::styled(
+            format!("{}▏", log_since.input),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+    ])];
+    if let Some(error) = log_since.error.as_ref() {
+        lines.push(Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(theme.popup_highlight),
+        )));
+    }
+    let instructions = Line::from("( enter ) to refetch, leave empty to clear, ( esc ) to cancel");
+
+    let max_line_width = lines
+        .iter()
+        .chain(std::iter::once(&instructions))
+        .map(Line::width)
+        .max()
+        .unwrap_or_default()
+        + 4;
+    let text_height = lines.len();
+    let area = popup(
+        text_height + 3,
+        max_line_width,
+        f.size(),
+        BoxLocation::MiddleCentre,
+    );
 
-    use std::{ops::RangeInclusive, sync::Arc};
+    let split_popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(u16::try_from(text_height).unwrap_or_default()),
+            Constraint::Length(1),
+        ])
+        .split(block.inner(area));
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(lines), split_popup[0]);
+    f.render_widget(
+        Paragraph::new(instructions).alignment(Alignment::Center),
+        split_popup[1],
+    );
+}
+
+/// Draw the limits popup, the selected input has the cursor, with the reason an input is invalid, if it failed to parse
+pub fn limits(f: &mut Frame, limits: &Limits, name: &ContainerName, theme: &Theme) {
+    let block = Block::default()
+        .title(format!(" Limits {} ", name.get()))
+        .border_type(BorderType::Rounded)
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_fg))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let input = |label: &'static str, value: &str, field: LimitsField| {
+        let (value, style) = if limits.field == field {
+            (
+                format!("{value}▏"),
+                Style::default().add_modifier(Modifier::BOLD),
+            )
+        } else {
+            (value.to_owned(), Style::default())
+        };
+        Line::from(vec![Span::from(label), Span::styled(value, style)])
+    };
+    let mut lines = vec![
+        input("  cpus: ", &limits.cpus, LimitsField::Cpus),
+        input("memory: ", &limits.memory, LimitsField::Memory),
+    ];
+    if let Some(error) = limits.error.as_ref() {
+        lines.push(Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(theme.popup_highlight),
+        )));
+    }
+    let instructions = Line::from(
+        "( tab ) next input, ( enter ) to update, empty is unchanged, ( esc ) to cancel",
+    );
+
+    let max_line_width = lines
+        .iter()
+        .chain(std::iter::once(&instructions))
+        .map(Line::width)
+        .max()
+        .unwrap_or_default()
+        + 4;
+    let text_height = lines.len();
+    let area = popup(
+        text_height + 3,
+        max_line_width,
+        f.size(),
+        BoxLocation::MiddleCentre,
+    );
+
+    let split_popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(u16::try_from(text_height).unwrap_or_default()),
+            Constraint::Length(1),
+        ])
+        .split(block.inner(area));
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(lines), split_popup[0]);
+    f.render_widget(
+        Paragraph::new(instructions).alignment(Alignment::Center),
+        split_popup[1],
+    );
+}
+
+/// Draw the inspect output of a container in a scrollable popup, covering most of the screen
+pub fn inspect(
+    f: &mut Frame,
+    app_data: &Arc<Mutex<AppData>>,
+    inspect: &Inspect,
+    name: &ContainerName,
+    fetch: &Fetch,
+) {
+    let text = app_data.lock().get_inspect_by_id(&inspect.id);
+    inspect_popup(f, text, &name.to_string(), inspect.offset, fetch);
+}
+
+/// Draw the inspect output of a network in a scrollable popup, in the same way as a container
+pub fn network_inspect(
+    f: &mut Frame,
+    app_data: &Arc<Mutex<AppData>>,
+    inspect: &NetworkInspect,
+    name: &str,
+    fetch: &Fetch,
+) {
+    let text = app_data.lock().get_network_inspect_by_id(&inspect.id);
+    inspect_popup(f, text, name, inspect.offset, fetch);
+}
+
+/// Draw pretty-printed inspect output, scrolled by offset lines, or a loading message if the output isn't yet available, or the error if it couldn't be fetched
+fn inspect_popup(f: &mut Frame, text: Option<String>, name: &str, offset: u16, fetch: &Fetch) {
+    let line_count = text.as_ref().map_or(0, |i| i.lines().count());
+
+    let block = Block::default()
+        .title(format!(" Inspect {name} "))
+        .title(
+            Title::from(format!(
+                " {}/{line_count} ( esc ) to close ",
+                usize::from(offset) + usize::from(line_count > 0)
+            ))
+            .position(Position::Bottom),
+        )
+        .border_type(BorderType::Rounded)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let area = f.size();
+    let area = popup(
+        usize::from(area.height.saturating_sub(4)),
+        usize::from(area.width.saturating_sub(8)),
+        area,
+        BoxLocation::MiddleCentre,
+    );
+
+    let paragraph = text.map_or_else(
+        || Paragraph::new(fetch.to_string()).alignment(Alignment::Center),
+        |text| Paragraph::new(text).scroll((offset, 0)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph.block(block), area);
+}
+
+/// Draw the environment variables of a container in a scrollable popup, one `KEY=VALUE` per line, values are masked unless toggled
+pub fn env(
+    f: &mut Frame,
+    app_data: &Arc<Mutex<AppData>>,
+    env: &EnvVars,
+    name: &ContainerName,
+    fetch: &Fetch,
+) {
+    let vars = app_data.lock().get_env_by_id(&env.id);
+    let line_count = vars.as_ref().map_or(0, Vec::len);
+    let mask = if env.masked { "show" } else { "hide" };
+
+    let block = Block::default()
+        .title(format!(" Environment {name} "))
+        .title(
+            Title::from(format!(
+                " {}/{line_count} ( m ) {mask} values ( esc ) to close ",
+                usize::from(env.offset) + usize::from(line_count > 0)
+            ))
+            .position(Position::Bottom),
+        )
+        .border_type(BorderType::Rounded)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let area = f.size();
+    let area = popup(
+        usize::from(area.height.saturating_sub(4)),
+        usize::from(area.width.saturating_sub(8)),
+        area,
+        BoxLocation::MiddleCentre,
+    );
+
+    let paragraph = vars.map_or_else(
+        || Paragraph::new(fetch.to_string()).alignment(Alignment::Center),
+        |vars| {
+            if vars.is_empty() {
+                Paragraph::new("no environment variables").alignment(Alignment::Center)
+            } else {
+                let lines = vars
+                    .iter()
+                    .map(|i| Line::from(env.format(i)))
+                    .collect::<Vec<_>>();
+                Paragraph::new(lines).scroll((env.offset, 0))
+            }
+        },
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph.block(block), area);
+}
+
+/// Draw the labels of a container in a scrollable popup, sorted by key, the keys of labels set by compose, swarm, or the image spec are bold
+pub fn labels(
+    f: &mut Frame,
+    app_data: &Arc<Mutex<AppData>>,
+    labels: &Labels,
+    name: &ContainerName,
+    fetch: &Fetch,
+) {
+    let items = app_data.lock().get_labels_by_id(&labels.id);
+    let line_count = items.as_ref().map_or(0, BTreeMap::len);
+
+    let block = Block::default()
+        .title(format!(" Labels {name} "))
+        .title(
+            Title::from(format!(
+                " {}/{line_count} ( esc ) to close ",
+                usize::from(labels.offset) + usize::from(line_count > 0)
+            ))
+            .position(Position::Bottom),
+        )
+        .border_type(BorderType::Rounded)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let area = f.size();
+    let area = popup(
+        usize::from(area.height.saturating_sub(4)),
+        usize::from(area.width.saturating_sub(8)),
+        area,
+        BoxLocation::MiddleCentre,
+    );
+
+    let paragraph = items.map_or_else(
+        || Paragraph::new(fetch.to_string()).alignment(Alignment::Center),
+        |items| {
+            if items.is_empty() {
+                Paragraph::new("no labels").alignment(Alignment::Center)
+            } else {
+                let lines = items
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let key = if KNOWN_LABELS.iter().any(|i| key.starts_with(i)) {
+                            Span::from(key).add_modifier(Modifier::BOLD)
+                        } else {
+                            Span::from(key)
+                        };
+                        Line::from(vec![key, Span::from(format!("={value}"))])
+                    })
+                    .collect::<Vec<_>>();
+                Paragraph::new(lines).scroll((labels.offset, 0))
+            }
+        },
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph.block(block), area);
+}
+
+/// Draw the mounts of a container in a scrollable popup, the column headings stay at the top whilst scrolling
+pub fn mounts(
+    f: &mut Frame,
+    app_data: &Arc<Mutex<AppData>>,
+    mounts: &Mounts,
+    name: &ContainerName,
+    fetch: &Fetch,
+) {
+    let items = app_data.lock().get_mounts_by_id(&mounts.id);
+    let line_count = items.as_ref().map_or(0, Vec::len);
+
+    let block = Block::default()
+        .title(format!(" Mounts {name} "))
+        .title(
+            Title::from(format!(
+                " {}/{line_count} ( esc ) to close ",
+                usize::from(mounts.offset) + usize::from(line_count > 0)
+            ))
+            .position(Position::Bottom),
+        )
+        .border_type(BorderType::Rounded)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let area = f.size();
+    let area = popup(
+        usize::from(area.height.saturating_sub(4)),
+        usize::from(area.width.saturating_sub(8)),
+        area,
+        BoxLocation::MiddleCentre,
+    );
+
+    let paragraph = items.map_or_else(
+        || Paragraph::new(fetch.to_string()).alignment(Alignment::Center),
+        |items| {
+            if items.is_empty() {
+                Paragraph::new("no mounts").alignment(Alignment::Center)
+            } else {
+                let width = |heading: &str, value: fn(&ContainerMount) -> &str| {
+                    items
+                        .iter()
+                        .map(|i| value(i).chars().count())
+                        .chain(std::iter::once(heading.len()))
+                        .max()
+                        .unwrap_or_default()
+                };
+                let typ = width("type", |i| &i.typ);
+                let source = width("source", |i| &i.source);
+                let destination = width("destination", |i| &i.destination);
+                let format = |values: [&str; 4]| {
+                    format!(
+                        " {:<typ$}{MARGIN}{:<source$}{MARGIN}{:<destination$}{MARGIN}{}",
+                        values[0], values[1], values[2], values[3]
+                    )
+                };
+                let lines = std::iter::once(Line::from(Span::styled(
+                    format(["type", "source", "destination", "mode"]),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )))
+                .chain(items.iter().skip(usize::from(mounts.offset)).map(|i| {
+                    let mode = if i.read_only { "ro" } else { "rw" };
+                    Line::from(format([&i.typ, &i.source, &i.destination, mode]))
+                }))
+                .collect::<Vec<_>>();
+                Paragraph::new(lines)
+            }
+        },
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph.block(block), area);
+}
+
+/// Draw the reconstructed docker run command of a container in a scrollable popup
+pub fn run_command(
+    f: &mut Frame,
+    app_data: &Arc<Mutex<AppData>>,
+    run_command: &RunCommand,
+    name: &ContainerName,
+    fetch: &Fetch,
+) {
+    let text = app_data.lock().get_run_command_by_id(&run_command.id);
+    let line_count = text.as_ref().map_or(0, |i| i.lines().count());
+
+    let block = Block::default()
+        .title(format!(" Run {name} "))
+        .title(
+            Title::from(format!(
+                " {}/{line_count} ( c ) copy ( esc ) to close ",
+                usize::from(run_command.offset) + usize::from(line_count > 0)
+            ))
+            .position(Position::Bottom),
+        )
+        .border_type(BorderType::Rounded)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let area = f.size();
+    let area = popup(
+        usize::from(area.height.saturating_sub(4)),
+        usize::from(area.width.saturating_sub(8)),
+        area,
+        BoxLocation::MiddleCentre,
+    );
+
+    let paragraph = text.map_or_else(
+        || Paragraph::new(fetch.to_string()).alignment(Alignment::Center),
+        |text| Paragraph::new(text).scroll((run_command.offset, 0)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph.block(block), area);
+}
+
+/// Draw the progress of an image pull, one line per layer, followed by the result, only the most recent lines are shown if they don't all fit.
+/// Once pulled, the container can be recreated to use the new image
+pub fn pull(f: &mut Frame, pull: &Pull, name: &ContainerName, loading_icon: &str, theme: &Theme) {
+    let (result, result_color, footer) = match &pull.state {
+        PullState::Pulling => (
+            format!("pulling {loading_icon}"),
+            theme.popup_fg,
+            String::from(" ( esc ) to close "),
+        ),
+        PullState::Pulled => (
+            format!("✓ pulled, recreate {name} to use the new image"),
+            theme.state_running,
+            String::from(" ( r ) recreate ( esc ) to close "),
+        ),
+        PullState::Failed(error) => (
+            format!("✖ {error}"),
+            theme.alert,
+            String::from(" ( esc ) to close "),
+        ),
+    };
+
+    let mut lines = pull.lines().into_iter().map(Line::from).collect::<Vec<_>>();
+    lines.push(Line::from(Span::styled(
+        result,
+        Style::default().fg(result_color),
+    )));
+
+    let title = format!(" Pull {} ", pull.image);
+    let max_line_width = lines
+        .iter()
+        .map(Line::width)
+        .chain([title.chars().count(), footer.chars().count()])
+        .max()
+        .unwrap_or_default()
+        + 4;
+
+    let block = Block::default()
+        .title(title)
+        .title(Title::from(footer).position(Position::Bottom))
+        .border_type(BorderType::Rounded)
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_fg))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let area = f.size();
+    let area = popup(
+        (lines.len() + 2).min(usize::from(area.height.saturating_sub(4))),
+        max_line_width.min(usize::from(area.width.saturating_sub(4))),
+        area,
+        BoxLocation::MiddleCentre,
+    );
+
+    // Always show the most recent lines, as the result is last
+    let offset = lines
+        .len()
+        .saturating_sub(usize::from(area.height.saturating_sub(2)));
+    let paragraph = Paragraph::new(lines)
+        .scroll((u16::try_from(offset).unwrap_or_default(), 0))
+        .block(block);
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+/// Draw the batch panel, the step each container is at, and once finished a summary of how many failed
+pub fn batch(f: &mut Frame, batch: &Batch, loading_icon: &str, theme: &Theme) {
+    let name_width = batch
+        .containers
+        .iter()
+        .map(|i| i.1.chars().count())
+        .max()
+        .unwrap_or_default();
+    let mut lines = batch
+        .containers
+        .iter()
+        .map(|(_, name, step)| {
+            let (text, color) = match step {
+                BatchStep::Done => (step.to_string(), theme.state_running),
+                BatchStep::Failed(_) => (step.to_string(), theme.alert),
+                BatchStep::Waiting => (step.to_string(), theme.popup_fg),
+                _ => (format!("{step} {loading_icon}"), theme.popup_fg),
+            };
+            Line::from(vec![
+                Span::from(format!("{name:<name_width$}{MARGIN}")),
+                Span::styled(text, Style::default().fg(color)),
+            ])
+        })
+        .collect::<Vec<_>>();
+    let finished = batch.is_finished();
+    if finished {
+        let color = if batch.failed() == 0 {
+            theme.state_running
+        } else {
+            theme.alert
+        };
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            batch.summary(),
+            Style::default().fg(color),
+        )));
+    }
+
+    let title = format!(" Batch {} ", batch.control);
+    let footer = " ( esc ) to close ";
+    let max_line_width = lines
+        .iter()
+        .map(Line::width)
+        .chain([title.chars().count(), footer.chars().count()])
+        .max()
+        .unwrap_or_default()
+        + 4;
+
+    let block = Block::default()
+        .title(title)
+        .title(Title::from(footer).position(Position::Bottom))
+        .border_type(BorderType::Rounded)
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_fg))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let area = f.size();
+    let area = popup(
+        (lines.len() + 2).min(usize::from(area.height.saturating_sub(4))),
+        max_line_width.min(usize::from(area.width.saturating_sub(4))),
+        area,
+        BoxLocation::MiddleCentre,
+    );
+
+    // Keep the container currently being sent its command in view, or the summary once finished
+    let visible = usize::from(area.height.saturating_sub(2));
+    let focus = batch
+        .containers
+        .iter()
+        .position(|i| !i.2.is_finished())
+        .filter(|_| !finished)
+        .unwrap_or_else(|| lines.len().saturating_sub(1));
+    let offset = (focus + 1).saturating_sub(visible);
+    let paragraph = Paragraph::new(lines)
+        .scroll((u16::try_from(offset).unwrap_or_default(), 0))
+        .block(block);
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+/// Draw an error popup over whole screen
+pub fn error(f: &mut Frame, error: &AppError, seconds: Option<u8>, theme: &Theme) {
+    let block = Block::default()
+        .title(" Error ")
+        .border_type(BorderType::Rounded)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let to_push = match error {
+        // Without a countdown, the connection was lost whilst running, and is being retried
+        AppError::DockerConnect => seconds.map_or_else(
+            || String::from("\n\n reconnecting...\n ( q ) quit oxker "),
+            |seconds| format!("\n\n {NAME}::v{VERSION} closing in {seconds:02} seconds"),
+        ),
+        _ => String::from("\n\n ( c ) clear error\n ( q ) quit oxker "),
+    };
+
+    let mut text = format!("\n{error}");
+
+    text.push_str(to_push.as_str());
+
+    // Find the maximum line width & height
+    let mut max_line_width = max_line_width(&text);
+    let mut lines = text.lines().count();
+
+    // Add some horizontal & vertical margins
+    max_line_width += 8;
+    lines += 3;
+
+    let paragraph = Paragraph::new(text)
+        .style(Style::default().bg(theme.error_bg).fg(theme.error_fg))
+        .block(block)
+        .alignment(Alignment::Center);
+
+    let area = popup(lines, max_line_width, f.size(), BoxLocation::MiddleCentre);
+
+    // let (paragraph, area) = gen_error(f, error, seconds);
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+/// Draw info box in one of the 9 BoxLocations
+// TODO is this broken?
+pub fn info(
+    f: &mut Frame,
+    text: &str,
+    instant: Instant,
+    gui_state: &Arc<Mutex<GuiState>>,
+    theme: &Theme,
+) {
+    let block = Block::default()
+        .title("")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::NONE);
+
+    let mut max_line_width = max_line_width(text);
+    let mut lines = text.lines().count();
+
+    // Add some horizontal & vertical margins
+    max_line_width += 8;
+    lines += 2;
+
+    let paragraph = Paragraph::new(text)
+        .style(Style::default().bg(theme.info_bg).fg(theme.info_fg))
+        .block(block)
+        .alignment(Alignment::Center);
+
+    let area = popup(lines, max_line_width, f.size(), BoxLocation::BottomRight);
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+    if instant.elapsed().as_millis() > 4000 {
+        gui_state.lock().reset_info_box();
+    }
+}
+
+/// draw a box in the one of the BoxLocations, based on max line width + number of lines
+fn popup(text_lines: usize, text_width: usize, r: Rect, box_location: BoxLocation) -> Rect {
+    // Make sure blank_space can't be an negative, as will crash
+    let calc = |x: u16, y: usize| usize::from(x).saturating_sub(y).saturating_div(2);
+
+    let blank_vertical = calc(r.height, text_lines);
+    let blank_horizontal = calc(r.width, text_width);
+
+    let (h_constraints, v_constraints) = box_location.get_constraints(
+        blank_horizontal.try_into().unwrap_or_default(),
+        blank_vertical.try_into().unwrap_or_default(),
+        text_lines.try_into().unwrap_or_default(),
+        text_width.try_into().unwrap_or_default(),
+    );
+
+    let indexes = box_location.get_indexes();
+
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(v_constraints)
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(h_constraints)
+        .split(popup_layout[indexes.0])[indexes.1]
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::many_single_char_names)]
+mod tests {
+
+    use std::{
+        collections::{BTreeMap, HashMap},
+        ops::RangeInclusive,
+        sync::Arc,
+    };
+
+    use bollard::service::{ImageSummary, Network, Volume, VolumeUsageData};
+
+    use parking_lot::Mutex;
+    use ratatui::{
+        backend::TestBackend,
+        buffer::Cell,
+        layout::Rect,
+        style::{Color, Modifier},
+        Terminal,
+    };
+    use uuid::Uuid;
+
+    use crate::{
+        app_data::{
+            Alert, AppData, ByteStats, ContainerAddress, ContainerCommand, ContainerId,
+            ContainerImage, ContainerMount, ContainerName, ContainerPorts, DockerControls, Engine,
+            Header, Health, ImageItem, IoTotals, LogStream, MemoryUnit, NetworkItem, Restarts,
+            SortedOrder, State, StatefulList, Uptime, VolumeItem,
+        },
+        app_error::AppError,
+        config::{Action, Keymap},
+        tests::{gen_appdata, gen_container_summary, gen_containers, gen_stdout},
+        ui::{
+            draw_frame, Batch, BatchStep, Cheatsheet, ConfirmControl, Fetch, GuiState, IdMode,
+            Limits, LogSince, Prune, Pull, PullState, Rename, RestartPolicy, SelectablePanel,
+            Status,
+        },
+    };
+
+    use super::{FrameData, Theme, VERSION};
+
+    struct TuiTestSetup {
+        app_data: Arc<Mutex<AppData>>,
+        gui_state: Arc<Mutex<GuiState>>,
+        fd: FrameData,
+        area: Rect,
+        terminal: Terminal<TestBackend>,
+        ids: Vec<ContainerId>,
+    }
+
+    const BORDER_CHARS: [&str; 6] = ["╭", "╮", "─", "│", "╰", "╯"];
+
+    /// Generate state to be used in *most* gui tests
+    fn test_setup(w: u16, h: u16, control_start: bool, container_start: bool) -> TuiTestSetup {
+        let backend = TestBackend::new(w, h);
+        let terminal = Terminal::new(backend).unwrap();
+
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        if control_start {
+            app_data.docker_controls_start();
+        }
+        if container_start {
+            app_data.containers_start();
+        }
+
+        let gui_state = GuiState::default();
+
+        let app_data = Arc::new(Mutex::new(app_data));
+        let gui_state = Arc::new(Mutex::new(gui_state));
+
+        let fd = FrameData::from((app_data.lock(), gui_state.lock()));
+        let area = Rect::new(0, 0, w, h);
+        TuiTestSetup {
+            app_data,
+            gui_state,
+            fd,
+            area,
+            terminal,
+            ids,
+        }
+    }
+
+    /// Insert some logs into the first container
+    fn gen_mounts() -> Vec<ContainerMount> {
+        vec![
+            ContainerMount {
+                typ: String::from("bind"),
+                source: String::from("/home/user/nginx.conf"),
+                destination: String::from("/etc/nginx/nginx.conf"),
+                read_only: true,
+            },
+            ContainerMount {
+                typ: String::from("volume"),
+                source: String::from("data"),
+                destination: String::from("/data"),
+                read_only: false,
+            },
+        ]
+    }
+
+    fn insert_logs(setup: &TuiTestSetup) {
+        let logs = (1..=3).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
+        setup
+            .app_data
+            .lock()
+            .update_log_by_id(gen_stdout(logs), &setup.ids[0]);
+    }
+
+    // ******************** //
+    // DockerControls panel //
+    // ******************** //
+
+    #[test]
+    /// Test that when DockerCommands are available, they are drawn correctly, dependant on container state
+    fn test_draw_blocks_commands_none() {
+        let (w, h) = (12, 6);
+        let mut setup = test_setup(w, h, false, false);
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let expected = [
+            "╭──────────╮",
+            "│          │",
+            "│          │",
+            "│          │",
+            "│          │",
+            "╰──────────╯",
+        ];
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                assert_eq!(result_cell.fg, Color::Reset);
+            }
+        }
+    }
+
+    #[test]
+    // Test that when DockerCommands are available, they are drawn correctly, dependant on container state
+    fn test_draw_blocks_commands_some() {
+        let (w, h) = (12, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let expected = [
+            "╭──────────╮",
+            "│▶ pause   │",
+            "│  restart │",
+            "│  stop    │",
+            "│  delete  │",
+            "╰──────────╯",
+        ];
+        let result = &setup.terminal.backend().buffer().content;
+
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+
+                // Check the text color is correct
+                match index {
+                    // pause
+                    15..=19 => {
+                        assert_eq!(result_cell.fg, Color::Yellow);
+                    }
+                    // restart
+                    27..=33 => {
+                        assert_eq!(result_cell.fg, Color::Magenta);
+                    }
+                    // stop
+                    39..=42 => {
+                        assert_eq!(result_cell.fg, Color::Red);
+                    }
+                    // delete
+                    51..=56 => {
+                        assert_eq!(result_cell.fg, Color::Gray);
+                    }
+                    // no text
+                    _ => {
+                        assert_eq!(result_cell.fg, Color::Reset);
+                    }
+                }
+                if result_cell.symbol().starts_with('▶') {
+                    assert_eq!(result_cell.fg, Color::Reset);
+                }
+            }
+        }
+
+        // Change the controls state
+        setup
+            .app_data
+            .lock()
+            .update_containers(&mut [gen_container_summary(1, "paused")]);
+        setup.app_data.lock().docker_controls_next();
+
+        let expected = [
+            "╭──────────╮",
+            "│  unpause │",
+            "│▶ stop    │",
+            "│  delete  │",
+            "│          │",
+            "╰──────────╯",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+
+                // Chceck the text color is correct
+                match index {
+                    // unpause
+                    15..=21 => {
+                        assert_eq!(result_cell.fg, Color::Blue);
+                    }
+                    // stop
+                    27..=30 => {
+                        assert_eq!(result_cell.fg, Color::Red);
+                    }
+                    // delete
+                    39..=44 => {
+                        assert_eq!(result_cell.fg, Color::Gray);
+                    }
+                    // no text
+                    _ => {
+                        assert_eq!(result_cell.fg, Color::Reset);
+                    }
+                }
+                if result_cell.symbol().starts_with('▶') {
+                    assert_eq!(result_cell.fg, Color::Reset);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// When control panel is selected, the border is blue, if not then white, selected text is highlighted
+    fn test_draw_blocks_commands_panel_selected_color() {
+        let (w, h) = (12, 6);
+        let mut setup = test_setup(w, h, true, true);
+        let expected = [
+            "╭──────────╮",
+            "│▶ pause   │",
+            "│  restart │",
+            "│  stop    │",
+            "│  delete  │",
+            "╰──────────╯",
+        ];
+
+        // Unselected, has a grey border
+        setup
+            .terminal
+            .draw(|f| {
+                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::Reset);
+                }
+            }
+        }
+
+        // Control panel now selected, should have a blue border
+        setup.gui_state.lock().next_panel();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::commands(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::LightCyan);
+                }
+                // Make sure that the selected line has bold text
+                match index {
+                    // pause
+                    13..=22 => {
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    _ => {
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                }
+            }
+        }
+    }
+
+    // *********************** //
+    // Container summary panel //
+    // *********************** //
+
+    // Check that the correct solor is applied to the state/status/cpu/memory section
+    fn check_expected(expected: [&str; 6], w: u16, _h: u16, setup: &TuiTestSetup, color: Color) {
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                if (145..=207).contains(&index) {
+                    assert_eq!(result_cell.fg, color);
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// No containers, panel unselected, then selected, border color changes correctly
+    fn test_draw_blocks_containers_none() {
+        let (w, h) = (25, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers = StatefulList::new(vec![]);
+
+        let expected = [
+            "╭ Containers ───────────╮",
+            "│  no containers found  │",
+            "│                       │",
+            "│                       │",
+            "│                       │",
+            "╰───────────────────────╯",
+        ];
+
+        setup.gui_state.lock().next_panel();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                assert_eq!(result_cell.fg, Color::Reset);
+            }
+        }
+
+        setup.gui_state.lock().previous_panel();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::LightCyan);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Containers panel drawn, selected line is bold, border is blue
+    fn test_draw_blocks_containers_some() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+        "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+        "│⚪  container_1   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+        "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+        "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+        "│                                                                                                                                │",
+        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+    ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                // result matches expected
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+
+                // Selected container is bold
+                match index {
+                    131 | 133..=258 => assert_eq!(result_cell.modifier, Modifier::BOLD),
+                    _ => {
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                }
+
+                // Border is blue
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::LightCyan);
+                }
+            }
+        }
+
+        // Change selected panel, border is now no longer blue
+        setup.gui_state.lock().next_panel();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+
+                // Border is gray
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::Reset);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Marked containers have a mark before their name, and the number of marked containers is in the title
+    fn test_draw_blocks_containers_marked() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers_next();
+        setup.app_data.lock().toggle_marked();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected = [
+        "╭ Containers 2/3 - 1 marked ─────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+        "│     container_1   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB        │",
+        "│⚪  ✚ container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB        │",
+        "│     container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB        │",
+        "│                                                                                                                                │",
+        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+    ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+            }
+        }
+    }
+
+    #[test]
+    /// Whilst a row number is being typed, each container is prefixed with its row number
+    fn test_draw_blocks_containers_go_to() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.gui_state.lock().go_to_start();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let expected = [
+        "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+        "│⚪  1 container_1   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB        │",
+        "│   2 container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB        │",
+        "│   3 container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB        │",
+        "│                                                                                                                                │",
+        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+    ];
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
+    }
+
+    #[test]
+    /// Containers with a healthcheck have a colored health indicator after their state, an unhealthy container is drawn in the unhealthy color
+    fn test_draw_blocks_containers_health() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup
+            .app_data
+            .lock()
+            .update_health_by_id(&setup.ids[0], Some(Health::Starting));
+        setup
+            .app_data
+            .lock()
+            .update_health_by_id(&setup.ids[1], Some(Health::Unhealthy));
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ✓ running ●          Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+            "│   container_2   ✓ running ●          Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+            "│                                                                                                                                │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+            }
+        }
+
+        // The indicator, and the state, of the healthy & unhealthy containers
+        let cell = |row: usize, char_index: usize| &result[row * usize::from(w) + char_index];
+        assert_eq!(cell(1, 28).fg, Color::Yellow);
+        assert_eq!(cell(1, 18).fg, Color::Green);
+        assert_eq!(cell(2, 28).fg, Color::LightRed);
+        assert_eq!(cell(2, 18).fg, Color::LightRed);
+        assert_eq!(cell(2, 18).modifier, Modifier::BOLD);
+    }
+
+    #[test]
+    /// The uptime of running containers, how long ago stopped containers finished, or `--` if unknown, is shown in the last column
+    fn test_draw_blocks_containers_uptime() {
+        let (w, h) = (180, 6);
+        let mut setup = test_setup(w, h, true, true);
+        let now = setup.app_data.lock().now();
+        setup.app_data.lock().containers.items[0].uptime = Some(Uptime {
+            started: Some(now - (3600 * 3 + 60 * 12 + 5)),
+            finished: None,
+        });
+        setup.app_data.lock().containers.items[1].state = State::Exited;
+        setup.app_data.lock().containers.items[1].uptime = Some(Uptime {
+            started: Some(now - 86_400 * 3),
+            finished: Some(now - 86_400 * 2),
+        });
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected = [
+            "╭ Containers 1/3 ──────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          -- /        --          -- /        --   3h 12m   │",
+            "│   container_2   ✖ exited             Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          -- /        --          -- /        --   2d ago   │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          -- /        --          -- /        --       --   │",
+            "│                                                                                                                                                                                  │",
+            "╰──────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+            }
+        }
+    }
+
+    #[test]
+    /// A freshly started container's name is highlighted, in bold for the first half of the window, and is back to normal once the window has passed
+    fn test_draw_blocks_containers_fresh() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+        let now = setup.app_data.lock().now();
+        for (index, seconds) in [40, 5, 20].into_iter().enumerate() {
+            setup.app_data.lock().containers.items[index].uptime = Some(Uptime {
+                started: Some(now - seconds),
+                finished: None,
+            });
+        }
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        let cell = |row: usize, char_index: usize| &result[row * usize::from(w) + char_index];
+        assert_eq!(cell(1, 4).symbol(), "c");
+        assert_eq!(cell(1, 4).fg, Color::Blue);
+        assert_eq!(cell(2, 4).fg, Color::LightYellow);
+        assert_eq!(cell(2, 4).modifier, Modifier::BOLD);
+        assert_eq!(cell(3, 4).fg, Color::LightYellow);
+        assert_eq!(cell(3, 4).modifier, Modifier::empty());
+
+        // Disabled with a window of 0
+        setup.app_data.lock().args.fresh = 0;
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        assert_eq!(result[usize::from(w) * 2 + 4].fg, Color::Blue);
+    }
+
+    #[test]
+    /// The id column shows the first 12 chars of an id, the whole id, or is removed, the column width adapts to the ids
+    fn test_draw_blocks_containers_id_mode() {
+        let (w, h) = (140, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers.items[0].id =
+            ContainerId::from("0123456789abcdef0123456789");
+
+        let row = |setup: &mut TuiTestSetup| {
+            let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+            setup
+                .terminal
+                .draw(|f| {
+                    super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                })
+                .unwrap();
+            setup.terminal.backend().buffer().content[usize::from(w)..usize::from(w) * 2]
+                .iter()
+                .map(ratatui::buffer::Cell::symbol)
+                .collect::<String>()
+        };
+
+        let result = row(&mut setup);
+        assert!(result.contains(" 0123456789ab   image_1"));
+
+        assert_eq!(setup.gui_state.lock().cycle_id_mode(), IdMode::Full);
+        let result = row(&mut setup);
+        assert!(result.contains(" 0123456789abcdef0123456789   image_1"));
+
+        assert_eq!(setup.gui_state.lock().cycle_id_mode(), IdMode::Hidden);
+        let result = row(&mut setup);
+        assert!(!result.contains("0123"));
+        assert!(result.contains("0.00 kB   image_1"));
+    }
+
+    #[test]
+    /// Whilst a container is above an alert threshold, its cpu, or memory, is highlighted, and the panel has an ALERT badge
+    fn test_draw_blocks_containers_alert() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers.items[0].alert = Alert {
+            cpu: true,
+            mem: false,
+        };
+        setup.app_data.lock().containers.items[1].alert = Alert {
+            cpu: false,
+            mem: true,
+        };
+        setup.gui_state.lock().status_push(Status::Alert);
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected = [
+            "╭ Containers 1/3 ───────────────────────────────────────────────────────────────────────────────────────────────────────── ALERT ╮",
+            "│⚪  container_1   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+            "│                                                                                                                                │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                match index {
+                    // ALERT badge, container_1 cpu, and container_2 memory
+                    122..=128 | 178..=187 | 318..=337 => {
+                        assert_eq!(result_cell.fg, Theme::default().alert);
+                    }
+                    _ => assert_ne!(result_cell.fg, Theme::default().alert),
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// The restart count & policy of the selected container are shown at the bottom of the panel, and flagged when crash looping
+    fn test_draw_blocks_containers_restarts() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers.items[0].restarts =
+            Some(Restarts::new(Some(1), Some("always"), None));
+        setup.app_data.lock().containers.items[1].restarts =
+            Some(Restarts::new(Some(8), Some("on-failure"), Some(10)));
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let last_row = |setup: &TuiTestSetup| {
+            setup.terminal.backend().buffer().content[usize::from(w * (h - 1))..]
+                .iter()
+                .map(|i| (i.symbol().to_owned(), i.fg))
+                .collect::<Vec<_>>()
+        };
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        let result = last_row(&setup);
+        assert_eq!(
+            result.iter().map(|i| i.0.as_str()).collect::<String>(),
+            "╰──────────────────────────────────────────────────────────────────────────────────────────────────── restarts 1 - policy always ╯"
+        );
+        assert!(result.iter().all(|i| i.1 != Theme::default().alert));
+
+        setup.app_data.lock().containers_next();
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        let result = last_row(&setup);
+        assert_eq!(
+            result.iter().map(|i| i.0.as_str()).collect::<String>(),
+            "╰───────────────────────────────────────────────────────────────────────────────────────────── restarts 8 - policy on-failure:10 ╯"
+        );
+        for (index, (_, fg)) in result.iter().enumerate() {
+            if (94..=128).contains(&index) {
+                assert_eq!(*fg, Theme::default().alert);
+            } else {
+                assert_ne!(*fg, Theme::default().alert);
+            }
+        }
+    }
+
+    #[test]
+    /// Grouped containers have a heading for each compose project, with the ungrouped containers last
+    fn test_draw_blocks_containers_grouped() {
+        let (w, h) = (130, 7);
+        let mut setup = test_setup(w, h, true, true);
+        for (index, item) in setup
+            .app_data
+            .lock()
+            .containers
+            .items
+            .iter_mut()
+            .enumerate()
+        {
+            item.project = (index < 2).then(|| "web".to_owned());
+        }
+        setup.app_data.lock().toggle_grouped();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected = [
+        "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+        "│⚪  ▾ web - 2/2 running                                                                                                          │",
+        "│   container_1   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+        "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+        "│   ▾ ungrouped - 1/1 running                                                                                                    │",
+        "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+    ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+            }
+        }
+    }
+
+    #[test]
+    /// A collapsed group is drawn as a single heading line, with the number of running containers
+    fn test_draw_blocks_containers_grouped_collapsed() {
+        let (w, h) = (130, 5);
+        let mut setup = test_setup(w, h, true, true);
+        for (index, item) in setup
+            .app_data
+            .lock()
+            .containers
+            .items
+            .iter_mut()
+            .enumerate()
+        {
+            item.project = (index < 2).then(|| "web".to_owned());
+        }
+        setup.app_data.lock().toggle_grouped();
+        setup.app_data.lock().toggle_group_collapsed();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected = [
+        "╭ Containers 1/2 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+        "│⚪  ▸ web - 2/2 running                                                                                                          │",
+        "│   ▾ ungrouped - 1/1 running                                                                                                    │",
+        "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+    ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+            }
+        }
+    }
+
+    #[test]
+    /// ALl columns on all rows are coloured correctly
+    fn test_draw_blocks_containers_colors() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+            "│                                                                                                                                │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let index_blue = [
+            134, 135, 136, 137, 138, 139, 140, 141, 142, 143, 144, 208, 209, 210, 211, 212, 213,
+            214, 215, 216, 217, 218, 219, 220, 221, 222, 223, 224, 225, 226, 227, 228,
+        ];
+        let index_blue = index_blue
+            .iter()
+            .flat_map(|&x| vec![x, x + 130, x + 260])
+            .collect::<Vec<_>>();
+        let index_green = [
+            145, 146, 147, 148, 149, 150, 151, 152, 153, 154, 155, 156, 157, 158, 159, 160, 161,
+            162, 163, 164, 165, 166, 167, 168, 169, 170, 171, 172, 173, 174, 175, 176, 177, 178,
+            179, 180, 181, 182, 183, 184, 185, 186, 187, 188, 189, 190, 191, 192, 193, 194, 195,
+            196, 197, 198, 199, 200, 201, 202, 203, 204, 205, 206, 207,
+        ];
+        let index_green = index_green
+            .iter()
+            .flat_map(|&x| vec![x, x + 130, x + 260])
+            .collect::<Vec<_>>();
+
+        // The start of the network i/o column is the same color as the rx column
+        let index_rx = [
+            229, 230, 231, 232, 233, 234, 235, 236, 237, 238, 249, 250, 251, 252, 253, 254, 255,
+            256, 257, 258,
+        ];
+        let index_rx = index_rx
+            .iter()
+            .flat_map(|&x| vec![x, x + 130, x + 260])
+            .collect::<Vec<_>>();
+
+        let index_tx = [239, 240, 241, 242, 243, 244, 245, 246, 247, 248];
+        let index_tx = index_tx
+            .iter()
+            .flat_map(|&x| vec![x, x + 130, x + 260])
+            .collect::<Vec<_>>();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+
+                let result_cell = &result[index];
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+
+                match index {
+                    _x if index_blue.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Blue);
+                    }
+                    _x if index_green.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Green);
+                    }
+                    _x if index_rx.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Rgb(255, 233, 193));
+                    }
+                    _x if index_tx.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Rgb(205, 140, 140));
+                    }
+                    (0..=130) | (259..=260) | (389..=390) | (519..=520) | (649..=779) => {
+                        assert_eq!(result_cell.fg, Color::LightCyan);
+                    }
+                    _ => {
+                        assert_eq!(result_cell.fg, Color::Reset);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// When long container/image name, it is truncated correctly
+    fn test_draw_blocks_containers_long_name_image() {
+        let (w, h) = (170, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers.items[0].name =
+            ContainerName::from("a_long_container_name_for_the_purposes_of_this_test");
+        setup.app_data.lock().containers.items[0].image =
+            ContainerImage::from("a_long_image_name_for_the_purposes_of_this_test");
+
+        let expected = [
+        "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+        "│⚪  a_long_container_name_for_the…   ॥ paused             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   …for_the_purposes_of_this_test   0.00 kB   0.00 kB        │",
+        "│                      container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2                          image_2   0.00 kB   0.00 kB        │",
+        "│                      container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3                          image_3   0.00 kB   0.00 kB        │",
+        "│                                                                                                                                                                        │",
+        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup.app_data.lock().containers.items[0].state = State::Paused;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+            }
+        }
+
+        // THis char: …
+    }
+
+    #[test]
+    /// When container is paused, correct colors displayed
+    fn test_draw_blocks_containers_paused() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+        "│⚪  container_1   ॥ paused             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+        "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+        "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+        "│                                                                                                                                │",
+        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup.app_data.lock().containers.items[0].state = State::Paused;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        check_expected(expected, w, h, &setup, Color::Yellow);
+    }
+
+    #[test]
+    /// When container is dead, correct colors displayed
+    fn test_draw_blocks_containers_dead() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ✖ dead               Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+            "│                                                                                                                                │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        setup.app_data.lock().containers.items[0].state = State::Dead;
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        check_expected(expected, w, h, &setup, Color::Red);
+    }
+
+    #[test]
+    /// When container is exited, correct colors displayed
+    fn test_draw_blocks_containers_exited() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ✖ exited             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+            "│                                                                                                                                │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        setup.app_data.lock().containers.items[0].state = State::Exited;
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        check_expected(expected, w, h, &setup, Color::Red);
+    }
+    #[test]
+    /// When container is paused, correct colors displayed
+    fn test_draw_blocks_containers_removing() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   removing             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+            "│                                                                                                                                │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        setup.app_data.lock().containers.items[0].state = State::Removing;
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        check_expected(expected, w, h, &setup, Color::LightRed);
+    }
+    #[test]
+    /// When container state is restarting, correct colors displayed
+    fn test_draw_blocks_containers_restarting() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ↻ restarting          Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB         │",
+            "│   container_2   ✓ running             Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB         │",
+            "│   container_3   ✓ running             Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB         │",
+            "│                                                                                                                                │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        setup.app_data.lock().containers.items[0].state = State::Restarting;
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        check_expected(expected, w, h, &setup, Color::LightGreen);
+    }
+    #[test]
+    /// When container state is unknown, correct colors displayed
+    fn test_draw_blocks_containers_unknown() {
+        let (w, h) = (130, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        let expected = [
+            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
+            "│⚪  container_1   ? unknown            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
+            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
+            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
+            "│                                                                                                                                │",
+            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        ];
+        setup.app_data.lock().containers.items[0].state = State::Unknown;
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        check_expected(expected, w, h, &setup, Color::Red);
+    }
+    // ********** //
+    // Logs panel //
+    // ********** //
+
+    #[test]
+    /// Until the logs of the selected container have been fetched a loading message is shown, rather than no logs, and an unreadable logging driver is explained
+    fn test_draw_blocks_logs_fetching() {
+        let (w, h) = (25, 6);
+        let mut setup = test_setup(w, h, true, true);
+        let uuid = Uuid::new_v4();
+        setup.gui_state.lock().next_loading(uuid);
+
+        let draw = |setup: &mut TuiTestSetup| {
+            let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+            setup
+                .terminal
+                .draw(|f| {
+                    super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                })
+                .unwrap();
+            setup.terminal.backend().buffer().content[usize::from(w)..usize::from(w) * 2]
+                .iter()
+                .map(ratatui::buffer::Cell::symbol)
+                .collect::<String>()
+        };
+
+        assert_eq!(draw(&mut setup), "│    fetching logs ⠙    │");
+
+        let id = setup.ids[0].clone();
+        setup.app_data.lock().update_log_by_id(vec![], &id);
+        assert_eq!(draw(&mut setup), "│     no logs found     │");
+
+        // An unreadable logging driver is explained, wrapped to fit the panel
+        setup.app_data.lock().set_logs_unreadable_by_id(&id, true);
+        assert_eq!(draw(&mut setup), "│   This container's    │");
+    }
+
+    #[test]
+    /// No logs, panel unselected, then selected, border color changes correctly
+    fn test_draw_blocks_logs_none() {
+        let (w, h) = (25, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers = StatefulList::new(vec![]);
+
+        let expected = [
+            "╭ Logs ─────────────────╮",
+            "│     no logs found     │",
+            "│                       │",
+            "│                       │",
+            "│                       │",
+            "╰───────────────────────╯",
+        ];
+
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                assert_eq!(result_cell.fg, Color::Reset);
+            }
+        }
+
+        setup.gui_state.lock().next_panel();
+        setup.gui_state.lock().next_panel();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        // When selected, has a blue border
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::LightCyan);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Parsing logs, spinner visible, and then animates by one frame
+    fn test_draw_blocks_logs_parsing() {
+        let (w, h) = (25, 6);
+        let mut setup = test_setup(w, h, true, true);
+        let uuid = Uuid::new_v4();
+        setup.gui_state.lock().next_loading(uuid);
+
+        let expected = [
+            "╭ Logs - container_1 ───╮",
+            "│    parsing logs ⠙     │",
+            "│                       │",
+            "│                       │",
+            "│                       │",
+            "╰───────────────────────╯",
+        ];
+
+        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        fd.init = true;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let test = |terminal: &Terminal<TestBackend>, expected: [&str; 6]| {
+            let result = &terminal.backend().buffer().content;
+            for (row_index, row) in expected.iter().enumerate() {
+                for (char_index, expected_char) in row.chars().enumerate() {
+                    let index = row_index * usize::from(w) + char_index;
+                    let result_cell = &result[index];
+
+                    assert_eq!(result_cell.symbol(), expected_char.to_string());
+                    assert_eq!(result_cell.fg, Color::Reset);
+                }
+            }
+        };
+
+        test(&setup.terminal, expected);
+
+        // animation moved by one frame
+        setup.gui_state.lock().next_loading(uuid);
+
+        let expected = [
+            "╭ Logs - container_1 ───╮",
+            "│    parsing logs ⠹     │",
+            "│                       │",
+            "│                       │",
+            "│                       │",
+            "╰───────────────────────╯",
+        ];
+
+        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        fd.init = true;
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        test(&setup.terminal, expected);
+    }
+
+    #[test]
+    /// A scrollbar is drawn over the right border of the logs panel, with the thumb following the scroll position, and isn't drawn when the logs fit
+    fn test_draw_blocks_logs_scrollbar() {
+        let (w, h) = (25, 5);
+        let mut setup = test_setup(w, h, true, true);
+        let right_border = |terminal: &Terminal<TestBackend>| {
+            let result = &terminal.backend().buffer().content;
+            (1..=3)
+                .map(|row| {
+                    result[row * usize::from(w) + usize::from(w) - 1]
+                        .symbol()
+                        .to_owned()
+                })
+                .collect::<String>()
+        };
+
+        insert_logs(&setup);
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        assert_eq!(right_border(&setup.terminal), "│││");
+
+        let logs = (4..=9).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
+        setup
+            .app_data
+            .lock()
+            .update_log_by_id(gen_stdout(logs), &setup.ids[0]);
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        assert_eq!(right_border(&setup.terminal), "││█");
+
+        setup.app_data.lock().log_start();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        assert_eq!(right_border(&setup.terminal), "█││");
+    }
+
+    #[test]
+    /// Logs correct displayed, changing log state also draws correctly
+    fn test_draw_blocks_logs_some() {
+        let (w, h) = (25, 6);
+        let mut setup = test_setup(w, h, true, true);
+
+        insert_logs(&setup);
+
+        let test = |terminal: &Terminal<TestBackend>,
+                    expected: [&str; 6],
+                    range: RangeInclusive<usize>| {
+            let result = &terminal.backend().buffer().content;
+
+            for (row_index, row) in expected.iter().enumerate() {
+                for (char_index, expected_char) in row.chars().enumerate() {
+                    let index = row_index * usize::from(w) + char_index;
+                    let result_cell = &result[index];
+
+                    assert_eq!(result_cell.symbol(), expected_char.to_string());
+                    assert_eq!(result_cell.fg, Color::Reset);
+
+                    if range.contains(&index) {
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    } else {
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                }
+            }
+        };
+
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        let expected = [
+            "╭ Logs 3/3 - container_1╮",
+            "│  line 1               │",
+            "│  line 2               │",
+            "│▶ line 3               │",
+            "│                       │",
+            "╰──────────── following ╯",
+        ];
+        test(&setup.terminal, expected, 76..=98);
+
+        // Change selected log line
+        setup.app_data.lock().log_previous();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let expected = [
+            "╭ Logs 2/3 paused - cont╮",
+            "│  line 1               │",
+            "│▶ line 2               │",
+            "│  line 3               │",
+            "│                       │",
+            "╰────── ( G ) to follow ╯",
+        ];
+        test(&setup.terminal, expected, 51..=73);
+    }
+
+    #[test]
+    /// When the log stream is paused, a PAUSED badge is shown in the top right of the logs panel
+    fn test_draw_blocks_logs_stream_paused() {
+        let (w, h) = (35, 6);
+        let mut setup = test_setup(w, h, true, true);
+        insert_logs(&setup);
+        setup.app_data.lock().toggle_log_stream();
+
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let expected = [
+            "╭ Logs 3/3 - container_1 ─ PAUSED ╮",
+            "│  line 1                         │",
+            "│  line 2                         │",
+            "│▶ line 3                         │",
+            "│                                 │",
+            "╰────────────────────── following ╯",
+        ];
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                if row_index == 0 && (26..=33).contains(&char_index) {
+                    assert_eq!(result_cell.modifier, Modifier::BOLD | Modifier::REVERSED);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// When log wrap is on, long lines are soft-wrapped to the width of the panel, otherwise they're truncated
+    fn test_draw_blocks_logs_wrap() {
+        let (w, h) = (20, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().update_log_by_id(
+            gen_stdout(vec![
+                "1 line 1".to_owned(),
+                "2 a very long line 2".to_owned(),
+            ]),
+            &setup.ids[0],
+        );
+
+        let test = |terminal: &Terminal<TestBackend>, expected: [&str; 6]| {
+            let result = &terminal.backend().buffer().content;
+            for (row_index, row) in expected.iter().enumerate() {
+                for (char_index, expected_char) in row.chars().enumerate() {
+                    let index = row_index * usize::from(w) + char_index;
+                    assert_eq!(result[index].symbol(), expected_char.to_string());
+                }
+            }
+        };
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+        let expected = [
+            "╭ Logs 2/2 - contai╮",
+            "│  line 1          │",
+            "│▶ a very long line│",
+            "│                  │",
+            "│                  │",
+            "╰─────── following ╯",
+        ];
+        test(&setup.terminal, expected);
+
+        setup.gui_state.lock().toggle_log_wrap();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        let expected = [
+            "╭ Logs 2/2 - contai╮",
+            "│  line 1          │",
+            "│▶ a very long line│",
+            "│   2              │",
+            "│                  │",
+            "╰─────── following ╯",
+        ];
+        test(&setup.terminal, expected);
+    }
+
+    #[test]
+    /// When log severity is on, lines containing a severity token are colored, otherwise no color is used
+    fn test_draw_blocks_logs_severity() {
+        let (w, h) = (25, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().update_log_by_id(
+            gen_stdout(vec![
+                "1 ERROR line 1".to_owned(),
+                "2 line 2".to_owned(),
+                "3 warn line 3".to_owned(),
+            ]),
+            &setup.ids[0],
+        );
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        for row in 1..=3 {
+            assert_eq!(result[row * usize::from(w) + 3].fg, Color::Reset);
+        }
+
+        setup.gui_state.lock().toggle_log_severity();
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        for (row, color) in [(1, Color::Red), (2, Color::Reset), (3, Color::Yellow)] {
+            for index in 3..w - 1 {
+                assert_eq!(result[row * usize::from(w) + usize::from(index)].fg, color);
+            }
+        }
+        // The stored log lines are unchanged
+        assert_eq!(
+            setup.app_data.lock().get_logs_text_by_id(&setup.ids[0]),
+            Some(vec![
+                "ERROR line 1".to_owned(),
+                "line 2".to_owned(),
+                "warn line 3".to_owned()
+            ])
+        );
+    }
+
+    #[test]
+    /// Stderr lines are colored, even when stdout lines are also shown
+    fn test_draw_blocks_logs_stderr() {
+        let (w, h) = (25, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().update_log_by_id(
+            vec![
+                (LogStream::Stdout, "1 line 1".to_owned()),
+                (LogStream::Stderr, "2 line 2".to_owned()),
+                (LogStream::Stdout, "3 line 3".to_owned()),
+            ],
+            &setup.ids[0],
+        );
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        for (row, color) in [(1, Color::Reset), (2, Color::LightRed), (3, Color::Reset)] {
+            for index in 3..9 {
+                assert_eq!(result[row * usize::from(w) + index].fg, color);
+            }
+        }
+    }
+
+    #[test]
+    /// In the log copy mode, the lines between the anchor and the selected line are reversed, and the number of selected lines is shown
+    fn test_draw_blocks_logs_copy() {
+        let (w, h) = (80, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().update_log_by_id(
+            gen_stdout(vec![
+                "1 line 1".to_owned(),
+                "2 line 2".to_owned(),
+                "3 line 3".to_owned(),
+            ]),
+            &setup.ids[0],
+        );
+        setup
+            .gui_state
+            .lock()
+            .set_log_copy(Some(setup.ids[0].clone()));
+        let anchor = setup.app_data.lock().get_log_position().map(|i| i - 1);
+        setup.gui_state.lock().set_log_copy_anchor(anchor);
+
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        for (row, reversed) in [(1, false), (2, true), (3, true)] {
+            assert_eq!(
+                result[row * usize::from(w) + 3]
+                    .modifier
+                    .contains(Modifier::REVERSED),
+                reversed
+            );
+        }
+        let footer = result[(usize::from(h) - 1) * usize::from(w)..]
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect::<String>();
+        assert!(footer.contains(" copy 2 lines ( y ) copy ( J / K ) select ( esc ) exit "));
+
+        setup.gui_state.lock().set_log_copy(None);
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+        let result = &setup.terminal.backend().buffer().content;
+        for row in 1..=3 {
+            assert!(!result[row * usize::from(w) + 3]
+                .modifier
+                .contains(Modifier::REVERSED));
+        }
+    }
+
+    #[test]
+    /// Full (long) name displayed in logs border
+    fn test_draw_blocks_logs_long_name() {
+        let (w, h) = (80, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers.items[0].name =
+            ContainerName::from("a_long_container_name_for_the_purposes_of_this_test");
+        setup.app_data.lock().containers.items[0].image =
+            ContainerImage::from("a_long_image_name_for_the_purposes_of_this_test");
+
+        insert_logs(&setup);
+
+        let expected = [
+            "╭ Logs 3/3 - a_long_container_name_for_the_purposes_of_this_test ──────────────╮",
+            "│  line 1                                                                      │",
+            "│  line 2                                                                      │",
+            "│▶ line 3                                                                      │",
+            "│                                                                              │",
+            "╰─────────────────────────────────────────────────────────────────── following ╯",
+        ];
 
-    use parking_lot::Mutex;
-    use ratatui::{
-        backend::TestBackend,
-        layout::Rect,
-        style::{Color, Modifier},
-        Terminal,
-    };
-    use uuid::Uuid;
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        setup
+            .terminal
+            .draw(|f| {
+                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
 
-    use crate::{
-        app_data::{
-            AppData, ContainerId, ContainerImage, ContainerName, ContainerPorts, Header,
-            SortedOrder, State, StatefulList,
-        },
-        app_error::AppError,
-        tests::{gen_appdata, gen_container_summary, gen_containers},
-        ui::{draw_frame, GuiState},
-    };
+        let result = &setup.terminal.backend().buffer().content;
 
-    use super::{FrameData, ORANGE, VERSION};
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
 
-    struct TuiTestSetup {
-        app_data: Arc<Mutex<AppData>>,
-        gui_state: Arc<Mutex<GuiState>>,
-        fd: FrameData,
-        area: Rect,
-        terminal: Terminal<TestBackend>,
-        ids: Vec<ContainerId>,
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+            }
+        }
     }
 
-    const BORDER_CHARS: [&str; 6] = ["╭", "╮", "─", "│", "╰", "╯"];
+    // ************ //
+    // Charts panel //
+    // ************ //
 
-    /// Generate state to be used in *most* gui tests
-    fn test_setup(w: u16, h: u16, control_start: bool, container_start: bool) -> TuiTestSetup {
-        let backend = TestBackend::new(w, h);
-        let terminal = Terminal::new(backend).unwrap();
+    const EXPECTED: [&str; 10] = [
+        "╭───────────── cpu 03.00% ─────────────╮╭────────── memory 30.00 kB ───────────╮",
+        "│10.00%│    •                          ││100.00 kB│   ••                       │",
+        "│      │   ••                          ││         │   ••                       │",
+        "│      │  •••                          ││         │  • •                       │",
+        "│      │  • •                          ││         │ •  •                       │",
+        "│      │ •   ••                        ││         │••  ••                      │",
+        "│      │•    •                         ││         │•   •                       │",
+        "│      │•    •                         ││         │•   •                       │",
+        "│      │                               ││100.00% of 30.00 kB ━━━━━━━━━━━━━━━━━━│",
+        "╰──────────────────────────────────────╯╰──────────────────────────────────────╯",
+    ];
+    const MEMORY_INDEX: [usize; 16] = [
+        134, 135, 214, 215, 293, 295, 372, 375, 451, 452, 455, 456, 531, 535, 611, 615,
+    ];
 
-        let (ids, containers) = gen_containers();
-        let mut app_data = gen_appdata(&containers);
-        if control_start {
-            app_data.docker_controls_start();
+    const CPU_INDEX: [usize; 15] = [
+        92, 171, 172, 250, 251, 252, 330, 332, 409, 413, 414, 488, 493, 568, 573,
+    ];
+
+    #[allow(clippy::cast_precision_loss)]
+    // Add fixed data to the cpu & mem vecdeques, that match the above data
+    fn insert_chart_data(setup: &TuiTestSetup) {
+        for i in 1..=10 {
+            setup.app_data.lock().update_stats_by_id(
+                &setup.ids[0],
+                Some((i as f64, 4)),
+                Some(i * 10000),
+                i * 10000,
+                IoTotals::default(),
+            );
         }
-        if container_start {
-            app_data.containers_start();
+        for i in 1..=3 {
+            setup.app_data.lock().update_stats_by_id(
+                &setup.ids[0],
+                Some((i as f64, 4)),
+                Some(i * 10000),
+                i * 10000,
+                IoTotals::default(),
+            );
         }
+    }
+    #[test]
+    /// Detail panel draws the key facts of the selected container, values too long for the panel are cut off, except the entrypoint & cmd, which are wrapped
+    fn test_draw_blocks_detail() {
+        let (w, h) = (40, 19);
+        let mut setup = test_setup(w, h, true, true);
 
-        let gui_state = GuiState::default();
+        insert_chart_data(&setup);
+        setup.app_data.lock().containers.items[0].command = Some(String::from(
+            "nginx -g 'daemon off;' --with-a-very-long-argument",
+        ));
+        setup.app_data.lock().containers.items[0].container_command = Some(ContainerCommand {
+            entrypoint: None,
+            cmd: Some(String::from(
+                r#"["nginx", "-g", "daemon off;", "--with-a-very-long-argument"]"#,
+            )),
+        });
+        setup.app_data.lock().containers.items[0].mounts = Some(gen_mounts());
+        setup.app_data.lock().containers.items[0].restarts =
+            Some(Restarts::new(Some(3), Some("always"), None));
+        setup.app_data.lock().containers.items[0].address = Some(ContainerAddress::Networks(vec![
+            (String::from("frontend"), String::from("172.19.0.3")),
+            (String::from("backend"), String::from("172.18.0.2")),
+        ]));
 
-        let app_data = Arc::new(Mutex::new(app_data));
-        let gui_state = Arc::new(Mutex::new(gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::detail(f, setup.area, &setup.app_data);
+            })
+            .unwrap();
 
-        let fd = FrameData::from((app_data.lock(), gui_state.lock()));
-        let area = Rect::new(0, 0, w, h);
-        TuiTestSetup {
-            app_data,
-            gui_state,
-            fd,
-            area,
-            terminal,
-            ids,
-        }
-    }
+        let expected = [
+            "╭─────────────── detail ───────────────╮",
+            "│      image image_1                   │",
+            "│         id 1                         │",
+            "│    command nginx -g 'daemon off;' --w│",
+            "│ entrypoint none                      │",
+            r#"│        cmd ["nginx", "-g", "daemon of│"#,
+            r#"│            f;", "--with-a-very-long-a│"#,
+            r#"│            rgument"]                 │"#,
+            "│    created 1970-01-01 00:00:01       │",
+            "│      ports 8001                      │",
+            "│         ip 172.19.0.3 (frontend), 172│",
+            "│            .18.0.2 (backend)         │",
+            "│     mounts 2                         │",
+            "│    restart always, 3 restarts        │",
+            "│     uptime --                        │",
+            "│        cpu 03.00%                    │",
+            "│     memory 30.00 kB / 30.00 kB       │",
+            "│        net ↓ 0.00 kB ↑ 0.00 kB       │",
+            "╰──────────────────────────────────────╯",
+        ];
 
-    /// Insert some logs into the first container
-    fn insert_logs(setup: &TuiTestSetup) {
-        let logs = (1..=3).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
-        setup.app_data.lock().update_log_by_id(logs, &setup.ids[0]);
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
     }
 
-    // ******************** //
-    // DockerControls panel //
-    // ******************** //
-
     #[test]
-    /// Test that when DockerCommands are available, they are drawn correctly, dependant on container state
-    fn test_draw_blocks_commands_none() {
-        let (w, h) = (12, 6);
-        let mut setup = test_setup(w, h, false, false);
+    /// A long image is wrapped in the detail panel, so that the full image is shown, as it's truncated in the containers panel
+    fn test_draw_blocks_detail_long_image() {
+        let (w, h) = (40, 5);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers.items[0].image =
+            ContainerImage::from("registry.example.com/org/application:1.2.3");
 
         setup
             .terminal
             .draw(|f| {
-                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+                super::detail(f, setup.area, &setup.app_data);
             })
             .unwrap();
 
         let expected = [
-            "╭──────────╮",
-            "│          │",
-            "│          │",
-            "│          │",
-            "│          │",
-            "╰──────────╯",
+            "╭─────────────── detail ───────────────╮",
+            "│      image registry.example.com/org/a│",
+            "│            pplication:1.2.3          │",
+            "│         id 1                         │",
+            "╰──────────────────────────────────────╯",
         ];
 
         let result = &setup.terminal.backend().buffer().content;
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                assert_eq!(result_cell.fg, Color::Reset);
+                assert_eq!(result[index].symbol(), expected_char.to_string());
             }
         }
     }
 
     #[test]
-    // Test that when DockerCommands are available, they are drawn correctly, dependant on container state
-    fn test_draw_blocks_commands_some() {
-        let (w, h) = (12, 6);
+    /// History panel draws the most recent cpu & memory samples as sparklines
+    fn test_draw_blocks_history() {
+        let (w, h) = (40, 4);
         let mut setup = test_setup(w, h, true, true);
 
+        insert_chart_data(&setup);
+
         setup
             .terminal
             .draw(|f| {
-                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+                super::history(f, setup.area, &setup.app_data, &Theme::default());
             })
             .unwrap();
 
         let expected = [
-            "╭──────────╮",
-            "│▶ pause   │",
-            "│  restart │",
-            "│  stop    │",
-            "│  delete  │",
-            "╰──────────╯",
+            "╭────────────── history ───────────────╮",
+            "│      cpu 03.00%  ▁▂▃▄▄▅▆▇█ ▁▂        │",
+            "│ memory 30.00 kB  ▁▂▃▄▄▅▆▇█ ▁▂        │",
+            "╰──────────────────────────────────────╯",
         ];
-        let result = &setup.terminal.backend().buffer().content;
 
+        let result = &setup.terminal.backend().buffer().content;
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
@@ -1150,60 +5052,81 @@ mod tests {
 
                 assert_eq!(result_cell.symbol(), expected_char.to_string());
 
-                // Check the text color is correct
-                match index {
-                    // pause
-                    15..=19 => {
-                        assert_eq!(result_cell.fg, Color::Yellow);
-                    }
-                    // restart
-                    27..=33 => {
-                        assert_eq!(result_cell.fg, Color::Magenta);
-                    }
-                    // stop
-                    39..=42 => {
-                        assert_eq!(result_cell.fg, Color::Red);
-                    }
-                    // delete
-                    51..=56 => {
-                        assert_eq!(result_cell.fg, Color::Gray);
-                    }
-                    // no text
-                    _ => {
-                        assert_eq!(result_cell.fg, Color::Reset);
+                match (row_index, char_index) {
+                    (0, 15..=23) => {
+                        assert_eq!(result_cell.fg, Color::Green);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
                     }
-                }
-                if result_cell.symbol().starts_with('▶') {
-                    assert_eq!(result_cell.fg, Color::Reset);
+                    (1, 1..=30) => assert_eq!(result_cell.fg, Color::Magenta),
+                    (2, 1..=30) => assert_eq!(result_cell.fg, Color::Cyan),
+                    _ => assert_eq!(result_cell.fg, Color::Reset),
                 }
             }
         }
+    }
 
-        // Change the controls state
-        setup
-            .app_data
-            .lock()
-            .update_containers(&mut vec![gen_container_summary(1, "paused")]);
-        setup.app_data.lock().docker_controls_next();
+    #[test]
+    /// Summary bar totals every container, or only the visible containers, and shows which
+    fn test_draw_blocks_summary() {
+        let (w, h) = (84, 1);
+        let mut setup = test_setup(w, h, true, true);
 
-        let expected = [
-            "╭──────────╮",
-            "│  resume  │",
-            "│▶ stop    │",
-            "│  delete  │",
-            "│          │",
-            "╰──────────╯",
-        ];
+        insert_chart_data(&setup);
+        setup.app_data.lock().containers.items[2].state = State::Exited;
+        setup.app_data.lock().cycle_summary();
+
+        let mut draw = |expected: &str| {
+            let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+            setup
+                .terminal
+                .draw(|f| {
+                    super::summary(f, setup.area, &setup.app_data, &fd);
+                })
+                .unwrap();
+            let result = &setup.terminal.backend().buffer().content;
+            for (char_index, expected_char) in expected.chars().enumerate() {
+                let result_cell = &result[char_index];
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                assert_eq!(result_cell.bg, Color::Magenta);
+                assert_eq!(result_cell.fg, Color::Black);
+            }
+        };
+        draw("  all containers: 2 running, 1 stopped, 3 total   cpu 03.00%   memory 30.00 kB  ");
+
+        setup.app_data.lock().cycle_summary();
+        for c in "container_3".chars() {
+            setup.app_data.lock().filter_push(c);
+        }
+        draw("  visible containers: 0 running, 1 stopped, 1 total   cpu 00.00%   memory 0.00 kB");
+    }
+
+    #[test]
+    /// When status is Running, but not data, charts drawn without dots etc
+    fn test_draw_blocks_charts_running_none() {
+        let (w, h) = (80, 10);
+        let mut setup = test_setup(w, h, true, true);
 
         setup
             .terminal
             .draw(|f| {
-                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+                super::chart(f, setup.area, &setup.app_data, &Theme::default());
             })
             .unwrap();
 
-        let result = &setup.terminal.backend().buffer().content;
+        let expected = [
+            "╭───────────── cpu 00.00% ─────────────╮╭─────────── memory 0.00 kB ───────────╮",
+            "│00.00%│                               ││0.00 kB│                              │",
+            "│      │                               ││       │                              │",
+            "│      │                               ││       │                              │",
+            "│      │                               ││       │                              │",
+            "│      │                               ││       │                              │",
+            "│      │                               ││       │                              │",
+            "│      │                               ││       │                              │",
+            "│      │                               ││       │                              │",
+            "╰──────────────────────────────────────╯╰──────────────────────────────────────╯",
+        ];
 
+        let result = &setup.terminal.backend().buffer().content;
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
@@ -1211,94 +5134,129 @@ mod tests {
 
                 assert_eq!(result_cell.symbol(), expected_char.to_string());
 
-                // Chceck the text color is correct
                 match index {
-                    // resume
-                    15..=20 => {
-                        assert_eq!(result_cell.fg, Color::Blue);
-                    }
-                    // stop
-                    27..=30 => {
-                        assert_eq!(result_cell.fg, Color::Red);
+                    // chart tiles - cpu 03.00% && memory 30.00 kB - are green
+                    14..=25 | 52..=67 => {
+                        assert_eq!(result_cell.fg, Color::Green);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
                     }
-                    // delete
-                    39..=44 => {
-                        assert_eq!(result_cell.fg, Color::Gray);
+                    // Cpu & Memory max are orange and bold
+                    81..=86 | 121..=127 => {
+                        assert_eq!(result_cell.fg, Theme::default().chart_max_running);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
                     }
-                    // no text
+                    // All others
                     _ => {
                         assert_eq!(result_cell.fg, Color::Reset);
+                        assert!(result_cell.modifier.is_empty());
                     }
                 }
-                if result_cell.symbol().starts_with('▶') {
-                    assert_eq!(result_cell.fg, Color::Reset);
-                }
             }
         }
     }
 
     #[test]
-    /// When control panel is selected, the border is blue, if not then white, selected text is highlighted
-    fn test_draw_blocks_commands_panel_selected_color() {
-        let (w, h) = (12, 6);
+    /// When status is Running, charts correctly drawn
+    fn test_draw_blocks_charts_running_some() {
+        let (w, h) = (80, 10);
         let mut setup = test_setup(w, h, true, true);
-        let expected = [
-            "╭──────────╮",
-            "│▶ pause   │",
-            "│  restart │",
-            "│  stop    │",
-            "│  delete  │",
-            "╰──────────╯",
-        ];
 
-        // Unselected, has a grey border
+        insert_chart_data(&setup);
+
         setup
             .terminal
             .draw(|f| {
-                super::commands(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+                super::chart(f, setup.area, &setup.app_data, &Theme::default());
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in expected.iter().enumerate() {
+        for (row_index, row) in EXPECTED.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
                 let result_cell = &result[index];
 
                 assert_eq!(result_cell.symbol(), expected_char.to_string());
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::Reset);
+                match index {
+                    // chart tiles - cpu 03.00% && memory 30.00 kB - are green
+                    14..=25 | 51..=67 => {
+                        assert_eq!(result_cell.fg, Color::Green);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    // Cpu & Memory max are orange and bold
+                    81..=86 | 121..=129 => {
+                        assert_eq!(result_cell.fg, Theme::default().chart_max_running);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    // memory gauge is red, as usage is at the memory limit
+                    681..=699 | 701..=718 => {
+                        assert_eq!(result_cell.fg, Theme::default().chart_memory_limit);
+                    }
+                    // cpu dots are magenta
+                    _x if CPU_INDEX.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Magenta);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                    // memory dots are cyan
+                    _x if MEMORY_INDEX.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Cyan);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                    // All others
+                    _ => {
+                        assert_eq!(result_cell.fg, Color::Reset);
+                        assert!(result_cell.modifier.is_empty());
+                    }
                 }
             }
         }
+    }
+
+    #[test]
+    /// Whens status paused, some text is now Yellow
+    fn test_draw_blocks_charts_paused() {
+        let (w, h) = (80, 10);
+        let mut setup = test_setup(w, h, true, true);
+
+        insert_chart_data(&setup);
+        setup.app_data.lock().containers.items[0].state = State::Paused;
 
-        // Control panel now selected, should have a blue border
-        setup.gui_state.lock().next_panel();
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
         setup
             .terminal
             .draw(|f| {
-                super::commands(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::chart(f, setup.area, &setup.app_data, &Theme::default());
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in expected.iter().enumerate() {
+        for (row_index, row) in EXPECTED.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
                 let result_cell = &result[index];
 
                 assert_eq!(result_cell.symbol(), expected_char.to_string());
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::LightCyan);
-                }
-                // Make sure that the selected line has bold text
                 match index {
-                    // pause
-                    13..=22 => {
+                    // Titles and y axis are yellow
+                    14..=25 | 51..=67 | 81..=86 | 121..=129 => {
+                        assert_eq!(result_cell.fg, Color::Yellow);
                         assert_eq!(result_cell.modifier, Modifier::BOLD);
                     }
+                    // memory gauge is red, as usage is at the memory limit
+                    681..=699 | 701..=718 => {
+                        assert_eq!(result_cell.fg, Theme::default().chart_memory_limit);
+                    }
+                    _x if CPU_INDEX.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Magenta);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                    // memory dots are cyan
+                    _x if MEMORY_INDEX.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Cyan);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                    // All others
                     _ => {
+                        assert_eq!(result_cell.fg, Color::Reset);
                         assert!(result_cell.modifier.is_empty());
                     }
                 }
@@ -1306,1345 +5264,2050 @@ mod tests {
         }
     }
 
-    // *********************** //
-    // Container summary panel //
-    // *********************** //
+    #[test]
+    /// When dead, text is read
+    fn test_draw_blocks_charts_dead() {
+        let (w, h) = (80, 10);
+        let mut setup = test_setup(w, h, true, true);
+        insert_chart_data(&setup);
+        setup.app_data.lock().containers.items[0].state = State::Dead;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::chart(f, setup.area, &setup.app_data, &Theme::default());
+            })
+            .unwrap();
 
-    // Check that the correct solor is applied to the state/status/cpu/memory section
-    fn check_expected(expected: [&str; 6], w: u16, _h: u16, setup: &TuiTestSetup, color: Color) {
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in expected.iter().enumerate() {
+        for (row_index, row) in EXPECTED.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
                 let result_cell = &result[index];
 
                 assert_eq!(result_cell.symbol(), expected_char.to_string());
-                if (145..=207).contains(&index) {
-                    assert_eq!(result_cell.fg, color);
-                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                match index {
+                    // Titles and y axis are red
+                    14..=25 | 51..=67 | 81..=86 | 121..=129 => {
+                        assert_eq!(result_cell.fg, Color::Red);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    // memory gauge is red, as usage is at the memory limit
+                    681..=699 | 701..=718 => {
+                        assert_eq!(result_cell.fg, Theme::default().chart_memory_limit);
+                    }
+                    // cpu dots are magenta
+                    _x if CPU_INDEX.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Magenta);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                    // memory dots are cyan
+                    _x if MEMORY_INDEX.contains(&index) => {
+                        assert_eq!(result_cell.fg, Color::Cyan);
+                        assert!(result_cell.modifier.is_empty());
+                    }
+                    // All others
+                    _ => {
+                        assert_eq!(result_cell.fg, Color::Reset);
+                        assert!(result_cell.modifier.is_empty());
+                    }
                 }
             }
         }
     }
 
     #[test]
-    /// No containers, panel unselected, then selected, border color changes correctly
-    fn test_draw_blocks_containers_none() {
-        let (w, h) = (25, 6);
+    /// Memory gauge drawn in the memory chart color when below the warning percentage of the memory limit
+    fn test_draw_blocks_charts_memory_gauge() {
+        let (w, h) = (80, 10);
         let mut setup = test_setup(w, h, true, true);
-        setup.app_data.lock().containers = StatefulList::new(vec![]);
-
-        let expected = [
-            "╭ Containers ───────────╮",
-            "│ no containers running │",
-            "│                       │",
-            "│                       │",
-            "│                       │",
-            "╰───────────────────────╯",
-        ];
-
-        setup.gui_state.lock().next_panel();
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        insert_chart_data(&setup);
+        setup.app_data.lock().containers.items[0].mem_limit = ByteStats::new(60000);
 
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::chart(f, setup.area, &setup.app_data, &Theme::default());
             })
             .unwrap();
 
+        let expected =
+            "│      │                               ││50.00% of 60.00 kB ━━━━━━━━━━━━━━━━━━━│";
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in expected.iter().enumerate() {
-            for (char_index, expected_char) in row.chars().enumerate() {
-                let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                assert_eq!(result_cell.fg, Color::Reset);
+        for (char_index, expected_char) in expected.chars().enumerate() {
+            let index = 8 * usize::from(w) + char_index;
+            let result_cell = &result[index];
+            assert_eq!(result_cell.symbol(), expected_char.to_string());
+            match index {
+                // label is bold
+                681..=698 => {
+                    assert_eq!(result_cell.fg, Theme::default().chart_memory);
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                }
+                // half of the gauge is filled
+                700..=708 => assert_eq!(result_cell.fg, Theme::default().chart_memory),
+                _ => assert_ne!(result_cell.fg, Theme::default().chart_memory),
             }
         }
 
-        setup.gui_state.lock().previous_panel();
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
-
+        // Only the text changes with the memory unit, the gauge is still half filled
+        setup.app_data.lock().set_memory_unit(MemoryUnit::Kib);
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::chart(f, setup.area, &setup.app_data, &Theme::default());
             })
             .unwrap();
-
+        let expected =
+            "│      │                               ││50.00% of 58.59 KiB ━━━━━━━━━━━━━━━━━━│";
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in expected.iter().enumerate() {
-            for (char_index, expected_char) in row.chars().enumerate() {
-                let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::LightCyan);
-                }
-            }
+        for (char_index, expected_char) in expected.chars().enumerate() {
+            let index = 8 * usize::from(w) + char_index;
+            assert_eq!(result[index].symbol(), expected_char.to_string());
         }
     }
 
+    // ******* //
+    // Headers //
+    // ******* //
+
     #[test]
-    /// Containers panel drawn, selected line is bold, border is blue
-    fn test_draw_blocks_containers_some() {
-        let (w, h) = (130, 6);
+    /// Heading back only has show/exit help when no containers, correctly coloured
+    fn test_draw_blocks_headers_no_containers() {
+        let (w, h) = (140, 1);
         let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers = StatefulList::new(vec![]);
 
-        let expected = [
-        "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-        "│⚪  container_1   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
-        "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
-        "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
-        "│                                                                                                                                │",
-        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
-    ];
+        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected =  "                                                                                                                         ( h ) show help    ";
 
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in expected.iter().enumerate() {
-            for (char_index, expected_char) in row.chars().enumerate() {
-                let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                // result matches expected
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-
-                // Selected container is bold
-                match index {
-                    131 | 133..=258 => assert_eq!(result_cell.modifier, Modifier::BOLD),
-                    _ => {
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                }
+        for (index, expected_char) in expected.chars().enumerate() {
+            let result_cell = &result[index];
 
-                // Border is blue
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::LightCyan);
-                }
-            }
+            assert_eq!(result_cell.symbol(), expected_char.to_string());
+            assert_eq!(result_cell.bg, Color::Magenta);
+            assert_eq!(result_cell.fg, Color::White);
         }
 
-        // Change selected panel, border is now no longer blue
-        setup.gui_state.lock().next_panel();
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        fd.help_visible = true;
+        let expected =  "                                                                                                                         ( h ) exit help    ";
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in expected.iter().enumerate() {
-            for (char_index, expected_char) in row.chars().enumerate() {
-                let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
+        for (index, expected_char) in expected.chars().enumerate() {
+            let result_cell = &result[index];
 
-                // Border is gray
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::Reset);
-                }
-            }
+            assert_eq!(result_cell.symbol(), expected_char.to_string());
+            assert_eq!(result_cell.bg, Color::Magenta);
+            assert_eq!(result_cell.fg, Color::Black);
         }
     }
 
     #[test]
-    /// ALl columns on all rows are coloured correctly
-    fn test_draw_blocks_containers_colors() {
-        let (w, h) = (130, 6);
+    /// Only the visible columns are drawn, in their configured order, in both the headings and the containers panel
+    fn test_draw_blocks_visible_columns() {
+        let (w, h) = (60, 5);
         let mut setup = test_setup(w, h, true, true);
-
-        let expected = [
-            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-            "│⚪  container_1   ✓ running            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
-            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
-            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
-            "│                                                                                                                                │",
-            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
-        ];
+        setup
+            .app_data
+            .lock()
+            .set_visible_columns(vec![Header::Image, Header::Name, Header::Cpu]);
+        setup.gui_state.lock().set_id_mode(IdMode::Hidden);
         let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
+        let heading_area = Rect::new(0, 0, w, 1);
+        let containers_area = Rect::new(0, 1, w, h - 1);
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::heading_bar(heading_area, f, &fd, &setup.gui_state);
+                super::containers(&setup.app_data, containers_area, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
-        let index_blue = [
-            134, 135, 136, 137, 138, 139, 140, 141, 142, 143, 144, 208, 209, 210, 211, 212, 213,
-            214, 215, 216, 217, 218, 219, 220, 221, 222, 223, 224, 225, 226, 227, 228,
-        ];
-        let index_blue = index_blue
-            .iter()
-            .flat_map(|&x| vec![x, x + 130, x + 260])
-            .collect::<Vec<_>>();
-        let index_green = [
-            145, 146, 147, 148, 149, 150, 151, 152, 153, 154, 155, 156, 157, 158, 159, 160, 161,
-            162, 163, 164, 165, 166, 167, 168, 169, 170, 171, 172, 173, 174, 175, 176, 177, 178,
-            179, 180, 181, 182, 183, 184, 185, 186, 187, 188, 189, 190, 191, 192, 193, 194, 195,
-            196, 197, 198, 199, 200, 201, 202, 203, 204, 205, 206, 207,
+        let expected = [
+            "      image          name       cpu        ( h ) show help  ",
+            "╭ Containers 1/3 ──────────────────────────────────────────╮",
+            "│⚪  image_1   container_1    00.00%                        █",
+            "│   image_2   container_2    00.00%                        │",
+            "╰──────────────────────────────────────────────────────────╯",
         ];
-        let index_green = index_green
-            .iter()
-            .flat_map(|&x| vec![x, x + 130, x + 260])
-            .collect::<Vec<_>>();
-
-        let index_rx = [229, 230, 231, 232, 233, 234, 235, 236, 237, 238];
-        let index_rx = index_rx
-            .iter()
-            .flat_map(|&x| vec![x, x + 130, x + 260])
-            .collect::<Vec<_>>();
-
-        let index_tx = [239, 240, 241, 242, 243, 244, 245, 246, 247, 248];
-        let index_tx = index_tx
-            .iter()
-            .flat_map(|&x| vec![x, x + 130, x + 260])
-            .collect::<Vec<_>>();
-
         let result = &setup.terminal.backend().buffer().content;
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
+    }
 
-                let result_cell = &result[index];
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
+    #[test]
+    /// Show all headings when containers present, colors valid
+    fn test_draw_blocks_headers_some_containers() {
+        let (w, h) = (140, 1);
+        let mut setup = test_setup(w, h, true, true);
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+
+        let expected =   "           name         state             status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ";
+        setup
+            .terminal
+            .draw(|f| {
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (index, expected_char) in expected.chars().enumerate() {
+            let result_cell = &result[index];
 
+            assert_eq!(result_cell.symbol(), expected_char.to_string());
+            assert_eq!(result_cell.bg, Color::Magenta);
+            assert_eq!(
+                result_cell.fg,
                 match index {
-                    _x if index_blue.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Blue);
-                    }
-                    _x if index_green.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Green);
-                    }
-                    _x if index_rx.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Rgb(255, 233, 193));
-                    }
-                    _x if index_tx.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Rgb(205, 140, 140));
-                    }
-                    (0..=130) | (259..=260) | (389..=390) | (519..=520) | (649..=779) => {
-                        assert_eq!(result_cell.fg, Color::LightCyan);
-                    }
-                    _ => {
-                        assert_eq!(result_cell.fg, Color::Reset);
-                    }
+                    (2..=122) => Color::Black,
+                    _ => Color::White,
                 }
-            }
+            );
         }
     }
 
     #[test]
-    /// When long container/image name, it is truncated correctly
-    fn test_draw_blocks_containers_long_name_image() {
-        let (w, h) = (170, 6);
+    /// Only show the headings that fit the reduced-in-size header section
+    fn test_draw_blocks_headers_some_containers_reduced_width() {
+        let (w, h) = (80, 1);
         let mut setup = test_setup(w, h, true, true);
-        setup.app_data.lock().containers.items[0].name =
-            ContainerName::from("a_long_container_name_for_the_purposes_of_this_test");
-        setup.app_data.lock().containers.items[0].image =
-            ContainerImage::from("a_long_image_name_for_the_purposes_of_this_test");
-
-        let expected = [
-        "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-        "│⚪  a_long_container_name_for_the…   ॥ paused             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   a_long_image_name_for_the_pur…   0.00 kB   0.00 kB        │",
-        "│                      container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2                          image_2   0.00 kB   0.00 kB        │",
-        "│                      container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3                          image_3   0.00 kB   0.00 kB        │",
-        "│                                                                                                                                                                        │",
-        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
-        ];
         let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
-        setup.app_data.lock().containers.items[0].state = State::Paused;
 
+        let expected =
+            "           name         state             status       cpu     ( h ) show help  ";
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in expected.iter().enumerate() {
-            for (char_index, expected_char) in row.chars().enumerate() {
-                let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
+        for (index, expected_char) in expected.chars().enumerate() {
+            let result_cell = &result[index];
 
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-            }
+            assert_eq!(result_cell.symbol(), expected_char.to_string());
+            assert_eq!(result_cell.bg, Color::Magenta);
+            assert_eq!(
+                result_cell.fg,
+                match index {
+                    (2..=62) => Color::Black,
+                    _ => Color::White,
+                }
+            );
         }
-
-        // THis char: …
     }
 
     #[test]
-    /// When container is paused, correct colors displayed
-    fn test_draw_blocks_containers_paused() {
-        let (w, h) = (130, 6);
+    /// When a container search is set, the search term is shown in the header, with a cursor whilst it's being edited
+    fn test_draw_blocks_headers_search() {
+        let (w, h) = (80, 1);
         let mut setup = test_setup(w, h, true, true);
-
-        let expected = [
-            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-        "│⚪  container_1   ॥ paused             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
-        "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
-        "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
-        "│                                                                                                                                │",
-        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
-        ];
+        setup.app_data.lock().search_push('c');
+        setup.gui_state.lock().status_push(Status::SearchContainers);
         let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
-        setup.app_data.lock().containers.items[0].state = State::Paused;
 
+        let expected =
+            "           name         state             status  search: c▏   ( h ) show help  ";
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
-        check_expected(expected, w, h, &setup, Color::Yellow);
+        let result = &setup.terminal.backend().buffer().content;
+        let row = result
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect::<String>();
+        assert_eq!(row, expected);
     }
 
     #[test]
-    /// When container is dead, correct colors displayed
-    fn test_draw_blocks_containers_dead() {
-        let (w, h) = (130, 6);
+    /// Whilst the go to input is open, the typed row number is shown in the header
+    fn test_draw_blocks_headers_go_to() {
+        let (w, h) = (80, 1);
         let mut setup = test_setup(w, h, true, true);
-
-        let expected = [
-            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-            "│⚪  container_1   ✖ dead               Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
-            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
-            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
-            "│                                                                                                                                │",
-            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
-        ];
-        setup.app_data.lock().containers.items[0].state = State::Dead;
+        setup.gui_state.lock().go_to_start();
+        setup.gui_state.lock().go_to_push('2');
         let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
             })
             .unwrap();
-        check_expected(expected, w, h, &setup, Color::Red);
+
+        let result = &setup.terminal.backend().buffer().content;
+        let row = result
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect::<String>();
+        assert_eq!(
+            row,
+            "           name         state             status   go to: 2▏   ( h ) show help  "
+        );
     }
 
     #[test]
-    /// When container is exited, correct colors displayed
-    fn test_draw_blocks_containers_exited() {
-        let (w, h) = (130, 6);
+    /// When a filter is set, the filter term is shown in the header, with a cursor whilst it's being edited
+    fn test_draw_blocks_headers_filter() {
+        let (w, h) = (80, 1);
         let mut setup = test_setup(w, h, true, true);
-
-        let expected = [
-            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-            "│⚪  container_1   ✖ exited             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
-            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
-            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
-            "│                                                                                                                                │",
-            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
-        ];
-        setup.app_data.lock().containers.items[0].state = State::Exited;
+        setup.app_data.lock().filter_push('c');
+        setup.gui_state.lock().status_push(Status::FilterContainers);
         let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
+        let expected =
+            "           name         state             status  filter: c▏   ( h ) show help  ";
+        setup
+            .terminal
+            .draw(|f| {
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        let row = result
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect::<String>();
+        assert_eq!(row, expected);
+
+        setup.gui_state.lock().status_del(Status::FilterContainers);
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        let expected =
+            "           name         state             status   filter: c   ( h ) show help  ";
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
-        check_expected(expected, w, h, &setup, Color::Red);
+        let result = &setup.terminal.backend().buffer().content;
+        let row = result
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect::<String>();
+        assert_eq!(row, expected);
     }
+
     #[test]
-    /// When container is paused, correct colors displayed
-    fn test_draw_blocks_containers_removing() {
-        let (w, h) = (130, 6);
+    /// The state filter is shown in the header, unless all containers are shown
+    fn test_draw_blocks_headers_state_filter() {
+        let (w, h) = (80, 1);
         let mut setup = test_setup(w, h, true, true);
-
-        let expected = [
-            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-            "│⚪  container_1   removing             Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
-            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
-            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
-            "│                                                                                                                                │",
-            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
-        ];
-        setup.app_data.lock().containers.items[0].state = State::Removing;
+        setup.app_data.lock().cycle_state_filter();
         let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
+        let expected =
+            "           name         state                 state: running   ( h ) show help  ";
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
-        check_expected(expected, w, h, &setup, Color::LightRed);
+        let result = &setup.terminal.backend().buffer().content;
+        let row = result
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect::<String>();
+        assert_eq!(row, expected);
     }
+
     #[test]
-    /// When container state is restarting, correct colors displayed
-    fn test_draw_blocks_containers_restarting() {
-        let (w, h) = (130, 6);
+    /// The update interval is shown in the header once it's been changed
+    fn test_draw_blocks_headers_interval() {
+        let (w, h) = (80, 1);
         let mut setup = test_setup(w, h, true, true);
-
-        let expected = [
-            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-            "│⚪  container_1   ↻ restarting          Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB         │",
-            "│   container_2   ✓ running             Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB         │",
-            "│   container_3   ✓ running             Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB         │",
-            "│                                                                                                                                │",
-            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
-        ];
-        setup.app_data.lock().containers.items[0].state = State::Restarting;
+        setup.app_data.lock().interval_faster();
         let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
+        let expected =
+            "           name         state                 refresh: 500ms   ( h ) show help  ";
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
-        check_expected(expected, w, h, &setup, Color::LightGreen);
+        let result = &setup.terminal.backend().buffer().content;
+        let row = result
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect::<String>();
+        assert_eq!(row, expected);
     }
+
     #[test]
-    /// When container state is unknown, correct colors displayed
-    fn test_draw_blocks_containers_unknown() {
-        let (w, h) = (130, 6);
+    /// The engine is shown in the header only when connected to Podman
+    fn test_draw_blocks_headers_engine() {
+        let (w, h) = (80, 1);
         let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().set_engine(Engine::Podman);
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
-        let expected = [
-            "╭ Containers 1/3 ────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮",
-            "│⚪  container_1   ? unknown            Up 1 hour    00.00%   0.00 kB / 0.00 kB          1   image_1   0.00 kB   0.00 kB          │",
-            "│   container_2   ✓ running            Up 2 hour    00.00%   0.00 kB / 0.00 kB          2   image_2   0.00 kB   0.00 kB          │",
-            "│   container_3   ✓ running            Up 3 hour    00.00%   0.00 kB / 0.00 kB          3   image_3   0.00 kB   0.00 kB          │",
-            "│                                                                                                                                │",
-            "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
-        ];
-        setup.app_data.lock().containers.items[0].state = State::Unknown;
+        let expected =
+            "           name         state             status      podman   ( h ) show help  ";
+        setup
+            .terminal
+            .draw(|f| {
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        let row = result
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect::<String>();
+        assert_eq!(row, expected);
+    }
+
+    #[test]
+    /// Read-only mode is always shown in the header
+    fn test_draw_blocks_headers_read_only() {
+        let (w, h) = (80, 1);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().args.read_only = true;
         let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
+        let expected =
+            "           name         state             status   read-only   ( h ) show help  ";
         setup
             .terminal
             .draw(|f| {
-                super::containers(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
             })
             .unwrap();
-        check_expected(expected, w, h, &setup, Color::Red);
+
+        let result = &setup.terminal.backend().buffer().content;
+        let row = result
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect::<String>();
+        assert_eq!(row, expected);
     }
-    // ********** //
-    // Logs panel //
-    // ********** //
 
     #[test]
-    /// No logs, panel unselected, then selected, border color changes correctly
-    fn test_draw_blocks_logs_none() {
-        let (w, h) = (25, 6);
+    /// The ping of the daemon is shown in the header, colored by the health of the connection
+    fn test_draw_blocks_headers_ping() {
+        let (w, h) = (80, 1);
         let mut setup = test_setup(w, h, true, true);
-        setup.app_data.lock().containers = StatefulList::new(vec![]);
+        let theme = setup.app_data.lock().theme.clone();
 
-        let expected = [
-            "╭ Logs ─────────────────╮",
-            "│     no logs found     │",
-            "│                       │",
-            "│                       │",
-            "│                       │",
-            "╰───────────────────────╯",
-        ];
+        for (round_trip, expected, color) in [
+            (
+                Some(std::time::Duration::from_millis(3)),
+                "           name         state             status       ● 3ms   ( h ) show help  ",
+                theme.health_healthy,
+            ),
+            (
+                Some(std::time::Duration::from_millis(400)),
+                "           name         state             status     ● 400ms   ( h ) show help  ",
+                theme.health_starting,
+            ),
+            (
+                None,
+                "           name         state                  ● no response   ( h ) show help  ",
+                theme.health_unhealthy,
+            ),
+        ] {
+            setup.app_data.lock().set_ping(round_trip);
+            let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+            setup
+                .terminal
+                .draw(|f| {
+                    super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+                })
+                .unwrap();
+
+            let result = &setup.terminal.backend().buffer().content;
+            let row = result
+                .iter()
+                .map(ratatui::buffer::Cell::symbol)
+                .collect::<String>();
+            assert_eq!(row, expected);
+            let index = row.chars().position(|i| i == '●').unwrap();
+            assert_eq!(result[index].fg, color);
+        }
+    }
 
-        let _fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+    #[test]
+    /// The cpu heading shows when the cpu usage is relative to every core
+    fn test_draw_blocks_headers_cpu_mode() {
+        let (w, h) = (80, 1);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().cycle_cpu_mode();
+        setup
+            .app_data
+            .lock()
+            .set_visible_columns(vec![Header::Name, Header::Cpu]);
+        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
+        let expected =
+            "           name         id     cpu/all                         ( h ) show help  ";
         setup
             .terminal
             .draw(|f| {
-                super::logs(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in expected.iter().enumerate() {
-            for (char_index, expected_char) in row.chars().enumerate() {
-                let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
+        let row = result
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect::<String>();
+        assert_eq!(row, expected);
+    }
+
+    #[test]
+    /// Test all combination of headers & sort by
+    fn test_draw_blocks_headers_sort_containers() {
+        let (w, h) = (140, 1);
+        let mut setup = test_setup(w, h, true, true);
+        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        let mut test = |expected: &str, range: RangeInclusive<usize>, x: (Header, SortedOrder)| {
+            fd.sorted_by = Some(x);
+
+            setup
+                .terminal
+                .draw(|f| {
+                    super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+                })
+                .unwrap();
 
+            let result = &setup.terminal.backend().buffer().content;
+            for (index, expected_char) in expected.chars().enumerate() {
+                let result_cell = &result[index];
                 assert_eq!(result_cell.symbol(), expected_char.to_string());
-                assert_eq!(result_cell.fg, Color::Reset);
+                assert_eq!(result_cell.bg, Color::Magenta);
+                assert_eq!(
+                    result_cell.fg,
+                    match index {
+                        0 | 1 => Color::White,
+                        // given range | help section
+                        x if range.contains(&x) || (123..=139).contains(&x) => Color::White,
+                        _ => Color::Black,
+                    }
+                );
             }
-        }
+        };
 
-        setup.gui_state.lock().next_panel();
-        setup.gui_state.lock().next_panel();
+        // Name
+        test("         ▲ name         state             status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 1..=14, (Header::Name, SortedOrder::Asc));
+        test("         ▼ name         state             status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 1..=14, (Header::Name, SortedOrder::Desc));
+
+        // state
+        test("           name       ▲ state             status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 15..=28, (Header::State, SortedOrder::Asc));
+        test("           name       ▼ state             status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 15..=28, (Header::State, SortedOrder::Desc));
+
+        // status
+        test("           name         state           ▲ status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 29..=47, (Header::Status, SortedOrder::Asc));
+        test("           name         state           ▼ status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 29..=47, (Header::Status, SortedOrder::Desc));
+
+        // cpu
+        test("           name         state             status     ▲ cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 48..=57, (Header::Cpu, SortedOrder::Asc));
+        test("           name         state             status     ▼ cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 48..=57, (Header::Cpu, SortedOrder::Desc));
+
+        // mem
+        test("           name         state             status       cpu      ▲ memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 58..=77, (Header::Memory, SortedOrder::Asc));
+        test("           name         state             status       cpu      ▼ memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 58..=77, (Header::Memory, SortedOrder::Desc));
+
+        // id
+        test("           name         state             status       cpu        memory/limit       ▲ id     image      ↓ rx      ↑ tx    ( h ) show help  ", 78..=88, (Header::Id, SortedOrder::Asc));
+        test("           name         state             status       cpu        memory/limit       ▼ id     image      ↓ rx      ↑ tx    ( h ) show help  ", 78..=88, (Header::Id, SortedOrder::Desc));
+
+        // image
+        test("           name         state             status       cpu        memory/limit         id   ▲ image      ↓ rx      ↑ tx    ( h ) show help  ", 89..=98, (Header::Image, SortedOrder::Asc));
+        test("           name         state             status       cpu        memory/limit         id   ▼ image      ↓ rx      ↑ tx    ( h ) show help  ", 89..=98, (Header::Image, SortedOrder::Desc));
+
+        // rx
+        test("           name         state             status       cpu        memory/limit         id     image    ▲ ↓ rx      ↑ tx    ( h ) show help  ", 99..=108, (Header::Rx, SortedOrder::Asc));
+        test("           name         state             status       cpu        memory/limit         id     image    ▼ ↓ rx      ↑ tx    ( h ) show help  ", 99..=108, (Header::Rx, SortedOrder::Desc));
+
+        // tx
+        test("           name         state             status       cpu        memory/limit         id     image      ↓ rx    ▲ ↑ tx    ( h ) show help  ", 109..=118, (Header::Tx, SortedOrder::Asc));
+        test("           name         state             status       cpu        memory/limit         id     image      ↓ rx    ▼ ↑ tx    ( h ) show help  ", 109..=118, (Header::Tx, SortedOrder::Desc));
+    }
+
+    #[test]
+    /// Show animation
+    fn test_draw_blocks_headers_animation() {
+        let (w, h) = (140, 1);
+        let mut setup = test_setup(w, h, true, true);
+        let uuid = Uuid::new_v4();
+        setup.gui_state.lock().next_loading(uuid);
         let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
-        // When selected, has a blue border
         setup
             .terminal
             .draw(|f| {
-                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
+        let expected =   " ⠙         name         state             status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ";
+
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in expected.iter().enumerate() {
-            for (char_index, expected_char) in row.chars().enumerate() {
-                let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
+        for (index, expected_char) in expected.chars().enumerate() {
+            let result_cell = &result[index];
 
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::LightCyan);
+            assert_eq!(result_cell.symbol(), expected_char.to_string());
+            assert_eq!(result_cell.bg, Color::Magenta);
+            assert_eq!(
+                result_cell.fg,
+                match index {
+                    (2..=122) => Color::Black,
+                    _ => Color::White,
                 }
-            }
+            );
         }
     }
 
+    // ********** //
+    // Help popup //
+    // ********** //
     #[test]
-    /// Parsing logs, spinner visible, and then animates by one frame
-    fn test_draw_blocks_logs_parsing() {
-        let (w, h) = (25, 6);
+    /// This will cause issues once the version has more than the current 5 chars (0.5.0)
+    // Help  popup is drawn correctly
+    #[allow(clippy::too_many_lines)]
+    fn test_draw_blocks_help() {
+        let (w, h) = (87, 79);
         let mut setup = test_setup(w, h, true, true);
-        let uuid = Uuid::new_v4();
-        setup.gui_state.lock().next_loading(uuid);
-
-        let expected = [
-            "╭ Logs - container_1 ───╮",
-            "│    parsing logs ⠙     │",
-            "│                       │",
-            "│                       │",
-            "│                       │",
-            "╰───────────────────────╯",
-        ];
-
-        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
-        fd.init = true;
 
         setup
             .terminal
             .draw(|f| {
-                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::help_box(f, &Theme::default());
             })
             .unwrap();
+        let expected = [
+            "                                                                                       ".to_owned(),
+            format!(" ╭ {VERSION} ────────────────────────────────────────────────────────────────────────────╮ "),
+            " │                                                                                   │ ".to_owned(),
+            " │                                      88                                           │ ".to_owned(),
+            " │                                      88                                           │ ".to_owned(),
+            " │                                      88                                           │ ".to_owned(),
+            " │             ,adPPYba,   8b,     ,d8  88   ,d8    ,adPPYba,  8b,dPPYba,            │ ".to_owned(),
+            r#" │            a8"     "8a   `Y8, ,8P'   88 ,a8"    a8P_____88  88P'   "Y8            │ "#.to_owned(),
+            r#" │            8b       d8     )888(     8888[      8PP"""""""  88                    │ "#.to_owned(),
+            r#" │            "8a,   ,a8"   ,d8" "8b,   88`"Yba,   "8b,   ,aa  88                    │ "#.to_owned(),
+            r#" │             `"YbbdP"'   8P'     `Y8  88   `Y8a   `"Ybbd8"'  88                    │ "#.to_owned(),
+            " │                                                                                   │ ".to_owned(),
+            " │                 A simple tui to view & control docker containers                  │ ".to_owned(),
+            " │                                                                                   │ ".to_owned(),
+            " │ ( tab ) or ( shift+tab ) change panels                                            │ ".to_owned(),
+            " │ ( ↑ ↓ ) or ( j k ) or ( PgUp PgDown ) or ( Home End ) change selected line        │ ".to_owned(),
+            " │ ( alt+d alt+u ) or ( alt+f alt+b ) scroll the logs by half, or a whole, page      │ ".to_owned(),
+            " │ ( enter ) send docker container command                                           │ ".to_owned(),
+            " │ ( e ) exec into a container                                                       │ ".to_owned(),
+            " │ ( i ) inspect a container - esc closes                                            │ ".to_owned(),
+            " │ ( a ) show environment variables, values are masked - m toggles                   │ ".to_owned(),
+            " │ ( ctrl+r ) show the docker run command, best-effort - c copies                    │ ".to_owned(),
+            " │ ( ctrl+u ) show the mounts of the selected container                              │ ".to_owned(),
+            " │ ( ctrl+t ) show the labels of the selected container                              │ ".to_owned(),
+            " │ ( y ) or ( Y ) copy the id or name of the selected container                      │ ".to_owned(),
+            " │ ( h ) toggle this help information                                                │ ".to_owned(),
+            " │ ( ? ) show every keybinding, including any set in the config file                 │ ".to_owned(),
+            " │ ( p ) or ( u ) pause or unpause the selected container                            │ ".to_owned(),
+            " │ ( x ) kill the selected container with a chosen signal                            │ ".to_owned(),
+            " │ ( ctrl+e ) rename the selected container                                          │ ".to_owned(),
+            " │ ( alt+r ) recreate the selected container, best-effort - confirm first            │ ".to_owned(),
+            " │ ( alt+p ) pull the image of the selected container - r then recreates             │ ".to_owned(),
+            " │ ( alt+a ) change the restart policy of the selected container                     │ ".to_owned(),
+            " │ ( alt+l ) change the cpu and memory limits of the selected container              │ ".to_owned(),
+            " │ ( delete ) delete the selected container, with force if running                   │ ".to_owned(),
+            " │ ( b ) toggle the images view, delete removes the selected image                   │ ".to_owned(),
+            " │ ( ctrl+w ) toggle the networks view, i inspects, delete removes if unused         │ ".to_owned(),
+            " │ ( ctrl+o ) toggle the volumes view, delete removes, ctrl+p prunes                 │ ".to_owned(),
+            " │ ( ctrl+p ) prune stopped containers, dangling images, and/or volumes              │ ".to_owned(),
+            " │ ( / ) search logs - tab toggles regex, ( n ) or ( N ) next or previous match      │ ".to_owned(),
+            " │ ( f ) filter containers by name, image, or compose service - esc clears           │ ".to_owned(),
+            " │ ( ctrl+f ) search container names, ( n ) or ( N ) next or previous match          │ ".to_owned(),
+            " │ ( : ) go to a container by its row number - type the number, then enter           │ ".to_owned(),
+            " │ ( F ) cycle the state filter - all, running, exited, paused                       │ ".to_owned(),
+            " │ ( s ) save logs to file                                                           │ ".to_owned(),
+            " │ ( t ) toggle log timestamps                                                       │ ".to_owned(),
+            " │ ( v ) toggle the cpu & memory history panel                                       │ ".to_owned(),
+            " │ ( d ) toggle the container detail panel                                           │ ".to_owned(),
+            " │ ( l ) toggle full screen logs                                                     │ ".to_owned(),
+            " │ ( w ) toggle wrapping of long log lines                                           │ ".to_owned(),
+            " │ ( g ) or ( G ) jump to the first or last line, G resumes following logs           │ ".to_owned(),
+            " │ ( ctrl+s ) pause or resume the log stream of the selected container               │ ".to_owned(),
+            " │ ( ctrl+l ) toggle coloring log lines by severity                                  │ ".to_owned(),
+            " │ ( ctrl+d ) cycle the log streams - all, stdout, stderr                            │ ".to_owned(),
+            " │ ( alt+m ) set a log marker, again shows only the newer lines, again clears        │ ".to_owned(),
+            " │ ( ctrl+k ) only fetch logs since a duration, e.g. 10m, or a timestamp             │ ".to_owned(),
+            " │ ( ctrl+b ) cycle the memory unit - auto, bytes, KiB, MiB, GiB                     │ ".to_owned(),
+            " │ ( ctrl+v ) toggle cpu usage between per core, and relative to every core          │ ".to_owned(),
+            " │ ( ctrl+g ) cycle the id column - hidden, short, full                              │ ".to_owned(),
+            " │ ( ctrl+a ) cycle the summary bar - hidden, all, or visible containers             │ ".to_owned(),
+            " │ ( ctrl+y ) copy log lines, ( J ) or ( K ) select a range, ( y ) copies            │ ".to_owned(),
+            " │ ( alt+y ) copy the visible log lines, ( alt+Y ) every buffered line               │ ".to_owned(),
+            " │ ( c ) group containers by compose project                                         │ ".to_owned(),
+            " │ ( z ) collapse or expand the selected group                                       │ ".to_owned(),
+            " │ ( space ) mark a container, commands apply to every marked container              │ ".to_owned(),
+            " │ ( + ) or ( - ) refresh docker information faster or slower                        │ ".to_owned(),
+            " │ ( m ) toggle mouse capture - if disabled, text on screen can be selected & copied │ ".to_owned(),
+            " │ ( 0 ) stop sort                                                                   │ ".to_owned(),
+            " │ ( 1 - 9 ) sort by header - or click header                                        │ ".to_owned(),
+            " │ ( o ) cycle the sorted column or ( r ) reverse the sort order                     │ ".to_owned(),
+            " │ ( esc ) close dialog                                                              │ ".to_owned(),
+            " │ ( q ) quit at any time                                                            │ ".to_owned(),
+            " │                                                                                   │ ".to_owned(),
+            " │        currently an early work in progress, all and any input appreciated         │ ".to_owned(),
+            " │                       https://github.com/mrjackwills/oxker                        │ ".to_owned(),
+            " │                                                                                   │ ".to_owned(),
+            " │                                                                                   │ ".to_owned(),
+            " ╰───────────────────────────────────────────────────────────────────────────────────╯ ".to_owned(),
+        ];
 
-        let test = |terminal: &Terminal<TestBackend>, expected: [&str; 6]| {
-            let result = &terminal.backend().buffer().content;
-            for (row_index, row) in expected.iter().enumerate() {
-                for (char_index, expected_char) in row.chars().enumerate() {
-                    let index = row_index * usize::from(w) + char_index;
-                    let result_cell = &result[index];
+        for (row_index, row) in expected.iter().enumerate() {
+            let mut bracket_key = vec![];
+            let mut push_bracket_key = false;
 
-                    assert_eq!(result_cell.symbol(), expected_char.to_string());
+            let result = &setup.terminal.backend().buffer().content;
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+                let result_str = result_cell.symbol();
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+
+                // First and last row, and first char and last char in each row, is empty
+                if row_index == 0
+                    || row_index == usize::from(h - 1)
+                    || char_index == 0
+                    || char_index == usize::from(w - 1)
+                {
                     assert_eq!(result_cell.fg, Color::Reset);
+                    assert_eq!(result_cell.bg, Color::Reset);
+                // Borders
+                } else if BORDER_CHARS.contains(&result_str) {
+                    assert_eq!(result_cell.fg, Color::Black);
+                    assert_eq!(result_cell.bg, Color::Magenta);
+                // everything else has a magenta background
+                } else {
+                    assert_eq!(result_cell.bg, Color::Magenta);
+                }
+
+                // check that ( [key] ) is white
+                if result_str == "(" {
+                    push_bracket_key = true;
+                    bracket_key.push(result_cell);
+                }
+                if push_bracket_key {
+                    bracket_key.push(result_cell);
+                    if result_str == ")" {
+                        push_bracket_key = false;
+                        for i in &bracket_key {
+                            assert_eq!(i.fg, Color::White);
+                        }
+                        bracket_key.clear();
+                    }
                 }
+                // TODO should really be testing every color of every str here
             }
-        };
+        }
+    }
 
-        test(&setup.terminal, expected);
+    // ************ //
+    // Delete popup //
+    // ************ //
 
-        // animation moved by one frame
-        setup.gui_state.lock().next_loading(uuid);
+    #[test]
+    /// Delete container popup is drawn correctly
+    fn test_draw_blocks_delete() {
+        let (w, h) = (82, 10);
+        let mut setup = test_setup(w, h, true, true);
 
         let expected = [
-            "╭ Logs - container_1 ───╮",
-            "│    parsing logs ⠹     │",
-            "│                       │",
-            "│                       │",
-            "│                       │",
-            "╰───────────────────────╯",
+            "                                                                                  ",
+            "        ╭──────────────────────── Confirm Delete ────────────────────────╮        ",
+            "        │                                                                │        ",
+            "        │     Are you sure you want to delete container: container_1     │        ",
+            "        │                                                                │        ",
+            "        │      ╭─────────────────────╮      ╭─────────────────────╮      │        ",
+            "        │      │        (N)o         │      │        (Y)es        │      │        ",
+            "        │      ╰─────────────────────╯      ╰─────────────────────╯      │        ",
+            "        ╰────────────────────────────────────────────────────────────────╯        ",
+            "                                                                                  ",
         ];
 
-        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
-        fd.init = true;
         setup
             .terminal
             .draw(|f| {
-                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::delete_confirm(
+                    f,
+                    &setup.gui_state,
+                    "container",
+                    "container_1",
+                    false,
+                    &Theme::default(),
+                );
             })
             .unwrap();
 
-        test(&setup.terminal, expected);
-    }
-
-    #[test]
-    /// Logs correct displayed, changing log state also draws correctly
-    fn test_draw_blocks_logs_some() {
-        let (w, h) = (25, 6);
-        let mut setup = test_setup(w, h, true, true);
-
-        insert_logs(&setup);
-
-        let test = |terminal: &Terminal<TestBackend>,
-                    expected: [&str; 6],
-                    range: RangeInclusive<usize>| {
-            let result = &terminal.backend().buffer().content;
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
 
-            for (row_index, row) in expected.iter().enumerate() {
-                for (char_index, expected_char) in row.chars().enumerate() {
-                    let index = row_index * usize::from(w) + char_index;
-                    let result_cell = &result[index];
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
 
-                    assert_eq!(result_cell.symbol(), expected_char.to_string());
+                if row_index == 0
+                    || row_index == usize::from(h - 1)
+                    || char_index < 8
+                    || char_index > usize::from(w - 9)
+                {
                     assert_eq!(result_cell.fg, Color::Reset);
+                    assert_eq!(result_cell.bg, Color::Reset);
+                } else {
+                    assert_eq!(result_cell.bg, Color::White);
+                }
 
-                    if range.contains(&index) {
-                        assert_eq!(result_cell.modifier, Modifier::BOLD);
-                    } else {
-                        assert!(result_cell.modifier.is_empty());
-                    }
+                // Borders are black
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::Black);
+                    // Container name is red
+                } else if row_index == 3 && (57..=67).contains(&char_index) {
+                    assert_eq!(result_cell.fg, Color::Red);
+                    // All other text is black
+                } else if !row_index == 0
+                    && !row_index == usize::from(h - 1)
+                    && !char_index < 8
+                    && !char_index > usize::from(w - 9)
+                {
+                    assert_eq!(result_cell.fg, Color::Black);
                 }
             }
-        };
+        }
+    }
+
+    #[test]
+    /// Delete container popup shows a force button when the container is running
+    fn test_draw_blocks_delete_force() {
+        let (w, h) = (82, 10);
+        let mut setup = test_setup(w, h, true, true);
 
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
-        setup
-            .terminal
-            .draw(|f| {
-                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
-            })
-            .unwrap();
         let expected = [
-            "╭ Logs 3/3 - container_1╮",
-            "│  line 1               │",
-            "│  line 2               │",
-            "│▶ line 3               │",
-            "│                       │",
-            "╰───────────────────────╯",
+            "                                                                                  ",
+            "        ╭──────────────────────── Confirm Delete ────────────────────────╮        ",
+            "        │                                                                │        ",
+            "        │     Are you sure you want to delete container: container_1     │        ",
+            "        │                                                                │        ",
+            "        │      ╭─────────────────────╮      ╭─────────────────────╮      │        ",
+            "        │      │        (N)o         │      │       (F)orce       │      │        ",
+            "        │      ╰─────────────────────╯      ╰─────────────────────╯      │        ",
+            "        ╰────────────────────────────────────────────────────────────────╯        ",
+            "                                                                                  ",
         ];
-        test(&setup.terminal, expected, 76..=98);
-
-        // Change selected log line
-        setup.app_data.lock().log_previous();
-        let _fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
 
         setup
             .terminal
             .draw(|f| {
-                super::logs(&setup.app_data, setup.area, f, &setup.fd, &setup.gui_state);
+                super::delete_confirm(
+                    f,
+                    &setup.gui_state,
+                    "container",
+                    "container_1",
+                    true,
+                    &Theme::default(),
+                );
             })
             .unwrap();
 
-        let expected = [
-            "╭ Logs 2/3 - container_1╮",
-            "│  line 1               │",
-            "│▶ line 2               │",
-            "│  line 3               │",
-            "│                       │",
-            "╰───────────────────────╯",
-        ];
-        test(&setup.terminal, expected, 51..=73);
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
     }
 
     #[test]
-    /// Full (long) name displayed in logs border
-    fn test_draw_blocks_logs_long_name() {
-        let (w, h) = (80, 6);
+    /// Delete container popup is drawn correctly
+    fn test_draw_blocks_delete_long_name() {
+        let (w, h) = (106, 10);
         let mut setup = test_setup(w, h, true, true);
-        setup.app_data.lock().containers.items[0].name =
-            ContainerName::from("a_long_container_name_for_the_purposes_of_this_test");
-        setup.app_data.lock().containers.items[0].image =
-            ContainerImage::from("a_long_image_name_for_the_purposes_of_this_test");
-
-        insert_logs(&setup);
+        let name = ContainerName::from("container_1_container_1_container_1");
+        setup.app_data.lock().containers.items[0].name = name.clone();
 
         let expected = [
-            "╭ Logs 3/3 - a_long_container_name_for_the_purposes_of_this_test ──────────────╮",
-            "│  line 1                                                                      │",
-            "│  line 2                                                                      │",
-            "│▶ line 3                                                                      │",
-            "│                                                                              │",
-            "╰──────────────────────────────────────────────────────────────────────────────╯",
+            "                                                                                                          ",
+            "        ╭──────────────────────────────────── Confirm Delete ────────────────────────────────────╮        ",
+            "        │                                                                                        │        ",
+            "        │     Are you sure you want to delete container: container_1_container_1_container_1     │        ",
+            "        │                                                                                        │        ",
+            "        │        ╭──────────────────────────────╮         ╭─────────────────────────────╮        │        ",
+            "        │        │             (N)o             │         │            (Y)es            │        │        ",
+            "        │        ╰──────────────────────────────╯         ╰─────────────────────────────╯        │        ",
+            "        ╰────────────────────────────────────────────────────────────────────────────────────────╯        ",
+            "                                                                                                          ",
         ];
 
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
         setup
             .terminal
             .draw(|f| {
-                super::logs(&setup.app_data, setup.area, f, &fd, &setup.gui_state);
+                super::delete_confirm(
+                    f,
+                    &setup.gui_state,
+                    "container",
+                    name.get(),
+                    false,
+                    &Theme::default(),
+                );
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
                 let result_cell = &result[index];
-
                 assert_eq!(result_cell.symbol(), expected_char.to_string());
+
+                if row_index == 0
+                    || row_index == usize::from(h - 1)
+                    || char_index < 8
+                    || char_index > usize::from(w - 9)
+                {
+                    assert_eq!(result_cell.fg, Color::Reset);
+                    assert_eq!(result_cell.bg, Color::Reset);
+                } else {
+                    assert_eq!(result_cell.bg, Color::White);
+                }
+
+                // Borders are black
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::Black);
+                // Container name is red
+                } else if row_index == 3 && (57..=82).contains(&char_index) {
+                    assert_eq!(result_cell.fg, Color::Red);
+                // All other text is black
+                } else if !row_index == 0
+                    && !row_index == usize::from(h - 1)
+                    && !char_index < 8
+                    && !char_index > usize::from(w - 9)
+                {
+                    assert_eq!(result_cell.fg, Color::Black);
+                }
             }
         }
     }
 
-    // ************ //
-    // Charts panel //
-    // ************ //
+    // ***** //
+    // popup //
+    // ***** //
 
-    const EXPECTED: [&str; 10] = [
-        "╭───────────── cpu 03.00% ─────────────╮╭────────── memory 30.00 kB ───────────╮",
-        "│10.00%│    •                          ││100.00 kB│   ••                       │",
-        "│      │   ••                          ││         │   ••                       │",
-        "│      │  •••                          ││         │  • •                       │",
-        "│      │  • •                          ││         │ •  •                       │",
-        "│      │ •   ••                        ││         │••  ••                      │",
-        "│      │•    •                         ││         │•   •                       │",
-        "│      │•    •                         ││         │•   •                       │",
-        "│      │                               ││         │                            │",
-        "╰──────────────────────────────────────╯╰──────────────────────────────────────╯",
-    ];
-    const MEMORY_INDEX: [usize; 16] = [
-        134, 135, 214, 215, 293, 295, 372, 375, 451, 452, 455, 456, 531, 535, 611, 615,
-    ];
+    #[test]
+    /// Destructive confirm popup shows the command, the container name, and the kill signal if one was chosen
+    fn test_draw_blocks_confirm_control() {
+        let (w, h) = (82, 10);
+        let mut setup = test_setup(w, h, true, true);
+        let confirm_control = ConfirmControl {
+            batch: false,
+            control: DockerControls::Kill,
+            ids: vec![setup.ids[0].clone()],
+            signal: Some(String::from("SIGTERM")),
+        };
 
-    const CPU_INDEX: [usize; 15] = [
-        92, 171, 172, 250, 251, 252, 330, 332, 409, 413, 414, 488, 493, 568, 573,
-    ];
+        let expected = [
+            "                                                                                  ",
+            "       ╭──────────────────────────── Confirm ─────────────────────────────╮       ",
+            "       │                                                                  │       ",
+            "       │      Are you sure you want to kill: container_1 with SIGTERM     │       ",
+            "       │                                                                  │       ",
+            "       │      ╭──────────────────────╮      ╭──────────────────────╮      │       ",
+            "       │      │         (N)o         │      │         (Y)es        │      │       ",
+            "       │      ╰──────────────────────╯      ╰──────────────────────╯      │       ",
+            "       ╰──────────────────────────────────────────────────────────────────╯       ",
+            "                                                                                  ",
+        ];
 
-    #[allow(clippy::cast_precision_loss)]
-    // Add fixed data to the cpu & mem vecdeques, that match the above data
-    fn insert_chart_data(setup: &TuiTestSetup) {
-        for i in 1..=10 {
-            setup.app_data.lock().update_stats_by_id(
-                &setup.ids[0],
-                Some(i as f64),
-                Some(i * 10000),
-                i * 10000,
-                i,
-                i,
-            );
-        }
-        for i in 1..=3 {
-            setup.app_data.lock().update_stats_by_id(
-                &setup.ids[0],
-                Some(i as f64),
-                Some(i * 10000),
-                i * 10000,
-                i,
-                i,
-            );
+        setup
+            .terminal
+            .draw(|f| {
+                super::confirm_control(f, &confirm_control, "container_1", &Theme::default());
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let result_cell = &result[row_index * usize::from(w) + char_index];
+                assert_eq!(result_cell.symbol(), expected_char.to_string());
+                // Container name is red
+                if row_index == 3 && (45..=55).contains(&char_index) {
+                    assert_eq!(result_cell.fg, Color::Red);
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                }
+            }
         }
     }
-    #[test]
-    /// When status is Running, but not data, charts drawn without dots etc
-    fn test_draw_blocks_charts_running_none() {
-        let (w, h) = (80, 10);
+
+    #[test]
+    /// Recreate confirm popup warns, in the alert color, that only some options are kept
+    fn test_draw_blocks_confirm_control_recreate() {
+        let (w, h) = (100, 10);
         let mut setup = test_setup(w, h, true, true);
+        let confirm_control = ConfirmControl {
+            batch: false,
+            control: DockerControls::Recreate,
+            ids: vec![setup.ids[0].clone()],
+            signal: None,
+        };
 
         setup
             .terminal
             .draw(|f| {
-                super::chart(f, setup.area, &setup.app_data);
+                super::confirm_control(f, &confirm_control, "container_1", &Theme::default());
             })
             .unwrap();
 
-        let expected = [
-            "╭───────────── cpu 00.00% ─────────────╮╭─────────── memory 0.00 kB ───────────╮",
-            "│00.00%│                               ││0.00 kB│                              │",
-            "│      │                               ││       │                              │",
-            "│      │                               ││       │                              │",
-            "│      │                               ││       │                              │",
-            "│      │                               ││       │                              │",
-            "│      │                               ││       │                              │",
-            "│      │                               ││       │                              │",
-            "│      │                               ││       │                              │",
-            "╰──────────────────────────────────────╯╰──────────────────────────────────────╯",
-        ];
-
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in expected.iter().enumerate() {
-            for (char_index, expected_char) in row.chars().enumerate() {
-                let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
+        let row = |index: usize| {
+            result[index * usize::from(w)..(index + 1) * usize::from(w)]
+                .iter()
+                .map(ratatui::buffer::Cell::symbol)
+                .collect::<String>()
+        };
+        assert!(row(3).contains("Are you sure you want to recreate: container_1"));
+        assert!(row(4).contains(super::RECREATE_WARNING));
+        let warning_start = row(4)
+            .split("only")
+            .next()
+            .map(|i| i.chars().count())
+            .unwrap();
+        assert_eq!(
+            result[4 * usize::from(w) + warning_start].fg,
+            Color::LightRed
+        );
+        assert!(row(6).contains("(Y)es"));
+    }
 
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
+    #[test]
+    /// The keybinding cheatsheet groups the keys by category, with the keys right aligned, and is scrolled by its offset
+    fn test_draw_blocks_cheatsheet() {
+        let (w, h) = (40, 8);
+        let mut setup = test_setup(w, h, true, true);
+        // Just the cheatsheet & quit keys of the general category, and the log pause key of the logs category
+        let keep = [Action::Cheatsheet, Action::Quit, Action::LogPause];
+        let sections = Keymap::default()
+            .cheatsheet()
+            .into_iter()
+            .map(|(category, actions)| {
+                let actions = actions
+                    .into_iter()
+                    .filter(|i| keep.contains(&i.1))
+                    .collect::<Vec<_>>();
+                (category, actions)
+            })
+            .filter(|(_, actions)| !actions.is_empty())
+            .collect();
+        let mut cheatsheet = Cheatsheet {
+            sections,
+            offset: 0,
+        };
+        assert_eq!(cheatsheet.line_count(), 6);
 
-                match index {
-                    // chart tiles - cpu 03.00% && memory 30.00 kB - are green
-                    14..=25 | 52..=67 => {
-                        assert_eq!(result_cell.fg, Color::Green);
-                        assert_eq!(result_cell.modifier, Modifier::BOLD);
-                    }
-                    // Cpu & Memory max are orange and bold
-                    81..=86 | 121..=127 => {
-                        assert_eq!(result_cell.fg, ORANGE);
-                        assert_eq!(result_cell.modifier, Modifier::BOLD);
-                    }
-                    // All others
-                    _ => {
-                        assert_eq!(result_cell.fg, Color::Reset);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                }
-            }
-        }
+        let mut draw = |cheatsheet: &Cheatsheet| {
+            setup
+                .terminal
+                .draw(|f| {
+                    super::cheatsheet(f, cheatsheet, &Theme::default());
+                })
+                .unwrap();
+            setup
+                .terminal
+                .backend()
+                .buffer()
+                .content
+                .chunks(usize::from(w))
+                .map(|row| {
+                    row.iter()
+                        .map(ratatui::buffer::Cell::symbol)
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let expected = [
+            "                                        ",
+            "    ╭──────── Keybindings ─────────╮    ",
+            "    │ general                      │    ",
+            "    │      ?  cheatsheet           │    ",
+            "    │   q, Q  quit                 │    ",
+            "    │                              │    ",
+            "    ╰──── 1/6 ( esc ) to close ────╯    ",
+            "                                        ",
+        ];
+        assert_eq!(draw(&cheatsheet), expected);
+
+        // Taller than the screen, so scrolled to the second category
+        cheatsheet.offset = 3;
+        let expected = [
+            "                                        ",
+            "    ╭──────── Keybindings ─────────╮    ",
+            "    │                              │    ",
+            "    │ logs                         │    ",
+            "    │ ctrl+s  log_pause            │    ",
+            "    │                              │    ",
+            "    ╰──── 4/6 ( esc ) to close ────╯    ",
+            "                                        ",
+        ];
+        assert_eq!(draw(&cheatsheet), expected);
     }
 
     #[test]
-    /// When status is Running, charts correctly drawn
-    fn test_draw_blocks_charts_running_some() {
-        let (w, h) = (80, 10);
+    /// Pull panel shows each layer, then the result, and only offers to recreate once pulled
+    fn test_draw_blocks_pull() {
+        let (w, h) = (70, 10);
         let mut setup = test_setup(w, h, true, true);
+        let mut pull = Pull::new(setup.ids[0].clone(), String::from("image_1"));
+        pull.update(Some(String::from("aaa")), String::from("Pull complete"));
+        pull.update(Some(String::from("bbb")), String::from("Downloading"));
 
-        insert_chart_data(&setup);
+        let expected = [
+            "                                                                      ",
+            "                                                                      ",
+            "                        ╭─── Pull image_1 ───╮                        ",
+            "                        │aaa: Pull complete  │                        ",
+            "                        │bbb: Downloading    │                        ",
+            "                        │pulling ⠙           │                        ",
+            "                        │                    │                        ",
+            "                        ╰─ ( esc ) to close ─╯                        ",
+            "                                                                      ",
+            "                                                                      ",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::chart(f, setup.area, &setup.app_data);
+                super::pull(
+                    f,
+                    &pull,
+                    &ContainerName::from("container_1"),
+                    "⠙",
+                    &Theme::default(),
+                );
             })
             .unwrap();
-
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in EXPECTED.iter().enumerate() {
+        for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
-                let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
+                let result_cell = &result[row_index * usize::from(w) + char_index];
                 assert_eq!(result_cell.symbol(), expected_char.to_string());
-                match index {
-                    // chart tiles - cpu 03.00% && memory 30.00 kB - are green
-                    14..=25 | 51..=67 => {
-                        assert_eq!(result_cell.fg, Color::Green);
-                        assert_eq!(result_cell.modifier, Modifier::BOLD);
-                    }
-                    // Cpu & Memory max are orange and bold
-                    81..=86 | 121..=129 => {
-                        assert_eq!(result_cell.fg, ORANGE);
-                        assert_eq!(result_cell.modifier, Modifier::BOLD);
-                    }
-                    // cpu dots are magenta
-                    _x if CPU_INDEX.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Magenta);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                    // memory dots are cyan
-                    _x if MEMORY_INDEX.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Cyan);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                    // All others
-                    _ => {
-                        assert_eq!(result_cell.fg, Color::Reset);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                }
             }
         }
+
+        // Draw the panel, and return the whole screen as a single string
+        let mut draw = |pull: &Pull| {
+            setup
+                .terminal
+                .draw(|f| {
+                    super::pull(
+                        f,
+                        pull,
+                        &ContainerName::from("container_1"),
+                        "⠙",
+                        &Theme::default(),
+                    );
+                })
+                .unwrap();
+            setup
+                .terminal
+                .backend()
+                .buffer()
+                .content
+                .iter()
+                .map(ratatui::buffer::Cell::symbol)
+                .collect::<String>()
+        };
+
+        pull.state = PullState::Pulled;
+        let screen = draw(&pull);
+        assert!(screen.contains("✓ pulled, recreate container_1 to use the new image"));
+        assert!(screen.contains("( r ) recreate ( esc ) to close"));
+
+        pull.state = PullState::Failed(AppError::DockerPullAuth);
+        let screen = draw(&pull);
+        assert!(screen.contains("✖ Unable to pull image, access denied"));
+        assert!(!screen.contains("( r ) recreate"));
     }
 
     #[test]
-    /// Whens status paused, some text is now Yellow
-    fn test_draw_blocks_charts_paused() {
-        let (w, h) = (80, 10);
+    /// Quit confirmation shows the number of pending commands, and whilst waiting, the loading icon instead of the wait key
+    fn test_draw_blocks_quit_confirm() {
+        let (w, h) = (70, 10);
         let mut setup = test_setup(w, h, true, true);
 
-        insert_chart_data(&setup);
-        setup.app_data.lock().containers.items[0].state = State::Paused;
+        let expected = [
+            "                                                                      ",
+            "        ╭─────────────────────── Quit ───────────────────────╮        ",
+            "        │                                                    │        ",
+            "        │      2 actions still in progress, quit anyway?     │        ",
+            "        │      ( w ) wait for them to finish, then quit      │        ",
+            "        │    ╭─────────────────╮      ╭─────────────────╮    │        ",
+            "        │    │      (N)o       │      │      (Y)es      │    │        ",
+            "        │    ╰─────────────────╯      ╰─────────────────╯    │        ",
+            "        ╰────────────────────────────────────────────────────╯        ",
+            "                                                                      ",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::chart(f, setup.area, &setup.app_data);
+                super::quit_confirm(f, 2, false, "⠋", &Theme::default());
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in EXPECTED.iter().enumerate() {
+        for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
-                let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
+                let result_cell = &result[row_index * usize::from(w) + char_index];
                 assert_eq!(result_cell.symbol(), expected_char.to_string());
-                match index {
-                    // Titles and y axis are yellow
-                    14..=25 | 51..=67 | 81..=86 | 121..=129 => {
-                        assert_eq!(result_cell.fg, Color::Yellow);
-                        assert_eq!(result_cell.modifier, Modifier::BOLD);
-                    }
-                    _x if CPU_INDEX.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Magenta);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                    // memory dots are cyan
-                    _x if MEMORY_INDEX.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Cyan);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                    // All others
-                    _ => {
-                        assert_eq!(result_cell.fg, Color::Reset);
-                        assert!(result_cell.modifier.is_empty());
-                    }
+                // Number of pending actions is red
+                if row_index == 3 && (15..=23).contains(&char_index) {
+                    assert_eq!(result_cell.fg, Color::Red);
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
                 }
             }
         }
-    }
 
-    #[test]
-    /// When dead, text is read
-    fn test_draw_blocks_charts_dead() {
-        let (w, h) = (80, 10);
-        let mut setup = test_setup(w, h, true, true);
-        insert_chart_data(&setup);
-        setup.app_data.lock().containers.items[0].state = State::Dead;
+        let expected = [
+            "                                                                      ",
+            "             ╭────────────────── Quit ──────────────────╮             ",
+            "             │                                          │             ",
+            "             │     Waiting for 1 action to finish ⠋     │             ",
+            "             │                                          │             ",
+            "             │   ╭──────────────╮    ╭──────────────╮   │             ",
+            "             │   │     (N)o     │    │     (Y)es    │   │             ",
+            "             │   ╰──────────────╯    ╰──────────────╯   │             ",
+            "             ╰──────────────────────────────────────────╯             ",
+            "                                                                      ",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::chart(f, setup.area, &setup.app_data);
+                super::quit_confirm(f, 1, true, "⠋", &Theme::default());
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (row_index, row) in EXPECTED.iter().enumerate() {
+        for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
-                let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-
+                let result_cell = &result[row_index * usize::from(w) + char_index];
                 assert_eq!(result_cell.symbol(), expected_char.to_string());
-                match index {
-                    // Titles and y axis are red
-                    14..=25 | 51..=67 | 81..=86 | 121..=129 => {
-                        assert_eq!(result_cell.fg, Color::Red);
-                        assert_eq!(result_cell.modifier, Modifier::BOLD);
-                    }
-                    // cpu dots are magenta
-                    _x if CPU_INDEX.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Magenta);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                    // memory dots are cyan
-                    _x if MEMORY_INDEX.contains(&index) => {
-                        assert_eq!(result_cell.fg, Color::Cyan);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                    // All others
-                    _ => {
-                        assert_eq!(result_cell.fg, Color::Reset);
-                        assert!(result_cell.modifier.is_empty());
-                    }
-                }
             }
         }
     }
 
-    // ******* //
-    // Headers //
-    // ******* //
-
     #[test]
-    /// Heading back only has show/exit help when no containers, correctly coloured
-    fn test_draw_blocks_headers_no_containers() {
-        let (w, h) = (140, 1);
+    /// Inspect panel shows a loading message until the inspect output is cached, then the scrolled output
+    fn test_draw_blocks_inspect() {
+        let (w, h) = (50, 10);
         let mut setup = test_setup(w, h, true, true);
-        setup.app_data.lock().containers = StatefulList::new(vec![]);
-
-        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        let id = setup.ids[0].clone();
+        let name = ContainerName::from("container_1");
+        setup.gui_state.lock().set_inspect(Some(id.clone()));
 
-        let expected =  "                                                                                                                         ( h ) show help    ";
+        let expected = [
+            "                                                  ",
+            "                                                  ",
+            "    ╭───────── Inspect container_1 ──────────╮    ",
+            "    │              inspecting ⠋              │    ",
+            "    │                                        │    ",
+            "    │                                        │    ",
+            "    │                                        │    ",
+            "    ╰───────── 0/0 ( esc ) to close ─────────╯    ",
+            "                                                  ",
+            "                                                  ",
+        ];
 
+        let inspect = setup.gui_state.lock().get_inspect().unwrap();
         setup
             .terminal
             .draw(|f| {
-                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+                super::inspect(f, &setup.app_data, &inspect, &name, &Fetch::Loading('⠋'));
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (index, expected_char) in expected.chars().enumerate() {
-            let result_cell = &result[index];
-
-            assert_eq!(result_cell.symbol(), expected_char.to_string());
-            assert_eq!(result_cell.bg, Color::Magenta);
-            assert_eq!(result_cell.fg, Color::White);
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
         }
 
-        fd.help_visible = true;
-        let expected =  "                                                                                                                         ( h ) exit help    ";
+        let text = (1..=10)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        setup.app_data.lock().update_inspect_by_id(&id, text);
+        setup.gui_state.lock().inspect_scroll_down(2, 10);
+        let inspect = setup.gui_state.lock().get_inspect().unwrap();
+
+        let expected = [
+            "                                                  ",
+            "                                                  ",
+            "    ╭───────── Inspect container_1 ──────────╮    ",
+            "    │line 3                                  │    ",
+            "    │line 4                                  │    ",
+            "    │line 5                                  │    ",
+            "    │line 6                                  │    ",
+            "    ╰──────── 3/10 ( esc ) to close ─────────╯    ",
+            "                                                  ",
+            "                                                  ",
+        ];
         setup
             .terminal
             .draw(|f| {
-                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+                super::inspect(f, &setup.app_data, &inspect, &name, &Fetch::Loading('⠋'));
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
-        for (index, expected_char) in expected.chars().enumerate() {
-            let result_cell = &result[index];
-
-            assert_eq!(result_cell.symbol(), expected_char.to_string());
-            assert_eq!(result_cell.bg, Color::Magenta);
-            assert_eq!(result_cell.fg, Color::Black);
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
         }
     }
 
-    #[test]
-    /// Show all headings when containers present, colors valid
-    fn test_draw_blocks_headers_some_containers() {
-        let (w, h) = (140, 1);
-        let mut setup = test_setup(w, h, true, true);
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+    #[test]
+    /// Environment variables panel masks values until toggled, and scrolls
+    fn test_draw_blocks_env() {
+        let (w, h) = (50, 10);
+        let mut setup = test_setup(w, h, true, true);
+        let id = setup.ids[0].clone();
+        let name = ContainerName::from("container_1");
+        setup.gui_state.lock().set_env(Some(id.clone()));
+        setup.app_data.lock().update_env_by_id(
+            &id,
+            vec![
+                "PATH=/usr/bin".to_owned(),
+                "PASSWORD=hunter2".to_owned(),
+                "EMPTY=".to_owned(),
+            ],
+        );
+
+        let draw = |setup: &mut TuiTestSetup, expected: &[&str]| {
+            let env = setup.gui_state.lock().get_env().unwrap();
+            setup
+                .terminal
+                .draw(|f| {
+                    super::env(f, &setup.app_data, &env, &name, &Fetch::Loading('⠋'));
+                })
+                .unwrap();
+            let result = &setup.terminal.backend().buffer().content;
+            for (row_index, row) in expected.iter().enumerate() {
+                for (char_index, expected_char) in row.chars().enumerate() {
+                    let index = row_index * usize::from(w) + char_index;
+                    assert_eq!(result[index].symbol(), expected_char.to_string());
+                }
+            }
+        };
+
+        draw(
+            &mut setup,
+            &[
+                "                                                  ",
+                "                                                  ",
+                "    ╭─────── Environment container_1 ────────╮    ",
+                "    │PATH=********                           │    ",
+                "    │PASSWORD=*******                        │    ",
+                "    │EMPTY=                                  │    ",
+                "    │                                        │    ",
+                "    ╰ 1/3 ( m ) show values ( esc ) to close ╯    ",
+                "                                                  ",
+                "                                                  ",
+            ],
+        );
+
+        setup.gui_state.lock().env_toggle_mask();
+        setup.gui_state.lock().env_scroll_down(1, 3);
+        draw(
+            &mut setup,
+            &[
+                "                                                  ",
+                "                                                  ",
+                "    ╭─────── Environment container_1 ────────╮    ",
+                "    │PASSWORD=hunter2                        │    ",
+                "    │EMPTY=                                  │    ",
+                "    │                                        │    ",
+                "    │                                        │    ",
+                "    ╰ 2/3 ( m ) hide values ( esc ) to close ╯    ",
+                "                                                  ",
+                "                                                  ",
+            ],
+        );
+    }
+
+    #[test]
+    /// Labels popup shows a loading message until inspected, then an empty message, or each label sorted by key, with compose labels in bold
+    fn test_draw_blocks_labels() {
+        let (w, h) = (50, 10);
+        let mut setup = test_setup(w, h, true, true);
+        let id = setup.ids[0].clone();
+        let name = ContainerName::from("container_1");
+        setup.gui_state.lock().set_labels(Some(id.clone()));
+
+        let draw = |setup: &mut TuiTestSetup, expected: &[&str]| {
+            let labels = setup.gui_state.lock().get_labels().unwrap();
+            setup
+                .terminal
+                .draw(|f| {
+                    super::labels(f, &setup.app_data, &labels, &name, &Fetch::Loading('⠋'));
+                })
+                .unwrap();
+            let result = &setup.terminal.backend().buffer().content;
+            for (row_index, row) in expected.iter().enumerate() {
+                for (char_index, expected_char) in row.chars().enumerate() {
+                    let index = row_index * usize::from(w) + char_index;
+                    assert_eq!(result[index].symbol(), expected_char.to_string());
+                }
+            }
+        };
+
+        draw(
+            &mut setup,
+            &[
+                "                                                  ",
+                "                                                  ",
+                "    ╭────────── Labels container_1 ──────────╮    ",
+                "    │              inspecting ⠋              │    ",
+                "    │                                        │    ",
+                "    │                                        │    ",
+                "    │                                        │    ",
+                "    ╰───────── 0/0 ( esc ) to close ─────────╯    ",
+                "                                                  ",
+                "                                                  ",
+            ],
+        );
 
-        let expected =   "           name       state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ";
         setup
-            .terminal
-            .draw(|f| {
-                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
-            })
-            .unwrap();
-
+            .app_data
+            .lock()
+            .update_labels_by_id(&id, BTreeMap::new());
+        draw(
+            &mut setup,
+            &[
+                "                                                  ",
+                "                                                  ",
+                "    ╭────────── Labels container_1 ──────────╮    ",
+                "    │                no labels               │    ",
+                "    │                                        │    ",
+                "    │                                        │    ",
+                "    │                                        │    ",
+                "    ╰───────── 0/0 ( esc ) to close ─────────╯    ",
+                "                                                  ",
+                "                                                  ",
+            ],
+        );
+
+        setup.app_data.lock().update_labels_by_id(
+            &id,
+            BTreeMap::from([
+                ("maintainer".to_owned(), "oxker".to_owned()),
+                ("com.docker.compose.project".to_owned(), "web".to_owned()),
+                ("com.docker.compose.service".to_owned(), "db".to_owned()),
+            ]),
+        );
+        draw(
+            &mut setup,
+            &[
+                "                                                  ",
+                "                                                  ",
+                "    ╭────────── Labels container_1 ──────────╮    ",
+                "    │com.docker.compose.project=web          │    ",
+                "    │com.docker.compose.service=db           │    ",
+                "    │maintainer=oxker                        │    ",
+                "    │                                        │    ",
+                "    ╰───────── 1/3 ( esc ) to close ─────────╯    ",
+                "                                                  ",
+                "                                                  ",
+            ],
+        );
         let result = &setup.terminal.backend().buffer().content;
-        for (index, expected_char) in expected.chars().enumerate() {
-            let result_cell = &result[index];
-
-            assert_eq!(result_cell.symbol(), expected_char.to_string());
-            assert_eq!(result_cell.bg, Color::Magenta);
-            assert_eq!(
-                result_cell.fg,
-                match index {
-                    (2..=122) => Color::Black,
-                    _ => Color::White,
-                }
-            );
-        }
+        assert!(result[3 * usize::from(w) + 5]
+            .modifier
+            .contains(Modifier::BOLD));
+        assert!(!result[5 * usize::from(w) + 5]
+            .modifier
+            .contains(Modifier::BOLD));
     }
 
     #[test]
-    /// Only show the headings that fit the reduced-in-size header section
-    fn test_draw_blocks_headers_some_containers_reduced_width() {
-        let (w, h) = (80, 1);
+    /// Mounts popup shows a loading message until inspected, then an empty message, or a row for each mount, with the headings kept whilst scrolling
+    fn test_draw_blocks_mounts() {
+        let (w, h) = (76, 10);
         let mut setup = test_setup(w, h, true, true);
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
-
-        let expected =
-            "           name       state               status       cpu     ( h ) show help  ";
         setup
-            .terminal
-            .draw(|f| {
-                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
-            })
-            .unwrap();
+            .gui_state
+            .lock()
+            .set_mounts(Some(setup.ids[0].clone()));
 
-        let result = &setup.terminal.backend().buffer().content;
-        for (index, expected_char) in expected.chars().enumerate() {
-            let result_cell = &result[index];
+        let draw = |setup: &mut TuiTestSetup| {
+            let mounts = setup.gui_state.lock().get_mounts().unwrap();
+            setup
+                .terminal
+                .draw(|f| {
+                    super::mounts(
+                        f,
+                        &setup.app_data,
+                        &mounts,
+                        &ContainerName::from("container_1"),
+                        &Fetch::Loading('⠋'),
+                    );
+                })
+                .unwrap();
+            let result = &setup.terminal.backend().buffer().content;
+            result
+                .chunks(usize::from(w))
+                .map(|row| row.iter().map(Cell::symbol).collect::<String>())
+                .collect::<Vec<_>>()
+        };
 
-            assert_eq!(result_cell.symbol(), expected_char.to_string());
-            assert_eq!(result_cell.bg, Color::Magenta);
-            assert_eq!(
-                result_cell.fg,
-                match index {
-                    (2..=62) => Color::Black,
-                    _ => Color::White,
-                }
-            );
-        }
+        let result = draw(&mut setup);
+        assert!(result[3].contains("inspecting ⠋"));
+
+        setup.app_data.lock().containers.items[0].mounts = Some(vec![]);
+        let result = draw(&mut setup);
+        assert!(result[3].contains("no mounts"));
+
+        setup.app_data.lock().containers.items[0].mounts = Some(gen_mounts());
+        let result = draw(&mut setup);
+        let expected = [
+            "    ╭─────────────────────── Mounts container_1 ───────────────────────╮    ",
+            "    │ type     source                  destination             mode    │    ",
+            "    │ bind     /home/user/nginx.conf   /etc/nginx/nginx.conf   ro      │    ",
+            "    │ volume   data                    /data                   rw      │    ",
+        ];
+        assert_eq!(&result[2..6], expected);
+
+        setup.gui_state.lock().mounts_scroll_down(1, 2);
+        let result = draw(&mut setup);
+        assert_eq!(result[3], expected[1]);
+        assert_eq!(result[4], expected[3]);
+        assert!(result[7].contains(" 2/2 ( esc ) to close "));
     }
 
     #[test]
-    /// Test all combination of headers & sort by
-    fn test_draw_blocks_headers_sort_containers() {
-        let (w, h) = (140, 1);
+    /// Docker run command panel shows a loading message until the command has been built, and scrolls
+    fn test_draw_blocks_run_command() {
+        let (w, h) = (50, 10);
         let mut setup = test_setup(w, h, true, true);
-        let mut fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
-        let mut test = |expected: &str, range: RangeInclusive<usize>, x: (Header, SortedOrder)| {
-            fd.sorted_by = Some(x);
+        let id = setup.ids[0].clone();
+        let name = ContainerName::from("container_1");
+        setup.gui_state.lock().set_run_command(Some(id.clone()));
 
+        let draw = |setup: &mut TuiTestSetup, expected: &[&str]| {
+            let run_command = setup.gui_state.lock().get_run_command().unwrap();
             setup
                 .terminal
                 .draw(|f| {
-                    super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+                    super::run_command(
+                        f,
+                        &setup.app_data,
+                        &run_command,
+                        &name,
+                        &Fetch::Loading('⠋'),
+                    );
                 })
                 .unwrap();
-
             let result = &setup.terminal.backend().buffer().content;
-            for (index, expected_char) in expected.chars().enumerate() {
-                let result_cell = &result[index];
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
-                assert_eq!(result_cell.bg, Color::Magenta);
-                assert_eq!(
-                    result_cell.fg,
-                    match index {
-                        0 | 1 => Color::White,
-                        // given range | help section
-                        x if range.contains(&x) || (123..=139).contains(&x) => Color::White,
-                        _ => Color::Black,
-                    }
-                );
+            for (row_index, row) in expected.iter().enumerate() {
+                for (char_index, expected_char) in row.chars().enumerate() {
+                    let index = row_index * usize::from(w) + char_index;
+                    assert_eq!(result[index].symbol(), expected_char.to_string());
+                }
             }
         };
 
-        // Name
-        test("         ▲ name       state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 1..=14, (Header::Name, SortedOrder::Asc));
-        test("         ▼ name       state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 1..=14, (Header::Name, SortedOrder::Desc));
+        draw(
+            &mut setup,
+            &[
+                "                                                  ",
+                "                                                  ",
+                "    ╭─────────── Run container_1 ────────────╮    ",
+                "    │              inspecting ⠋              │    ",
+                "    │                                        │    ",
+                "    │                                        │    ",
+                "    │                                        │    ",
+                "    ╰─── 0/0 ( c ) copy ( esc ) to close ────╯    ",
+                "                                                  ",
+                "                                                  ",
+            ],
+        );
+
+        setup.app_data.lock().update_run_command_by_id(
+            &id,
+            "docker run -d \\\n  --name container_1 \\\n  image_1".to_owned(),
+        );
+        draw(
+            &mut setup,
+            &[
+                "                                                  ",
+                "                                                  ",
+                "    ╭─────────── Run container_1 ────────────╮    ",
+                "    │docker run -d \\                         │    ",
+                "    │  --name container_1 \\                  │    ",
+                "    │  image_1                               │    ",
+                "    │                                        │    ",
+                "    ╰─── 1/3 ( c ) copy ( esc ) to close ────╯    ",
+                "                                                  ",
+                "                                                  ",
+            ],
+        );
+
+        setup.gui_state.lock().run_command_scroll_down(1, 3);
+        draw(
+            &mut setup,
+            &[
+                "                                                  ",
+                "                                                  ",
+                "    ╭─────────── Run container_1 ────────────╮    ",
+                "    │  --name container_1 \\                  │    ",
+                "    │  image_1                               │    ",
+                "    │                                        │    ",
+                "    │                                        │    ",
+                "    ╰─── 2/3 ( c ) copy ( esc ) to close ────╯    ",
+                "                                                  ",
+                "                                                  ",
+            ],
+        );
+    }
 
-        // state
-        test("           name     ▲ state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 15..=26, (Header::State, SortedOrder::Asc));
-        test("           name     ▼ state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 15..=26, (Header::State, SortedOrder::Desc));
+    #[test]
+    /// Images list has its own column headings, and the selected image is highlighted
+    fn test_draw_blocks_images() {
+        let (w, h) = (80, 6);
+        let mut setup = test_setup(w, h, true, true);
+        let summary = |id: &str, tag: &str, created, size| ImageSummary {
+            id: id.to_owned(),
+            repo_tags: vec![tag.to_owned()],
+            created,
+            size,
+            ..Default::default()
+        };
+        let containers = HashMap::from([("sha256:abcdef0123".to_owned(), 2)]);
+        setup.app_data.lock().update_images(vec![
+            ImageItem::new(
+                &summary(
+                    "sha256:abcdef0123",
+                    "redis:latest",
+                    1_735_689_600,
+                    2_000_000,
+                ),
+                &containers,
+            ),
+            ImageItem::new(
+                &summary("sha256:0123456789", "<none>:<none>", 0, 3000),
+                &containers,
+            ),
+        ]);
 
-        // status
-        test("           name       state             ▲ status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 27..=47, (Header::Status, SortedOrder::Asc));
-        test("           name       state             ▼ status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 27..=47, (Header::Status, SortedOrder::Desc));
+        let expected = [
+            "╭ Images 1/2 ──────────────────────────────────────────────────────────────────╮",
+            "│   repo:tag              id      size               created   containers      │",
+            "│⚪  redis:latest    abcdef01   2.00 MB   2025-01-01 00:00:00            2      │",
+            "│   <none>:<none>   01234567   3.00 kB   1970-01-01 00:00:00            0      │",
+            "│                                                                              │",
+            "╰──────────────────────────────────────────────────────────────────────────────╯",
+        ];
 
-        // cpu
-        test("           name       state               status     ▲ cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 48..=57, (Header::Cpu, SortedOrder::Asc));
-        test("           name       state               status     ▼ cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 48..=57, (Header::Cpu, SortedOrder::Desc));
+        setup
+            .terminal
+            .draw(|f| {
+                super::images(f, setup.area, &setup.app_data, &setup.fd);
+            })
+            .unwrap();
 
-        // mem
-        test("           name       state               status       cpu      ▲ memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 58..=77, (Header::Memory, SortedOrder::Asc));
-        test("           name       state               status       cpu      ▼ memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ", 58..=77, (Header::Memory, SortedOrder::Desc));
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
+    }
 
-        // id
-        test("           name       state               status       cpu        memory/limit       ▲ id     image      ↓ rx      ↑ tx    ( h ) show help  ", 78..=88, (Header::Id, SortedOrder::Asc));
-        test("           name       state               status       cpu        memory/limit       ▼ id     image      ↓ rx      ↑ tx    ( h ) show help  ", 78..=88, (Header::Id, SortedOrder::Desc));
+    #[test]
+    /// Networks list has its own column headings, in the same layout as the images list
+    fn test_draw_blocks_networks() {
+        let (w, h) = (70, 6);
+        let mut setup = test_setup(w, h, true, true);
+        let network = |id: &str, name: &str, driver: &str| Network {
+            id: Some(id.to_owned()),
+            name: Some(name.to_owned()),
+            driver: Some(driver.to_owned()),
+            scope: Some(String::from("local")),
+            ..Default::default()
+        };
+        let containers = HashMap::from([("abcdef0123".to_owned(), 3)]);
+        setup.app_data.lock().update_networks(vec![
+            NetworkItem::new(&network("abcdef0123", "bridge", "bridge"), &containers),
+            NetworkItem::new(&network("0123456789", "network_1", "overlay"), &containers),
+        ]);
 
-        // image
-        test("           name       state               status       cpu        memory/limit         id   ▲ image      ↓ rx      ↑ tx    ( h ) show help  ", 89..=98, (Header::Image, SortedOrder::Asc));
-        test("           name       state               status       cpu        memory/limit         id   ▼ image      ↓ rx      ↑ tx    ( h ) show help  ", 89..=98, (Header::Image, SortedOrder::Desc));
+        let expected = [
+            "╭ Networks 1/2 ──────────────────────────────────────────────────────╮",
+            "│   name              id    driver   scope   containers              │",
+            "│⚪  bridge      abcdef01    bridge   local            3              │",
+            "│   network_1   01234567   overlay   local            0              │",
+            "│                                                                    │",
+            "╰────────────────────────────────────────────────────────────────────╯",
+        ];
 
-        // rx
-        test("           name       state               status       cpu        memory/limit         id     image    ▲ ↓ rx      ↑ tx    ( h ) show help  ", 99..=108, (Header::Rx, SortedOrder::Asc));
-        test("           name       state               status       cpu        memory/limit         id     image    ▼ ↓ rx      ↑ tx    ( h ) show help  ", 99..=108, (Header::Rx, SortedOrder::Desc));
+        setup
+            .terminal
+            .draw(|f| {
+                super::networks(f, setup.area, &setup.app_data, &setup.fd);
+            })
+            .unwrap();
 
-        // tx
-        test("           name       state               status       cpu        memory/limit         id     image      ↓ rx    ▲ ↑ tx    ( h ) show help  ", 109..=118, (Header::Tx, SortedOrder::Asc));
-        test("           name       state               status       cpu        memory/limit         id     image      ↓ rx    ▼ ↑ tx    ( h ) show help  ", 109..=118, (Header::Tx, SortedOrder::Desc));
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
     }
 
     #[test]
-    /// Show animation
-    fn test_draw_blocks_headers_animation() {
-        let (w, h) = (140, 1);
+    /// Volumes list shows a dash when the size isn't known
+    fn test_draw_blocks_volumes() {
+        let (w, h) = (70, 6);
         let mut setup = test_setup(w, h, true, true);
-        let uuid = Uuid::new_v4();
-        setup.gui_state.lock().next_loading(uuid);
-        let fd = FrameData::from((setup.app_data.lock(), setup.gui_state.lock()));
+        let volume = |name: &str, size: Option<i64>| Volume {
+            name: name.to_owned(),
+            driver: String::from("local"),
+            mountpoint: format!("/volumes/{name}"),
+            usage_data: size.map(|size| VolumeUsageData { size, ref_count: 1 }),
+            ..Default::default()
+        };
+        setup.app_data.lock().update_volumes(vec![
+            VolumeItem::new(&volume("volume_2", None)),
+            VolumeItem::new(&volume("volume_1", Some(2_000_000))),
+        ]);
+
+        let expected = [
+            "╭ Volumes 1/2 ───────────────────────────────────────────────────────╮",
+            "│   name       driver      size          mountpoint                  │",
+            "│⚪  volume_1    local   2.00 MB   /volumes/volume_1                  │",
+            "│   volume_2    local         -   /volumes/volume_2                  │",
+            "│                                                                    │",
+            "╰────────────────────────────────────────────────────────────────────╯",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::heading_bar(setup.area, f, &fd, &setup.gui_state);
+                super::volumes(f, setup.area, &setup.app_data, &setup.fd);
             })
             .unwrap();
 
-        let expected =   " ⠙         name       state               status       cpu        memory/limit         id     image      ↓ rx      ↑ tx    ( h ) show help  ";
-
         let result = &setup.terminal.backend().buffer().content;
-        for (index, expected_char) in expected.chars().enumerate() {
-            let result_cell = &result[index];
-
-            assert_eq!(result_cell.symbol(), expected_char.to_string());
-            assert_eq!(result_cell.bg, Color::Magenta);
-            assert_eq!(
-                result_cell.fg,
-                match index {
-                    (2..=122) => Color::Black,
-                    _ => Color::White,
-                }
-            );
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
         }
     }
 
-    // ********** //
-    // Help popup //
-    // ********** //
     #[test]
-    /// This will cause issues once the version has more than the current 5 chars (0.5.0)
-    // Help  popup is drawn correctly
-    fn test_draw_blocks_help() {
-        let (w, h) = (87, 32);
+    /// Prune popup shows the number of stopped containers, and which targets are selected
+    fn test_draw_blocks_prune() {
+        let (w, h) = (54, 9);
         let mut setup = test_setup(w, h, true, true);
+        let mut prune = Prune::new(2);
+        prune.images = true;
+        prune.containers = false;
+
+        let expected = [
+            "                                                      ",
+            "  ╭──────────────────── Prune ─────────────────────╮  ",
+            "  │[ ] ( c ) remove 2 stopped containers           │  ",
+            "  │[x] ( i ) remove all dangling images            │  ",
+            "  │[ ] ( v ) remove all unused anonymous volumes   │  ",
+            "  │                                                │  ",
+            "  │      ( enter ) to prune, ( esc ) to cancel     │  ",
+            "  ╰────────────────────────────────────────────────╯  ",
+            "                                                      ",
+        ];
 
         setup
             .terminal
             .draw(|f| {
-                super::help_box(f);
+                super::prune(f, prune, &Theme::default());
             })
             .unwrap();
-        let expected = [
-            "                                                                                       ".to_owned(),
-            format!(" ╭ {VERSION} ────────────────────────────────────────────────────────────────────────────╮ "),
-            " │                                                                                   │ ".to_owned(),
-            " │                                      88                                           │ ".to_owned(),
-            " │                                      88                                           │ ".to_owned(),
-            " │                                      88                                           │ ".to_owned(),
-            " │             ,adPPYba,   8b,     ,d8  88   ,d8    ,adPPYba,  8b,dPPYba,            │ ".to_owned(),
-            r#" │            a8"     "8a   `Y8, ,8P'   88 ,a8"    a8P_____88  88P'   "Y8            │ "#.to_owned(),
-            r#" │            8b       d8     )888(     8888[      8PP"""""""  88                    │ "#.to_owned(),
-            r#" │            "8a,   ,a8"   ,d8" "8b,   88`"Yba,   "8b,   ,aa  88                    │ "#.to_owned(),
-            r#" │             `"YbbdP"'   8P'     `Y8  88   `Y8a   `"Ybbd8"'  88                    │ "#.to_owned(),
-            " │                                                                                   │ ".to_owned(),
-            " │                 A simple tui to view & control docker containers                  │ ".to_owned(),
-            " │                                                                                   │ ".to_owned(),
-            " │ ( tab ) or ( shift+tab ) change panels                                            │ ".to_owned(),
-            " │ ( ↑ ↓ ) or ( j k ) or ( PgUp PgDown ) or ( Home End ) change selected line        │ ".to_owned(),
-            " │ ( enter ) send docker container command                                           │ ".to_owned(),
-            " │ ( e ) exec into a container                                                       │ ".to_owned(),
-            " │ ( h ) toggle this help information                                                │ ".to_owned(),
-            " │ ( s ) save logs to file                                                           │ ".to_owned(),
-            " │ ( m ) toggle mouse capture - if disabled, text on screen can be selected & copied │ ".to_owned(),
-            " │ ( 0 ) stop sort                                                                   │ ".to_owned(),
-            " │ ( 1 - 9 ) sort by header - or click header                                        │ ".to_owned(),
-            " │ ( esc ) close dialog                                                              │ ".to_owned(),
-            " │ ( q ) quit at any time                                                            │ ".to_owned(),
-            " │                                                                                   │ ".to_owned(),
-            " │        currently an early work in progress, all and any input appreciated         │ ".to_owned(),
-            " │                       https://github.com/mrjackwills/oxker                        │ ".to_owned(),
-            " │                                                                                   │ ".to_owned(),
-            " │                                                                                   │ ".to_owned(),
-            " ╰───────────────────────────────────────────────────────────────────────────────────╯ ".to_owned(),
-        ];
 
+        let result = &setup.terminal.backend().buffer().content;
         for (row_index, row) in expected.iter().enumerate() {
-            let mut bracket_key = vec![];
-            let mut push_bracket_key = false;
-
-            let result = &setup.terminal.backend().buffer().content;
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
-                let result_str = result_cell.symbol();
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
+    }
 
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
+    #[test]
+    /// Restart policy popup lists every policy, with the selected policy highlighted
+    fn test_draw_blocks_restart_policy() {
+        let (w, h) = (68, 10);
+        let mut setup = test_setup(w, h, true, true);
+        let mut restart_policy = RestartPolicy::new(ContainerId::from("1"), Some("on-failure:3"));
 
-                // First and last row, and first char and last char in each row, is empty
-                if row_index == 0
-                    || row_index == usize::from(h - 1)
-                    || char_index == 0
-                    || char_index == usize::from(w - 1)
-                {
-                    assert_eq!(result_cell.fg, Color::Reset);
-                    assert_eq!(result_cell.bg, Color::Reset);
-                // Borders
-                } else if BORDER_CHARS.contains(&result_str) {
-                    assert_eq!(result_cell.fg, Color::Black);
-                    assert_eq!(result_cell.bg, Color::Magenta);
-                // everything else has a magenta background
-                } else {
-                    assert_eq!(result_cell.bg, Color::Magenta);
-                }
+        let expected = [
+            "                                                                    ",
+            "  ╭───────────────── Restart policy container_1 ─────────────────╮  ",
+            "  │  no                                                          │  ",
+            "  │▶ on-failure                                                  │  ",
+            "  │  always                                                      │  ",
+            "  │  unless-stopped                                              │  ",
+            "  │                                                              │  ",
+            "  │ ( ↑ ↓ ) select a policy, ( enter ) to set, ( esc ) to cancel │  ",
+            "  ╰──────────────────────────────────────────────────────────────╯  ",
+            "                                                                    ",
+        ];
 
-                // check that ( [key] ) is white
-                if result_str == "(" {
-                    push_bracket_key = true;
-                    bracket_key.push(result_cell);
-                }
-                if push_bracket_key {
-                    bracket_key.push(result_cell);
-                    if result_str == ")" {
-                        push_bracket_key = false;
-                        for i in &bracket_key {
-                            assert_eq!(i.fg, Color::White);
-                        }
-                        bracket_key.clear();
-                    }
-                }
-                // TODO should really be testing every color of every str here
+        setup
+            .terminal
+            .draw(|f| {
+                super::restart_policy(
+                    f,
+                    &mut restart_policy,
+                    &ContainerName::from("container_1"),
+                    &Theme::default(),
+                );
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
             }
         }
+        assert!(result[3 * usize::from(w) + 5]
+            .modifier
+            .contains(Modifier::BOLD));
+        assert!(!result[2 * usize::from(w) + 5]
+            .modifier
+            .contains(Modifier::BOLD));
     }
 
-    // ************ //
-    // Delete popup //
-    // ************ //
-
     #[test]
-    /// Delete container popup is drawn correctly
-    fn test_draw_blocks_delete() {
-        let (w, h) = (82, 10);
+    /// Batch panel shows the step of each container, and a summary once every container has finished
+    fn test_draw_blocks_batch() {
+        let (w, h) = (50, 10);
         let mut setup = test_setup(w, h, true, true);
+        let mut batch = Batch::new(
+            DockerControls::Restart,
+            vec![
+                (ContainerId::from("1"), String::from("container_1")),
+                (ContainerId::from("2"), String::from("web")),
+            ],
+        );
+        batch.containers[0].2 = BatchStep::Failed("stop");
+        batch.containers[1].2 = BatchStep::Starting;
+
+        let mut draw = |batch: &Batch| {
+            setup
+                .terminal
+                .draw(|f| {
+                    super::batch(f, batch, "⠙", &Theme::default());
+                })
+                .unwrap();
+            let result = &setup.terminal.backend().buffer().content;
+            let color = result[4 * usize::from(w) + 23].fg;
+            let rows = result
+                .chunks(usize::from(w))
+                .map(|row| row.iter().map(ratatui::buffer::Cell::symbol).collect())
+                .collect::<Vec<String>>();
+            (rows, color)
+        };
 
-        let expected = [
-            "                                                                                  ",
-            "        ╭──────────────────────── Confirm Delete ────────────────────────╮        ",
-            "        │                                                                │        ",
-            "        │     Are you sure you want to delete container: container_1     │        ",
-            "        │                                                                │        ",
-            "        │      ╭─────────────────────╮      ╭─────────────────────╮      │        ",
-            "        │      │        (N)o         │      │        (Y)es        │      │        ",
-            "        │      ╰─────────────────────╯      ╰─────────────────────╯      │        ",
-            "        ╰────────────────────────────────────────────────────────────────╯        ",
-            "                                                                                  ",
-        ];
+        let (rows, color) = draw(&batch);
+        assert_eq!(
+            rows[3..7],
+            [
+                "        ╭──────── Batch restart ─────────╮        ",
+                "        │container_1   ✖ failed to stop  │        ",
+                "        │web           starting ⠙        │        ",
+                "        ╰─────── ( esc ) to close ───────╯        ",
+            ]
+        );
+        assert_eq!(color, Theme::default().alert);
+
+        batch.containers[1].2 = BatchStep::Done;
+        let (rows, _) = draw(&batch);
+        assert_eq!(
+            rows[2..8],
+            [
+                "    ╭──────────── Batch restart ─────────────╮    ",
+                "    │container_1   ✖ failed to stop          │    ",
+                "    │web           ✓ done                    │    ",
+                "    │                                        │    ",
+                "    │restarted 1 of 2 containers, 1 failed   │    ",
+                "    ╰─────────── ( esc ) to close ───────────╯    ",
+            ]
+        );
+    }
+
+    #[test]
+    /// Limits popup shows both inputs, the cursor is only on the selected input, with the error, if any, below
+    fn test_draw_blocks_limits() {
+        let (w, h) = (87, 6);
+        let mut setup = test_setup(w, h, true, true);
+        let mut limits = Limits::new(ContainerId::from("1"));
+        limits.cpus = String::from("1.5");
+        limits.memory = String::from("512x");
+        limits.field = super::LimitsField::Memory;
+        limits.error = Some(String::from("invalid memory, e.g. 512m or 2g"));
 
         setup
             .terminal
             .draw(|f| {
-                super::delete_confirm(f, &setup.gui_state, &ContainerName::from("container_1"));
+                super::limits(
+                    f,
+                    &limits,
+                    &ContainerName::from("container_1"),
+                    &Theme::default(),
+                );
             })
             .unwrap();
 
         let result = &setup.terminal.backend().buffer().content;
+        let expected = [
+            "  ╭────────────────────────────── Limits container_1 ───────────────────────────────╮  ",
+            "  │  cpus: 1.5                                                                      │  ",
+            "  │memory: 512x▏                                                                    │  ",
+            "  │invalid memory, e.g. 512m or 2g                                                  │  ",
+            "  │ ( tab ) next input, ( enter ) to update, empty is unchanged, ( esc ) to cancel  │  ",
+            "  ╰─────────────────────────────────────────────────────────────────────────────────╯  ",
+        ];
         for (row_index, row) in expected.iter().enumerate() {
             for (char_index, expected_char) in row.chars().enumerate() {
                 let index = row_index * usize::from(w) + char_index;
-                let result_cell = &result[index];
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
+        assert!(result[2 * usize::from(w) + 11]
+            .modifier
+            .contains(Modifier::BOLD));
+        assert!(!result[usize::from(w) + 11]
+            .modifier
+            .contains(Modifier::BOLD));
+        assert_eq!(
+            result[3 * usize::from(w) + 3].fg,
+            Theme::default().popup_highlight
+        );
+    }
 
-                assert_eq!(result_cell.symbol(), expected_char.to_string());
+    #[test]
+    /// Rename popup shows the current name in the title, and the new name in the highlight color until it's valid
+    fn test_draw_blocks_rename() {
+        let (w, h) = (47, 6);
+        let mut setup = test_setup(w, h, true, true);
+        let name = ContainerName::from("container_1");
 
-                if row_index == 0
-                    || row_index == usize::from(h - 1)
-                    || char_index < 8
-                    || char_index > usize::from(w - 9)
-                {
-                    assert_eq!(result_cell.fg, Color::Reset);
-                    assert_eq!(result_cell.bg, Color::Reset);
-                } else {
-                    assert_eq!(result_cell.bg, Color::White);
-                }
+        for (new_name, fg) in [("_new_name", Color::Red), ("new_name", Color::Black)] {
+            let rename = Rename {
+                id: ContainerId::from("1"),
+                name: new_name.to_owned(),
+            };
+            let expected = [
+                "                                               ".to_owned(),
+                "  ╭────────── Rename container_1 ───────────╮  ".to_owned(),
+                format!("  │name: {:<35}│  ", format!("{new_name}▏")),
+                "  │ ( enter ) to rename, ( esc ) to cancel  │  ".to_owned(),
+                "  ╰─────────────────────────────────────────╯  ".to_owned(),
+                "                                               ".to_owned(),
+            ];
 
-                // Borders are black
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::Black);
-                    // Container name is red
-                } else if row_index == 3 && (57..=67).contains(&char_index) {
-                    assert_eq!(result_cell.fg, Color::Red);
-                    // All other text is black
-                } else if !row_index == 0
-                    && !row_index == usize::from(h - 1)
-                    && !char_index < 8
-                    && !char_index > usize::from(w - 9)
-                {
-                    assert_eq!(result_cell.fg, Color::Black);
+            setup
+                .terminal
+                .draw(|f| {
+                    super::rename(f, &rename, &name, &Theme::default());
+                })
+                .unwrap();
+
+            let result = &setup.terminal.backend().buffer().content;
+            for (row_index, row) in expected.iter().enumerate() {
+                for (char_index, expected_char) in row.chars().enumerate() {
+                    let index = row_index * usize::from(w) + char_index;
+                    let result_cell = &result[index];
+                    assert_eq!(result_cell.symbol(), expected_char.to_string());
+                    if row_index == 2 && (9..9 + new_name.len()).contains(&char_index) {
+                        assert_eq!(result_cell.fg, fg);
+                    }
                 }
             }
         }
     }
 
     #[test]
-    /// Delete container popup is drawn correctly
-    fn test_draw_blocks_delete_long_name() {
-        let (w, h) = (106, 10);
+    /// Log since popup shows the input, and, once an invalid since has been submitted, the expected formats in the highlight color
+    fn test_draw_blocks_log_since() {
+        let (w, h) = (70, 7);
         let mut setup = test_setup(w, h, true, true);
-        let name = ContainerName::from("container_1_container_1_container_1");
-        setup.app_data.lock().containers.items[0].name = name.clone();
-
+        let log_since = LogSince {
+            input: "10x".to_owned(),
+            error: Some("invalid, e.g. 10m, 1h30m, or 2024-03-01T12:00:00Z".to_owned()),
+        };
         let expected = [
-            "                                                                                                          ",
-            "        ╭──────────────────────────────────── Confirm Delete ────────────────────────────────────╮        ",
-            "        │                                                                                        │        ",
-            "        │     Are you sure you want to delete container: container_1_container_1_container_1     │        ",
-            "        │                                                                                        │        ",
-            "        │        ╭──────────────────────────────╮         ╭─────────────────────────────╮        │        ",
-            "        │        │             (N)o             │         │            (Y)es            │        │        ",
-            "        │        ╰──────────────────────────────╯         ╰─────────────────────────────╯        │        ",
-            "        ╰────────────────────────────────────────────────────────────────────────────────────────╯        ",
-            "                                                                                                          ",
+            "                                                                      ",
+            "  ╭────────────────────────── Logs Since ──────────────────────────╮  ",
+            "  │since: 10x▏                                                     │  ",
+            "  │invalid, e.g. 10m, 1h30m, or 2024-03-01T12:00:00Z               │  ",
+            "  │  ( enter ) to refetch, leave empty to clear, ( esc ) to cancel │  ",
+            "  ╰────────────────────────────────────────────────────────────────╯  ",
+            "                                                                      ",
         ];
 
         setup
             .terminal
             .draw(|f| {
-                super::delete_confirm(f, &setup.gui_state, &name);
+                super::log_since(f, &log_since, &Theme::default());
             })
             .unwrap();
 
@@ -2654,40 +7317,13 @@ mod tests {
                 let index = row_index * usize::from(w) + char_index;
                 let result_cell = &result[index];
                 assert_eq!(result_cell.symbol(), expected_char.to_string());
-
-                if row_index == 0
-                    || row_index == usize::from(h - 1)
-                    || char_index < 8
-                    || char_index > usize::from(w - 9)
-                {
-                    assert_eq!(result_cell.fg, Color::Reset);
-                    assert_eq!(result_cell.bg, Color::Reset);
-                } else {
-                    assert_eq!(result_cell.bg, Color::White);
-                }
-
-                // Borders are black
-                if BORDER_CHARS.contains(&result_cell.symbol()) {
-                    assert_eq!(result_cell.fg, Color::Black);
-                // Container name is red
-                } else if row_index == 3 && (57..=82).contains(&char_index) {
+                if row_index == 3 && (3..52).contains(&char_index) {
                     assert_eq!(result_cell.fg, Color::Red);
-                // All other text is black
-                } else if !row_index == 0
-                    && !row_index == usize::from(h - 1)
-                    && !char_index < 8
-                    && !char_index > usize::from(w - 9)
-                {
-                    assert_eq!(result_cell.fg, Color::Black);
                 }
             }
         }
     }
 
-    // ***** //
-    // popup //
-    // ***** //
-
     #[test]
     /// Info box drawn in bottom right
     fn test_draw_blocks_info() {
@@ -2709,7 +7345,13 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::info(f, "test", std::time::Instant::now(), &setup.gui_state);
+                super::info(
+                    f,
+                    "test",
+                    std::time::Instant::now(),
+                    &setup.gui_state,
+                    &Theme::default(),
+                );
             })
             .unwrap();
 
@@ -2746,7 +7388,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::error(f, AppError::DockerConnect, Some(4));
+                super::error(f, &AppError::DockerConnect, Some(4), &Theme::default());
             })
             .unwrap();
 
@@ -2788,6 +7430,41 @@ mod tests {
         }
     }
 
+    #[test]
+    /// Test that the docker connect error, without a countdown, shows that a reconnect is in progress
+    fn test_draw_blocks_reconnect_error() {
+        let (w, h) = (46, 10);
+        let mut setup = test_setup(w, h, true, true);
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::error(f, &AppError::DockerConnect, None, &Theme::default());
+            })
+            .unwrap();
+
+        let expected = [
+            "                                              ",
+            "    ╭────────────── Error ───────────────╮    ",
+            "    │                                    │    ",
+            "    │   Unable to access docker daemon   │    ",
+            "    │                                    │    ",
+            "    │           reconnecting...          │    ",
+            "    │          ( q ) quit oxker          │    ",
+            "    │                                    │    ",
+            "    ╰────────────────────────────────────╯    ",
+            "                                              ",
+        ];
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
+    }
+
     #[test]
     /// Test that the clearable error popup is centered, red background, white border, white text, and displays the correct text
     fn test_draw_blocks_clearable_error() {
@@ -2797,7 +7474,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::error(f, AppError::DockerExec, Some(4));
+                super::error(f, &AppError::DockerExec, Some(4), &Theme::default());
             })
             .unwrap();
 
@@ -2851,7 +7528,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::ports(f, setup.area, &setup.app_data, max_lens);
+                super::ports(f, setup.area, &setup.app_data, max_lens, &Theme::default());
             })
             .unwrap();
 
@@ -2888,7 +7565,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::ports(f, setup.area, &setup.app_data, max_lens);
+                super::ports(f, setup.area, &setup.app_data, max_lens, &Theme::default());
             })
             .unwrap();
 
@@ -2931,6 +7608,7 @@ mod tests {
                 ip: None,
                 private: 8002,
                 public: None,
+                protocol: None,
             });
         setup.app_data.lock().containers.items[0]
             .ports
@@ -2938,6 +7616,7 @@ mod tests {
                 ip: Some("127.0.0.1".to_owned()),
                 private: 8003,
                 public: Some(8003),
+                protocol: None,
             });
 
         let max_lens = setup.app_data.lock().get_longest_port();
@@ -2945,15 +7624,15 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::ports(f, setup.area, &setup.app_data, max_lens);
+                super::ports(f, setup.area, &setup.app_data, max_lens, &Theme::default());
             })
             .unwrap();
 
         let expected = [
             "╭─────────── ports ────────────╮",
             "│       ip   private   public  │",
-            "│               8001           │",
-            "│               8002           │",
+            "│               8001       --  │",
+            "│               8002       --  │",
             "│127.0.0.1      8003     8003  │",
             "│                              │",
             "│                              │",
@@ -2990,6 +7669,52 @@ mod tests {
         }
     }
 
+    #[test]
+    /// The protocol is shown after the private port, and an unpublished port has no public port
+    fn test_draw_blocks_ports_protocols() {
+        let (w, h) = (32, 6);
+        let mut setup = test_setup(w, h, true, true);
+        setup.app_data.lock().containers.items[0].ports = vec![
+            ContainerPorts {
+                ip: Some("0.0.0.0".to_owned()),
+                private: 53,
+                public: Some(5353),
+                protocol: Some("udp".to_owned()),
+            },
+            ContainerPorts {
+                ip: None,
+                private: 80,
+                public: None,
+                protocol: Some("tcp".to_owned()),
+            },
+        ];
+
+        let max_lens = setup.app_data.lock().get_longest_port();
+        setup
+            .terminal
+            .draw(|f| {
+                super::ports(f, setup.area, &setup.app_data, max_lens, &Theme::default());
+            })
+            .unwrap();
+
+        let expected = [
+            "╭─────────── ports ────────────╮",
+            "│     ip   private   public    │",
+            "│0.0.0.0    53/udp     5353    │",
+            "│           80/tcp       --    │",
+            "│                              │",
+            "╰──────────────────────────────╯",
+        ];
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(expected_char.to_string(), result[index].symbol());
+            }
+        }
+    }
+
     #[test]
     /// Port section title color correct dependant on state
     fn test_draw_blocks_ports_container_state() {
@@ -3001,14 +7726,14 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::ports(f, setup.area, &setup.app_data, max_lens);
+                super::ports(f, setup.area, &setup.app_data, max_lens, &Theme::default());
             })
             .unwrap();
 
         let expected = [
             "╭─────────── ports ────────────╮",
             "│   ip   private   public      │",
-            "│           8001               │",
+            "│           8001       --      │",
             "│                              │",
             "│                              │",
             "│                              │",
@@ -3041,7 +7766,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::ports(f, setup.area, &setup.app_data, max_lens);
+                super::ports(f, setup.area, &setup.app_data, max_lens, &Theme::default());
             })
             .unwrap();
 
@@ -3049,7 +7774,7 @@ mod tests {
         let expected = [
             "╭─────────── ports ────────────╮",
             "│   ip   private   public      │",
-            "│           8001               │",
+            "│           8001       --      │",
             "│                              │",
             "│                              │",
             "│                              │",
@@ -3096,10 +7821,11 @@ mod tests {
                 ip: Some("127.0.0.1".to_owned()),
                 private: 8003,
                 public: Some(8003),
+                protocol: None,
             });
 
         let expected = [
-            "           name       state               status       cpu          memory/limit         id     image      ↓ rx      ↑ tx                      ( h ) show help  ",
+            "           name         state             status       cpu          memory/limit         id     image      ↓ rx      ↑ tx                      ( h ) show help  ",
         "╭ Containers 1/3 ──────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮╭──────────────╮",
         "│⚪  container_1   ✓ running            Up 1 hour    03.00%   30.00 kB / 30.00 kB          1   image_1   0.00 kB   0.00 kB                      ││▶ pause       │",
         "│   container_2   ✓ running            Up 2 hour    00.00%    0.00 kB /  0.00 kB          2   image_2   0.00 kB   0.00 kB                      ││  restart     │",
@@ -3122,12 +7848,12 @@ mod tests {
         "│                                                                                                                                                              │",
         "│                                                                                                                                                              │",
         "│                                                                                                                                                              │",
-        "╰──────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        "╰─────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────── following ╯",
         "╭───────────────────────── cpu 03.00% ──────────────────────────╮╭─────────────────────── memory 30.00 kB ───────────────────────╮╭────────── ports ───────────╮",
         "│10.00%│     ••••                                               ││100.00 kB│     •••                                             ││       ip   private   public│",
-        "│      │  •••   •                                               ││         │  •••  •                                             ││               8001         │",
+        "│      │  •••   •                                               ││         │  •••  •                                             ││               8001       --│",
         "│      │••       •••                                            ││         │••      •••                                          ││127.0.0.1      8003     8003│",
-        "│      │                                                        ││         │                                                     ││                            │",
+        "│      │                                                        ││100.00% of 30.00 kB ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━││                            │",
         "╰───────────────────────────────────────────────────────────────╯╰───────────────────────────────────────────────────────────────╯╰────────────────────────────╯",
         ];
         setup
@@ -3148,6 +7874,51 @@ mod tests {
         }
     }
 
+    #[test]
+    /// In full screen mode only the heading bar and logs panel are drawn, and the logs panel is selected
+    fn test_draw_blocks_whole_layout_logs_fullscreen() {
+        let (w, h) = (100, 8);
+        let mut setup = test_setup(w, h, true, true);
+
+        insert_logs(&setup);
+        setup.gui_state.lock().toggle_logs_fullscreen();
+        assert_eq!(
+            setup.gui_state.lock().get_selected_panel(),
+            SelectablePanel::Logs
+        );
+
+        let expected = [
+        "                                                                                 ( h ) show help    ",
+        "╭ Logs 3/3 - container_1 ──────────────────────────────────────────────────────────────────────────╮",
+        "│  line 1                                                                                          │",
+        "│  line 2                                                                                          │",
+        "│▶ line 3                                                                                          │",
+        "│                                                                                                  │",
+        "│                                                                                                  │",
+        "╰─────────────────────────────────────────────────────────────────────────────────────── following ╯",
+        ];
+        setup
+            .terminal
+            .draw(|f| {
+                draw_frame(f, &setup.app_data, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                let result_cell = &result[index];
+
+                assert_eq!(result_cell.symbol(), expected_char.to_string(),);
+            }
+        }
+
+        // Toggling again restores the normal layout
+        setup.gui_state.lock().toggle_logs_fullscreen();
+        assert!(!setup.gui_state.lock().get_logs_fullscreen());
+    }
+
     #[test]
     /// Check that the whole layout is drawn correctly when have long container name and long image name
     fn test_draw_blocks_whole_layout_long_name() {
@@ -3162,6 +7933,7 @@ mod tests {
                 ip: Some("127.0.0.1".to_owned()),
                 private: 8003,
                 public: Some(8003),
+                protocol: None,
             });
 
         setup.app_data.lock().containers.items[0].name =
@@ -3170,9 +7942,9 @@ mod tests {
             ContainerImage::from("a_long_image_name_for_the_purposes_of_this_test");
 
         let expected = [
-        "                              name       state               status       cpu          memory/limit         id                            image      ↓ rx      ↑ tx          ( h ) show help  ",
+        "                              name         state             status       cpu          memory/limit         id                            image      ↓ rx      ↑ tx          ( h ) show help  ",
         "╭ Containers 1/3 ─────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╮╭─────────────────╮",
-        "│⚪  a_long_container_name_for_the…   ✓ running            Up 1 hour    03.00%   30.00 kB / 30.00 kB          1   a_long_image_name_for_the_pur…   0.00 kB   0.00 kB       ││▶ pause          │",
+        "│⚪  a_long_container_name_for_the…   ✓ running            Up 1 hour    03.00%   30.00 kB / 30.00 kB          1   …for_the_purposes_of_this_test   0.00 kB   0.00 kB       ││▶ pause          │",
         "│                      container_2   ✓ running            Up 2 hour    00.00%    0.00 kB /  0.00 kB          2                          image_2   0.00 kB   0.00 kB       ││  restart        │",
         "│                      container_3   ✓ running            Up 3 hour    00.00%    0.00 kB /  0.00 kB          3                          image_3   0.00 kB   0.00 kB       ││  stop           │",
         "│                                                                                                                                                                         ││  delete         │",
@@ -3193,12 +7965,12 @@ mod tests {
         "│                                                                                                                                                                                            │",
         "│                                                                                                                                                                                            │",
         "│                                                                                                                                                                                            │",
-        "╰────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────╯",
+        "╰───────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────── following ╯",
         "╭───────────────────────────────── cpu 03.00% ─────────────────────────────────╮╭────────────────────────────── memory 30.00 kB ───────────────────────────────╮╭────────── ports ───────────╮",
         "│10.00%│       ••••                                                            ││100.00 kB│      •••••                                                         ││       ip   private   public│",
-        "│      │   ••••   •                                                            ││         │   •••    •                                                         ││               8001         │",
+        "│      │   ••••   •                                                            ││         │   •••    •                                                         ││               8001       --│",
         "│      │•••        ••••                                                        ││         │•••        •••                                                      ││127.0.0.1      8003     8003│",
-        "│      │                                                                       ││         │                                                                    ││                            │",
+        "│      │                                                                       ││100.00% of 30.00 kB ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━││                            │",
         "╰──────────────────────────────────────────────────────────────────────────────╯╰──────────────────────────────────────────────────────────────────────────────╯╰────────────────────────────╯",
         ];
         setup
@@ -3218,4 +7990,122 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    /// On a narrow, short, terminal the columns that don't fit are dropped, the commands panel keeps its minimum width, and the charts are hidden, so the logs use their space
+    fn test_draw_blocks_whole_layout_narrow() {
+        let (w, h) = (40, 14);
+        let mut setup = test_setup(w, h, true, true);
+        insert_chart_data(&setup);
+        insert_logs(&setup);
+
+        let expected = [
+            "           name        ( h ) show help  ",
+            "╭ Containers 1/3 ──────────╮╭──────────╮",
+            "│⚪  container_1            ││▶ pause   │",
+            "│   container_2            ││  restart │",
+            "│   container_3            ││  stop    │",
+            "│                          ││  delete  │",
+            "╰──────────────────────────╯╰──────────╯",
+            "╭ Logs 3/3 - container_1 ──────────────╮",
+            "│  line 1                              │",
+            "│  line 2                              │",
+            "│▶ line 3                              │",
+            "│                                      │",
+            "│                                      │",
+            "╰─────────────────────────── following ╯",
+        ];
+        setup
+            .terminal
+            .draw(|f| {
+                draw_frame(f, &setup.app_data, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
+    }
+
+    #[test]
+    /// On a narrow terminal the detail panel is stacked beneath the logs, rather than beside them
+    fn test_draw_blocks_whole_layout_narrow_detail() {
+        let (w, h) = (60, 24);
+        let mut setup = test_setup(w, h, true, true);
+        insert_chart_data(&setup);
+        insert_logs(&setup);
+        setup.gui_state.lock().toggle_detail();
+
+        let expected = [
+            "           name         state              ( h ) show help  ",
+            "╭ Containers 1/3 ──────────────────────────────╮╭──────────╮",
+            "│⚪  container_1   ✓ running                    ││▶ pause   │",
+            "│   container_2   ✓ running                    ││  restart │",
+            "│   container_3   ✓ running                    ││  stop    │",
+            "│                                              ││  delete  │",
+            "│                                              ││          │",
+            "│                                              ││          │",
+            "╰──────────────────────────────────────────────╯╰──────────╯",
+            "╭ Logs 3/3 - container_1 ──────────────────────────────────╮",
+            "│  line 1                                                  │",
+            "│  line 2                                                  │",
+            "│▶ line 3                                                  │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "╰─────────────────────────────────────────────── following ╯",
+            "╭───────────────────────── detail ─────────────────────────╮",
+            "│      image image_1                                       │",
+            "│         id 1                                             │",
+            "│    command --                                            │",
+            "│ entrypoint --                                            │",
+            "│        cmd --                                            │",
+            "╰──────────────────────────────────────────────────────────╯",
+        ];
+        setup
+            .terminal
+            .draw(|f| {
+                draw_frame(f, &setup.app_data, &setup.gui_state);
+            })
+            .unwrap();
+
+        let result = &setup.terminal.backend().buffer().content;
+        for (row_index, row) in expected.iter().enumerate() {
+            for (char_index, expected_char) in row.chars().enumerate() {
+                let index = row_index * usize::from(w) + char_index;
+                assert_eq!(result[index].symbol(), expected_char.to_string());
+            }
+        }
+    }
+
+    #[test]
+    /// Every panel, and popup, can be drawn on a tiny terminal without panicking, as in a small tmux pane
+    fn test_draw_blocks_whole_layout_small_sizes() {
+        for w in (1..=100).step_by(9) {
+            for h in (1..=30).step_by(4) {
+                let mut setup = test_setup(w, h, true, true);
+                insert_chart_data(&setup);
+                insert_logs(&setup);
+                setup.gui_state.lock().toggle_detail();
+                setup.gui_state.lock().toggle_history();
+                setup.app_data.lock().cycle_summary();
+                setup.gui_state.lock().status_push(Status::Help);
+                setup
+                    .gui_state
+                    .lock()
+                    .set_delete_container(Some(ContainerId::from("1")));
+                setup.gui_state.lock().set_info_box("info");
+                setup
+                    .terminal
+                    .draw(|f| {
+                        draw_frame(f, &setup.app_data, &setup.gui_state);
+                    })
+                    .unwrap();
+            }
+        }
+    }
 }
@@ -1,7 +1,9 @@
 use parking_lot::Mutex;
 use ratatui::layout::{Constraint, Rect};
+use regex::Regex;
 use std::{
     collections::{HashMap, HashSet},
+    fmt,
     sync::Arc,
     time::Instant,
 };
@@ -9,10 +11,91 @@ use tokio::task::JoinHandle;
 use uuid::Uuid;
 
 use crate::{
-    app_data::{ContainerId, Header},
+    app_data::{ContainerId, DockerControls, Header, ImageId, NetworkId, StatefulList, VolumeName},
+    app_error::AppError,
+    config::{CheatsheetSections, ViewConfig},
     exec::ExecMode,
 };
 
+/// The main view, either the containers, with their logs, charts, & ports, or the images, networks, or volumes list
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum View {
+    #[default]
+    Containers,
+    Images,
+    Networks,
+    Volumes,
+}
+
+/// Shown in a popup whilst its data is being fetched from Docker, e.g. the inspect output, and replaced by the data once it arrives.
+/// If the fetch failed the error is shown instead, rather than leaving the popup loading forever
+#[derive(Debug, Clone)]
+pub enum Fetch {
+    Loading(char),
+    Failed(AppError),
+}
+
+impl fmt::Display for Fetch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Loading(icon) => write!(f, "inspecting {icon}"),
+            Self::Failed(error) => write!(f, "✖ {error}"),
+        }
+    }
+}
+
+/// How the id column of the containers panel is shown, cycled through hidden, short, and full
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum IdMode {
+    Hidden,
+    #[default]
+    Short,
+    Full,
+}
+
+impl IdMode {
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Hidden => Self::Short,
+            Self::Short => Self::Full,
+            Self::Full => Self::Hidden,
+        }
+    }
+
+    /// The id as shown in the id column, a short id is the first 12 chars, the same as `docker ps`
+    pub fn format(self, id: &ContainerId) -> String {
+        match self {
+            Self::Full => id.get().to_owned(),
+            Self::Hidden | Self::Short => id.get().chars().take(12).collect(),
+        }
+    }
+
+    /// Remove the id column whilst hidden, or add it after the name column if it isn't one of the configured columns
+    pub fn apply(self, mut columns: Vec<Header>) -> Vec<Header> {
+        if self == Self::Hidden {
+            columns.retain(|i| i != &Header::Id);
+        } else if !columns.contains(&Header::Id) {
+            let index = columns
+                .iter()
+                .position(|i| i == &Header::Name)
+                .map_or(0, |i| i + 1);
+            columns.insert(index, Header::Id);
+        }
+        columns
+    }
+}
+
+impl fmt::Display for IdMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Hidden => "hidden",
+            Self::Short => "short",
+            Self::Full => "full",
+        };
+        write!(f, "{disp}")
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum SelectablePanel {
     #[default]
@@ -151,6 +234,547 @@ impl BoxLocation {
     }
 }
 
+/// Common signals that are offered in the kill popup
+const COMMON_SIGNALS: [&str; 6] = [
+    "SIGTERM", "SIGKILL", "SIGHUP", "SIGINT", "SIGUSR1", "SIGUSR2",
+];
+
+/// All the signal names, without the SIG prefix, that the Docker daemon will accept
+const KNOWN_SIGNALS: [&str; 32] = [
+    "ABRT", "ALRM", "BUS", "CHLD", "CONT", "FPE", "HUP", "ILL", "INT", "IO", "IOT", "KILL", "PIPE",
+    "PROF", "PWR", "QUIT", "SEGV", "STKFLT", "STOP", "SYS", "TERM", "TRAP", "TSTP", "TTIN", "TTOU",
+    "URG", "USR1", "USR2", "VTALRM", "WINCH", "XCPU", "XFSZ",
+];
+
+/// Validate, and normalize, a user supplied signal, e.g. "hup" becomes "SIGHUP", numeric signals are passed as is
+pub fn validate_signal(input: &str) -> Option<String> {
+    let input = input.trim().to_uppercase();
+    if input.is_empty() {
+        return None;
+    }
+    if let Ok(number) = input.parse::<u8>() {
+        return (1..=64).contains(&number).then(|| number.to_string());
+    }
+    let name = input.strip_prefix("SIG").unwrap_or(&input);
+    if KNOWN_SIGNALS.contains(&name) {
+        Some(format!("SIG{name}"))
+    } else {
+        None
+    }
+}
+
+/// State of the kill popup, a signal can either be selected from a list of common signals, or typed in manually
+#[derive(Debug, Clone)]
+pub struct KillSignal {
+    pub id: ContainerId,
+    pub signals: StatefulList<&'static str>,
+    pub input: String,
+}
+
+impl KillSignal {
+    pub fn new(id: ContainerId) -> Self {
+        let mut signals = StatefulList::new(COMMON_SIGNALS.to_vec());
+        signals.start();
+        Self {
+            id,
+            signals,
+            input: String::new(),
+        }
+    }
+
+    /// The signal to send, a typed signal takes priority over the selected list item
+    /// Returns None if the typed signal isn't valid
+    pub fn get_signal(&self) -> Option<String> {
+        if self.input.is_empty() {
+            self.signals
+                .state
+                .selected()
+                .and_then(|i| self.signals.items.get(i))
+                .map(|i| (*i).to_owned())
+        } else {
+            validate_signal(&self.input)
+        }
+    }
+}
+
+/// The restart policies that can be set on a container, in the order they are listed in the restart policy popup
+const RESTART_POLICIES: [&str; 4] = ["no", "on-failure", "always", "unless-stopped"];
+
+/// State of the restart policy popup, the current policy of the container is selected when opened
+#[derive(Debug, Clone)]
+pub struct RestartPolicy {
+    pub id: ContainerId,
+    pub policies: StatefulList<&'static str>,
+}
+
+impl RestartPolicy {
+    /// An `on-failure` policy with a maximum retry count, e.g. `on-failure:3`, selects `on-failure`, an unknown policy selects the first
+    pub fn new(id: ContainerId, current: Option<&str>) -> Self {
+        let mut policies = StatefulList::new(RESTART_POLICIES.to_vec());
+        let index = current
+            .and_then(|current| {
+                let current = current.split_once(':').map_or(current, |i| i.0);
+                RESTART_POLICIES.iter().position(|i| *i == current)
+            })
+            .unwrap_or_default();
+        policies.state.select(Some(index));
+        Self { id, policies }
+    }
+
+    /// The selected restart policy
+    pub fn get_policy(&self) -> Option<&'static str> {
+        self.policies
+            .state
+            .selected()
+            .and_then(|i| self.policies.items.get(i))
+            .copied()
+    }
+}
+
+/// Validate a container name against the rules the Docker daemon enforces, `[a-zA-Z0-9][a-zA-Z0-9_.-]+`
+pub fn validate_container_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphanumeric())
+        && name.len() > 1
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+/// State of the log since popup, prefilled with the current since, the error is set when an invalid since is submitted
+#[derive(Debug, Clone, Default)]
+pub struct LogSince {
+    pub input: String,
+    pub error: Option<String>,
+}
+
+/// The two inputs of the limits popup
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LimitsField {
+    #[default]
+    Cpus,
+    Memory,
+}
+
+/// State of the limits popup, an empty input leaves that limit unchanged, the error is set when an invalid limit is submitted
+#[derive(Debug, Clone)]
+pub struct Limits {
+    pub id: ContainerId,
+    pub cpus: String,
+    pub memory: String,
+    pub field: LimitsField,
+    pub error: Option<String>,
+}
+
+impl Limits {
+    pub const fn new(id: ContainerId) -> Self {
+        Self {
+            id,
+            cpus: String::new(),
+            memory: String::new(),
+            field: LimitsField::Cpus,
+            error: None,
+        }
+    }
+
+    /// The new limits, as nano cpus and bytes, `None` for an empty input.
+    /// Returns the error to show in the popup if an input is invalid, or if both are empty
+    pub fn parse(&self) -> Result<(Option<i64>, Option<i64>), &'static str> {
+        let cpus = if self.cpus.trim().is_empty() {
+            None
+        } else {
+            Some(parse_cpus(&self.cpus).ok_or("invalid cpus, e.g. 0.5 or 2")?)
+        };
+        let memory = if self.memory.trim().is_empty() {
+            None
+        } else {
+            Some(parse_memory(&self.memory).ok_or("invalid memory, e.g. 512m or 2g")?)
+        };
+        if cpus.is_none() && memory.is_none() {
+            return Err("set a cpus and/or memory limit");
+        }
+        Ok((cpus, memory))
+    }
+}
+
+/// Parse a number of cpus, as `docker update --cpus` accepts, into nano cpus, the daemon doesn't accept less than 0.01
+#[allow(clippy::cast_possible_truncation)]
+pub fn parse_cpus(input: &str) -> Option<i64> {
+    input
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|i| i.is_finite() && *i >= 0.01 && *i <= 1024.0)
+        .map(|i| (i * 1_000_000_000.0).round() as i64)
+}
+
+/// Parse a memory limit, as `docker update --memory` accepts, into bytes, the unit is one of b, k, m, or g, and defaults to bytes
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+pub fn parse_memory(input: &str) -> Option<i64> {
+    let input = input.trim().to_lowercase();
+    let (number, unit) = input.split_at(
+        input
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(input.len()),
+    );
+    let multiplier = match unit {
+        "" | "b" => 1.0,
+        "k" | "kb" => 1024.0,
+        "m" | "mb" => 1024.0 * 1024.0,
+        "g" | "gb" => 1024.0 * 1024.0 * 1024.0,
+        _ => return None,
+    };
+    number
+        .parse::<f64>()
+        .ok()
+        .map(|i| (i * multiplier).floor())
+        .filter(|i| i.is_finite() && *i >= 1.0 && *i < i64::MAX as f64)
+        .map(|i| i as i64)
+}
+
+/// State of the rename popup, prefilled with the current name of the container
+#[derive(Debug, Clone)]
+pub struct Rename {
+    pub id: ContainerId,
+    pub name: String,
+}
+
+/// A stop, restart, kill, or delete, held in the confirmation popup until confirmed, only used when "--confirm-destructive" is set.
+/// Marked containers, and collapsed groups, are sent as a batch, even if only a single container
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfirmControl {
+    pub batch: bool,
+    pub control: DockerControls,
+    pub ids: Vec<ContainerId>,
+    pub signal: Option<String>,
+}
+
+/// What can be removed by the prune popup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneTarget {
+    Containers,
+    Images,
+    Volumes,
+}
+
+/// State of the prune popup, stopped containers, dangling images, & unused volumes are selected separately.
+/// Only containers are selected when opened, or only volumes when opened from the volumes view
+#[derive(Debug, Clone, Copy)]
+pub struct Prune {
+    pub containers: bool,
+    pub images: bool,
+    pub volumes: bool,
+    pub stopped: usize,
+}
+
+impl Prune {
+    pub const fn new(stopped: usize) -> Self {
+        Self {
+            containers: true,
+            images: false,
+            volumes: false,
+            stopped,
+        }
+    }
+
+    /// Open the prune popup from the volumes view, with only volumes selected
+    pub const fn volumes(stopped: usize) -> Self {
+        Self {
+            containers: false,
+            images: false,
+            volumes: true,
+            stopped,
+        }
+    }
+
+    /// Check if there is anything selected to prune
+    pub const fn is_selected(self) -> bool {
+        self.containers || self.images || self.volumes
+    }
+}
+
+/// State of the save logs popup, the path can be edited before the logs are written
+#[derive(Debug, Clone)]
+pub struct SaveLogs {
+    pub id: ContainerId,
+    pub path: String,
+}
+
+/// State of the inspect panel, the inspect output itself is cached in the ContainerItem
+#[derive(Debug, Clone)]
+pub struct Inspect {
+    pub id: ContainerId,
+    pub offset: u16,
+}
+
+/// State of the docker run command panel, the command itself is cached in the ContainerItem
+#[derive(Debug, Clone)]
+pub struct RunCommand {
+    pub id: ContainerId,
+    pub offset: u16,
+}
+
+/// The step a container of a batch command is at, a failed container keeps the step that failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchStep {
+    Waiting,
+    Stopping,
+    Starting,
+    Removing,
+    Done,
+    Failed(&'static str),
+}
+
+impl BatchStep {
+    /// Done or failed, so the container won't change again
+    pub const fn is_finished(self) -> bool {
+        matches!(self, Self::Done | Self::Failed(_))
+    }
+}
+
+impl fmt::Display for BatchStep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Waiting => write!(f, "waiting"),
+            Self::Stopping => write!(f, "stopping"),
+            Self::Starting => write!(f, "starting"),
+            Self::Removing => write!(f, "removing"),
+            Self::Done => write!(f, "✓ done"),
+            Self::Failed(step) => write!(f, "✖ failed to {step}"),
+        }
+    }
+}
+
+/// State of the batch panel, the progress of a start, stop, restart, or delete, sent to several containers in turn.
+/// The names are kept, as a deleted container is removed from the containers list
+#[derive(Debug, Clone)]
+pub struct Batch {
+    pub control: DockerControls,
+    pub containers: Vec<(ContainerId, String, BatchStep)>,
+}
+
+impl Batch {
+    pub fn new(control: DockerControls, containers: Vec<(ContainerId, String)>) -> Self {
+        Self {
+            control,
+            containers: containers
+                .into_iter()
+                .map(|(id, name)| (id, name, BatchStep::Waiting))
+                .collect(),
+        }
+    }
+
+    /// Every container is either done, or has failed
+    pub fn is_finished(&self) -> bool {
+        self.containers.iter().all(|i| i.2.is_finished())
+    }
+
+    /// The number of containers that failed
+    pub fn failed(&self) -> usize {
+        self.containers
+            .iter()
+            .filter(|i| matches!(i.2, BatchStep::Failed(_)))
+            .count()
+    }
+
+    /// A summary of the batch, once finished, e.g. "restarted 2 of 3 containers, 1 failed"
+    pub fn summary(&self) -> String {
+        let total = self.containers.len();
+        let failed = self.failed();
+        let past_tense = self.control.past_tense();
+        if failed == 0 {
+            format!("{past_tense} {total} containers")
+        } else {
+            format!(
+                "{past_tense} {} of {total} containers, {failed} failed",
+                total - failed
+            )
+        }
+    }
+}
+
+/// How far an image pull has got, a failed pull keeps the error, to be shown in the pull panel
+#[derive(Debug, Clone)]
+pub enum PullState {
+    Pulling,
+    Pulled,
+    Failed(AppError),
+}
+
+/// State of the image pull panel, the status of each layer is kept in the order Docker first reports it,
+/// along with the latest status that isn't for a single layer, e.g. "Digest: sha256:..."
+#[derive(Debug, Clone)]
+pub struct Pull {
+    pub id: ContainerId,
+    pub image: String,
+    pub layers: Vec<(String, String)>,
+    pub status: Option<String>,
+    pub state: PullState,
+}
+
+impl Pull {
+    pub const fn new(id: ContainerId, image: String) -> Self {
+        Self {
+            id,
+            image,
+            layers: vec![],
+            status: None,
+            state: PullState::Pulling,
+        }
+    }
+
+    /// Update the status of a layer, or the overall status if there's no layer id
+    pub fn update(&mut self, layer: Option<String>, status: String) {
+        match layer {
+            Some(layer) => {
+                if let Some(item) = self.layers.iter_mut().find(|(id, _)| id == &layer) {
+                    item.1 = status;
+                } else {
+                    self.layers.push((layer, status));
+                }
+            }
+            None => self.status = Some(status),
+        }
+    }
+
+    /// Every line to show in the pull panel, one per layer, then the overall status
+    pub fn lines(&self) -> Vec<String> {
+        self.layers
+            .iter()
+            .map(|(id, status)| format!("{id}: {status}"))
+            .chain(self.status.clone())
+            .collect()
+    }
+}
+
+/// State of the keybinding cheatsheet, the keys are read from the keymap when opened, so reflect any keys set in the config file
+#[derive(Debug, Clone)]
+pub struct Cheatsheet {
+    pub sections: CheatsheetSections,
+    pub offset: u16,
+}
+
+impl Cheatsheet {
+    /// Total number of lines, each category has a heading, and is separated from the previous category by an empty line
+    pub fn line_count(&self) -> usize {
+        self.sections
+            .iter()
+            .map(|(_, actions)| actions.len() + 2)
+            .sum::<usize>()
+            .saturating_sub(1)
+    }
+}
+
+/// State of the network inspect panel, the inspect output itself is cached in AppData
+#[derive(Debug, Clone)]
+pub struct NetworkInspect {
+    pub id: NetworkId,
+    pub offset: u16,
+}
+
+/// State of the mounts panel, the mounts themselves are read from the inspect data in the ContainerItem
+#[derive(Debug, Clone)]
+pub struct Mounts {
+    pub id: ContainerId,
+    pub offset: u16,
+}
+
+/// State of the labels panel, the labels themselves are cached in the ContainerItem
+#[derive(Debug, Clone)]
+pub struct Labels {
+    pub id: ContainerId,
+    pub offset: u16,
+}
+
+/// State of the environment variables panel, the variables themselves are cached in the ContainerItem.
+/// Values are masked by default, as they often contain secrets
+#[derive(Debug, Clone)]
+pub struct EnvVars {
+    pub id: ContainerId,
+    pub offset: u16,
+    pub masked: bool,
+}
+
+impl EnvVars {
+    /// Format a `KEY=VALUE` variable, replacing each char of the value with an asterisk if masked
+    pub fn format(&self, var: &str) -> String {
+        match var.split_once('=') {
+            Some((key, value)) if self.masked => {
+                format!("{key}={}", "*".repeat(value.chars().count()))
+            }
+            _ => var.to_owned(),
+        }
+    }
+}
+
+/// State of the log copy mode, the cursor is the selected log line of the container.
+/// The anchor is the position, see `Logs::position()`, where a range selection started, if any
+#[derive(Debug, Clone)]
+pub struct LogCopy {
+    pub id: ContainerId,
+    pub anchor: Option<usize>,
+}
+
+/// The log search, by default a plain substring search, but can be toggled to use a regex
+/// The regex is compiled on each change to the query, rather than on every frame
+#[derive(Debug, Clone, Default)]
+pub struct LogSearch {
+    query: String,
+    use_regex: bool,
+    regex: Option<Result<Regex, String>>,
+}
+
+impl LogSearch {
+    pub fn get_query(&self) -> &str {
+        &self.query
+    }
+
+    pub const fn is_regex(&self) -> bool {
+        self.use_regex
+    }
+
+    /// The error message if the query is an invalid regex
+    pub fn get_error(&self) -> Option<&str> {
+        self.regex
+            .as_ref()
+            .and_then(|i| i.as_ref().err().map(String::as_str))
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.query.push(c);
+        self.compile();
+    }
+
+    pub fn pop(&mut self) {
+        self.query.pop();
+        self.compile();
+    }
+
+    pub fn toggle_regex(&mut self) {
+        self.use_regex = !self.use_regex;
+        self.compile();
+    }
+
+    /// Only compile the regex if it's actually going to be used
+    fn compile(&mut self) {
+        self.regex = if self.use_regex && !self.query.is_empty() {
+            Some(Regex::new(&self.query).map_err(|_| String::from("invalid regex")))
+        } else {
+            None
+        };
+    }
+
+    /// Check if a line of text matches the query, an empty query, or invalid regex, never matches
+    pub fn is_match(&self, text: &str) -> bool {
+        if self.query.is_empty() {
+            return false;
+        }
+        match self.regex.as_ref() {
+            Some(Ok(regex)) => regex.is_match(text),
+            Some(Err(_)) => false,
+            None => text.contains(&self.query),
+        }
+    }
+}
+
 // loading animation frames
 const FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
 const FRAMES_LEN: u8 = 9;
@@ -159,35 +783,230 @@ const FRAMES_LEN: u8 = 9;
 /// Various functions (e.g input handler), operate differently depending upon current Status
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub enum Status {
+    Alert,
+    Batch,
+    Cheatsheet,
+    ConfirmControl,
     DeleteConfirm,
     DockerConnect,
+    Env,
     Error,
     Exec,
+    FilterContainers,
+    GoTo,
     Help,
     Init,
-    Logs,
+    Inspect,
+    KillSignal,
+    Labels,
+    Limits,
+    LogCopy,
+    LogSince,
+    Mounts,
+    NetworkInspect,
+    Prune,
+    Pull,
+    QuitConfirm,
+    Rename,
+    RestartPolicy,
+    RunCommand,
+    SaveLogs,
+    SearchContainers,
+    SearchLogs,
 }
 
 /// Global gui_state, stored in an Arc<Mutex>
 #[derive(Debug, Default, Clone)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct GuiState {
+    batch: Option<Batch>,
+    cheatsheet: Option<Cheatsheet>,
+    confirm_control: Option<ConfirmControl>,
     delete_container: Option<ContainerId>,
+    delete_image: Option<ImageId>,
     delete_map: HashMap<DeleteButton, Rect>,
+    delete_network: Option<NetworkId>,
+    delete_volume: Option<VolumeName>,
     heading_map: HashMap<Header, Rect>,
     is_loading: HashSet<Uuid>,
     loading_index: u8,
     panel_map: HashMap<SelectablePanel, Rect>,
+    /// Docker commands, e.g. a restart, that have been sent but not yet finished
+    pending_actions: HashSet<Uuid>,
     selected_panel: SelectablePanel,
     status: HashSet<Status>,
+    env: Option<EnvVars>,
+    detail_visible: bool,
     exec_mode: Option<ExecMode>,
+    fetch_error: Option<AppError>,
+    go_to: Option<String>,
+    history_visible: bool,
+    id_mode: IdMode,
+    logs_fullscreen: bool,
+    log_severity: bool,
+    log_wrap: bool,
+    inspect: Option<Inspect>,
+    kill_signal: Option<KillSignal>,
+    labels: Option<Labels>,
+    limits: Option<Limits>,
+    log_copy: Option<LogCopy>,
+    log_search: Option<LogSearch>,
+    log_since: Option<LogSince>,
+    mounts: Option<Mounts>,
+    network_inspect: Option<NetworkInspect>,
+    prune: Option<Prune>,
+    pull: Option<Pull>,
+    quit_waiting: bool,
+    redraw: bool,
+    rename: Option<Rename>,
+    restart_policy: Option<RestartPolicy>,
+    run_command: Option<RunCommand>,
+    save_logs: Option<SaveLogs>,
+    view: View,
     pub info_box_text: Option<(String, Instant)>,
 }
 impl GuiState {
+    /// Create the gui state, with log severity highlighting enabled if set in the config file
+    pub fn new(log_severity: bool) -> Self {
+        Self {
+            log_severity,
+            ..Self::default()
+        }
+    }
+
+    /// Restore the saved visibility of the detail & history panels, and log wrapping
+    pub const fn restore_view(&mut self, view: &ViewConfig) {
+        if let Some(detail) = view.detail {
+            self.detail_visible = detail;
+        }
+        if let Some(history) = view.history {
+            self.history_visible = history;
+        }
+        if let Some(log_wrap) = view.log_wrap {
+            self.log_wrap = log_wrap;
+        }
+    }
+
+    /// Mark the gui as changed, so that the next frame gets drawn
+    pub const fn set_redraw(&mut self) {
+        self.redraw = true;
+    }
+
+    /// Check if the gui has changed since the last frame was drawn, resetting the flag
+    pub const fn take_redraw(&mut self) -> bool {
+        std::mem::replace(&mut self.redraw, false)
+    }
+
     /// Clear panels hash map, so on resize can fix the sizes for mouse clicks
     pub fn clear_area_map(&mut self) {
         self.panel_map.clear();
     }
 
+    /// Check if the container detail panel is visible
+    pub const fn get_detail_visible(&self) -> bool {
+        self.detail_visible
+    }
+
+    /// Show, or hide, the container detail panel
+    pub const fn toggle_detail(&mut self) {
+        self.detail_visible = !self.detail_visible;
+    }
+
+    /// Check if the cpu & memory history panel is visible
+    pub const fn get_history_visible(&self) -> bool {
+        self.history_visible
+    }
+
+    /// Show, or hide, the cpu & memory history panel
+    pub const fn toggle_history(&mut self) {
+        self.history_visible = !self.history_visible;
+    }
+
+    /// Get how the id column is shown
+    pub const fn get_id_mode(&self) -> IdMode {
+        self.id_mode
+    }
+
+    /// Set how the id column is shown, used at startup to hide the id column if it isn't one of the configured columns
+    pub const fn set_id_mode(&mut self, id_mode: IdMode) {
+        self.id_mode = id_mode;
+    }
+
+    /// Cycle the id column between hidden, short, and full, the header areas are cleared, as the columns have moved
+    pub fn cycle_id_mode(&mut self) -> IdMode {
+        self.id_mode = self.id_mode.next();
+        self.heading_map.clear();
+        self.id_mode
+    }
+
+    /// Check if the logs panel is drawn full screen, hiding every other panel
+    pub const fn get_logs_fullscreen(&self) -> bool {
+        self.logs_fullscreen
+    }
+
+    /// Toggle the full screen logs panel, which also selects the logs panel, so that scrolling moves the logs.
+    /// The panel & header areas are cleared, as the hidden panels and headers can no longer be clicked
+    pub fn toggle_logs_fullscreen(&mut self) {
+        self.logs_fullscreen = !self.logs_fullscreen;
+        if self.logs_fullscreen {
+            self.selected_panel = SelectablePanel::Logs;
+        }
+        self.panel_map.clear();
+        self.heading_map.clear();
+    }
+
+    /// Check if log lines are colored by severity
+    pub const fn get_log_severity(&self) -> bool {
+        self.log_severity
+    }
+
+    /// Toggle coloring log lines by severity
+    pub const fn toggle_log_severity(&mut self) {
+        self.log_severity = !self.log_severity;
+    }
+
+    /// Check if long log lines are soft-wrapped, rather than truncated
+    pub const fn get_log_wrap(&self) -> bool {
+        self.log_wrap
+    }
+
+    /// Toggle soft-wrapping of long log lines
+    pub const fn toggle_log_wrap(&mut self) {
+        self.log_wrap = !self.log_wrap;
+    }
+
+    /// Get the number of rows, and the wrap width, of the logs panel, used to scroll the logs by a page.
+    /// Excludes the borders, and the width of the highlight symbol, so matches the wrapping when drawn, without soft-wrapping every line is a single row
+    pub fn get_log_page(&self) -> Option<(usize, usize)> {
+        self.panel_map.get(&SelectablePanel::Logs).map(|area| {
+            (
+                usize::from(area.height.saturating_sub(2)),
+                if self.log_wrap {
+                    usize::from(area.width.saturating_sub(4))
+                } else {
+                    usize::MAX
+                },
+            )
+        })
+    }
+
+    /// Get the current main view
+    pub const fn get_view(&self) -> View {
+        self.view
+    }
+
+    /// Switch to the given images, networks, or volumes view, or back to the containers view if it's already open.
+    /// The panel & header areas are cleared, as the hidden panels and headers can no longer be clicked
+    pub fn toggle_view(&mut self, view: View) {
+        self.view = if self.view == view {
+            View::Containers
+        } else {
+            view
+        };
+        self.panel_map.clear();
+        self.heading_map.clear();
+    }
+
     /// Get the currently selected panel
     pub const fn get_selected_panel(&self) -> SelectablePanel {
         self.selected_panel
@@ -195,19 +1014,21 @@ impl GuiState {
 
     /// Check if a given Rect (a clicked area of 1x1), interacts with any known panels
     pub fn panel_intersect(&mut self, rect: Rect) {
-        if let Some(data) = self
-            .panel_map
-            .iter()
-            .filter(|i| i.1.intersects(rect))
-            .collect::<Vec<_>>()
-            .first()
-        {
-            self.selected_panel = *data.0;
+        if let Some((panel, _)) = self.panel_at(rect) {
+            self.selected_panel = panel;
         }
     }
 
+    /// Get the panel, and its area, that a given Rect (a clicked, or scrolled, area of 1x1) is in
+    pub fn panel_at(&self, rect: Rect) -> Option<(SelectablePanel, Rect)> {
+        self.panel_map
+            .iter()
+            .find(|i| i.1.intersects(rect))
+            .map(|(panel, area)| (*panel, *area))
+    }
+
     /// Check if a given Rect (a clicked area of 1x1), interacts with any known delete button
-    pub fn button_intersect(&mut self, rect: Rect) -> Option<DeleteButton> {
+    pub fn button_intersect(&self, rect: Rect) -> Option<DeleteButton> {
         self.delete_map
             .iter()
             .filter(|i| i.1.intersects(rect))
@@ -217,7 +1038,7 @@ impl GuiState {
     }
 
     /// Check if a given Rect (a clicked area of 1x1), interacts with any known panels
-    pub fn header_intersect(&mut self, rect: Rect) -> Option<Header> {
+    pub fn header_intersect(&self, rect: Rect) -> Option<Header> {
         self.heading_map
             .iter()
             .filter(|i| i.1.intersects(rect))
@@ -264,15 +1085,710 @@ impl GuiState {
         self.delete_container = id;
     }
 
-    /// Check if the current gui_status contains any of the given status'
-    /// Don't really like this methodology for gui state, needs a re-think
-    pub fn status_contains(&self, status: &[Status]) -> bool {
-        status.iter().any(|i| self.status.contains(i))
+    pub fn get_delete_image(&self) -> Option<ImageId> {
+        self.delete_image.clone()
     }
 
-    /// Remove a gui_status into the current gui_status HashSet
-    /// Remove exec mode & deleteConfirm is required
+    /// Set, or clear, the image to delete, which uses the same Delete Confirm dialog as a container
+    pub fn set_delete_image(&mut self, id: Option<ImageId>) {
+        if id.is_some() {
+            self.status.insert(Status::DeleteConfirm);
+        } else {
+            self.delete_map.clear();
+            self.status.remove(&Status::DeleteConfirm);
+        }
+        self.delete_image = id;
+    }
+
+    pub fn get_delete_network(&self) -> Option<NetworkId> {
+        self.delete_network.clone()
+    }
+
+    /// Set, or clear, the network to delete, which uses the same Delete Confirm dialog as a container
+    pub fn set_delete_network(&mut self, id: Option<NetworkId>) {
+        if id.is_some() {
+            self.status.insert(Status::DeleteConfirm);
+        } else {
+            self.delete_map.clear();
+            self.status.remove(&Status::DeleteConfirm);
+        }
+        self.delete_network = id;
+    }
+
+    pub fn get_delete_volume(&self) -> Option<VolumeName> {
+        self.delete_volume.clone()
+    }
+
+    /// Set, or clear, the volume to delete, which uses the same Delete Confirm dialog as a container
+    pub fn set_delete_volume(&mut self, name: Option<VolumeName>) {
+        if name.is_some() {
+            self.status.insert(Status::DeleteConfirm);
+        } else {
+            self.delete_map.clear();
+            self.status.remove(&Status::DeleteConfirm);
+        }
+        self.delete_volume = name;
+    }
+
+    /// Get the command waiting to be confirmed
+    pub fn get_confirm_control(&self) -> Option<ConfirmControl> {
+        self.confirm_control.clone()
+    }
+
+    /// Set, or clear, the command waiting to be confirmed, will insert/remove the ConfirmControl status
+    pub fn set_confirm_control(&mut self, confirm_control: Option<ConfirmControl>) {
+        if confirm_control.is_some() {
+            self.status.insert(Status::ConfirmControl);
+        } else {
+            self.status.remove(&Status::ConfirmControl);
+        }
+        self.confirm_control = confirm_control;
+    }
+
+    /// Get the current state of the kill popup
+    pub fn get_kill_signal(&self) -> Option<KillSignal> {
+        self.kill_signal.clone()
+    }
+
+    /// Set, or clear, the kill popup, will insert/remove the KillSignal status
+    pub fn set_kill_signal(&mut self, kill_signal: Option<KillSignal>) {
+        if kill_signal.is_some() {
+            self.status.insert(Status::KillSignal);
+        } else {
+            self.status.remove(&Status::KillSignal);
+        }
+        self.kill_signal = kill_signal;
+    }
+
+    /// Change the selected signal in the kill popup, also clears any typed signal
+    pub fn kill_signal_next(&mut self) {
+        if let Some(kill_signal) = self.kill_signal.as_mut() {
+            kill_signal.input.clear();
+            kill_signal.signals.next();
+        }
+    }
+
+    /// Change the selected signal in the kill popup, also clears any typed signal
+    pub fn kill_signal_previous(&mut self) {
+        if let Some(kill_signal) = self.kill_signal.as_mut() {
+            kill_signal.input.clear();
+            kill_signal.signals.previous();
+        }
+    }
+
+    /// Add a char to the typed signal in the kill popup
+    pub fn kill_signal_push(&mut self, c: char) {
+        if let Some(kill_signal) = self.kill_signal.as_mut() {
+            kill_signal.input.push(c);
+        }
+    }
+
+    /// Remove the last char from the typed signal in the kill popup
+    pub fn kill_signal_pop(&mut self) {
+        if let Some(kill_signal) = self.kill_signal.as_mut() {
+            kill_signal.input.pop();
+        }
+    }
+
+    /// Get the current state of the restart policy popup
+    pub fn get_restart_policy(&self) -> Option<RestartPolicy> {
+        self.restart_policy.clone()
+    }
+
+    /// Set, or clear, the restart policy popup, will insert/remove the RestartPolicy status
+    pub fn set_restart_policy(&mut self, restart_policy: Option<RestartPolicy>) {
+        if restart_policy.is_some() {
+            self.status.insert(Status::RestartPolicy);
+        } else {
+            self.status.remove(&Status::RestartPolicy);
+        }
+        self.restart_policy = restart_policy;
+    }
+
+    /// Select the next restart policy in the restart policy popup
+    pub fn restart_policy_next(&mut self) {
+        if let Some(restart_policy) = self.restart_policy.as_mut() {
+            restart_policy.policies.next();
+        }
+    }
+
+    /// Select the previous restart policy in the restart policy popup
+    pub fn restart_policy_previous(&mut self) {
+        if let Some(restart_policy) = self.restart_policy.as_mut() {
+            restart_policy.policies.previous();
+        }
+    }
+
+    /// Get the current state of the prune popup
+    pub const fn get_prune(&self) -> Option<Prune> {
+        self.prune
+    }
+
+    /// Set, or clear, the prune popup, will insert/remove the Prune status
+    pub fn set_prune(&mut self, prune: Option<Prune>) {
+        if prune.is_some() {
+            self.status.insert(Status::Prune);
+        } else {
+            self.status.remove(&Status::Prune);
+        }
+        self.prune = prune;
+    }
+
+    /// Toggle if stopped containers, dangling images, or unused volumes, will be pruned
+    pub const fn prune_toggle(&mut self, target: PruneTarget) {
+        if let Some(prune) = self.prune.as_mut() {
+            match target {
+                PruneTarget::Containers => prune.containers = !prune.containers,
+                PruneTarget::Images => prune.images = !prune.images,
+                PruneTarget::Volumes => prune.volumes = !prune.volumes,
+            }
+        }
+    }
+
+    /// Get the current state of the environment variables panel
+    pub fn get_env(&self) -> Option<EnvVars> {
+        self.env.clone()
+    }
+
+    /// Open the environment variables panel for a given container, with values masked, or close it, will insert/remove the Env status
+    pub fn set_env(&mut self, id: Option<ContainerId>) {
+        self.fetch_error = None;
+        if id.is_some() {
+            self.status.insert(Status::Env);
+        } else {
+            self.status.remove(&Status::Env);
+        }
+        self.env = id.map(|id| EnvVars {
+            id,
+            offset: 0,
+            masked: true,
+        });
+    }
+
+    /// Show or hide the values in the environment variables panel
+    pub const fn env_toggle_mask(&mut self) {
+        if let Some(env) = self.env.as_mut() {
+            env.masked = !env.masked;
+        }
+    }
+
+    /// Scroll the environment variables panel down by x lines, max is the total number of variables
+    pub fn env_scroll_down(&mut self, x: u16, max: usize) {
+        if let Some(env) = self.env.as_mut() {
+            let max = u16::try_from(max.saturating_sub(1)).unwrap_or(u16::MAX);
+            env.offset = env.offset.saturating_add(x).min(max);
+        }
+    }
+
+    /// Scroll the environment variables panel up by x lines
+    pub const fn env_scroll_up(&mut self, x: u16) {
+        if let Some(env) = self.env.as_mut() {
+            env.offset = env.offset.saturating_sub(x);
+        }
+    }
+
+    /// Get the current state of the labels panel
+    pub fn get_labels(&self) -> Option<Labels> {
+        self.labels.clone()
+    }
+
+    /// Open the labels panel for a given container, or close it, will insert/remove the Labels status
+    pub fn set_labels(&mut self, id: Option<ContainerId>) {
+        self.fetch_error = None;
+        if id.is_some() {
+            self.status.insert(Status::Labels);
+        } else {
+            self.status.remove(&Status::Labels);
+        }
+        self.labels = id.map(|id| Labels { id, offset: 0 });
+    }
+
+    /// Scroll the labels panel down by x lines, max is the total number of labels
+    pub fn labels_scroll_down(&mut self, x: u16, max: usize) {
+        if let Some(labels) = self.labels.as_mut() {
+            let max = u16::try_from(max.saturating_sub(1)).unwrap_or(u16::MAX);
+            labels.offset = labels.offset.saturating_add(x).min(max);
+        }
+    }
+
+    /// Scroll the labels panel up by x lines
+    pub const fn labels_scroll_up(&mut self, x: u16) {
+        if let Some(labels) = self.labels.as_mut() {
+            labels.offset = labels.offset.saturating_sub(x);
+        }
+    }
+
+    /// Get the current state of the inspect panel
+    pub fn get_inspect(&self) -> Option<Inspect> {
+        self.inspect.clone()
+    }
+
+    /// Open the inspect panel for a given container, or close it, will insert/remove the Inspect status
+    pub fn set_inspect(&mut self, id: Option<ContainerId>) {
+        self.fetch_error = None;
+        if id.is_some() {
+            self.status.insert(Status::Inspect);
+        } else {
+            self.status.remove(&Status::Inspect);
+        }
+        self.inspect = id.map(|id| Inspect { id, offset: 0 });
+    }
+
+    /// Scroll the inspect panel down by x lines, max is the total number of lines
+    pub fn inspect_scroll_down(&mut self, x: u16, max: usize) {
+        if let Some(inspect) = self.inspect.as_mut() {
+            let max = u16::try_from(max.saturating_sub(1)).unwrap_or(u16::MAX);
+            inspect.offset = inspect.offset.saturating_add(x).min(max);
+        }
+    }
+
+    /// Scroll the inspect panel up by x lines
+    pub const fn inspect_scroll_up(&mut self, x: u16) {
+        if let Some(inspect) = self.inspect.as_mut() {
+            inspect.offset = inspect.offset.saturating_sub(x);
+        }
+    }
+
+    /// Get the current state of the mounts panel
+    pub fn get_mounts(&self) -> Option<Mounts> {
+        self.mounts.clone()
+    }
+
+    /// Open the mounts panel for a given container, or close it, will insert/remove the Mounts status
+    pub fn set_mounts(&mut self, id: Option<ContainerId>) {
+        if id.is_some() {
+            self.status.insert(Status::Mounts);
+        } else {
+            self.status.remove(&Status::Mounts);
+        }
+        self.mounts = id.map(|id| Mounts { id, offset: 0 });
+    }
+
+    /// Scroll the mounts panel down by x lines, max is the total number of mounts
+    pub fn mounts_scroll_down(&mut self, x: u16, max: usize) {
+        if let Some(mounts) = self.mounts.as_mut() {
+            let max = u16::try_from(max.saturating_sub(1)).unwrap_or(u16::MAX);
+            mounts.offset = mounts.offset.saturating_add(x).min(max);
+        }
+    }
+
+    /// Scroll the mounts panel up by x lines
+    pub const fn mounts_scroll_up(&mut self, x: u16) {
+        if let Some(mounts) = self.mounts.as_mut() {
+            mounts.offset = mounts.offset.saturating_sub(x);
+        }
+    }
+
+    /// Get the current state of the keybinding cheatsheet
+    pub fn get_cheatsheet(&self) -> Option<Cheatsheet> {
+        self.cheatsheet.clone()
+    }
+
+    /// Open the keybinding cheatsheet with the given keys, or close it, will insert/remove the Cheatsheet status
+    pub fn set_cheatsheet(&mut self, sections: Option<CheatsheetSections>) {
+        if sections.is_some() {
+            self.status.insert(Status::Cheatsheet);
+        } else {
+            self.status.remove(&Status::Cheatsheet);
+        }
+        self.cheatsheet = sections.map(|sections| Cheatsheet {
+            sections,
+            offset: 0,
+        });
+    }
+
+    /// Scroll the keybinding cheatsheet down by x lines, to at most the last line
+    pub fn cheatsheet_scroll_down(&mut self, x: u16) {
+        if let Some(cheatsheet) = self.cheatsheet.as_mut() {
+            let max = u16::try_from(cheatsheet.line_count().saturating_sub(1)).unwrap_or(u16::MAX);
+            cheatsheet.offset = cheatsheet.offset.saturating_add(x).min(max);
+        }
+    }
+
+    /// Scroll the keybinding cheatsheet up by x lines
+    pub const fn cheatsheet_scroll_up(&mut self, x: u16) {
+        if let Some(cheatsheet) = self.cheatsheet.as_mut() {
+            cheatsheet.offset = cheatsheet.offset.saturating_sub(x);
+        }
+    }
+
+    /// Get the current state of the network inspect panel
+    pub fn get_network_inspect(&self) -> Option<NetworkInspect> {
+        self.network_inspect.clone()
+    }
+
+    /// Open the inspect panel for a given network, or close it, will insert/remove the NetworkInspect status
+    pub fn set_network_inspect(&mut self, id: Option<NetworkId>) {
+        self.fetch_error = None;
+        if id.is_some() {
+            self.status.insert(Status::NetworkInspect);
+        } else {
+            self.status.remove(&Status::NetworkInspect);
+        }
+        self.network_inspect = id.map(|id| NetworkInspect { id, offset: 0 });
+    }
+
+    /// Scroll the network inspect panel down by x lines, max is the total number of lines
+    pub fn network_inspect_scroll_down(&mut self, x: u16, max: usize) {
+        if let Some(inspect) = self.network_inspect.as_mut() {
+            let max = u16::try_from(max.saturating_sub(1)).unwrap_or(u16::MAX);
+            inspect.offset = inspect.offset.saturating_add(x).min(max);
+        }
+    }
+
+    /// Scroll the network inspect panel up by x lines
+    pub const fn network_inspect_scroll_up(&mut self, x: u16) {
+        if let Some(inspect) = self.network_inspect.as_mut() {
+            inspect.offset = inspect.offset.saturating_sub(x);
+        }
+    }
+
+    /// Get the current state of the docker run command panel
+    pub fn get_run_command(&self) -> Option<RunCommand> {
+        self.run_command.clone()
+    }
+
+    /// Open the docker run command panel for a given container, or close it, will insert/remove the RunCommand status
+    pub fn set_run_command(&mut self, id: Option<ContainerId>) {
+        self.fetch_error = None;
+        if id.is_some() {
+            self.status.insert(Status::RunCommand);
+        } else {
+            self.status.remove(&Status::RunCommand);
+        }
+        self.run_command = id.map(|id| RunCommand { id, offset: 0 });
+    }
+
+    /// Scroll the docker run command panel down by x lines, max is the total number of lines
+    pub fn run_command_scroll_down(&mut self, x: u16, max: usize) {
+        if let Some(run_command) = self.run_command.as_mut() {
+            let max = u16::try_from(max.saturating_sub(1)).unwrap_or(u16::MAX);
+            run_command.offset = run_command.offset.saturating_add(x).min(max);
+        }
+    }
+
+    /// Scroll the docker run command panel up by x lines
+    pub const fn run_command_scroll_up(&mut self, x: u16) {
+        if let Some(run_command) = self.run_command.as_mut() {
+            run_command.offset = run_command.offset.saturating_sub(x);
+        }
+    }
+
+    /// Get the current state of the image pull panel
+    pub fn get_pull(&self) -> Option<Pull> {
+        self.pull.clone()
+    }
+
+    /// Open the image pull panel, or close it, will insert/remove the Pull status
+    pub fn set_pull(&mut self, pull: Option<Pull>) {
+        if pull.is_some() {
+            self.status.insert(Status::Pull);
+        } else {
+            self.status.remove(&Status::Pull);
+        }
+        self.pull = pull;
+    }
+
+    /// Update the pull panel with the progress of a pull, ignored if the panel has since been closed, or is showing another container
+    pub fn pull_progress(&mut self, id: &ContainerId, layer: Option<String>, status: String) {
+        if let Some(pull) = self.pull.as_mut().filter(|i| &i.id == id) {
+            pull.update(layer, status);
+            self.redraw = true;
+        }
+    }
+
+    /// Mark a pull as finished, with the error if it failed.
+    /// Returns false if the pull panel has since been closed, so that the result can be shown elsewhere
+    pub fn pull_finished(&mut self, id: &ContainerId, error: Option<AppError>) -> bool {
+        self.redraw = true;
+        self.pull
+            .as_mut()
+            .filter(|i| &i.id == id)
+            .map(|pull| {
+                pull.state = error.map_or(PullState::Pulled, PullState::Failed);
+            })
+            .is_some()
+    }
+
+    /// Get the current state of the batch panel
+    pub fn get_batch(&self) -> Option<Batch> {
+        self.batch.clone()
+    }
+
+    /// Set, or clear, the batch panel, will insert/remove the Batch status
+    pub fn set_batch(&mut self, batch: Option<Batch>) {
+        if batch.is_some() {
+            self.status.insert(Status::Batch);
+        } else {
+            self.status.remove(&Status::Batch);
+        }
+        self.batch = batch;
+    }
+
+    /// Update the step of a container in the batch panel, ignored if the panel has since been closed.
+    /// Returns false if the panel is closed, so that the result can be shown elsewhere
+    pub fn batch_step(&mut self, id: &ContainerId, step: BatchStep) -> bool {
+        self.redraw = true;
+        self.batch
+            .as_mut()
+            .and_then(|batch| batch.containers.iter_mut().find(|i| &i.0 == id))
+            .map(|container| container.2 = step)
+            .is_some()
+    }
+
+    /// Get the current state of the save logs popup
+    pub fn get_save_logs(&self) -> Option<SaveLogs> {
+        self.save_logs.clone()
+    }
+
+    /// Set, or clear, the save logs popup, will insert/remove the SaveLogs status
+    pub fn set_save_logs(&mut self, save_logs: Option<SaveLogs>) {
+        if save_logs.is_some() {
+            self.status.insert(Status::SaveLogs);
+        } else {
+            self.status.remove(&Status::SaveLogs);
+        }
+        self.save_logs = save_logs;
+    }
+
+    /// Add a char to the path in the save logs popup
+    pub fn save_logs_push(&mut self, c: char) {
+        if let Some(save_logs) = self.save_logs.as_mut() {
+            save_logs.path.push(c);
+        }
+    }
+
+    /// Remove the last char from the path in the save logs popup
+    pub fn save_logs_pop(&mut self) {
+        if let Some(save_logs) = self.save_logs.as_mut() {
+            save_logs.path.pop();
+        }
+    }
+
+    /// Get the current state of the log since popup
+    pub fn get_log_since(&self) -> Option<LogSince> {
+        self.log_since.clone()
+    }
+
+    /// Set, or clear, the log since popup, will insert/remove the LogSince status
+    pub fn set_log_since(&mut self, log_since: Option<LogSince>) {
+        if log_since.is_some() {
+            self.status.insert(Status::LogSince);
+        } else {
+            self.status.remove(&Status::LogSince);
+        }
+        self.log_since = log_since;
+    }
+
+    /// Add a char to the since in the log since popup, any error is cleared
+    pub fn log_since_push(&mut self, c: char) {
+        if let Some(log_since) = self.log_since.as_mut() {
+            log_since.input.push(c);
+            log_since.error = None;
+        }
+    }
+
+    /// Remove the last char from the since in the log since popup, any error is cleared
+    pub fn log_since_pop(&mut self) {
+        if let Some(log_since) = self.log_since.as_mut() {
+            log_since.input.pop();
+            log_since.error = None;
+        }
+    }
+
+    /// Show why the submitted since is invalid
+    pub fn log_since_error(&mut self, error: String) {
+        if let Some(log_since) = self.log_since.as_mut() {
+            log_since.error = Some(error);
+        }
+    }
+
+    /// The row number typed into the go to input, None if the input isn't open
+    pub fn get_go_to(&self) -> Option<String> {
+        self.go_to.clone()
+    }
+
+    /// Open the go to input, will insert the GoTo status
+    pub fn go_to_start(&mut self) {
+        self.go_to = Some(String::new());
+        self.status.insert(Status::GoTo);
+    }
+
+    /// Add a digit to the go to input, anything else is ignored, as are digits past the first nine
+    pub fn go_to_push(&mut self, c: char) {
+        if let Some(go_to) = self.go_to.as_mut() {
+            if c.is_ascii_digit() && go_to.len() < 9 {
+                go_to.push(c);
+            }
+        }
+    }
+
+    /// Remove the last digit from the go to input
+    pub fn go_to_pop(&mut self) {
+        if let Some(go_to) = self.go_to.as_mut() {
+            go_to.pop();
+        }
+    }
+
+    /// Close the go to input, will remove the GoTo status, returns the typed row number, if any
+    pub fn go_to_finish(&mut self) -> Option<usize> {
+        self.status.remove(&Status::GoTo);
+        self.go_to.take().and_then(|i| i.parse().ok())
+    }
+
+    /// Get the current state of the limits popup
+    pub fn get_limits(&self) -> Option<Limits> {
+        self.limits.clone()
+    }
+
+    /// Set, or clear, the limits popup, will insert/remove the Limits status
+    pub fn set_limits(&mut self, limits: Option<Limits>) {
+        if limits.is_some() {
+            self.status.insert(Status::Limits);
+        } else {
+            self.status.remove(&Status::Limits);
+        }
+        self.limits = limits;
+    }
+
+    /// Add a char to the selected input of the limits popup, any error is cleared
+    pub fn limits_push(&mut self, c: char) {
+        if let Some(limits) = self.limits.as_mut() {
+            match limits.field {
+                LimitsField::Cpus => limits.cpus.push(c),
+                LimitsField::Memory => limits.memory.push(c),
+            }
+            limits.error = None;
+        }
+    }
+
+    /// Remove the last char from the selected input of the limits popup, any error is cleared
+    pub fn limits_pop(&mut self) {
+        if let Some(limits) = self.limits.as_mut() {
+            match limits.field {
+                LimitsField::Cpus => limits.cpus.pop(),
+                LimitsField::Memory => limits.memory.pop(),
+            };
+            limits.error = None;
+        }
+    }
+
+    /// Switch between the cpus and memory inputs of the limits popup
+    pub const fn limits_toggle_field(&mut self) {
+        if let Some(limits) = self.limits.as_mut() {
+            limits.field = match limits.field {
+                LimitsField::Cpus => LimitsField::Memory,
+                LimitsField::Memory => LimitsField::Cpus,
+            };
+        }
+    }
+
+    /// Show an error in the limits popup, until the input is changed
+    pub fn limits_error(&mut self, error: String) {
+        if let Some(limits) = self.limits.as_mut() {
+            limits.error = Some(error);
+        }
+    }
+
+    /// Get the current state of the rename popup
+    pub fn get_rename(&self) -> Option<Rename> {
+        self.rename.clone()
+    }
+
+    /// Set, or clear, the rename popup, will insert/remove the Rename status
+    pub fn set_rename(&mut self, rename: Option<Rename>) {
+        if rename.is_some() {
+            self.status.insert(Status::Rename);
+        } else {
+            self.status.remove(&Status::Rename);
+        }
+        self.rename = rename;
+    }
+
+    /// Add a char to the name in the rename popup
+    pub fn rename_push(&mut self, c: char) {
+        if let Some(rename) = self.rename.as_mut() {
+            rename.name.push(c);
+        }
+    }
+
+    /// Remove the last char from the name in the rename popup
+    pub fn rename_pop(&mut self) {
+        if let Some(rename) = self.rename.as_mut() {
+            rename.name.pop();
+        }
+    }
+
+    /// Get the current state of the log copy mode
+    pub fn get_log_copy(&self) -> Option<LogCopy> {
+        self.log_copy.clone()
+    }
+
+    /// Start, or stop, the log copy mode for a given container, will insert/remove the LogCopy status.
+    /// The logs panel is selected, so that the cursor is highlighted
+    pub fn set_log_copy(&mut self, id: Option<ContainerId>) {
+        if id.is_some() {
+            self.status.insert(Status::LogCopy);
+            self.selected_panel = SelectablePanel::Logs;
+        } else {
+            self.status.remove(&Status::LogCopy);
+        }
+        self.log_copy = id.map(|id| LogCopy { id, anchor: None });
+    }
+
+    /// Set, or remove, the position where the range selection of the log copy mode started
+    pub const fn set_log_copy_anchor(&mut self, anchor: Option<usize>) {
+        if let Some(log_copy) = self.log_copy.as_mut() {
+            log_copy.anchor = anchor;
+        }
+    }
+
+    /// Get the current log search
+    pub fn get_log_search(&self) -> Option<LogSearch> {
+        self.log_search.clone()
+    }
+
+    /// Start a new log search, or edit the current one, will insert the SearchLogs status, which is used whilst the query is being typed
+    pub fn start_log_search(&mut self) {
+        if self.log_search.is_none() {
+            self.log_search = Some(LogSearch::default());
+        }
+        self.status.insert(Status::SearchLogs);
+    }
+
+    /// Finish typing the search query, the search stays active, unless the query is empty
+    pub fn finish_log_search(&mut self) {
+        self.status.remove(&Status::SearchLogs);
+        if self
+            .log_search
+            .as_ref()
+            .is_some_and(|i| i.get_query().is_empty())
+        {
+            self.log_search = None;
+        }
+    }
+
+    /// Remove the log search completely
+    pub fn clear_log_search(&mut self) {
+        self.status.remove(&Status::SearchLogs);
+        self.log_search = None;
+    }
+
+    /// Get a mutable reference to the log search, in order to edit the query
+    pub const fn get_log_search_mut(&mut self) -> Option<&mut LogSearch> {
+        self.log_search.as_mut()
+    }
+
+    /// Check if the current gui_status contains any of the given status'
+    /// Don't really like this methodology for gui state, needs a re-think
+    pub fn status_contains(&self, status: &[Status]) -> bool {
+        status.iter().any(|i| self.status.contains(i))
+    }
+
+    /// Remove a gui_status into the current gui_status HashSet
+    /// Remove exec mode & deleteConfirm is required
     pub fn status_del(&mut self, status: Status) {
+        self.redraw = true;
         self.status.remove(&status);
         match status {
             Status::DeleteConfirm => {
@@ -293,28 +1809,26 @@ impl GuiState {
         self.status.insert(Status::Exec);
     }
 
-    pub fn get_exec_mode(&mut self) -> Option<ExecMode> {
+    pub fn get_exec_mode(&self) -> Option<ExecMode> {
         self.exec_mode.clone()
     }
 
     /// Insert a gui_status into the current gui_status HashSet
     /// If the status is Exec, it won't get inserted, set_exec_mode() should be used instead
     pub fn status_push(&mut self, status: Status) {
-        match status {
-            Status::Exec => (),
-            _ => {
-                self.status.insert(status);
-            }
+        if status != Status::Exec {
+            self.status.insert(status);
+            self.redraw = true;
         }
     }
 
     /// Change to next selectable panel
-    pub fn next_panel(&mut self) {
+    pub const fn next_panel(&mut self) {
         self.selected_panel = self.selected_panel.next();
     }
 
     /// Change to previous selectable panel
-    pub fn previous_panel(&mut self) {
+    pub const fn previous_panel(&mut self) {
         self.selected_panel = self.selected_panel.prev();
     }
 
@@ -326,6 +1840,7 @@ impl GuiState {
             self.loading_index += 1;
         }
         self.is_loading.insert(uuid);
+        self.redraw = true;
     }
 
     /// If is_loading has any entries, return the char at FRAMES[index], else an empty char, which needs to take up the same space, hence ' '
@@ -343,6 +1858,7 @@ impl GuiState {
         if self.is_loading.is_empty() {
             self.loading_index = 0;
         }
+        self.redraw = true;
     }
 
     /// Animate the loading icon in its own Tokio thread
@@ -360,19 +1876,683 @@ impl GuiState {
         })
     }
 
-    /// Stop the loading_spin function, and reset gui loading status
+    /// Stop the loading_spin function, and reset gui loading status, the command is no longer pending
     pub fn stop_loading_animation(&mut self, handle: &JoinHandle<()>, loading_uuid: Uuid) {
         handle.abort();
         self.remove_loading(loading_uuid);
+        self.pending_actions.remove(&loading_uuid);
+    }
+
+    /// Track a Docker command that changes a container, image, network, or volume, until its loading animation is stopped
+    pub fn insert_pending_action(&mut self, uuid: Uuid) {
+        self.pending_actions.insert(uuid);
+    }
+
+    /// The number of Docker commands that are still being processed
+    pub fn pending_actions(&self) -> usize {
+        self.pending_actions.len()
+    }
+
+    /// Show the quit confirmation, waiting is reset, so a previous wait is never resumed
+    pub fn open_quit_confirm(&mut self) {
+        self.quit_waiting = false;
+        self.status.insert(Status::QuitConfirm);
+    }
+
+    /// Close the quit confirmation, cancelling any wait
+    pub fn close_quit_confirm(&mut self) {
+        self.quit_waiting = false;
+        self.status.remove(&Status::QuitConfirm);
+    }
+
+    /// Wait for the pending commands to finish before quitting
+    pub const fn set_quit_waiting(&mut self) {
+        self.quit_waiting = true;
+    }
+
+    pub const fn get_quit_waiting(&self) -> bool {
+        self.quit_waiting
+    }
+
+    /// A fetch for a popup failed, it's ignored if the popup has since been closed
+    pub fn set_fetch_error(&mut self, status: Status, error: AppError) {
+        if self.status.contains(&status) {
+            self.fetch_error = Some(error);
+            self.redraw = true;
+        }
+    }
+
+    /// What to show in a popup whilst its data hasn't arrived
+    pub fn get_fetch(&self) -> Fetch {
+        self.fetch_error
+            .clone()
+            .map_or_else(|| Fetch::Loading(self.get_loading()), Fetch::Failed)
     }
 
     /// Set info box content
     pub fn set_info_box(&mut self, text: &str) {
         self.info_box_text = Some((text.to_owned(), std::time::Instant::now()));
+        self.redraw = true;
     }
 
     /// Remove info box content
     pub fn reset_info_box(&mut self) {
         self.info_box_text = None;
+        self.redraw = true;
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use ratatui::layout::Rect;
+
+    use super::{
+        parse_cpus, parse_memory, validate_container_name, validate_signal, Batch, BatchStep,
+        GuiState, IdMode, KillSignal, Limits, LimitsField, LogSearch, Prune, PruneTarget, Pull,
+        PullState, Region, RestartPolicy, SelectablePanel, Status, View,
+    };
+    use crate::{
+        app_data::{ContainerId, DockerControls, Header, ImageId, NetworkId, VolumeName},
+        app_error::AppError,
+        config::Keymap,
+    };
+
+    #[test]
+    /// A failed fetch is only shown in the popup that's still open, and is cleared when a popup is opened again
+    fn test_gui_state_fetch() {
+        let mut gui_state = GuiState::new(true);
+        let id = ContainerId::from("1");
+        gui_state.set_inspect(Some(id.clone()));
+        assert_eq!(gui_state.get_fetch().to_string(), "inspecting  ");
+
+        gui_state.set_fetch_error(Status::Env, AppError::DockerInspect);
+        assert_eq!(gui_state.get_fetch().to_string(), "inspecting  ");
+
+        gui_state.set_fetch_error(Status::Inspect, AppError::DockerInspect);
+        assert_eq!(
+            gui_state.get_fetch().to_string(),
+            "✖ Unable to inspect container"
+        );
+
+        gui_state.set_inspect(None);
+        gui_state.set_inspect(Some(id));
+        assert_eq!(gui_state.get_fetch().to_string(), "inspecting  ");
+    }
+
+    #[test]
+    /// Layers are updated in place, in the order first reported, and progress for a closed, or different, pull is ignored
+    fn test_gui_state_pull() {
+        let mut gui_state = GuiState::new(true);
+        let id = ContainerId::from("1");
+        gui_state.pull_progress(&id, None, String::from("ignored"));
+        assert!(!gui_state.pull_finished(&id, None));
+        assert!(gui_state.get_pull().is_none());
+
+        gui_state.set_pull(Some(Pull::new(id.clone(), String::from("image_1:latest"))));
+        assert!(gui_state.status_contains(&[Status::Pull]));
+        gui_state.pull_progress(&id, Some(String::from("aaa")), String::from("Waiting"));
+        gui_state.pull_progress(&id, Some(String::from("bbb")), String::from("Waiting"));
+        gui_state.pull_progress(
+            &id,
+            Some(String::from("aaa")),
+            String::from("Pull complete"),
+        );
+        gui_state.pull_progress(&id, None, String::from("Digest: sha256:abc"));
+        gui_state.pull_progress(&ContainerId::from("2"), None, String::from("ignored"));
+
+        let pull = gui_state.get_pull().unwrap();
+        assert_eq!(
+            pull.lines(),
+            ["aaa: Pull complete", "bbb: Waiting", "Digest: sha256:abc"]
+        );
+        assert!(matches!(pull.state, PullState::Pulling));
+
+        assert!(!gui_state.pull_finished(&ContainerId::from("2"), None));
+        assert!(gui_state.pull_finished(&id, Some(AppError::DockerPullNotFound)));
+        assert!(matches!(
+            gui_state.get_pull().unwrap().state,
+            PullState::Failed(AppError::DockerPullNotFound)
+        ));
+
+        gui_state.set_pull(None);
+        assert!(!gui_state.status_contains(&[Status::Pull]));
+    }
+
+    #[test]
+    /// Pending commands are counted, and opening, or closing, the quit confirmation resets waiting
+    fn test_gui_state_quit_confirm() {
+        let mut gui_state = GuiState::new(true);
+        assert_eq!(gui_state.pending_actions(), 0);
+        gui_state.insert_pending_action(uuid::Uuid::new_v4());
+        gui_state.insert_pending_action(uuid::Uuid::new_v4());
+        assert_eq!(gui_state.pending_actions(), 2);
+
+        gui_state.open_quit_confirm();
+        assert!(gui_state.status_contains(&[Status::QuitConfirm]));
+        assert!(!gui_state.get_quit_waiting());
+        gui_state.set_quit_waiting();
+        assert!(gui_state.get_quit_waiting());
+
+        gui_state.close_quit_confirm();
+        assert!(!gui_state.status_contains(&[Status::QuitConfirm]));
+        assert!(!gui_state.get_quit_waiting());
+    }
+
+    #[test]
+    /// The id mode cycles hidden, short, full, and adds or removes the id column
+    fn test_gui_state_id_mode() {
+        let mut gui_state = GuiState::new(true);
+        assert_eq!(gui_state.get_id_mode(), IdMode::Short);
+        assert_eq!(gui_state.cycle_id_mode(), IdMode::Full);
+        assert_eq!(gui_state.cycle_id_mode(), IdMode::Hidden);
+        assert_eq!(gui_state.cycle_id_mode(), IdMode::Short);
+
+        let id = ContainerId::from("0123456789abcdef0123");
+        assert_eq!(IdMode::Short.format(&id), "0123456789ab");
+        assert_eq!(IdMode::Full.format(&id), "0123456789abcdef0123");
+
+        let columns = vec![Header::Name, Header::Id, Header::Cpu];
+        assert_eq!(IdMode::Short.apply(columns.clone()), columns);
+        assert_eq!(
+            IdMode::Hidden.apply(columns),
+            vec![Header::Name, Header::Cpu]
+        );
+        assert_eq!(
+            IdMode::Full.apply(vec![Header::Cpu, Header::Name]),
+            vec![Header::Cpu, Header::Name, Header::Id]
+        );
+        assert_eq!(IdMode::Full.to_string(), "full");
+    }
+
+    #[test]
+    /// Signals are normalized, and invalid signals rejected
+    fn test_gui_state_validate_signal() {
+        assert_eq!(validate_signal("SIGHUP"), Some("SIGHUP".to_owned()));
+        assert_eq!(validate_signal("hup"), Some("SIGHUP".to_owned()));
+        assert_eq!(validate_signal(" sigusr1 "), Some("SIGUSR1".to_owned()));
+        assert_eq!(validate_signal("9"), Some("9".to_owned()));
+        assert_eq!(validate_signal("0"), None);
+        assert_eq!(validate_signal("65"), None);
+        assert_eq!(validate_signal(""), None);
+        assert_eq!(validate_signal("SIGNOPE"), None);
+    }
+
+    #[test]
+    /// Container names must start with an alphanumeric char, and only contain alphanumerics, underscores, dots, and dashes
+    fn test_gui_state_validate_container_name() {
+        assert!(validate_container_name("container_1"));
+        assert!(validate_container_name("a.b-c_d"));
+        assert!(validate_container_name("1a"));
+        assert!(!validate_container_name(""));
+        assert!(!validate_container_name("a"));
+        assert!(!validate_container_name("_container"));
+        assert!(!validate_container_name("-container"));
+        assert!(!validate_container_name("container 1"));
+        assert!(!validate_container_name("container/1"));
+        assert!(!validate_container_name("contäiner"));
+    }
+
+    #[test]
+    /// Typed signal takes priority over the selected signal
+    fn test_gui_state_kill_signal_get_signal() {
+        let mut kill_signal = KillSignal::new(ContainerId::from("1"));
+        assert_eq!(kill_signal.get_signal(), Some("SIGTERM".to_owned()));
+
+        kill_signal.signals.next();
+        assert_eq!(kill_signal.get_signal(), Some("SIGKILL".to_owned()));
+
+        kill_signal.input.push_str("winch");
+        assert_eq!(kill_signal.get_signal(), Some("SIGWINCH".to_owned()));
+
+        kill_signal.input.push('x');
+        assert_eq!(kill_signal.get_signal(), None);
+    }
+
+    #[test]
+    /// The current restart policy is selected when opened, ignoring any maximum retry count, and an unknown policy selects the first
+    fn test_gui_state_restart_policy() {
+        let id = ContainerId::from("1");
+        let policy = |current| RestartPolicy::new(id.clone(), current).get_policy();
+        assert_eq!(policy(Some("always")), Some("always"));
+        assert_eq!(policy(Some("on-failure:3")), Some("on-failure"));
+        assert_eq!(policy(Some("unknown")), Some("no"));
+        assert_eq!(policy(None), Some("no"));
+
+        let mut gui_state = GuiState::default();
+        gui_state.set_restart_policy(Some(RestartPolicy::new(id.clone(), Some("no"))));
+        assert!(gui_state.status_contains(&[Status::RestartPolicy]));
+        gui_state.restart_policy_next();
+        gui_state.restart_policy_next();
+        assert_eq!(
+            gui_state.get_restart_policy().unwrap().get_policy(),
+            Some("always")
+        );
+        gui_state.restart_policy_previous();
+        assert_eq!(
+            gui_state.get_restart_policy().unwrap().get_policy(),
+            Some("on-failure")
+        );
+
+        gui_state.set_restart_policy(None);
+        assert!(!gui_state.status_contains(&[Status::RestartPolicy]));
+        assert!(gui_state.get_restart_policy().is_none());
+    }
+
+    #[test]
+    /// Each container's step is updated by id, and the summary counts the failed containers once finished
+    fn test_gui_state_batch() {
+        let (id_1, id_2) = (ContainerId::from("1"), ContainerId::from("2"));
+        let mut gui_state = GuiState::default();
+        assert!(!gui_state.batch_step(&id_1, BatchStep::Done));
+
+        gui_state.set_batch(Some(Batch::new(
+            DockerControls::Restart,
+            vec![
+                (id_1.clone(), String::from("container_1")),
+                (id_2.clone(), String::from("container_2")),
+            ],
+        )));
+        assert!(gui_state.status_contains(&[Status::Batch]));
+        assert!(gui_state.batch_step(&id_1, BatchStep::Stopping));
+        assert!(!gui_state.get_batch().unwrap().is_finished());
+        assert!(!gui_state.batch_step(&ContainerId::from("3"), BatchStep::Done));
+
+        gui_state.batch_step(&id_1, BatchStep::Done);
+        gui_state.batch_step(&id_2, BatchStep::Failed("start"));
+        let batch = gui_state.get_batch().unwrap();
+        assert!(batch.is_finished());
+        assert_eq!(batch.failed(), 1);
+        assert_eq!(batch.summary(), "restarted 1 of 2 containers, 1 failed");
+        assert_eq!(batch.containers[1].2.to_string(), "✖ failed to start");
+
+        gui_state.batch_step(&id_2, BatchStep::Done);
+        assert_eq!(
+            gui_state.get_batch().unwrap().summary(),
+            "restarted 2 containers"
+        );
+
+        gui_state.set_batch(None);
+        assert!(!gui_state.status_contains(&[Status::Batch]));
+    }
+
+    #[test]
+    /// Cpus are converted to nano cpus, memory to bytes, anything else is rejected
+    fn test_gui_state_limits_parse() {
+        assert_eq!(parse_cpus("1.5"), Some(1_500_000_000));
+        assert_eq!(parse_cpus(" 2 "), Some(2_000_000_000));
+        assert_eq!(parse_cpus("0.01"), Some(10_000_000));
+        assert!(parse_cpus("0.001").is_none());
+        assert!(parse_cpus("-1").is_none());
+        assert!(parse_cpus("one").is_none());
+
+        assert_eq!(parse_memory("512"), Some(512));
+        assert_eq!(parse_memory("512b"), Some(512));
+        assert_eq!(parse_memory("4k"), Some(4096));
+        assert_eq!(parse_memory("512m"), Some(536_870_912));
+        assert_eq!(parse_memory("512MB"), Some(536_870_912));
+        assert_eq!(parse_memory("1.5g"), Some(1_610_612_736));
+        assert!(parse_memory("0").is_none());
+        assert!(parse_memory("10x").is_none());
+        assert!(parse_memory("m").is_none());
+        assert!(parse_memory("1.2.3m").is_none());
+    }
+
+    #[test]
+    /// Only digits are added to the go to input, and finishing returns the typed row number
+    fn test_gui_state_go_to() {
+        let mut gui_state = GuiState::default();
+        gui_state.go_to_push('1');
+        assert!(gui_state.get_go_to().is_none());
+
+        gui_state.go_to_start();
+        assert!(gui_state.status_contains(&[Status::GoTo]));
+        for c in "1a2-3".chars() {
+            gui_state.go_to_push(c);
+        }
+        assert_eq!(gui_state.get_go_to(), Some(String::from("123")));
+        gui_state.go_to_pop();
+        assert_eq!(gui_state.go_to_finish(), Some(12));
+        assert!(!gui_state.status_contains(&[Status::GoTo]));
+        assert!(gui_state.get_go_to().is_none());
+
+        gui_state.go_to_start();
+        assert_eq!(gui_state.go_to_finish(), None);
+
+        gui_state.go_to_start();
+        for _ in 0..12 {
+            gui_state.go_to_push('9');
+        }
+        assert_eq!(gui_state.go_to_finish(), Some(999_999_999));
+    }
+
+    #[test]
+    /// Input goes to the selected field, an empty field leaves that limit unchanged
+    fn test_gui_state_limits() {
+        let mut gui_state = GuiState::default();
+        gui_state.set_limits(Some(Limits::new(ContainerId::from("1"))));
+        assert!(gui_state.status_contains(&[Status::Limits]));
+        assert_eq!(
+            gui_state.get_limits().unwrap().parse(),
+            Err("set a cpus and/or memory limit")
+        );
+
+        gui_state.limits_push('2');
+        gui_state.limits_toggle_field();
+        assert_eq!(gui_state.get_limits().unwrap().field, LimitsField::Memory);
+        for c in "1gx".chars() {
+            gui_state.limits_push(c);
+        }
+        let error = gui_state.get_limits().unwrap().parse().unwrap_err();
+        assert_eq!(error, "invalid memory, e.g. 512m or 2g");
+        gui_state.limits_error(error.to_owned());
+        assert!(gui_state.get_limits().unwrap().error.is_some());
+
+        gui_state.limits_pop();
+        let limits = gui_state.get_limits().unwrap();
+        assert!(limits.error.is_none());
+        assert_eq!(limits.cpus, "2");
+        assert_eq!(limits.memory, "1g");
+        assert_eq!(
+            limits.parse(),
+            Ok((Some(2_000_000_000), Some(1_073_741_824)))
+        );
+
+        gui_state.limits_toggle_field();
+        gui_state.limits_pop();
+        assert_eq!(
+            gui_state.get_limits().unwrap().parse(),
+            Ok((None, Some(1_073_741_824)))
+        );
+
+        gui_state.set_limits(None);
+        assert!(!gui_state.status_contains(&[Status::Limits]));
+    }
+
+    #[test]
+    /// Plain search is a substring match, regex search reports an invalid regex
+    fn test_gui_state_log_search() {
+        let mut search = LogSearch::default();
+        assert!(!search.is_match("anything"));
+
+        "a.c".chars().for_each(|c| search.push(c));
+        assert!(search.is_match("xa.cx"));
+        assert!(!search.is_match("abc"));
+        assert!(search.get_error().is_none());
+
+        search.toggle_regex();
+        assert!(search.is_match("abc"));
+
+        search.push('(');
+        assert_eq!(search.get_error(), Some("invalid regex"));
+        assert!(!search.is_match("abc"));
+
+        search.pop();
+        assert!(search.get_error().is_none());
+    }
+
+    #[test]
+    /// The log copy mode selects the logs panel, and the anchor is removed when the copy mode is restarted
+    fn test_gui_state_log_copy() {
+        let mut gui_state = GuiState::default();
+        assert!(gui_state.get_log_copy().is_none());
+        gui_state.set_log_copy_anchor(Some(1));
+        assert!(gui_state.get_log_copy().is_none());
+
+        gui_state.set_log_copy(Some(ContainerId::from("1")));
+        assert!(gui_state.status_contains(&[Status::LogCopy]));
+        assert_eq!(gui_state.get_selected_panel(), SelectablePanel::Logs);
+        gui_state.set_log_copy_anchor(Some(3));
+        let log_copy = gui_state.get_log_copy().unwrap();
+        assert_eq!(log_copy.id, ContainerId::from("1"));
+        assert_eq!(log_copy.anchor, Some(3));
+
+        gui_state.set_log_copy(Some(ContainerId::from("1")));
+        assert_eq!(gui_state.get_log_copy().unwrap().anchor, None);
+
+        gui_state.set_log_copy(None);
+        assert!(gui_state.get_log_copy().is_none());
+        assert!(!gui_state.status_contains(&[Status::LogCopy]));
+    }
+
+    #[test]
+    /// Inspect panel scroll offset is kept within the number of lines, and reset when re-opened
+    fn test_gui_state_inspect_scroll() {
+        let mut gui_state = GuiState::default();
+        gui_state.set_inspect(Some(ContainerId::from("1")));
+        assert!(gui_state.status_contains(&[Status::Inspect]));
+
+        gui_state.inspect_scroll_up(1);
+        assert_eq!(gui_state.get_inspect().unwrap().offset, 0);
+
+        gui_state.inspect_scroll_down(10, 5);
+        assert_eq!(gui_state.get_inspect().unwrap().offset, 4);
+
+        gui_state.inspect_scroll_up(2);
+        assert_eq!(gui_state.get_inspect().unwrap().offset, 2);
+
+        gui_state.set_inspect(Some(ContainerId::from("1")));
+        assert_eq!(gui_state.get_inspect().unwrap().offset, 0);
+
+        gui_state.set_inspect(None);
+        assert!(gui_state.get_inspect().is_none());
+        assert!(!gui_state.status_contains(&[Status::Inspect]));
+    }
+
+    #[test]
+    /// Labels panel scroll offset is kept within the number of labels, and reset when re-opened
+    fn test_gui_state_labels_scroll() {
+        let mut gui_state = GuiState::default();
+        gui_state.set_labels(Some(ContainerId::from("1")));
+        assert!(gui_state.status_contains(&[Status::Labels]));
+
+        gui_state.labels_scroll_down(10, 3);
+        assert_eq!(gui_state.get_labels().unwrap().offset, 2);
+        gui_state.labels_scroll_up(1);
+        assert_eq!(gui_state.get_labels().unwrap().offset, 1);
+
+        gui_state.set_labels(Some(ContainerId::from("1")));
+        assert_eq!(gui_state.get_labels().unwrap().offset, 0);
+
+        gui_state.set_labels(None);
+        assert!(gui_state.get_labels().is_none());
+        assert!(!gui_state.status_contains(&[Status::Labels]));
+    }
+
+    #[test]
+    /// Environment variables are masked when opened, the mask can be toggled, and a value can contain an equals sign
+    fn test_gui_state_env_mask() {
+        let mut gui_state = GuiState::default();
+        gui_state.set_env(Some(ContainerId::from("1")));
+        assert!(gui_state.status_contains(&[Status::Env]));
+
+        let env = gui_state.get_env().unwrap();
+        assert_eq!(env.format("PASSWORD=hunter2"), "PASSWORD=*******");
+        assert_eq!(env.format("EMPTY="), "EMPTY=");
+        assert_eq!(env.format("NO_VALUE"), "NO_VALUE");
+        assert_eq!(env.format("URL=a=b"), "URL=***");
+
+        gui_state.env_toggle_mask();
+        let env = gui_state.get_env().unwrap();
+        assert_eq!(env.format("PASSWORD=hunter2"), "PASSWORD=hunter2");
+        assert_eq!(env.format("URL=a=b"), "URL=a=b");
+
+        gui_state.env_scroll_down(10, 3);
+        assert_eq!(gui_state.get_env().unwrap().offset, 2);
+
+        gui_state.set_env(Some(ContainerId::from("1")));
+        let env = gui_state.get_env().unwrap();
+        assert!(env.masked);
+        assert_eq!(env.offset, 0);
+
+        gui_state.set_env(None);
+        assert!(gui_state.get_env().is_none());
+        assert!(!gui_state.status_contains(&[Status::Env]));
+    }
+
+    #[test]
+    /// Prune popup only selects containers when opened, and each target is toggled separately
+    fn test_gui_state_prune_toggle() {
+        let mut gui_state = GuiState::default();
+        gui_state.set_prune(Some(Prune::new(2)));
+        assert!(gui_state.status_contains(&[Status::Prune]));
+
+        let prune = gui_state.get_prune().unwrap();
+        assert!(prune.containers);
+        assert!(!prune.images);
+        assert_eq!(prune.stopped, 2);
+
+        gui_state.prune_toggle(PruneTarget::Images);
+        gui_state.prune_toggle(PruneTarget::Containers);
+        let prune = gui_state.get_prune().unwrap();
+        assert!(!prune.containers);
+        assert!(prune.images);
+        assert!(!prune.volumes);
+        assert!(prune.is_selected());
+
+        gui_state.prune_toggle(PruneTarget::Images);
+        assert!(!gui_state.get_prune().unwrap().is_selected());
+
+        gui_state.prune_toggle(PruneTarget::Volumes);
+        assert!(gui_state.get_prune().unwrap().volumes);
+
+        gui_state.set_prune(Some(Prune::volumes(2)));
+        let prune = gui_state.get_prune().unwrap();
+        assert!(!prune.containers);
+        assert!(!prune.images);
+        assert!(prune.volumes);
+
+        gui_state.set_prune(None);
+        assert!(gui_state.get_prune().is_none());
+        assert!(!gui_state.status_contains(&[Status::Prune]));
+    }
+
+    #[test]
+    /// The view toggles between containers & images, or networks, and deleting an image or network uses the Delete Confirm status
+    fn test_gui_state_view() {
+        let mut gui_state = GuiState::default();
+        assert_eq!(gui_state.get_view(), View::Containers);
+        gui_state.toggle_view(View::Images);
+        assert_eq!(gui_state.get_view(), View::Images);
+
+        gui_state.set_delete_image(Some(ImageId::from("1")));
+        assert!(gui_state.status_contains(&[Status::DeleteConfirm]));
+        gui_state.set_delete_image(None);
+        assert!(!gui_state.status_contains(&[Status::DeleteConfirm]));
+
+        gui_state.toggle_view(View::Images);
+        assert_eq!(gui_state.get_view(), View::Containers);
+
+        gui_state.toggle_view(View::Images);
+        gui_state.toggle_view(View::Networks);
+        assert_eq!(gui_state.get_view(), View::Networks);
+
+        gui_state.set_delete_network(Some(NetworkId::from("1")));
+        assert!(gui_state.status_contains(&[Status::DeleteConfirm]));
+        gui_state.set_delete_network(None);
+        assert!(!gui_state.status_contains(&[Status::DeleteConfirm]));
+
+        gui_state.toggle_view(View::Networks);
+        assert_eq!(gui_state.get_view(), View::Containers);
+
+        gui_state.toggle_view(View::Volumes);
+        assert_eq!(gui_state.get_view(), View::Volumes);
+        gui_state.set_delete_volume(Some(VolumeName::from("volume_1")));
+        assert!(gui_state.status_contains(&[Status::DeleteConfirm]));
+        gui_state.set_delete_volume(None);
+        assert!(!gui_state.status_contains(&[Status::DeleteConfirm]));
+    }
+
+    #[test]
+    /// Network inspect panel scroll offset is kept within the number of lines, and reset when re-opened
+    fn test_gui_state_network_inspect_scroll() {
+        let mut gui_state = GuiState::default();
+        gui_state.set_network_inspect(Some(NetworkId::from("1")));
+        assert!(gui_state.status_contains(&[Status::NetworkInspect]));
+
+        gui_state.network_inspect_scroll_down(10, 5);
+        assert_eq!(gui_state.get_network_inspect().unwrap().offset, 4);
+        gui_state.network_inspect_scroll_up(3);
+        assert_eq!(gui_state.get_network_inspect().unwrap().offset, 1);
+
+        gui_state.set_network_inspect(Some(NetworkId::from("1")));
+        assert_eq!(gui_state.get_network_inspect().unwrap().offset, 0);
+
+        gui_state.set_network_inspect(None);
+        assert!(gui_state.get_network_inspect().is_none());
+        assert!(!gui_state.status_contains(&[Status::NetworkInspect]));
+    }
+
+    #[test]
+    /// The log page excludes the borders, and the highlight symbol when wrapping, and is only known once the logs panel has been drawn
+    fn test_gui_state_log_page() {
+        let mut gui_state = GuiState::default();
+        assert_eq!(gui_state.get_log_page(), None);
+        gui_state.update_region_map(
+            Region::Panel(SelectablePanel::Logs),
+            Rect::new(0, 0, 40, 12),
+        );
+        assert!(!gui_state.get_log_wrap());
+        assert_eq!(gui_state.get_log_page(), Some((10, usize::MAX)));
+
+        gui_state.toggle_log_wrap();
+        assert!(gui_state.get_log_wrap());
+        assert_eq!(gui_state.get_log_page(), Some((10, 36)));
+
+        gui_state.toggle_log_wrap();
+        assert_eq!(gui_state.get_log_page(), Some((10, usize::MAX)));
+    }
+
+    #[test]
+    /// Status changes, the info box, and the loading animation mark the gui as changed, and taking the flag resets it
+    fn test_gui_state_redraw() {
+        let mut gui_state = GuiState::default();
+        assert!(!gui_state.take_redraw());
+
+        gui_state.status_push(Status::Help);
+        assert!(gui_state.take_redraw());
+        assert!(!gui_state.take_redraw());
+
+        gui_state.set_info_box("info");
+        assert!(gui_state.take_redraw());
+
+        let uuid = uuid::Uuid::new_v4();
+        gui_state.next_loading(uuid);
+        assert!(gui_state.take_redraw());
+        gui_state.remove_loading(uuid);
+        assert!(gui_state.take_redraw());
+
+        gui_state.set_redraw();
+        assert!(gui_state.take_redraw());
+        assert!(!gui_state.take_redraw());
+    }
+
+    #[test]
+    /// The cheatsheet offset is kept within the number of lines, and reset when re-opened
+    fn test_gui_state_cheatsheet_scroll() {
+        let mut gui_state = GuiState::default();
+        let sections = Keymap::default().cheatsheet();
+        // Every action apart from start, stop, & restart is bound by default, each of the six categories has a heading, and all but the first a separator
+        let line_count = 82 - 3 + 6 + 5;
+        gui_state.set_cheatsheet(Some(sections.clone()));
+        assert!(gui_state.status_contains(&[Status::Cheatsheet]));
+        assert_eq!(gui_state.get_cheatsheet().unwrap().line_count(), line_count);
+
+        gui_state.cheatsheet_scroll_down(u16::MAX);
+        assert_eq!(
+            usize::from(gui_state.get_cheatsheet().unwrap().offset),
+            line_count - 1
+        );
+        gui_state.cheatsheet_scroll_up(u16::MAX);
+        gui_state.cheatsheet_scroll_down(4);
+        gui_state.cheatsheet_scroll_up(3);
+        assert_eq!(gui_state.get_cheatsheet().unwrap().offset, 1);
+
+        gui_state.set_cheatsheet(Some(sections));
+        assert_eq!(gui_state.get_cheatsheet().unwrap().offset, 0);
+
+        gui_state.set_cheatsheet(None);
+        assert!(gui_state.get_cheatsheet().is_none());
+        assert!(!gui_state.status_contains(&[Status::Cheatsheet]));
     }
 }
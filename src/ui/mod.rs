@@ -1,5 +1,6 @@
 use anyhow::Result;
 use crossterm::{
+    cursor::Show,
     event::{self, DisableMouseCapture, Event},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
@@ -7,7 +8,7 @@ use crossterm::{
 use parking_lot::{Mutex, MutexGuard};
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     Frame, Terminal,
 };
 use std::{
@@ -24,20 +25,45 @@ mod draw_blocks;
 mod gui_state;
 
 pub use self::color_match::*;
-pub use self::gui_state::{DeleteButton, GuiState, SelectablePanel, Status};
+pub use self::gui_state::{
+    validate_container_name, Batch, BatchStep, Cheatsheet, ConfirmControl, DeleteButton, EnvVars,
+    Fetch, GuiState, IdMode, Inspect, KillSignal, Labels, Limits, LogSearch, LogSince, Mounts,
+    NetworkInspect, Prune, PruneTarget, Pull, PullState, Rename, RestartPolicy, RunCommand,
+    SaveLogs, SelectablePanel, Status, View,
+};
 use crate::{
-    app_data::{AppData, Columns, ContainerId, Header, SortedOrder},
+    app_data::{
+        AppData, Columns, ContainerId, CpuMode, Engine, Header, ImageId, NetworkId, Ping,
+        SortedOrder, State, StateFilter, Summary, VolumeName,
+    },
     app_error::AppError,
+    config::Theme,
     exec::TerminalSize,
     input_handler::InputMessages,
 };
 
+/// Width of the container detail sidebar, including its borders
+const DETAIL_WIDTH: u16 = 40;
+
+/// The commands panel is a tenth of the width, but never narrower than the longest command, `▶ recreate`, and its borders
+const COMMANDS_MIN_WIDTH: u16 = 12;
+
+/// Below this width the detail panel is drawn beneath the logs, rather than beside them, and the charts & ports are hidden
+const NARROW_WIDTH: u16 = 80;
+
+/// The charts & ports are hidden when they would be shorter than this, a border and two rows of chart
+const CHARTS_MIN_HEIGHT: u16 = 4;
+
+/// A frame is only drawn when the docker data or gui state has changed, but at least this often, so that time based content, such as uptimes & the info box, stays current
+const MIN_REDRAW: Duration = Duration::from_secs(1);
+
 pub struct Ui {
     app_data: Arc<Mutex<AppData>>,
     gui_state: Arc<Mutex<GuiState>>,
     input_poll_rate: Duration,
     input_tx: Sender<InputMessages>,
     is_running: Arc<AtomicBool>,
+    mouse: bool,
     now: Instant,
     terminal: Terminal<CrosstermBackend<Stdout>>,
     cursor_position: (u16, u16),
@@ -62,8 +88,10 @@ impl Ui {
         gui_state: Arc<Mutex<GuiState>>,
         input_tx: Sender<InputMessages>,
         is_running: Arc<AtomicBool>,
+        mouse: bool,
     ) {
-        if let Ok(mut terminal) = Self::setup_terminal() {
+        if let Ok(mut terminal) = Self::setup_terminal(mouse) {
+            Self::set_panic_hook(&is_running);
             // let args = app_data.lock().args.clone();
             let cursor_position = terminal.get_cursor().unwrap_or_default();
             let mut ui = Self {
@@ -73,6 +101,7 @@ impl Ui {
                 input_poll_rate: std::time::Duration::from_millis(100),
                 input_tx,
                 is_running,
+                mouse,
                 now: Instant::now(),
                 terminal,
             };
@@ -81,27 +110,48 @@ impl Ui {
             }
             if let Err(e) = ui.reset_terminal() {
                 error!("{e}");
-            };
+            }
         } else {
             error!("Terminal Error");
         }
     }
 
-    /// Setup the terminal for full-screen drawing mode, with mouse capture
-    fn setup_terminal() -> Result<Terminal<CrosstermBackend<Stdout>>> {
-        let stdout = Self::init_terminal()?;
+    /// Setup the terminal for full-screen drawing mode, with mouse capture unless disabled in the config file
+    fn setup_terminal(mouse: bool) -> Result<Terminal<CrosstermBackend<Stdout>>> {
+        let stdout = Self::init_terminal(mouse)?;
         let backend = CrosstermBackend::new(stdout);
         Ok(Terminal::new(backend)?)
     }
 
-    fn init_terminal() -> Result<Stdout> {
+    fn init_terminal(mouse: bool) -> Result<Stdout> {
         enable_raw_mode()?;
         let mut stdout = io::stdout();
         execute!(stdout, EnterAlternateScreen)?;
-        Self::enable_mouse_capture()?;
+        if mouse {
+            Self::enable_mouse_capture()?;
+        }
         Ok(stdout)
     }
 
+    /// Restore the terminal before the panic message is printed, else a panic in any thread leaves the users shell in raw mode, and stuck in the alternate screen.
+    /// Also stop the application, as a panic in a spawned task doesn't end the process, unless built with `panic = 'abort'`
+    fn set_panic_hook(is_running: &Arc<AtomicBool>) {
+        let is_running = Arc::clone(is_running);
+        let original_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            is_running.store(false, Ordering::SeqCst);
+            disable_raw_mode().ok();
+            execute!(
+                io::stdout(),
+                LeaveAlternateScreen,
+                DisableMouseCapture,
+                Show
+            )
+            .ok();
+            original_hook(info);
+        }));
+    }
+
     /// reset the terminal back to default settings
     pub fn reset_terminal(&mut self) -> Result<()> {
         self.terminal.clear()?;
@@ -121,6 +171,7 @@ impl Ui {
     /// Draw the the error message ui, for 5 seconds, with a countdown
     fn err_loop(&mut self) -> Result<(), AppError> {
         let mut seconds = 5;
+        let theme = self.app_data.lock().get_theme();
         loop {
             if self.now.elapsed() >= std::time::Duration::from_secs(1) {
                 seconds -= 1;
@@ -132,7 +183,7 @@ impl Ui {
 
             if self
                 .terminal
-                .draw(|f| draw_blocks::error(f, AppError::DockerConnect, Some(seconds)))
+                .draw(|f| draw_blocks::error(f, &AppError::DockerConnect, Some(seconds), &theme))
                 .is_err()
             {
                 return Err(AppError::Terminal);
@@ -156,24 +207,32 @@ impl Ui {
         }
         self.terminal.clear().ok();
         self.reset_terminal().ok();
-        Self::init_terminal().ok();
+        Self::init_terminal(self.mouse).ok();
         self.gui_state.lock().status_del(Status::Exec);
     }
 
     /// The loop for drawing the main UI to the terminal
     async fn gui_loop(&mut self) -> Result<(), AppError> {
+        let mut last_draw: Option<Instant> = None;
         while self.is_running.load(Ordering::SeqCst) {
             let exec = self.gui_state.lock().status_contains(&[Status::Exec]);
             if exec {
                 self.exec().await;
+                last_draw = None;
             }
 
-            if self
-                .terminal
-                .draw(|frame| draw_frame(frame, &self.app_data, &self.gui_state))
-                .is_err()
-            {
-                return Err(AppError::Terminal);
+            // Both flags are always taken, so that neither carries over into the next frame
+            let gui_changed = self.gui_state.lock().take_redraw();
+            let data_changed = self.app_data.lock().take_redraw();
+            if gui_changed || data_changed || last_draw.is_none_or(|i| i.elapsed() >= MIN_REDRAW) {
+                if self
+                    .terminal
+                    .draw(|frame| draw_frame(frame, &self.app_data, &self.gui_state))
+                    .is_err()
+                {
+                    return Err(AppError::Terminal);
+                }
+                last_draw = Some(Instant::now());
             }
 
             if crossterm::event::poll(self.input_poll_rate).unwrap_or(false) {
@@ -197,6 +256,7 @@ impl Ui {
                     } else if let Event::Resize(_, _) = event {
                         self.gui_state.lock().clear_area_map();
                         self.terminal.autoresize().ok();
+                        last_draw = None;
                     }
                 }
             }
@@ -221,24 +281,77 @@ impl Ui {
 
 /// Frequent data required by multiple framde drawing functions, can reduce mutex reads by placing it all in here
 #[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct FrameData {
+    alert: bool,
+    batch: Option<Batch>,
+    changed_interval: Option<u32>,
+    cheatsheet: Option<Cheatsheet>,
     columns: Columns,
+    confirm_control: Option<ConfirmControl>,
+    cpu_mode: CpuMode,
     delete_confirm: Option<ContainerId>,
+    delete_image: Option<ImageId>,
+    delete_network: Option<NetworkId>,
+    delete_volume: Option<VolumeName>,
+    detail_visible: bool,
+    engine: Engine,
+    env: Option<EnvVars>,
+    fetch: Fetch,
+    filter_editing: bool,
+    filter_term: Option<String>,
     has_containers: bool,
     has_error: Option<AppError>,
     height: u16,
     help_visible: bool,
+    history_visible: bool,
+    id_mode: IdMode,
     init: bool,
     info_text: Option<(String, Instant)>,
+    inspect: Option<Inspect>,
+    kill_signal: Option<KillSignal>,
+    labels: Option<Labels>,
+    limits: Option<Limits>,
+    log_copy: Option<(usize, usize)>,
+    go_to: Option<String>,
+    log_fetching: bool,
+    logs_unavailable: Option<String>,
+    log_follow: bool,
+    log_search: Option<LogSearch>,
+    log_search_editing: bool,
+    log_since: Option<LogSince>,
+    ping: Ping,
+    prune: Option<Prune>,
+    pull: Option<Pull>,
+    read_only: bool,
+    /// The number of pending Docker commands, and if waiting for them to finish, whilst the quit confirmation is open
+    quit_confirm: Option<(usize, bool)>,
+    rename: Option<Rename>,
+    restart_policy: Option<RestartPolicy>,
+    mounts: Option<Mounts>,
+    network_inspect: Option<NetworkInspect>,
+    run_command: Option<RunCommand>,
+    save_logs: Option<SaveLogs>,
     loading_icon: String,
+    logs_fullscreen: bool,
+    log_severity: bool,
+    log_stream_paused: bool,
+    log_wrap: bool,
+    search_editing: bool,
+    search_term: Option<String>,
     selected_panel: SelectablePanel,
     sorted_by: Option<(Header, SortedOrder)>,
+    state_filter: StateFilter,
+    summary: Summary,
+    theme: Theme,
+    view: View,
+    visible_columns: Vec<Header>,
 }
 
 impl From<(MutexGuard<'_, AppData>, MutexGuard<'_, GuiState>)> for FrameData {
     fn from(data: (MutexGuard<'_, AppData>, MutexGuard<'_, GuiState>)) -> Self {
         // set max height for container section, needs +5 to deal with docker commands list and borders
-        let height = data.0.get_container_len();
+        let height = data.0.get_container_lines();
         let height = if height < 12 {
             u16::try_from(height + 5).unwrap_or_default()
         } else {
@@ -246,41 +359,387 @@ impl From<(MutexGuard<'_, AppData>, MutexGuard<'_, GuiState>)> for FrameData {
         };
 
         Self {
-            columns: data.0.get_width(),
+            alert: data.1.status_contains(&[Status::Alert]),
+            changed_interval: data.0.get_changed_interval(),
+            cheatsheet: data.1.get_cheatsheet(),
+            columns: data.0.get_width(data.1.get_id_mode()),
+            confirm_control: data.1.get_confirm_control(),
+            cpu_mode: data.0.get_cpu_mode(),
             delete_confirm: data.1.get_delete_container(),
+            delete_image: data.1.get_delete_image(),
+            delete_network: data.1.get_delete_network(),
+            delete_volume: data.1.get_delete_volume(),
+            detail_visible: data.1.get_detail_visible(),
+            engine: data.0.get_engine(),
+            env: data.1.get_env(),
+            fetch: data.1.get_fetch(),
+            filter_editing: data.1.status_contains(&[Status::FilterContainers]),
+            filter_term: data.0.get_filter_term().cloned(),
             has_containers: data.0.get_container_len() > 0,
             has_error: data.0.get_error(),
             height,
             help_visible: data.1.status_contains(&[Status::Help]),
+            history_visible: data.1.get_history_visible(),
+            id_mode: data.1.get_id_mode(),
             init: data.1.status_contains(&[Status::Init]),
             info_text: data.1.info_box_text.clone(),
+            inspect: data.1.get_inspect(),
+            kill_signal: data.1.get_kill_signal(),
+            labels: data.1.get_labels(),
+            log_copy: data
+                .1
+                .get_log_copy()
+                .filter(|i| data.0.get_selected_container_id().as_ref() == Some(&i.id))
+                .and_then(|i| data.0.get_log_range(i.anchor)),
+            go_to: data.1.get_go_to(),
+            log_fetching: data.0.get_logs_fetching(),
+            logs_unavailable: data.0.get_logs_unavailable(),
+            log_follow: data.0.get_log_follow(),
+            log_search: data.1.get_log_search(),
+            log_search_editing: data.1.status_contains(&[Status::SearchLogs]),
+            log_since: data.1.get_log_since(),
+            ping: data.0.get_ping(),
+            prune: data.1.get_prune(),
+            pull: data.1.get_pull(),
+            read_only: data.0.args.read_only,
+            batch: data.1.get_batch(),
+            quit_confirm: data
+                .1
+                .status_contains(&[Status::QuitConfirm])
+                .then(|| (data.1.pending_actions(), data.1.get_quit_waiting())),
+            rename: data.1.get_rename(),
+            restart_policy: data.1.get_restart_policy(),
+            limits: data.1.get_limits(),
+            mounts: data.1.get_mounts(),
+            network_inspect: data.1.get_network_inspect(),
+            run_command: data.1.get_run_command(),
+            save_logs: data.1.get_save_logs(),
             loading_icon: data.1.get_loading().to_string(),
+            logs_fullscreen: data.1.get_logs_fullscreen(),
+            log_severity: data.1.get_log_severity(),
+            log_stream_paused: data.0.get_log_stream_paused(),
+            log_wrap: data.1.get_log_wrap(),
+            search_editing: data.1.status_contains(&[Status::SearchContainers]),
+            search_term: data.0.get_search_term().cloned(),
             selected_panel: data.1.get_selected_panel(),
             sorted_by: data.0.get_sorted(),
+            state_filter: data.0.get_state_filter(),
+            summary: data.0.get_summary(),
+            theme: data.0.get_theme(),
+            view: data.1.get_view(),
+            visible_columns: data.1.get_id_mode().apply(data.0.get_visible_columns()),
         }
     }
 }
 
-/// Draw the main ui to a frame of the terminal
-fn draw_frame(f: &mut Frame, app_data: &Arc<Mutex<AppData>>, gui_state: &Arc<Mutex<GuiState>>) {
-    let fd = FrameData::from((app_data.lock(), gui_state.lock()));
+/// Draw the container, image, network, & volume delete, confirm command, kill, prune, save logs, container & network inspect, environment variables, mounts, docker run command, and image pull popups, if any are open
+#[allow(clippy::too_many_lines)]
+fn draw_popups(
+    f: &mut Frame,
+    app_data: &Arc<Mutex<AppData>>,
+    gui_state: &Arc<Mutex<GuiState>>,
+    fd: &FrameData,
+) {
+    if let Some(id) = fd.delete_confirm.as_ref() {
+        let force = app_data
+            .lock()
+            .get_container_state_by_id(id)
+            .is_some_and(State::requires_force);
+        app_data.lock().get_container_name_by_id(id).map_or_else(
+            || {
+                // If a container is deleted outside of oxker but whilst the Delete Confirm dialog is open, it can get caught in kind of a dead lock situation
+                // so if in that unique situation, just clear the delete_container id
+                gui_state.lock().set_delete_container(None);
+            },
+            |name| {
+                draw_blocks::delete_confirm(
+                    f,
+                    gui_state,
+                    "container",
+                    name.get(),
+                    force,
+                    &fd.theme,
+                );
+            },
+        );
+    }
 
-    let whole_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Max(1), Constraint::Min(1)].as_ref())
-        .split(f.size());
+    if let Some(confirm_control) = fd.confirm_control.as_ref() {
+        // A single container is shown by name, if it has since been removed there is nothing left to confirm
+        let name = match confirm_control.ids.as_slice() {
+            [id] => app_data
+                .lock()
+                .get_container_name_by_id(id)
+                .map(|i| i.get().to_owned()),
+            ids => Some(format!("{} containers", ids.len())),
+        };
+        if let Some(name) = name {
+            draw_blocks::confirm_control(f, confirm_control, &name, &fd.theme);
+        } else {
+            gui_state.lock().set_confirm_control(None);
+        }
+    }
+
+    if let Some(id) = fd.delete_image.as_ref() {
+        let image = app_data
+            .lock()
+            .get_image_by_id(id)
+            .map(|i| (i.tags.clone(), i.in_use()));
+        image.map_or_else(
+            || {
+                // Same as above, image removed whilst the popup is open
+                gui_state.lock().set_delete_image(None);
+            },
+            |(tags, force)| {
+                draw_blocks::delete_confirm(f, gui_state, "image", &tags, force, &fd.theme);
+            },
+        );
+    }
+
+    if let Some(id) = fd.delete_network.as_ref() {
+        let name = app_data
+            .lock()
+            .get_network_by_id(id)
+            .map(|i| i.name.clone());
+        name.map_or_else(
+            || {
+                // Same as above, network removed whilst the popup is open
+                gui_state.lock().set_delete_network(None);
+            },
+            |name| {
+                draw_blocks::delete_confirm(f, gui_state, "network", &name, false, &fd.theme);
+            },
+        );
+    }
+
+    if let Some(name) = fd.delete_volume.as_ref() {
+        if app_data.lock().get_volume_by_name(name).is_some() {
+            draw_blocks::delete_confirm(f, gui_state, "volume", name.get(), false, &fd.theme);
+        } else {
+            // Same as above, volume removed whilst the popup is open
+            gui_state.lock().set_delete_volume(None);
+        }
+    }
+
+    if let Some(mut kill_signal) = fd.kill_signal.clone() {
+        app_data
+            .lock()
+            .get_container_name_by_id(&kill_signal.id)
+            .map_or_else(
+                || {
+                    // Same as above, container removed whilst the popup is open
+                    gui_state.lock().set_kill_signal(None);
+                },
+                |name| {
+                    draw_blocks::kill_signal(f, &mut kill_signal, &name, &fd.theme);
+                },
+            );
+    }
+
+    if let Some(mut restart_policy) = fd.restart_policy.clone() {
+        app_data
+            .lock()
+            .get_container_name_by_id(&restart_policy.id)
+            .map_or_else(
+                || {
+                    // Same as above, container removed whilst the popup is open
+                    gui_state.lock().set_restart_policy(None);
+                },
+                |name| {
+                    draw_blocks::restart_policy(f, &mut restart_policy, &name, &fd.theme);
+                },
+            );
+    }
+
+    if let Some(limits) = fd.limits.as_ref() {
+        app_data
+            .lock()
+            .get_container_name_by_id(&limits.id)
+            .map_or_else(
+                || {
+                    // Same as above, container removed whilst the popup is open
+                    gui_state.lock().set_limits(None);
+                },
+                |name| {
+                    draw_blocks::limits(f, limits, &name, &fd.theme);
+                },
+            );
+    }
+
+    if let Some(prune) = fd.prune {
+        draw_blocks::prune(f, prune, &fd.theme);
+    }
+
+    if let Some(rename) = fd.rename.as_ref() {
+        app_data
+            .lock()
+            .get_container_name_by_id(&rename.id)
+            .map_or_else(
+                || {
+                    // Same as above, container removed whilst the popup is open
+                    gui_state.lock().set_rename(None);
+                },
+                |name| {
+                    draw_blocks::rename(f, rename, &name, &fd.theme);
+                },
+            );
+    }
+
+    if let Some(log_since) = fd.log_since.as_ref() {
+        draw_blocks::log_since(f, log_since, &fd.theme);
+    }
+
+    if let Some(save_logs) = fd.save_logs.as_ref() {
+        draw_blocks::save_logs(f, save_logs, &fd.theme);
+    }
+
+    if let Some(inspect) = fd.inspect.as_ref() {
+        app_data
+            .lock()
+            .get_container_name_by_id(&inspect.id)
+            .map_or_else(
+                || {
+                    // Same as above, container removed whilst the panel is open
+                    gui_state.lock().set_inspect(None);
+                },
+                |name| {
+                    draw_blocks::inspect(f, app_data, inspect, &name, &fd.fetch);
+                },
+            );
+    }
 
+    if let Some(inspect) = fd.network_inspect.as_ref() {
+        let name = app_data
+            .lock()
+            .get_network_by_id(&inspect.id)
+            .map(|i| i.name.clone());
+        name.map_or_else(
+            || {
+                // Same as above, network removed whilst the panel is open
+                gui_state.lock().set_network_inspect(None);
+            },
+            |name| {
+                draw_blocks::network_inspect(f, app_data, inspect, &name, &fd.fetch);
+            },
+        );
+    }
+
+    if let Some(env) = fd.env.as_ref() {
+        app_data
+            .lock()
+            .get_container_name_by_id(&env.id)
+            .map_or_else(
+                || {
+                    // Same as above, container removed whilst the panel is open
+                    gui_state.lock().set_env(None);
+                },
+                |name| {
+                    draw_blocks::env(f, app_data, env, &name, &fd.fetch);
+                },
+            );
+    }
+
+    if let Some(labels) = fd.labels.as_ref() {
+        app_data
+            .lock()
+            .get_container_name_by_id(&labels.id)
+            .map_or_else(
+                || {
+                    // Same as above, container removed whilst the panel is open
+                    gui_state.lock().set_labels(None);
+                },
+                |name| {
+                    draw_blocks::labels(f, app_data, labels, &name, &fd.fetch);
+                },
+            );
+    }
+
+    if let Some(mounts) = fd.mounts.as_ref() {
+        app_data
+            .lock()
+            .get_container_name_by_id(&mounts.id)
+            .map_or_else(
+                || {
+                    // Same as above, container removed whilst the panel is open
+                    gui_state.lock().set_mounts(None);
+                },
+                |name| {
+                    draw_blocks::mounts(f, app_data, mounts, &name, &fd.fetch);
+                },
+            );
+    }
+
+    if let Some(run_command) = fd.run_command.as_ref() {
+        app_data
+            .lock()
+            .get_container_name_by_id(&run_command.id)
+            .map_or_else(
+                || {
+                    // Same as above, container removed whilst the panel is open
+                    gui_state.lock().set_run_command(None);
+                },
+                |name| {
+                    draw_blocks::run_command(f, app_data, run_command, &name, &fd.fetch);
+                },
+            );
+    }
+
+    if let Some(pull) = fd.pull.as_ref() {
+        app_data
+            .lock()
+            .get_container_name_by_id(&pull.id)
+            .map_or_else(
+                || {
+                    // Same as above, container removed whilst the panel is open
+                    gui_state.lock().set_pull(None);
+                },
+                |name| {
+                    draw_blocks::pull(f, pull, &name, &fd.loading_icon, &fd.theme);
+                },
+            );
+    }
+
+    if let Some(batch) = fd.batch.as_ref() {
+        draw_blocks::batch(f, batch, &fd.loading_icon, &fd.theme);
+    }
+
+    if let Some((pending, waiting)) = fd.quit_confirm {
+        // Every command finished before a choice was made, so there is nothing left to confirm
+        if pending == 0 && !waiting {
+            gui_state.lock().close_quit_confirm();
+        } else {
+            draw_blocks::quit_confirm(f, pending, waiting, &fd.loading_icon, &fd.theme);
+        }
+    }
+}
+
+/// Draw the containers, commands, logs, optional history, charts, and ports panels
+fn draw_panels(
+    f: &mut Frame,
+    area: Rect,
+    app_data: &Arc<Mutex<AppData>>,
+    gui_state: &Arc<Mutex<GuiState>>,
+    fd: &FrameData,
+) {
     // Split into 3, containers+controls, logs, then graphs
-    // This one is the issue!
+    // On a short terminal the containers panel only takes up to half of the height, so that the logs are still visible
     let upper_main = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Max(fd.height), Constraint::Min(1)].as_ref())
-        .split(whole_layout[1]);
-
-    let top_split = if fd.has_containers {
-        vec![Constraint::Percentage(90), Constraint::Percentage(10)]
-    } else {
+        .constraints(
+            [
+                Constraint::Max(fd.height.min((area.height / 2).max(3))),
+                Constraint::Min(1),
+            ]
+            .as_ref(),
+        )
+        .split(area);
+
+    let top_split = if !fd.has_containers {
         vec![Constraint::Percentage(100)]
+    } else if area.width / 10 < COMMANDS_MIN_WIDTH {
+        vec![Constraint::Min(1), Constraint::Length(COMMANDS_MIN_WIDTH)]
+    } else {
+        vec![Constraint::Percentage(90), Constraint::Percentage(10)]
     };
     // Containers + docker commands
     let top_panel = Layout::default()
@@ -288,7 +747,11 @@ fn draw_frame(f: &mut Frame, app_data: &Arc<Mutex<AppData>>, gui_state: &Arc<Mut
         .constraints(top_split)
         .split(upper_main[0]);
 
-    let lower_split = if fd.has_containers {
+    // The charts are unreadable when squeezed, so on a narrow, or short, terminal they are hidden, and the logs use the space instead
+    let show_charts = fd.has_containers
+        && area.width >= NARROW_WIDTH
+        && upper_main[1].height * 3 / 10 >= CHARTS_MIN_HEIGHT;
+    let lower_split = if show_charts {
         vec![Constraint::Percentage(70), Constraint::Percentage(30)]
     } else {
         vec![Constraint::Percentage(100)]
@@ -300,29 +763,43 @@ fn draw_frame(f: &mut Frame, app_data: &Arc<Mutex<AppData>>, gui_state: &Arc<Mut
         .constraints(lower_split)
         .split(upper_main[1]);
 
-    draw_blocks::containers(app_data, top_panel[0], f, &fd, gui_state);
+    draw_blocks::containers(app_data, top_panel[0], f, fd, gui_state);
 
-    draw_blocks::logs(app_data, lower_main[0], f, &fd, gui_state);
-
-    draw_blocks::heading_bar(whole_layout[0], f, &fd, gui_state);
+    // The detail sidebar, and the history panel, take their space from the logs panel, so the charts keep their size.
+    // On a narrow terminal the detail panel is stacked beneath the logs, as beside them neither would be readable
+    let logs_area = if fd.has_containers && fd.detail_visible {
+        let (direction, constraint) = if area.width < NARROW_WIDTH {
+            (Direction::Vertical, Constraint::Percentage(50))
+        } else {
+            (Direction::Horizontal, Constraint::Length(DETAIL_WIDTH))
+        };
+        let split = Layout::default()
+            .direction(direction)
+            .constraints([Constraint::Min(1), constraint])
+            .split(lower_main[0]);
+        draw_blocks::detail(f, split[1], app_data);
+        split[0]
+    } else {
+        lower_main[0]
+    };
+    let logs_area = if fd.has_containers && fd.history_visible {
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(4)])
+            .split(logs_area);
+        draw_blocks::history(f, split[1], app_data, &fd.theme);
+        split[0]
+    } else {
+        logs_area
+    };
 
-    if let Some(id) = fd.delete_confirm.as_ref() {
-        app_data.lock().get_container_name_by_id(id).map_or_else(
-            || {
-                // If a container is deleted outside of oxker but whilst the Delete Confirm dialog is open, it can get caught in kind of a dead lock situation
-                // so if in that unique situation, just clear the delete_container id
-                gui_state.lock().set_delete_container(None);
-            },
-            |name| {
-                draw_blocks::delete_confirm(f, gui_state, &name);
-            },
-        );
-    }
+    draw_blocks::logs(app_data, logs_area, f, fd, gui_state);
 
     // only draw commands + charts if there are containers
     if fd.has_containers {
-        draw_blocks::commands(app_data, top_panel[1], f, &fd, gui_state);
-
+        draw_blocks::commands(app_data, top_panel[1], f, fd, gui_state);
+    }
+    if show_charts {
         // Can calculate the max string length here, and then use that to keep the ports section as small as possible (+4 for some padding + border)
         let max_lens = app_data.lock().get_longest_port();
         let ports_len = u16::try_from(max_lens.0 + max_lens.1 + max_lens.2 + 2).unwrap_or(26);
@@ -332,20 +809,77 @@ fn draw_frame(f: &mut Frame, app_data: &Arc<Mutex<AppData>>, gui_state: &Arc<Mut
             .constraints([Constraint::Min(1), Constraint::Max(ports_len)])
             .split(lower_main[1]);
 
-        draw_blocks::chart(f, lower[0], app_data);
-        draw_blocks::ports(f, lower[1], app_data, max_lens);
+        draw_blocks::chart(f, lower[0], app_data, &fd.theme);
+        draw_blocks::ports(f, lower[1], app_data, max_lens, &fd.theme);
     }
+}
+
+/// Draw the main ui to a frame of the terminal, in full screen logs mode only the heading bar and logs panel are drawn, and in the images, networks, or volumes view only the heading bar and the list
+fn draw_frame(f: &mut Frame, app_data: &Arc<Mutex<AppData>>, gui_state: &Arc<Mutex<GuiState>>) {
+    let mut fd = FrameData::from((app_data.lock(), gui_state.lock()));
+
+    // A full id is too wide for a narrow terminal, so the short id is used instead, rather than pushing the other columns off screen
+    if fd.id_mode == IdMode::Full
+        && fd.columns.row_width(&fd.visible_columns) > usize::from(f.size().width)
+    {
+        fd.id_mode = IdMode::Short;
+        fd.columns = app_data.lock().get_width(IdMode::Short);
+    }
+
+    // Columns that don't fit beside the commands panel are dropped, from the right, rather than being cut off part way, the first column is always kept
+    let width = f.size().width;
+    let commands_width = (width / 10).max(COMMANDS_MIN_WIDTH);
+    let available = usize::from(width.saturating_sub(commands_width));
+    while fd.visible_columns.len() > 1 && fd.columns.row_width(&fd.visible_columns) > available {
+        fd.visible_columns.pop();
+    }
+
+    let whole_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Max(1), Constraint::Min(1)].as_ref())
+        .split(f.size());
+
+    // The summary bar is drawn beneath the heading bar, taking a line from the containers view
+    let main_area =
+        if fd.summary != Summary::Hidden && fd.view == View::Containers && !fd.logs_fullscreen {
+            let split = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Max(1), Constraint::Min(1)])
+                .split(whole_layout[1]);
+            draw_blocks::summary(f, split[0], app_data, &fd);
+            split[1]
+        } else {
+            whole_layout[1]
+        };
+
+    match fd.view {
+        View::Images => draw_blocks::images(f, whole_layout[1], app_data, &fd),
+        View::Networks => draw_blocks::networks(f, whole_layout[1], app_data, &fd),
+        View::Volumes => draw_blocks::volumes(f, whole_layout[1], app_data, &fd),
+        View::Containers if fd.logs_fullscreen => {
+            draw_blocks::logs(app_data, whole_layout[1], f, &fd, gui_state);
+        }
+        View::Containers => draw_panels(f, main_area, app_data, gui_state, &fd),
+    }
+
+    draw_blocks::heading_bar(whole_layout[0], f, &fd, gui_state);
+
+    draw_popups(f, app_data, gui_state, &fd);
 
     if let Some((text, instant)) = fd.info_text {
-        draw_blocks::info(f, &text, instant, gui_state);
+        draw_blocks::info(f, &text, instant, gui_state, &fd.theme);
     }
 
     // Check if error, and show popup if so
     if fd.help_visible {
-        draw_blocks::help_box(f);
+        draw_blocks::help_box(f, &fd.theme);
+    }
+
+    if let Some(cheatsheet) = fd.cheatsheet.as_ref() {
+        draw_blocks::cheatsheet(f, cheatsheet, &fd.theme);
     }
 
-    if let Some(error) = fd.has_error {
-        draw_blocks::error(f, error, None);
+    if let Some(error) = fd.has_error.as_ref() {
+        draw_blocks::error(f, error, None, &fd.theme);
     }
 }